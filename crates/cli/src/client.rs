@@ -14,9 +14,10 @@ use crate::daemon_process::{
     start_daemon_background, stop_daemon_sync, wrap_with_startup_error,
 };
 
-use oj_wire::{ProtocolError, Request, Response};
+use oj_core::Event;
+use oj_wire::{EventFilter, ProtocolError, Request, Response};
 use thiserror::Error;
-use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, BufReader, Lines};
 use tokio::net::{TcpStream, UnixStream};
 
 #[path = "client_queries.rs"]
@@ -359,6 +360,9 @@ impl DaemonClient {
             Transport::Tcp(addr) => {
                 let stream = TcpStream::connect(addr).await?;
                 let (mut reader, mut writer) = stream.into_split();
+                if !matches!(request, Request::Hello { .. }) {
+                    Self::authenticate(&mut reader, &mut writer).await?;
+                }
                 send_on_stream(&mut reader, &mut writer, request, read_timeout, write_timeout).await
             }
         }
@@ -406,20 +410,62 @@ impl DaemonClient {
             Transport::Unix(path) => {
                 let stream = UnixStream::connect(path).await?;
                 let (mut reader, mut writer) = stream.into_split();
-                let response = Self::attach_handshake(&request, &mut reader, &mut writer).await?;
+                let response = Self::handshake(&request, &mut reader, &mut writer).await?;
                 Self::interpret_attach(response, reader, writer)
             }
             Transport::Tcp(addr) => {
                 let stream = TcpStream::connect(addr).await?;
                 let (mut reader, mut writer) = stream.into_split();
-                let response = Self::attach_handshake(&request, &mut reader, &mut writer).await?;
+                Self::authenticate(&mut reader, &mut writer).await?;
+                let response = Self::handshake(&request, &mut reader, &mut writer).await?;
                 Self::interpret_attach(response, reader, writer)
             }
         }
     }
 
-    /// Send attach handshake and return the raw response.
-    async fn attach_handshake<R, W>(
+    /// Subscribe to daemon events matching `filter`.
+    ///
+    /// Sends `Subscribe`; after `Subscribed`, the connection carries one JSON
+    /// event per line for as long as the returned stream is kept.
+    pub async fn subscribe(&self, filter: EventFilter) -> Result<EventStream, ClientError> {
+        let request = Request::Subscribe { filter };
+
+        match &self.transport {
+            Transport::Unix(path) => {
+                let stream = UnixStream::connect(path).await?;
+                let (mut reader, mut writer) = stream.into_split();
+                let response = Self::handshake(&request, &mut reader, &mut writer).await?;
+                EventStream::open(response, reader, writer)
+            }
+            Transport::Tcp(addr) => {
+                let stream = TcpStream::connect(addr).await?;
+                let (mut reader, mut writer) = stream.into_split();
+                Self::authenticate(&mut reader, &mut writer).await?;
+                let response = Self::handshake(&request, &mut reader, &mut writer).await?;
+                EventStream::open(response, reader, writer)
+            }
+        }
+    }
+
+    /// Open a TCP connection with the `Hello` handshake, which carries the
+    /// auth token. The daemon then takes one more request on the connection.
+    async fn authenticate<R, W>(reader: &mut R, writer: &mut W) -> Result<(), ClientError>
+    where
+        R: tokio::io::AsyncReadExt + Unpin,
+        W: tokio::io::AsyncWriteExt + Unpin,
+    {
+        let hello = Request::Hello {
+            version: concat!(env!("CARGO_PKG_VERSION"), "+", env!("BUILD_GIT_HASH")).to_string(),
+            token: crate::env::auth_token(),
+        };
+        match Self::handshake(&hello, reader, writer).await? {
+            Response::Hello { .. } => Ok(()),
+            other => Self::reject(other),
+        }
+    }
+
+    /// Send a connection-upgrading request and return the raw response.
+    async fn handshake<R, W>(
         request: &Request,
         reader: &mut R,
         writer: &mut W,
@@ -462,6 +508,47 @@ impl DaemonClient {
     }
 }
 
+/// Live events from a `Subscribe` connection.
+pub struct EventStream {
+    lines: Lines<BufReader<Box<dyn AsyncRead + Unpin + Send>>>,
+    /// Kept open: closing our half tells the daemon we have gone away.
+    _writer: Box<dyn AsyncWrite + Unpin + Send>,
+}
+
+impl EventStream {
+    fn open<R, W>(response: Response, reader: R, writer: W) -> Result<Self, ClientError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        W: AsyncWrite + Unpin + Send + 'static,
+    {
+        match response {
+            Response::Subscribed => {
+                let reader: Box<dyn AsyncRead + Unpin + Send> = Box::new(reader);
+                Ok(Self { lines: BufReader::new(reader).lines(), _writer: Box::new(writer) })
+            }
+            other => DaemonClient::reject(other),
+        }
+    }
+
+    /// Next event, or `None` once the daemon closes the stream.
+    pub async fn next(&mut self) -> Option<Event> {
+        loop {
+            let line = self.lines.next_line().await.ok()??;
+            if let Ok(event) = serde_json::from_str(&line) {
+                return Some(event);
+            }
+        }
+    }
+
+    /// Wait for the next event. Never completes once the stream has closed,
+    /// so callers racing it against a timer fall back to the timer.
+    pub async fn changed(&mut self) {
+        if self.next().await.is_none() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
 /// Send a request and read a response on a generic stream.
 async fn send_on_stream<R, W>(
     reader: &mut R,
@@ -26,6 +26,15 @@ impl DaemonClient {
         }
     }
 
+    /// Query for jobs moved into the job archive
+    pub async fn list_archived_jobs(&self) -> Result<Vec<oj_wire::JobSummary>, ClientError> {
+        let query = Request::Query { query: Query::ListArchivedJobs };
+        match self.send(&query).await? {
+            Response::Jobs { jobs } => Ok(jobs),
+            other => Self::reject(other),
+        }
+    }
+
     /// Query for a specific job
     pub async fn get_job(&self, id: &str) -> Result<Option<oj_wire::JobDetail>, ClientError> {
         let request = Request::Query { query: Query::GetJob { id: id.to_string() } };
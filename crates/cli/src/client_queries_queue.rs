@@ -19,12 +19,16 @@ impl DaemonClient {
         project: &str,
         queue: &str,
         data: serde_json::Value,
+        priority: Option<i32>,
+        not_before_ms: Option<u64>,
     ) -> Result<QueuePushResult, ClientError> {
         let request = Request::QueuePush {
             project_path: project_path.to_path_buf(),
             project: project.to_string(),
             queue: queue.to_string(),
             data,
+            priority,
+            not_before_ms,
         };
         match self.send(&request).await? {
             Response::QueuePushed { queue, item_id } => {
@@ -223,13 +227,18 @@ impl DaemonClient {
 
     // -- Decision commands --
 
-    /// List pending decisions
+    /// List pending decisions, optionally only those for one assignee or reviewer
     pub async fn list_decisions(
         &self,
         project: &str,
+        assignee: Option<&str>,
     ) -> Result<Vec<oj_wire::DecisionSummary>, ClientError> {
-        let request =
-            Request::Query { query: Query::ListDecisions { project: project.to_string() } };
+        let request = Request::Query {
+            query: Query::ListDecisions {
+                project: project.to_string(),
+                assignee: assignee.map(String::from),
+            },
+        };
         match self.send(&request).await? {
             Response::Decisions { decisions } => Ok(decisions),
             other => Self::reject(other),
@@ -3,7 +3,7 @@
 
 //! Tests for daemon client behavior.
 
-use super::{ClientError, DaemonClient};
+use super::{ClientError, DaemonClient, EventStream};
 use crate::client_lifecycle::log_connection_error;
 use crate::daemon_process::{cleanup_stale_socket, probe_socket};
 use serial_test::serial;
@@ -134,3 +134,33 @@ fn test_stale_lock_cleanup() {
 
     assert!(!socket_path.exists(), "stale socket should be removed");
 }
+
+/// Events arrive one JSON object per line; lines that do not parse are skipped.
+#[tokio::test]
+async fn event_stream_reads_json_lines_until_closed() -> Result<(), Box<dyn std::error::Error>> {
+    use oj_core::{Event, JobId};
+    use oj_wire::Response;
+    use tokio::io::AsyncWriteExt;
+
+    let (client, mut daemon) = tokio::io::duplex(4096);
+    let (reader, writer) = tokio::io::split(client);
+    let mut stream = EventStream::open(Response::Subscribed, reader, writer)?;
+
+    let event = Event::JobAdvanced { id: JobId::from_string("job-1"), step: "done".to_string() };
+    let line = format!("not json\n{}\n", serde_json::to_string(&event)?);
+    daemon.write_all(line.as_bytes()).await?;
+    drop(daemon);
+
+    assert_eq!(stream.next().await, Some(event));
+    assert_eq!(stream.next().await, None);
+    Ok(())
+}
+
+#[test]
+fn event_stream_rejects_error_response() {
+    let (client, _daemon) = tokio::io::duplex(64);
+    let (reader, writer) = tokio::io::split(client);
+    let response = oj_wire::Response::Error { message: "nope".to_string() };
+    let result = EventStream::open(response, reader, writer);
+    assert!(matches!(result, Err(ClientError::Rejected(ref m)) if m == "nope"));
+}
@@ -204,8 +204,8 @@ pub(crate) fn apply_status(text: &str) -> String {
     let code = match first_word {
         "completed" | "done" | "running" | "started" | "ready" | "on" => "\x1b[32m",
         "waiting" | "escalated" | "pending" | "idle" | "orphaned" | "suspended" | "stopping"
-        | "stopped" | "creating" | "cleaning" | "full" | "off" => "\x1b[33m",
-        "failed" | "cancelled" | "dead" | "gone" | "error" => "\x1b[31m",
+        | "stopped" | "creating" | "cleaning" | "full" | "off" | "throttled" => "\x1b[33m",
+        "failed" | "cancelled" | "dead" | "gone" | "error" | "timed" => "\x1b[31m",
         _ => return text.to_string(),
     };
     format!("{code}{text}{RESET}")
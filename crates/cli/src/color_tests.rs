@@ -188,6 +188,16 @@ fn status_compound_waiting_gets_yellow() {
     assert!(result.contains("\x1b[33m"), "expected yellow ANSI for compound waiting status");
 }
 
+#[test]
+#[serial]
+fn status_compound_throttled_gets_yellow() {
+    std::env::set_var("COLOR", "1");
+    std::env::remove_var("NO_COLOR");
+
+    let result = status("throttled (rate)");
+    assert!(result.contains("\x1b[33m"), "expected yellow ANSI for throttled worker");
+}
+
 #[test]
 #[serial]
 fn green_helper() {
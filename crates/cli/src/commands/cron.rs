@@ -23,7 +23,7 @@ pub struct CronArgs {
 pub enum CronCommand {
     /// List all crons and their status
     List {},
-    /// Start a cron (begins interval timer)
+    /// Start a cron (arms its timer)
     Start {
         /// Cron name from runbook (required unless --all)
         name: Option<String>,
@@ -31,7 +31,7 @@ pub enum CronCommand {
         #[arg(long)]
         all: bool,
     },
-    /// Stop a cron (cancels interval timer)
+    /// Stop a cron (cancels its timer)
     Stop {
         /// Cron name from runbook (required unless --all)
         name: Option<String>,
@@ -44,7 +44,7 @@ pub enum CronCommand {
         /// Cron name from runbook
         name: String,
     },
-    /// Run the cron's job once now (ignores interval)
+    /// Run the cron's job once now (ignores its interval or schedule)
     Once {
         /// Cron name from runbook
         name: String,
@@ -148,8 +148,9 @@ pub async fn handle(
                 let cols = vec![
                     Column::left("KIND"),
                     Column::left("PROJECT"),
-                    Column::left("INTERVAL"),
+                    Column::left("SCHEDULE"),
                     Column::left("TARGET"),
+                    Column::left("NEXT"),
                     Column::left("TIME"),
                     Column::status("STATUS"),
                 ];
@@ -159,8 +160,9 @@ pub async fn handle(
                     let cells = vec![
                         c.name.clone(),
                         ns.to_string(),
-                        c.interval.clone(),
+                        c.timing(),
                         c.target.clone(),
+                        c.next_fire.clone().unwrap_or_else(|| "-".to_string()),
                         c.time.clone(),
                         c.status.clone(),
                     ];
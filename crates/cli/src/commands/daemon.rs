@@ -9,8 +9,10 @@ use crate::output::{display_log, format_or_json, handle_list, OutputFormat};
 use crate::table::{Column, Table};
 use anyhow::{anyhow, Result};
 use clap::{Args, Subcommand};
+use oj_wire::EventFilter;
+use std::ffi::OsString;
 use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Args)]
@@ -57,12 +59,37 @@ pub enum DaemonCommand {
         #[arg(long, short)]
         follow: bool,
     },
+    /// Stream events as the daemon processes them
+    Events {
+        /// Only events in this project
+        #[arg(long)]
+        project: Option<String>,
+        /// Only events for this job or crew (ID or prefix, repeatable)
+        #[arg(long = "owner")]
+        owners: Vec<String>,
+        /// Only this event kind, e.g. `job:advanced`, or category, e.g. `step` (repeatable)
+        #[arg(long = "kind")]
+        kinds: Vec<String>,
+    },
     /// List orphaned jobs detected at startup
     Orphans {
         /// Dismiss an orphaned job by ID (or prefix)
         #[arg(long)]
         dismiss: Option<String>,
     },
+    /// Write daemon state to a file, for moving it to another host
+    Export {
+        /// File to write
+        file: PathBuf,
+    },
+    /// Load an export into this host's empty state directory (daemon stopped)
+    Import {
+        /// File written by `oj daemon export`
+        file: PathBuf,
+        /// Rewrite paths under OLD to NEW, e.g. /home/me/src=/srv/src (repeatable)
+        #[arg(long = "map", value_name = "OLD=NEW")]
+        maps: Vec<String>,
+    },
 }
 
 pub async fn daemon(args: DaemonArgs, format: OutputFormat) -> Result<()> {
@@ -78,8 +105,13 @@ pub async fn daemon(args: DaemonArgs, format: OutputFormat) -> Result<()> {
         Some(DaemonCommand::Logs { limit, no_limit, follow }) => {
             logs(limit, no_limit, follow, format).await
         }
+        Some(DaemonCommand::Events { project, owners, kinds }) => {
+            events(EventFilter { project, owners, kinds }, format).await
+        }
         Some(DaemonCommand::Orphans { dismiss: Some(id) }) => dismiss_orphan(id, format).await,
         Some(DaemonCommand::Orphans { dismiss: None }) => orphans(format).await,
+        Some(DaemonCommand::Export { file }) => run_ojd(export_args(&file)),
+        Some(DaemonCommand::Import { file, maps }) => run_ojd(import_args(&file, &maps)),
         None => {
             // No subcommand — show colorized help
             let cmd = crate::find_subcommand(crate::cli_command(), &["daemon"]);
@@ -233,6 +265,23 @@ async fn logs(limit: usize, no_limit: bool, follow: bool, format: OutputFormat)
     Ok(())
 }
 
+async fn events(filter: EventFilter, format: OutputFormat) -> Result<()> {
+    let client = DaemonClient::connect().map_err(|e| anyhow!("{}", e))?;
+    let mut stream = client.subscribe(filter).await.map_err(|e| anyhow!("{}", e))?;
+
+    while let Some(event) = stream.next().await {
+        match format {
+            OutputFormat::Text => println!("{}", event.log_summary()),
+            OutputFormat::Json => {
+                if let Ok(line) = serde_json::to_string(&event) {
+                    println!("{}", line);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 async fn orphans(format: OutputFormat) -> Result<()> {
     let client = DaemonClient::connect().map_err(|e| anyhow!("{}", e))?;
     let orphans = client.list_orphans().await.map_err(|e| anyhow!("{}", e))?;
@@ -304,6 +353,29 @@ fn format_uptime(secs: u64) -> String {
     }
 }
 
+fn export_args(file: &Path) -> Vec<OsString> {
+    vec!["export".into(), file.into()]
+}
+
+fn import_args(file: &Path, maps: &[String]) -> Vec<OsString> {
+    let mut args: Vec<OsString> = vec!["import".into()];
+    for map in maps {
+        args.extend(["--map".into(), map.into()]);
+    }
+    args.push(file.into());
+    args
+}
+
+/// Run an offline `ojd` subcommand, which reads and writes the state
+/// directory itself.
+fn run_ojd(args: Vec<OsString>) -> Result<()> {
+    let status = Command::new(find_ojd_binary()?).args(&args).status()?;
+    if !status.success() {
+        return Err(anyhow!("ojd {} failed", args[0].to_string_lossy()));
+    }
+    Ok(())
+}
+
 fn find_ojd_binary() -> Result<PathBuf> {
     let current_exe = std::env::current_exe().ok();
 
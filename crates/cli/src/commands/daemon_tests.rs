@@ -85,3 +85,79 @@ fn daemon_subcommand_help_contains_expected_content() {
     assert!(help.contains("stop"), "daemon help should mention stop subcommand, got:\n{help}");
     assert!(help.contains("status"), "daemon help should mention status subcommand, got:\n{help}");
 }
+
+// -- Events -------------------------------------------------------------------
+
+#[test]
+fn daemon_events_collects_repeated_filters() {
+    let matches = crate::cli_command()
+        .try_get_matches_from([
+            "oj",
+            "daemon",
+            "events",
+            "--project",
+            "api",
+            "--owner",
+            "job-1",
+            "--owner",
+            "crw-2",
+            "--kind",
+            "step",
+        ])
+        .unwrap();
+    let cli = crate::Cli::from_arg_matches(&matches).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(crate::Commands::Daemon(super::DaemonArgs {
+            command: Some(super::DaemonCommand::Events { ref project, ref owners, ref kinds }),
+            ..
+        })) if project.as_deref() == Some("api") && owners == &["job-1", "crw-2"] && kinds == &["step"]
+    ));
+}
+
+// -- Export / import ----------------------------------------------------------
+
+#[test]
+fn daemon_import_passes_maps_to_ojd() {
+    let matches = crate::cli_command()
+        .try_get_matches_from([
+            "oj",
+            "daemon",
+            "import",
+            "oj.export",
+            "--map",
+            "/home/me/src=/srv/src",
+            "--map",
+            "/opt/a=/opt/b",
+        ])
+        .unwrap();
+    let cli = crate::Cli::from_arg_matches(&matches).unwrap();
+    let Some(crate::Commands::Daemon(super::DaemonArgs {
+        command: Some(super::DaemonCommand::Import { file, maps }),
+        ..
+    })) = cli.command
+    else {
+        unreachable!("expected daemon import");
+    };
+    assert_eq!(
+        super::import_args(&file, &maps),
+        ["import", "--map", "/home/me/src=/srv/src", "--map", "/opt/a=/opt/b", "oj.export"]
+    );
+}
+
+#[test]
+fn daemon_export_requires_file() {
+    let err = crate::cli_command().try_get_matches_from(["oj", "daemon", "export"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+
+    let matches =
+        crate::cli_command().try_get_matches_from(["oj", "daemon", "export", "oj.export"]).unwrap();
+    let cli = crate::Cli::from_arg_matches(&matches).unwrap();
+    assert!(matches!(
+        cli.command,
+        Some(crate::Commands::Daemon(super::DaemonArgs {
+            command: Some(super::DaemonCommand::Export { ref file }),
+            ..
+        })) if super::export_args(file) == ["export", "oj.export"]
+    ));
+}
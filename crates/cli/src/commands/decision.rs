@@ -26,7 +26,17 @@ pub struct DecisionArgs {
 #[derive(Subcommand)]
 pub enum DecisionCommand {
     /// List pending decisions
-    List {},
+    List {
+        /// Only decisions assigned to, or reviewable by, this person
+        #[arg(long)]
+        assignee: Option<String>,
+    },
+    /// List pending decisions waiting on you (assignee or reviewer)
+    Inbox {
+        /// Whose inbox to show (default: $OJ_USER, then $USER)
+        #[arg(long)]
+        user: Option<String>,
+    },
     /// Show details of a decision
     Show {
         /// Decision ID (or prefix)
@@ -49,7 +59,7 @@ pub enum DecisionCommand {
 impl DecisionCommand {
     pub fn client_kind(&self) -> ClientKind {
         match self {
-            Self::List {} | Self::Show { .. } => ClientKind::Query,
+            Self::List { .. } | Self::Inbox { .. } | Self::Show { .. } => ClientKind::Query,
             Self::Resolve { .. } | Self::Review {} => ClientKind::Action,
         }
     }
@@ -63,14 +73,26 @@ pub async fn handle(
     format: OutputFormat,
 ) -> Result<()> {
     match command {
-        DecisionCommand::List {} => {
-            let mut decisions = client.list_decisions(project).await?;
+        DecisionCommand::List { assignee } => {
+            let mut decisions = client.list_decisions(project, assignee.as_deref()).await?;
             filter_by_project(&mut decisions, project_filter, |d| &d.project);
             handle_list(format, &decisions, "No pending decisions", |items, out| {
                 format_decision_list(out, items);
             })?;
         }
 
+        DecisionCommand::Inbox { user } => {
+            let Some(user) = user.or_else(crate::env::user) else {
+                anyhow::bail!("no user for inbox: pass --user or set OJ_USER");
+            };
+            let mut decisions = client.list_decisions(project, Some(&user)).await?;
+            filter_by_project(&mut decisions, project_filter, |d| &d.project);
+            let empty = format!("No pending decisions for {}", user);
+            handle_list(format, &decisions, &empty, |items, out| {
+                format_decision_list(out, items);
+            })?;
+        }
+
         DecisionCommand::Show { id } => {
             let decision = client.get_decision(&id).await?;
             if let Some(d) = decision {
@@ -90,7 +112,7 @@ pub async fn handle(
                 anyhow::bail!("review does not support --output json");
             }
 
-            let mut decisions = client.list_decisions(project).await?;
+            let mut decisions = client.list_decisions(project, None).await?;
             if let Some(proj) = project_filter {
                 decisions.retain(|d| d.project == proj);
             }
@@ -237,6 +259,12 @@ pub(crate) fn format_decision_detail(
     };
     let _ = writeln!(out, "{} {}", color::context("Source:  "), source_display);
     let _ = writeln!(out, "{} {}", color::context("Age:    "), age);
+    if let Some(ref assignee) = d.assignee {
+        let _ = writeln!(out, "{} {}", color::context("Assignee:"), assignee);
+    }
+    if !d.reviewers.is_empty() {
+        let _ = writeln!(out, "{} {}", color::context("Reviewers:"), d.reviewers.join(", "));
+    }
     if !d.agent_id.as_str().is_empty() {
         let _ =
             writeln!(out, "{} {}", color::context("Agent:  "), color::muted(d.agent_id.short(8)));
@@ -248,6 +276,9 @@ pub(crate) fn format_decision_detail(
             writeln!(out, "{} {}", color::context("Superseded by:"), color::muted(sup_id.short(8)));
     } else if d.resolved_at_ms.is_some() {
         let _ = writeln!(out, "{} {}", color::context("Status: "), color::status("completed"));
+        if d.timed_out {
+            let _ = writeln!(out, "{} by timeout", color::context("Resolved:"));
+        }
         if let Some(&c) = d.choices.first() {
             let label =
                 d.options.iter().find(|o| o.number == c).map(|o| o.label.as_str()).unwrap_or("?");
@@ -258,6 +289,26 @@ pub(crate) fn format_decision_detail(
         }
     }
 
+    if let (None, Some(expires_at_ms), Some(c)) =
+        (d.resolved_at_ms, d.expires_at_ms, d.default_choice)
+    {
+        let now_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let remaining = oj_core::format_elapsed(expires_at_ms.saturating_sub(now_ms) / 1000);
+        let label =
+            d.options.iter().find(|o| o.number == c).map(|o| o.label.as_str()).unwrap_or("?");
+        let _ = writeln!(
+            out,
+            "{} in {}, then {} ({})",
+            color::context("Expires:"),
+            remaining,
+            c,
+            label
+        );
+    }
+
     let _ = writeln!(out);
     let _ = writeln!(out, "{}", color::header("Context:"));
     for line in d.context.lines() {
@@ -351,33 +402,51 @@ pub(crate) fn format_decision_list(
     out: &mut (impl Write + ?Sized),
     decisions: &[oj_wire::DecisionSummary],
 ) {
-    let cols = vec![
+    // Show ASSIGNEE column only when any decision is assigned
+    let show_assignee = decisions.iter().any(|d| d.assignee.is_some() || !d.reviewers.is_empty());
+
+    let mut cols = vec![
         Column::muted("ID").with_max(8),
         Column::left("PROJECT"),
         Column::left("AGENT").with_max(18),
         Column::left("AGE"),
         Column::left("SOURCE"),
-        Column::left("SUMMARY").with_max(50),
     ];
+    if show_assignee {
+        cols.push(Column::left("ASSIGNEE").with_max(16));
+    }
+    cols.push(Column::left("SUMMARY").with_max(50));
     let mut table = Table::new(cols);
 
     for d in decisions {
         let ns = if d.project.is_empty() { "-" } else { &d.project };
         let owner = if d.owner_name.is_empty() { &d.owner_id } else { &d.owner_name };
-        let cells = vec![
+        let mut cells = vec![
             d.id.to_string(),
             ns.to_string(),
             owner.to_string(),
             format_time_ago(d.created_at_ms),
             d.source.clone(),
-            d.summary.clone(),
         ];
+        if show_assignee {
+            cells.push(assignee_cell(d));
+        }
+        cells.push(d.summary.clone());
         table.row(cells);
     }
 
     table.render(out);
 }
 
+/// Assignee, or the reviewers when nobody is assigned
+fn assignee_cell(d: &oj_wire::DecisionSummary) -> String {
+    match (&d.assignee, d.reviewers.is_empty()) {
+        (Some(assignee), _) => assignee.clone(),
+        (None, false) => d.reviewers.join(","),
+        (None, true) => "-".to_string(),
+    }
+}
+
 #[cfg(test)]
 #[path = "decision_tests.rs"]
 mod tests;
@@ -16,7 +16,17 @@ struct TestCli {
 #[test]
 fn parse_list() {
     let cli = TestCli::parse_from(["test", "list"]);
-    assert!(matches!(cli.command, DecisionCommand::List {}));
+    assert!(matches!(cli.command, DecisionCommand::List { assignee: None }));
+}
+
+#[test]
+fn parse_assignee_filters() {
+    let list = TestCli::parse_from(["test", "list", "--assignee", "alice"]);
+    assert!(matches!(list.command, DecisionCommand::List { assignee: Some(a) } if a == "alice"));
+    let inbox = TestCli::parse_from(["test", "inbox", "--user", "alice"]);
+    assert!(matches!(inbox.command, DecisionCommand::Inbox { user: Some(u) } if u == "alice"));
+    let inbox = TestCli::parse_from(["test", "inbox"]);
+    assert!(matches!(inbox.command, DecisionCommand::Inbox { user: None }));
 }
 
 #[test]
@@ -80,6 +90,8 @@ fn make_decision(id: &str, project: &str, job: &str) -> DecisionSummary {
         summary: "Should we proceed?".to_string(),
         created_at_ms: 0,
         project: project.to_string(),
+        assignee: None,
+        reviewers: vec![],
     }
 }
 
@@ -112,6 +124,11 @@ fn make_detail(resolved: bool) -> DecisionDetail {
         resolved_at_ms: if resolved { Some(1000) } else { None },
         superseded_by: None,
         project: "myproject".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     }
 }
 
@@ -156,6 +173,32 @@ fn list_with_project_column() {
     assert!(lines[2].contains("other"));
 }
 
+#[test]
+fn list_shows_assignee_column_when_assigned() {
+    let plain = vec![make_decision("abcdef1234567890", "", "build")];
+    let mut buf = Vec::new();
+    super::format_decision_list(&mut buf, &plain);
+    assert!(!output_string(&buf).contains("ASSIGNEE"));
+
+    let decisions = vec![
+        DecisionSummary { assignee: Some("alice".to_string()), ..plain[0].clone() },
+        DecisionSummary {
+            reviewers: vec!["bob".to_string(), "carol".to_string()],
+            ..make_decision("1234567890abcdef", "", "deploy")
+        },
+        make_decision("fedcba0987654321", "", "test"),
+    ];
+    let mut buf = Vec::new();
+    super::format_decision_list(&mut buf, &decisions);
+    let out = output_string(&buf);
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert!(lines[0].contains("ASSIGNEE"));
+    assert!(lines[1].contains("alice"));
+    assert!(lines[2].contains("bob,carol"));
+    assert!(lines[3].contains(" - "));
+}
+
 // --- format_decision_detail tests ---
 
 #[test]
@@ -208,6 +251,61 @@ fn format_decision_detail_resolved() {
     assert!(!out.contains("oj decision resolve"));
 }
 
+#[test]
+fn format_decision_detail_timed_out() {
+    let d = DecisionDetail { timed_out: true, message: None, ..make_detail(true) };
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &d, true);
+    let out = output_string(&buf);
+
+    assert!(out.contains("Resolved: by timeout"));
+    assert!(out.contains("1 (Yes)"));
+}
+
+#[test]
+fn format_decision_detail_shows_pending_expiry() {
+    let now_ms = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    let d = DecisionDetail {
+        expires_at_ms: Some(now_ms + 2 * 3_600_000 + 30_000),
+        default_choice: Some(2),
+        ..make_detail(false)
+    };
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &d, true);
+    let out = output_string(&buf);
+
+    assert!(out.contains("Expires: in 2h"), "{}", out);
+    assert!(out.contains("then 2 (No)"), "{}", out);
+}
+
+#[test]
+fn format_decision_detail_without_expiry() {
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &make_detail(false), true);
+    assert!(!output_string(&buf).contains("Expires:"));
+}
+
+#[test]
+fn format_decision_detail_shows_assignment() {
+    let d = DecisionDetail {
+        assignee: Some("alice".to_string()),
+        reviewers: vec!["bob".to_string(), "carol".to_string()],
+        ..make_detail(false)
+    };
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &d, true);
+    let out = output_string(&buf);
+    assert!(out.contains("Assignee: alice"), "{}", out);
+    assert!(out.contains("Reviewers: bob, carol"), "{}", out);
+
+    let mut buf = Vec::new();
+    super::format_decision_detail(&mut buf, &make_detail(false), true);
+    assert!(!output_string(&buf).contains("Assignee:"));
+}
+
 // --- parse_review_input tests ---
 
 #[yare::parameterized(
@@ -325,6 +423,11 @@ fn format_decision_detail_grouped_questions() {
         resolved_at_ms: None,
         superseded_by: None,
         project: "myproject".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     };
 
     let mut buf = Vec::new();
@@ -415,6 +518,11 @@ fn format_plan_decision_detail() {
         resolved_at_ms: None,
         superseded_by: None,
         project: "myproject".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     };
 
     let mut buf = Vec::new();
@@ -50,6 +50,10 @@ pub enum JobCommand {
         /// Show all jobs (no limit)
         #[arg(long, conflicts_with = "limit")]
         no_limit: bool,
+
+        /// List archived jobs instead of live ones
+        #[arg(long)]
+        archived: bool,
     },
     /// Show details of a job
     Show {
@@ -247,8 +251,12 @@ pub async fn handle(
     format: OutputFormat,
 ) -> Result<()> {
     match command {
-        JobCommand::List { name, status, limit, no_limit } => {
-            let mut jobs = client.list_jobs().await?;
+        JobCommand::List { name, status, limit, no_limit, archived } => {
+            let mut jobs = if archived {
+                client.list_archived_jobs().await?
+            } else {
+                client.list_jobs().await?
+            };
             filter_by_project(&mut jobs, project, |p| &p.project);
 
             // Filter by name substring
@@ -276,7 +284,16 @@ pub async fn handle(
             let job = client.get_job(&id).await?;
             format_or_json(format, &job, || {
                 if let Some(p) = &job {
-                    println!("{} {}", color::header("Job:"), p.id);
+                    if p.archived {
+                        println!(
+                            "{} {} {}",
+                            color::header("Job:"),
+                            p.id,
+                            color::muted("(archived)")
+                        );
+                    } else {
+                        println!("{} {}", color::header("Job:"), p.id);
+                    }
                     println!("  {} {}", color::context("Name:"), p.name);
                     if !p.project.is_empty() {
                         println!("  {} {}", color::context("Project:"), p.project);
@@ -304,7 +321,12 @@ pub async fn handle(
                                 step.finished_at_ms,
                             );
                             let status = match (&step.outcome, &step.detail) {
-                                (StepOutcomeKind::Failed | StepOutcomeKind::Waiting, Some(d)) => {
+                                (
+                                    StepOutcomeKind::Failed
+                                    | StepOutcomeKind::TimedOut
+                                    | StepOutcomeKind::Waiting,
+                                    Some(d),
+                                ) => {
                                     format!("{} ({})", step.outcome, truncate(d, 40))
                                 }
                                 _ => step.outcome.to_string(),
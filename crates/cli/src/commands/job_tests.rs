@@ -118,6 +118,7 @@ fn make_detail(name: &str, steps: Vec<StepRecordDetail>) -> JobDetail {
         steps,
         agents: vec![],
         project: String::new(),
+        archived: false,
     }
 }
 
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use anyhow::Result;
 
 use oj_core::StepOutcomeKind;
+use oj_wire::EventFilter;
 
 use crate::client::DaemonClient;
 use crate::exit_error::ExitError;
@@ -40,6 +41,10 @@ pub async fn handle(
     let mut canonical_ids: HashMap<String, String> = HashMap::new();
     let mut step_trackers: HashMap<String, StepTracker> = HashMap::new();
     let show_prefix = ids.len() > 1;
+    // Event stream for the jobs being waited on, opened after the first
+    // pass resolves their IDs. Polling continues as a fallback.
+    let mut events = None;
+    let mut subscribed = false;
 
     loop {
         for input_id in &ids {
@@ -95,7 +100,21 @@ pub async fn handle(
             break;
         }
 
-        match poller.tick().await {
+        if !subscribed {
+            subscribed = true;
+            let owners = ids.iter().filter_map(|id| canonical_ids.get(id).cloned()).collect();
+            events = client.subscribe(EventFilter { owners, ..Default::default() }).await.ok();
+            // Re-check now in case a job moved before the subscription began
+            continue;
+        }
+
+        let wake = async {
+            match events.as_mut() {
+                Some(stream) => stream.changed().await,
+                None => std::future::pending().await,
+            }
+        };
+        match poller.tick_or(wake).await {
             Tick::Ready => {}
             Tick::Timeout => {
                 return Err(ExitError::new(2, "Timeout waiting for job(s)".to_string()).into());
@@ -132,8 +151,10 @@ pub(crate) fn print_step_progress(
             continue;
         }
 
-        let is_terminal =
-            matches!(step.outcome, StepOutcomeKind::Completed | StepOutcomeKind::Failed);
+        let is_terminal = matches!(
+            step.outcome,
+            StepOutcomeKind::Completed | StepOutcomeKind::Failed | StepOutcomeKind::TimedOut
+        );
 
         if is_terminal {
             // Print "started" for steps we haven't announced yet (skipped running state)
@@ -154,6 +175,9 @@ pub(crate) fn print_step_progress(
                     };
                     let _ = writeln!(out, "{}{} failed ({}){}", prefix, step.name, elapsed, suffix);
                 }
+                StepOutcomeKind::TimedOut => {
+                    let _ = writeln!(out, "{}{} timed out ({})", prefix, step.name, elapsed);
+                }
                 _ => unreachable!(),
             }
             tracker.printed_count = i + 1;
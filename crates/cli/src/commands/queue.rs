@@ -6,12 +6,13 @@
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use std::path::Path;
+use std::time::Duration;
 
 use crate::client::{ClientKind, DaemonClient, QueuePushResult, QueueRetryResult};
 use crate::color;
 use crate::output::{
-    display_log, format_or_json, format_time_ago, handle_list, poll_log_follow,
-    print_prune_results, OutputFormat,
+    display_log, format_or_json, format_time_ago, format_time_until, handle_list, now_ms,
+    poll_log_follow, print_prune_results, OutputFormat,
 };
 use crate::table::{Column, Table};
 
@@ -32,6 +33,13 @@ pub enum QueueCommand {
         /// Item variables (can be repeated: --var key=value)
         #[arg(long = "var", value_parser = super::job::parse_key_value)]
         var: Vec<(String, String)>,
+        /// Item priority; higher runs sooner in queues with `order = "priority"`
+        /// (defaults to the queue's `priority`)
+        #[arg(long, allow_negative_numbers = true)]
+        priority: Option<i32>,
+        /// Hold the item back from workers for this long (e.g. "30m", "2h")
+        #[arg(long, value_parser = super::job::parse_duration)]
+        delay: Option<Duration>,
     },
     /// List all known queues
     List {},
@@ -155,7 +163,7 @@ pub async fn handle(
     format: OutputFormat,
 ) -> Result<()> {
     match command {
-        QueueCommand::Push { queue, data, var } => {
+        QueueCommand::Push { queue, data, var, priority, delay } => {
             // Build data map; allow empty data for external queues (triggers poll)
             let json_data = if data.is_none() && var.is_empty() {
                 serde_json::Value::Object(serde_json::Map::new())
@@ -163,7 +171,11 @@ pub async fn handle(
                 build_data_map(data, var)?
             };
 
-            match client.queue_push(project_path, project, &queue, json_data).await? {
+            let not_before_ms = delay.map(|d| now_ms() + d.as_millis() as u64);
+            match client
+                .queue_push(project_path, project, &queue, json_data, priority, not_before_ms)
+                .await?
+            {
                 QueuePushResult::Pushed { queue, item_id } => {
                     println!("Pushed item '{}' to queue '{}'", item_id, queue);
                 }
@@ -331,24 +343,45 @@ pub async fn handle(
                 &items,
                 &format!("No items in queue '{}'", queue),
                 |items, out| {
-                    let mut table = Table::new(vec![
-                        Column::muted("ID"),
-                        Column::status("STATUS"),
+                    // Only queues that use priorities get the column
+                    let show_priority = items.iter().any(|i| i.priority != 0);
+                    let now = now_ms();
+                    let show_starts =
+                        items.iter().any(|i| i.not_before_ms.is_some_and(|t| t > now));
+                    let show_retry = items.iter().any(|i| i.retry_at_ms.is_some_and(|t| t > now));
+                    let mut columns = vec![Column::muted("ID"), Column::status("STATUS")];
+                    if show_priority {
+                        columns.push(Column::right("PRIORITY"));
+                    }
+                    if show_starts {
+                        columns.push(Column::right("STARTS"));
+                    }
+                    if show_retry {
+                        columns.push(Column::right("RETRY"));
+                    }
+                    columns.extend([
                         Column::right("AGE"),
                         Column::left("WORKER"),
                         Column::left("DATA"),
                     ]);
+                    let mut table = Table::new(columns);
                     for item in items {
                         let data_str = format_item_data(&item.data);
                         let worker = item.worker_name.as_deref().unwrap_or("-").to_string();
                         let age = format_time_ago(item.pushed_at_ms);
-                        table.row(vec![
-                            oj_core::short(&item.id, 8).to_string(),
-                            item.status.clone(),
-                            age,
-                            worker,
-                            data_str,
-                        ]);
+                        let mut row =
+                            vec![oj_core::short(&item.id, 8).to_string(), item.status.clone()];
+                        if show_priority {
+                            row.push(item.priority.to_string());
+                        }
+                        if show_starts {
+                            row.push(item.not_before_ms.map_or("-".into(), format_time_until));
+                        }
+                        if show_retry {
+                            row.push(item.retry_at_ms.map_or("-".into(), format_time_until));
+                        }
+                        row.extend([age, worker, data_str]);
+                        table.row(row);
                     }
                     table.render(out);
                 },
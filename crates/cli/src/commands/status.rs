@@ -395,16 +395,17 @@ fn format_text(
 
             let _ = writeln!(out, "  {}", color::header("Crons:"));
             let c_name = crons.iter().map(|c| c.name.len()).max().unwrap_or(0);
-            let c_int = crons.iter().map(|c| c.interval.len()).max().unwrap_or(0);
+            let timings: Vec<String> = crons.iter().map(|c| c.timing()).collect();
+            let c_int = timings.iter().map(|t| t.len()).max().unwrap_or(0);
             let labels: Vec<&str> =
                 crons.iter().map(|c| if c.status == "running" { "on" } else { "off" }).collect();
             let c_st = labels.iter().map(|l: &&str| l.len()).max().unwrap_or(0);
-            for (c, label) in crons.iter().zip(labels.iter()) {
+            for ((c, label), timing) in crons.iter().zip(labels.iter()).zip(timings.iter()) {
                 let _ = writeln!(
                     out,
                     "    {:<c_name$}  {:<c_int$}  {}  {}",
                     c.name,
-                    c.interval,
+                    timing,
                     color::status(&format!("{:<c_st$}", label)),
                     c.time,
                 );
@@ -195,6 +195,8 @@ fn non_tty_frame_with_full_status_has_no_ansi_escapes() {
         active: 1,
         concurrency: 4,
         updated_at_ms: 0,
+        throttle: None,
+        throttled_until_ms: None,
     });
     ns.queues.push(oj_wire::QueueStatus {
         name: "tasks".to_string(),
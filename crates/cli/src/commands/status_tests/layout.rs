@@ -118,6 +118,8 @@ fn worker_columns_are_aligned_across_rows() {
         active: 1,
         concurrency: 4,
         updated_at_ms: 0,
+        throttle: None,
+        throttled_until_ms: None,
     });
     ns.workers.push(oj_wire::WorkerSummary {
         name: "long-worker-name".to_string(),
@@ -127,6 +129,8 @@ fn worker_columns_are_aligned_across_rows() {
         active: 0,
         concurrency: 2,
         updated_at_ms: 0,
+        throttle: None,
+        throttled_until_ms: None,
     });
 
     let output = format_text(30, &[ns], None, None);
@@ -157,6 +161,8 @@ fn worker_shows_full_at_max_concurrency() {
         active: 3,
         concurrency: 3,
         updated_at_ms: 0,
+        throttle: None,
+        throttled_until_ms: None,
     });
 
     let output = format_text(30, &[ns], None, None);
@@ -177,17 +183,23 @@ fn cron_columns_are_aligned_across_rows() {
         name: "a".to_string(),
         project: "myproject".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
         target: "job:check".to_string(),
         status: "running".to_string(),
         time: "in 12m".to_string(),
+        next_fire: None,
     });
     ns.crons.push(oj_wire::CronSummary {
         name: "long-cron-name".to_string(),
         project: "myproject".to_string(),
         interval: "1h".to_string(),
+        schedule: None,
+        timezone: None,
         target: "job:deploy".to_string(),
         status: "stopped".to_string(),
         time: "3h ago".to_string(),
+        next_fire: None,
     });
 
     let output = format_text(30, &[ns], None, None);
@@ -205,6 +217,33 @@ fn cron_columns_are_aligned_across_rows() {
     );
 }
 
+#[test]
+#[serial]
+fn cron_schedule_shown_with_timezone() {
+    setup_no_color();
+
+    let mut ns = empty_ns("myproject");
+    ns.crons.push(oj_wire::CronSummary {
+        name: "standup".to_string(),
+        project: "myproject".to_string(),
+        interval: String::new(),
+        schedule: Some("0 9 * * MON-FRI".to_string()),
+        timezone: Some("+05:30".to_string()),
+        target: "job:post".to_string(),
+        status: "running".to_string(),
+        time: "in 3h".to_string(),
+        next_fire: Some("2026-03-03 09:00 +05:30".to_string()),
+    });
+
+    let output = format_text(30, &[ns], None, None);
+
+    assert!(
+        output.lines().any(|l| l.contains("standup") && l.contains("0 9 * * MON-FRI (+05:30)")),
+        "output: {}",
+        output
+    );
+}
+
 #[test]
 #[serial]
 fn crons_sorted_alphabetically() {
@@ -216,9 +255,12 @@ fn crons_sorted_alphabetically() {
             name: name.to_string(),
             project: "myproject".to_string(),
             interval: "1h".to_string(),
+            schedule: None,
+            timezone: None,
             target: "job:check".to_string(),
             status: "running".to_string(),
             time: "in 5m".to_string(),
+            next_fire: None,
         });
     }
 
@@ -343,6 +385,8 @@ fn workers_sorted_alphabetically() {
             active,
             concurrency: 2,
             updated_at_ms: 0,
+            throttle: None,
+            throttled_until_ms: None,
         });
     }
 
@@ -9,8 +9,9 @@ use clap::{Args, Subcommand};
 use crate::client::{ClientKind, DaemonClient};
 use crate::color;
 use crate::output::{
-    display_log, filter_by_project, handle_list, poll_log_follow, print_prune_results,
-    print_start_results, print_stop_results, require_name_or_all, OutputFormat,
+    display_log, filter_by_project, format_time_until, handle_list, poll_log_follow,
+    print_prune_results, print_start_results, print_stop_results, require_name_or_all,
+    OutputFormat,
 };
 use crate::table::{Column, Table};
 
@@ -145,7 +146,8 @@ pub async fn handle(
             filter_by_project(&mut workers, project_filter, |w| &w.project);
             workers.sort_by(|a, b| b.updated_at_ms.cmp(&a.updated_at_ms));
             handle_list(format, &workers, "No workers found", |items, out| {
-                let cols = vec![
+                let show_resumes = items.iter().any(|w| w.throttle.is_some());
+                let mut cols = vec![
                     Column::left("KIND"),
                     Column::left("PROJECT"),
                     Column::left("QUEUE"),
@@ -153,17 +155,27 @@ pub async fn handle(
                     Column::left("ACTIVE"),
                     Column::left("CONCURRENCY"),
                 ];
+                if show_resumes {
+                    cols.push(Column::right("RESUMES"));
+                }
                 let mut table = Table::new(cols);
                 for w in items {
                     let ns = if w.project.is_empty() { "-" } else { &w.project };
-                    let cells = vec![
+                    let status = match &w.throttle {
+                        Some(reason) => format!("throttled ({reason})"),
+                        None => w.status.clone(),
+                    };
+                    let mut cells = vec![
                         w.name.clone(),
                         ns.to_string(),
                         w.queue.clone(),
-                        w.status.clone(),
+                        status,
                         w.active.to_string(),
                         w.concurrency.to_string(),
                     ];
+                    if show_resumes {
+                        cells.push(w.throttled_until_ms.map_or("-".into(), format_time_until));
+                    }
                     table.row(cells);
                 }
                 table.render(out);
@@ -39,6 +39,13 @@ pub fn project() -> Option<String> {
     std::env::var("OJ_PROJECT").ok().filter(|s| !s.is_empty())
 }
 
+// --- Decisions ---
+
+/// Who `oj decision inbox` is for: OJ_USER, else the login name
+pub fn user() -> Option<String> {
+    ["OJ_USER", "USER"].iter().find_map(|var| std::env::var(var).ok().filter(|s| !s.is_empty()))
+}
+
 // --- Color ---
 
 pub fn no_color() -> bool {
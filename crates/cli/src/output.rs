@@ -31,12 +31,26 @@ pub fn format_time_ago(epoch_ms: u64) -> String {
     if epoch_ms == 0 {
         return "-".to_string();
     }
-    let now_ms = std::time::SystemTime::now()
+    let elapsed_secs = now_ms().saturating_sub(epoch_ms) / 1000;
+    oj_core::format_elapsed(elapsed_secs)
+}
+
+/// Format a future timestamp as time remaining (e.g., "in 5m"), or "-" once
+/// it has passed.
+pub fn format_time_until(epoch_ms: u64) -> String {
+    let remaining_secs = epoch_ms.saturating_sub(now_ms()) / 1000;
+    if remaining_secs == 0 {
+        return "-".to_string();
+    }
+    format!("in {}", oj_core::format_elapsed(remaining_secs))
+}
+
+/// Current time in epoch milliseconds.
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap_or_default()
-        .as_millis() as u64;
-    let elapsed_secs = now_ms.saturating_sub(epoch_ms) / 1000;
-    oj_core::format_elapsed(elapsed_secs)
+        .as_millis() as u64
 }
 
 /// Print prune results in text or JSON format.
@@ -3,7 +3,7 @@
 
 use serde::Serialize;
 
-use super::{print_capture_frame, print_prune_results, OutputFormat};
+use super::{format_time_until, now_ms, print_capture_frame, print_prune_results, OutputFormat};
 
 #[derive(Debug, Clone, Serialize)]
 struct FakeEntry {
@@ -73,3 +73,15 @@ fn print_capture_frame_does_not_panic() {
 fn print_capture_frame_empty_output() {
     print_capture_frame("deadbeef", "");
 }
+
+#[test]
+fn format_time_until_future() {
+    // Extra seconds keep the test stable if the clock ticks
+    assert_eq!(format_time_until(now_ms() + 2 * 3600 * 1000 + 30_000), "in 2h");
+}
+
+#[test]
+fn format_time_until_past_is_dash() {
+    assert_eq!(format_time_until(now_ms() - 1000), "-");
+    assert_eq!(format_time_until(0), "-");
+}
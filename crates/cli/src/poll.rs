@@ -44,22 +44,31 @@ impl Poller {
     /// both before and after sleeping). Returns [`Tick::Interrupted`] if
     /// Ctrl+C was pressed during the sleep.
     pub async fn tick(&mut self) -> Tick {
-        if let Some(deadline) = self.deadline {
-            if Instant::now() >= deadline {
-                return Tick::Timeout;
-            }
+        self.tick_or(std::future::pending()).await
+    }
+
+    /// Like [`Poller::tick`], but also ready as soon as `wake` completes.
+    pub async fn tick_or(&mut self, wake: impl Future<Output = ()>) -> Tick {
+        if self.expired() {
+            return Tick::Timeout;
         }
 
         tokio::select! {
             _ = &mut self.ctrl_c => Tick::Interrupted,
-            _ = tokio::time::sleep(self.interval) => {
-                if let Some(deadline) = self.deadline {
-                    if Instant::now() >= deadline {
-                        return Tick::Timeout;
-                    }
-                }
-                Tick::Ready
-            }
+            _ = tokio::time::sleep(self.interval) => self.ready_or_timeout(),
+            _ = wake => self.ready_or_timeout(),
+        }
+    }
+
+    fn expired(&self) -> bool {
+        self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn ready_or_timeout(&self) -> Tick {
+        if self.expired() {
+            Tick::Timeout
+        } else {
+            Tick::Ready
         }
     }
 }
@@ -44,3 +44,17 @@ async fn tick_no_timeout_polls_indefinitely() {
         assert!(matches!(result, Tick::Ready));
     }
 }
+
+#[tokio::test]
+async fn tick_or_wakes_before_interval() {
+    let mut poller = Poller::new(Duration::from_secs(60), Some(Duration::from_secs(5)));
+    let result = tokio::time::timeout(Duration::from_secs(1), poller.tick_or(async {})).await;
+    assert!(matches!(result, Ok(Tick::Ready)));
+}
+
+#[tokio::test]
+async fn tick_or_falls_back_to_interval() {
+    let mut poller = Poller::new(Duration::from_millis(10), Some(Duration::from_secs(5)));
+    let result = poller.tick_or(std::future::pending()).await;
+    assert!(matches!(result, Tick::Ready));
+}
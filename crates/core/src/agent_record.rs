@@ -61,6 +61,14 @@ pub enum AgentRuntime {
     Kubernetes,
 }
 
+crate::simple_display! {
+    AgentRuntime {
+        Local => "local",
+        Docker => "docker",
+        Kubernetes => "kubernetes",
+    }
+}
+
 /// Status of an agent in the unified agent record.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
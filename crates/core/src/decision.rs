@@ -63,6 +63,21 @@ pub struct Decision {
     /// was created for the same owner.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<DecisionId>,
+    /// When the decision resolves itself with `default_choice` if nobody answers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at_ms: Option<u64>,
+    /// 1-indexed option chosen on expiry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_choice: Option<usize>,
+    /// Set when the decision was resolved by its expiry rather than a human
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    /// Person responsible for answering
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// Others who may answer
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<String>,
 }
 
 impl DecisionOption {
@@ -114,6 +129,11 @@ impl Decision {
     pub fn chosen(&self) -> Option<usize> {
         self.choices.first().copied()
     }
+
+    /// Whether `user` is this decision's assignee or one of its reviewers.
+    pub fn is_for(&self, user: &str) -> bool {
+        self.assignee.as_deref() == Some(user) || self.reviewers.iter().any(|r| r == user)
+    }
 }
 
 #[cfg(test)]
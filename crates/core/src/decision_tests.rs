@@ -120,6 +120,11 @@ fn decision_serde_roundtrip() {
         resolved_at_ms: None,
         superseded_by: None,
         project: "myproject".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     };
     let json = serde_json::to_string(&decision).unwrap();
     let parsed: Decision = serde_json::from_str(&json).unwrap();
@@ -146,6 +151,11 @@ fn decision_is_resolved() {
         resolved_at_ms: None,
         superseded_by: None,
         project: String::new(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     };
     assert!(!decision.is_resolved());
 
@@ -153,6 +163,35 @@ fn decision_is_resolved() {
     assert!(decision.is_resolved());
 }
 
+#[yare::parameterized(
+    assignee = { "alice", true },
+    reviewer = { "bob", true },
+    other    = { "carol", false },
+)]
+fn decision_is_for(user: &str, expected: bool) {
+    let decision = Decision {
+        id: DecisionId::from_string("dec-1"),
+        agent_id: AgentId::from_string("agent-1"),
+        owner: JobId::from_string("job-1").into(),
+        source: DecisionSource::Idle,
+        context: String::new(),
+        options: vec![],
+        questions: None,
+        choices: vec![],
+        message: None,
+        created_at_ms: 1_000_000,
+        resolved_at_ms: None,
+        superseded_by: None,
+        project: String::new(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: Some("alice".to_string()),
+        reviewers: vec!["bob".to_string()],
+    };
+    assert_eq!(decision.is_for(user), expected);
+}
+
 #[test]
 fn decision_id_display() {
     let id = DecisionId::from_string("abc-123");
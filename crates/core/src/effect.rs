@@ -6,6 +6,8 @@
 use crate::agent::AgentId;
 use crate::container::ContainerConfig;
 use crate::event::Event;
+use crate::job::JobId;
+use crate::notify::Notification;
 use crate::owner::OwnerId;
 
 use crate::timer::TimerId;
@@ -114,6 +116,9 @@ pub enum Effect {
         container: Option<ContainerConfig>,
     },
 
+    /// Kill a job step's running shell command (and its process group)
+    KillShell { job_id: JobId, step: String },
+
     // === Worker effects ===
     /// Run the queue's list command to get available items
     PollQueue { worker_name: String, project: String, list_command: String, cwd: PathBuf },
@@ -131,13 +136,8 @@ pub enum Effect {
     },
 
     // === Notification effects ===
-    /// Send a desktop notification
-    Notify {
-        /// Notification title
-        title: String,
-        /// Notification message body
-        message: String,
-    },
+    /// Send a notification through the project's notifier
+    Notify { notification: Notification },
 }
 
 impl Effect {
@@ -154,6 +154,7 @@ impl Effect {
             Effect::SetTimer { .. } => "set_timer",
             Effect::CancelTimer { .. } => "cancel_timer",
             Effect::Shell { .. } => "shell",
+            Effect::KillShell { .. } => "kill_shell",
             Effect::PollQueue { .. } => "poll_queue",
             Effect::TakeQueueItem { .. } => "take_queue_item",
             Effect::Notify { .. } => "notify",
@@ -206,6 +207,9 @@ impl Effect {
                 }
                 fields
             }
+            Effect::KillShell { job_id, step } => {
+                vec![("job_id", job_id.to_string()), ("step", step.clone())]
+            }
             Effect::PollQueue { worker_name, cwd, .. } => {
                 vec![("worker", worker_name.clone()), ("cwd", cwd.display().to_string())]
             }
@@ -214,7 +218,9 @@ impl Effect {
                 ("cwd", cwd.display().to_string()),
                 ("item_id", item_id.clone()),
             ],
-            Effect::Notify { title, .. } => vec![("title", title.clone())],
+            Effect::Notify { notification } => {
+                vec![("kind", notification.kind.to_string()), ("title", notification.title.clone())]
+            }
         }
     }
 
@@ -2,7 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::*;
-use crate::{JobId, OwnerId};
+use crate::{JobId, Notification, NotifyKind, OwnerId};
 
 #[test]
 fn effect_serialization_roundtrip() {
@@ -46,6 +46,7 @@ fn effect_serialization_roundtrip() {
             env: [("KEY".to_string(), "value".to_string())].into_iter().collect(),
             container: None,
         },
+        Effect::KillShell { job_id: JobId::from_string("job-1"), step: "init".to_string() },
         Effect::PollQueue {
             worker_name: "fixer".to_string(),
             project: String::new(),
@@ -60,7 +61,9 @@ fn effect_serialization_roundtrip() {
             item_id: "item-1".to_string(),
             item: serde_json::json!({"id": "item-1", "title": "test"}),
         },
-        Effect::Notify { title: "Build complete".to_string(), message: "Success!".to_string() },
+        Effect::Notify {
+            notification: Notification::new(NotifyKind::OnDone, "Build complete", "Success!"),
+        },
     ];
 
     for effect in effects {
@@ -127,6 +130,10 @@ fn traced_effect_names() {
             },
             "shell",
         ),
+        (
+            Effect::KillShell { job_id: JobId::from_string("p"), step: "init".to_string() },
+            "kill_shell",
+        ),
         (
             Effect::PollQueue {
                 worker_name: "w".to_string(),
@@ -147,7 +154,10 @@ fn traced_effect_names() {
             },
             "take_queue_item",
         ),
-        (Effect::Notify { title: "t".to_string(), message: "m".to_string() }, "notify"),
+        (
+            Effect::Notify { notification: Notification::new(NotifyKind::OnDone, "t", "m") },
+            "notify",
+        ),
     ];
 
     for (effect, expected_name) in cases {
@@ -252,6 +262,12 @@ fn traced_effect_fields() {
         ]
     );
 
+    // Test KillShell fields
+    let effect =
+        Effect::KillShell { job_id: JobId::from_string("job-1"), step: "build".to_string() };
+    let fields = effect.fields();
+    assert_eq!(fields, vec![("job_id", "job-1".to_string()), ("step", "build".to_string())]);
+
     // Test PollQueue fields
     let effect = Effect::PollQueue {
         worker_name: "fixer".to_string(),
@@ -282,7 +298,8 @@ fn traced_effect_fields() {
     );
 
     // Test Notify fields
-    let effect = Effect::Notify { title: "Build".to_string(), message: "Done".to_string() };
+    let effect =
+        Effect::Notify { notification: Notification::new(NotifyKind::OnDone, "Build", "Done") };
     let fields = effect.fields();
-    assert_eq!(fields, vec![("title", "Build".to_string())]);
+    assert_eq!(fields, vec![("kind", "on_done".to_string()), ("title", "Build".to_string())]);
 }
@@ -145,6 +145,15 @@ fn log_summary_job_advanced() {
     assert_eq!(event.log_summary(), "job:advanced id=j1 step=deploy");
 }
 
+#[test]
+fn log_summary_job_forked() {
+    let event = Event::JobForked {
+        id: JobId::from_string("j1"),
+        steps: vec!["lint".to_string(), "test".to_string()],
+    };
+    assert_eq!(event.log_summary(), "job:forked id=j1 steps=lint,test");
+}
+
 #[test]
 fn log_summary_job_updated() {
     let event = Event::JobUpdated { id: JobId::from_string("j1"), vars: HashMap::new() };
@@ -173,6 +182,10 @@ fn log_summary_job_cancelling_cancel_deleted() {
         Event::JobDeleted { id: JobId::from_string("j3") }.log_summary(),
         "job:deleted id=j3"
     );
+    assert_eq!(
+        Event::JobArchived { id: JobId::from_string("j4") }.log_summary(),
+        "job:archived id=j4"
+    );
 }
 
 #[test]
@@ -258,10 +271,21 @@ fn log_summary_step_events() {
             job_id: JobId::from_string("j1"),
             step: "test".to_string(),
             error: "oops".to_string(),
+            timed_out: false,
         }
         .log_summary(),
         "step:failed job=j1 step=test"
     );
+    assert_eq!(
+        Event::StepFailed {
+            job_id: JobId::from_string("j1"),
+            step: "test".to_string(),
+            error: "timed out after 20m".to_string(),
+            timed_out: true,
+        }
+        .log_summary(),
+        "step:failed job=j1 step=test timed_out"
+    );
 }
 
 #[test]
@@ -319,6 +343,9 @@ fn log_summary_cron_started_stopped() {
             project_path: PathBuf::from("/proj"),
             runbook_hash: "abc".to_string(),
             interval: "1h".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: crate::CronCatchup::None,
             target: crate::RunTarget::job("build"),
             project: String::new(),
         }
@@ -487,6 +514,8 @@ fn log_summary_queue_events() {
             item_id: "i1".to_string(),
             data: HashMap::new(),
             pushed_at_ms: 0,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         }
         .log_summary(),
@@ -517,6 +546,7 @@ fn log_summary_queue_events() {
             item_id: "i1".to_string(),
             error: "e".to_string(),
             project: String::new(),
+            retry_at_ms: None,
         }
         .log_summary(),
         "queue:failed queue=bugs item=i1"
@@ -535,6 +565,7 @@ fn log_summary_queue_events() {
             queue: "bugs".to_string(),
             item_id: "i1".to_string(),
             project: String::new(),
+            scheduled: false,
         }
         .log_summary(),
         "queue:retry queue=bugs item=i1"
@@ -562,6 +593,10 @@ fn log_summary_decision_created_job_owner() {
         questions: None,
         created_at_ms: 0,
         project: String::new(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     };
     assert_eq!(event.log_summary(), "decision:created id=d1 job=j1 source=Gate");
 }
@@ -578,6 +613,10 @@ fn log_summary_decision_created_crew_owner() {
         questions: None,
         created_at_ms: 0,
         project: String::new(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     };
     assert_eq!(event.log_summary(), "decision:created id=d1 crew=ar1 source=Question");
 }
@@ -590,6 +629,7 @@ fn log_summary_decision_resolved_with_chosen() {
         message: None,
         resolved_at_ms: 0,
         project: String::new(),
+        timed_out: false,
     };
     assert_eq!(event.log_summary(), "decision:resolved id=d1 chosen=2");
 }
@@ -602,10 +642,24 @@ fn log_summary_decision_resolved_no_chosen() {
         message: Some("custom".to_string()),
         resolved_at_ms: 0,
         project: String::new(),
+        timed_out: false,
     };
     assert_eq!(event.log_summary(), "decision:resolved id=d1");
 }
 
+#[test]
+fn log_summary_decision_resolved_timed_out() {
+    let event = Event::DecisionResolved {
+        id: DecisionId::from_string("d1"),
+        choices: vec![1],
+        message: None,
+        resolved_at_ms: 0,
+        project: String::new(),
+        timed_out: true,
+    };
+    assert_eq!(event.log_summary(), "decision:resolved id=d1 chosen=1 timed_out");
+}
+
 #[test]
 fn log_summary_crew_created_no_namespace() {
     let event = Event::CrewCreated {
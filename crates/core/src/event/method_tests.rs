@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Tests for `Event` methods: `job_id`, `name`, `from_agent_state`,
+//! Tests for `Event` methods: `job_id`, `owner`, `name`, `from_agent_state`,
 //! `as_agent_state`.
 
 use crate::crew::CrewId;
@@ -65,6 +65,27 @@ fn event_job_id_returns_none_for_non_job_events() {
     }
 }
 
+#[test]
+fn event_owner_covers_jobs_crews_and_owned_events() {
+    let job = JobId::from_string("job-1");
+    let crew = CrewId::from_string("crw-1");
+    let cases = vec![
+        (Event::JobAdvanced { id: job, step: "build".to_string() }, Some(OwnerId::job(job))),
+        (Event::StepCompleted { job_id: job, step: "build".to_string() }, Some(OwnerId::job(job))),
+        (Event::CrewDeleted { id: crew }, Some(OwnerId::crew(crew))),
+        (
+            Event::AgentWorking { id: AgentId::from_string("a1"), owner: crew.into() },
+            Some(OwnerId::crew(crew)),
+        ),
+        (Event::AgentIdle { id: AgentId::from_string("a1") }, None),
+        (Event::Shutdown, None),
+    ];
+
+    for (event, expected) in cases {
+        assert_eq!(event.owner(), expected, "wrong owner for {:?}", event);
+    }
+}
+
 #[test]
 fn event_from_agent_state() {
     let agent_id = AgentId::from_string("test");
@@ -116,6 +137,8 @@ fn event_queue_name_returns_correct_strings() {
             item_id: "i".to_string(),
             data: HashMap::new(),
             pushed_at_ms: 0,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         }
         .name(),
@@ -146,6 +169,7 @@ fn event_queue_name_returns_correct_strings() {
             item_id: "i".to_string(),
             error: "e".to_string(),
             project: String::new(),
+            retry_at_ms: None,
         }
         .name(),
         "queue:failed"
@@ -155,6 +179,7 @@ fn event_queue_name_returns_correct_strings() {
             queue: "q".to_string(),
             item_id: "i".to_string(),
             project: String::new(),
+            scheduled: false,
         }
         .name(),
         "queue:retry"
@@ -216,6 +241,10 @@ fn event_decision_name() {
             questions: None,
             created_at_ms: 0,
             project: String::new(),
+            expires_at_ms: None,
+            default_choice: None,
+            assignee: None,
+            reviewers: vec![],
         }
         .name(),
         "decision:created"
@@ -227,6 +256,7 @@ fn event_decision_name() {
             message: None,
             resolved_at_ms: 0,
             project: String::new(),
+            timed_out: false,
         }
         .name(),
         "decision:resolved"
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Event methods — name, log summary, job_id, agent state conversion
+//! Event methods — name, log summary, job_id, owner, agent state conversion
 
 use super::Event;
 use crate::agent::{AgentId, AgentState};
@@ -62,6 +62,7 @@ impl Event {
             Event::CommandRun { .. } => "command:run",
             Event::JobCreated { .. } => "job:created",
             Event::JobAdvanced { .. } => "job:advanced",
+            Event::JobForked { .. } => "job:forked",
             Event::JobUpdated { .. } => "job:updated",
             Event::JobResume { .. } => "job:resume",
             Event::JobFailing { .. } => "job:failing",
@@ -70,6 +71,7 @@ impl Event {
             Event::JobSuspending { .. } => "job:suspending",
             Event::JobSuspend { .. } => "job:suspend",
             Event::JobDeleted { .. } => "job:deleted",
+            Event::JobArchived { .. } => "job:archived",
             Event::RunbookLoaded { .. } => "runbook:loaded",
             Event::AgentSpawned { .. } => "agent:spawned",
             Event::AgentSpawnFailed { .. } => "agent:spawn:failed",
@@ -150,6 +152,7 @@ impl Event {
                 format!("{t} id={id}{} kind={kind} name={name}", ns_fragment(project))
             }
             Event::JobAdvanced { id, step } => format!("{t} id={id} step={step}"),
+            Event::JobForked { id, steps } => format!("{t} id={id} steps={}", steps.join(",")),
             Event::JobUpdated { id, .. } => format!("{t} id={id}"),
             Event::JobResume { id, .. } => format!("{t} id={id}"),
             Event::JobFailing { id } => format!("{t} id={id}"),
@@ -158,6 +161,7 @@ impl Event {
             Event::JobSuspending { id } => format!("{t} id={id}"),
             Event::JobSuspend { id } => format!("{t} id={id}"),
             Event::JobDeleted { id } => format!("{t} id={id}"),
+            Event::JobArchived { id } => format!("{t} id={id}"),
 
             // -- runbook --
             Event::RunbookLoaded { hash, version, runbook } => {
@@ -174,6 +178,9 @@ impl Event {
             }
 
             // -- step --
+            Event::StepFailed { job_id, step, timed_out: true, .. } => {
+                format!("{t} job={job_id} step={step} timed_out")
+            }
             Event::StepStarted { job_id, step, .. }
             | Event::StepWaiting { job_id, step, .. }
             | Event::StepCompleted { job_id, step }
@@ -242,11 +249,12 @@ impl Event {
             Event::DecisionCreated { id, owner, source, .. } => {
                 format!("{t} id={id} {} source={source:?}", owner.log())
             }
-            Event::DecisionResolved { id, choices, .. } => {
+            Event::DecisionResolved { id, choices, timed_out, .. } => {
+                let timed_out = if *timed_out { " timed_out" } else { "" };
                 if let Some(c) = choices.first() {
-                    format!("{t} id={id} chosen={c}")
+                    format!("{t} id={id} chosen={c}{timed_out}")
                 } else {
-                    format!("{t} id={id}")
+                    format!("{t} id={id}{timed_out}")
                 }
             }
 
@@ -283,6 +291,7 @@ impl Event {
 
             Event::JobCreated { id, .. }
             | Event::JobAdvanced { id, .. }
+            | Event::JobForked { id, .. }
             | Event::JobUpdated { id, .. }
             | Event::JobResume { id, .. }
             | Event::JobFailing { id, .. }
@@ -290,7 +299,8 @@ impl Event {
             | Event::JobCancel { id, .. }
             | Event::JobSuspending { id, .. }
             | Event::JobSuspend { id, .. }
-            | Event::JobDeleted { id, .. } => Some(id),
+            | Event::JobDeleted { id, .. }
+            | Event::JobArchived { id, .. } => Some(id),
 
             Event::StepStarted { job_id, .. }
             | Event::StepWaiting { job_id, .. }
@@ -307,6 +317,33 @@ impl Event {
             _ => None,
         }
     }
+
+    /// The job or crew this event belongs to, when the event names one.
+    pub fn owner(&self) -> Option<OwnerId> {
+        match self {
+            Event::CommandRun { owner, .. }
+            | Event::AgentWorking { owner, .. }
+            | Event::AgentWaiting { owner, .. }
+            | Event::AgentFailed { owner, .. }
+            | Event::AgentExited { owner, .. }
+            | Event::AgentGone { owner, .. }
+            | Event::AgentSpawned { owner, .. }
+            | Event::AgentSpawnFailed { owner, .. }
+            | Event::WorkspaceCreated { owner, .. }
+            | Event::CronOnce { owner, .. }
+            | Event::CronFired { owner, .. }
+            | Event::WorkerDispatched { owner, .. }
+            | Event::DecisionCreated { owner, .. } => Some(*owner),
+
+            Event::CrewCreated { id, .. }
+            | Event::CrewStarted { id, .. }
+            | Event::CrewUpdated { id, .. }
+            | Event::CrewResume { id, .. }
+            | Event::CrewDeleted { id } => Some(OwnerId::crew(*id)),
+
+            _ => self.job_id().map(|id| OwnerId::job(*id)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -10,6 +10,7 @@ use crate::crew::{CrewId, CrewStatus};
 use crate::decision::{DecisionId, DecisionOption, DecisionSource};
 use crate::job::JobId;
 use crate::owner::OwnerId;
+use crate::records::CronCatchup;
 use crate::target::RunTarget;
 use crate::timer::TimerId;
 use crate::workspace::WorkspaceId;
@@ -217,6 +218,10 @@ pub enum Event {
     #[serde(rename = "job:advanced")]
     JobAdvanced { id: JobId, step: String },
 
+    /// Job fanned out into parallel branches (array `on_done` or ready joins).
+    #[serde(rename = "job:forked")]
+    JobForked { id: JobId, steps: Vec<String> },
+
     #[serde(rename = "job:updated")]
     JobUpdated { id: JobId, vars: HashMap<String, String> },
 
@@ -250,6 +255,10 @@ pub enum Event {
     #[serde(rename = "job:deleted")]
     JobDeleted { id: JobId },
 
+    /// Terminal job moved out of state into the job archive
+    #[serde(rename = "job:archived")]
+    JobArchived { id: JobId },
+
     #[serde(rename = "step:started")]
     StepStarted {
         job_id: JobId,
@@ -278,7 +287,14 @@ pub enum Event {
     StepCompleted { job_id: JobId, step: String },
 
     #[serde(rename = "step:failed")]
-    StepFailed { job_id: JobId, step: String, error: String },
+    StepFailed {
+        job_id: JobId,
+        step: String,
+        error: String,
+        /// The step exceeded its `timeout` rather than failing on its own
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        timed_out: bool,
+    },
 
     #[serde(rename = "shell:exited")]
     ShellExited {
@@ -321,7 +337,14 @@ pub enum Event {
         project: String,
         project_path: PathBuf,
         runbook_hash: String,
+        /// Empty for crons that fire on a calendar `schedule`
         interval: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schedule: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timezone: Option<String>,
+        #[serde(default, skip_serializing_if = "CronCatchup::is_none")]
+        catchup: CronCatchup,
         target: RunTarget,
     },
 
@@ -391,6 +414,10 @@ pub enum Event {
         item_id: String,
         data: HashMap<String, String>,
         pushed_at_ms: u64,
+        #[serde(default)]
+        priority: i32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        not_before_ms: Option<u64>,
     },
 
     #[serde(rename = "queue:taken")]
@@ -400,13 +427,29 @@ pub enum Event {
     QueueCompleted { queue: String, project: String, item_id: String },
 
     #[serde(rename = "queue:failed")]
-    QueueFailed { queue: String, project: String, item_id: String, error: String },
+    QueueFailed {
+        queue: String,
+        project: String,
+        item_id: String,
+        error: String,
+        /// When the item's automatic retry fires (epoch ms), if one is scheduled
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        retry_at_ms: Option<u64>,
+    },
 
     #[serde(rename = "queue:dropped")]
     QueueDropped { queue: String, project: String, item_id: String },
 
     #[serde(rename = "queue:retry")]
-    QueueRetry { queue: String, project: String, item_id: String },
+    QueueRetry {
+        queue: String,
+        project: String,
+        item_id: String,
+        /// Fired by the retry timer rather than by `oj queue retry`; keeps the
+        /// item's failure count so backoff and `attempts` keep counting
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        scheduled: bool,
+    },
 
     #[serde(rename = "queue:dead")]
     QueueDead { queue: String, project: String, item_id: String },
@@ -426,6 +469,18 @@ pub enum Event {
         options: Vec<DecisionOption>,
         #[serde(default, skip_serializing_if = "Option::is_none")]
         questions: Option<QuestionData>,
+        /// When the decision resolves itself with `default_choice` if nobody answers
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expires_at_ms: Option<u64>,
+        /// 1-indexed option chosen on expiry
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default_choice: Option<usize>,
+        /// Person responsible for answering
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        assignee: Option<String>,
+        /// Others who may answer
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        reviewers: Vec<String>,
     },
 
     #[serde(rename = "decision:resolved")]
@@ -440,6 +495,9 @@ pub enum Event {
         /// Freeform text (nudge message, custom answer)
         #[serde(default, skip_serializing_if = "Option::is_none")]
         message: Option<String>,
+        /// Resolved by the expiry timer rather than a human
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        timed_out: bool,
     },
 
     /// Catch-all for unknown event types (extensibility)
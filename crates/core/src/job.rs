@@ -66,6 +66,7 @@ pub enum StepOutcome {
     Completed,
     Failed(String),
     Waiting(String),
+    TimedOut(String),
 }
 
 /// Tag-only variant of [`StepStatus`] for protocol DTOs (strips associated data).
@@ -115,6 +116,7 @@ pub enum StepOutcomeKind {
     Completed,
     Failed,
     Waiting,
+    TimedOut,
 }
 
 impl From<&StepOutcome> for StepOutcomeKind {
@@ -124,6 +126,7 @@ impl From<&StepOutcome> for StepOutcomeKind {
             StepOutcome::Completed => StepOutcomeKind::Completed,
             StepOutcome::Failed(_) => StepOutcomeKind::Failed,
             StepOutcome::Waiting(_) => StepOutcomeKind::Waiting,
+            StepOutcome::TimedOut(_) => StepOutcomeKind::TimedOut,
         }
     }
 }
@@ -134,6 +137,7 @@ crate::simple_display! {
         Completed => "completed",
         Failed => "failed",
         Waiting => "waiting",
+        TimedOut => "timed out",
     }
 }
 
@@ -289,6 +293,10 @@ pub struct Job {
     /// Used to suppress auto-resume from our own nudge text.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_nudge_at: Option<u64>,
+    /// Status of each parallel branch while the job is fanned out.
+    /// Empty for sequential execution.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub branches: HashMap<String, StepStatus>,
 }
 
 impl Job {
@@ -330,6 +338,7 @@ impl Job {
             step_visits: HashMap::new(),
             cron_name: config.cron_name,
             last_nudge_at: None,
+            branches: HashMap::new(),
         }
     }
 
@@ -351,6 +360,19 @@ impl Job {
         }
     }
 
+    /// Finalize the most recent unfinished record for a named step.
+    ///
+    /// Used for parallel branches, where the named step's record is not
+    /// necessarily the last one in the history.
+    pub fn finalize_step(&mut self, name: &str, outcome: StepOutcome, epoch_ms: u64) {
+        if let Some(record) =
+            self.step_history.iter_mut().rfind(|r| r.name == name && r.finished_at_ms.is_none())
+        {
+            record.finished_at_ms = Some(epoch_ms);
+            record.outcome = outcome;
+        }
+    }
+
     /// Update the outcome of the most recent step record (without finalizing)
     pub fn update_current_step_outcome(&mut self, outcome: StepOutcome) {
         if let Some(record) = self.step_history.last_mut() {
@@ -372,6 +394,36 @@ impl Job {
         });
     }
 
+    /// Update the outcome of a named step's most recent unfinished record
+    /// (without finalizing). Used for parallel branches.
+    pub fn update_step_outcome(&mut self, name: &str, outcome: StepOutcome) {
+        if let Some(record) =
+            self.step_history.iter_mut().rfind(|r| r.name == name && r.finished_at_ms.is_none())
+        {
+            record.outcome = outcome;
+        }
+    }
+
+    /// Set the agent on a named step's most recent unfinished record.
+    /// Used for parallel branches.
+    pub fn set_step_agent(&mut self, name: &str, agent_id: Option<&str>, agent_name: Option<&str>) {
+        if let Some(record) =
+            self.step_history.iter_mut().rfind(|r| r.name == name && r.finished_at_ms.is_none())
+        {
+            if let Some(agent_id) = agent_id {
+                record.agent_id = Some(agent_id.to_string());
+            }
+            if let Some(agent_name) = agent_name {
+                record.agent_name = Some(agent_name.to_string());
+            }
+        }
+    }
+
+    /// Agent of the most recent record of a step, if it ran one.
+    pub fn step_agent_id(&self, step: &str) -> Option<&str> {
+        self.step_history.iter().rfind(|r| r.name == step).and_then(|r| r.agent_id.as_deref())
+    }
+
     /// Set the agent_id on the most recent step record (if it's still running).
     pub fn set_current_step_agent_id(&mut self, agent_id: &str) {
         if let Some(record) = self.step_history.last_mut() {
@@ -398,6 +450,47 @@ impl Job {
             || self.step == "suspended"
     }
 
+    /// Check if the job is fanned out into parallel branches
+    pub fn is_parallel(&self) -> bool {
+        !self.branches.is_empty()
+    }
+
+    /// Check if a step is a parallel branch that has not yet finished
+    /// (including an agent branch waiting on a decision)
+    pub fn is_branch_active(&self, step: &str) -> bool {
+        matches!(
+            self.branches.get(step),
+            Some(StepStatus::Pending | StepStatus::Running | StepStatus::Waiting(_))
+        )
+    }
+
+    /// Active branches, sorted by name
+    pub fn active_branches(&self) -> Vec<&str> {
+        let mut active: Vec<&str> =
+            self.branches.keys().map(String::as_str).filter(|s| self.is_branch_active(s)).collect();
+        active.sort_unstable();
+        active
+    }
+
+    /// The active branch currently run by the given agent, if any
+    pub fn agent_branch(&self, agent_id: &str) -> Option<&str> {
+        self.active_branches().into_iter().find(|s| self.step_agent_id(s) == Some(agent_id))
+    }
+
+    /// The job as seen from one of its branches: `step` and `step_status`
+    /// are the branch's, so per-step agent handling applies to it unchanged.
+    pub fn branch_view(&self, branch: &str) -> Job {
+        let mut view = self.clone();
+        view.step_status = self.branches.get(branch).cloned().unwrap_or(StepStatus::Pending);
+        view.step = branch.to_string();
+        view
+    }
+
+    /// Check if this job is a `branch_view` of an active branch
+    pub fn is_branch_view(&self) -> bool {
+        self.is_branch_active(&self.step)
+    }
+
     /// Check if the job is suspended (terminal but resumable, never pruned)
     pub fn is_suspended(&self) -> bool {
         self.step == "suspended"
@@ -456,6 +549,7 @@ crate::builder! {
             total_retries: u32 = 0,
             step_visits: HashMap<String, u32> = HashMap::new(),
             last_nudge_at: Option<u64> = None,
+            branches: HashMap<String, StepStatus> = HashMap::new(),
         }
         option {
             workspace_id: WorkspaceId = None,
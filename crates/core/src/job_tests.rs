@@ -316,6 +316,51 @@ fn suspended_iff_suspended_variant(status: StepStatus, expected: bool) {
     assert_eq!(status.is_suspended(), expected);
 }
 
+#[yare::parameterized(
+    pending     = { StepStatus::Pending,                      true },
+    running     = { StepStatus::Running,                      true },
+    waiting     = { StepStatus::Waiting(Some("d-1".into())),  true },
+    completed   = { StepStatus::Completed,                    false },
+    failed      = { StepStatus::Failed,                       false },
+)]
+fn branch_active_until_finished(status: StepStatus, expected: bool) {
+    let mut job = Job::new(test_config("job-1"), &FakeClock::new());
+    job.branches.insert("lint".to_string(), status);
+    assert_eq!(job.is_branch_active("lint"), expected);
+    assert!(!job.is_branch_active("test"));
+}
+
+#[test]
+fn branch_view_takes_branch_step_and_status() {
+    let mut job = Job::new(test_config("job-1"), &FakeClock::new());
+    job.branches.insert("lint".to_string(), StepStatus::Waiting(Some("d-1".into())));
+    job.push_step("lint", 0);
+
+    let view = job.branch_view("lint");
+
+    assert_eq!(view.step, "lint");
+    assert_eq!(view.step_status, StepStatus::Waiting(Some("d-1".into())));
+    assert!(view.is_branch_view());
+    assert!(!job.is_branch_view());
+}
+
+#[test]
+fn agent_branch_finds_branch_by_its_agent() {
+    let mut job = Job::new(test_config("job-1"), &FakeClock::new());
+    for step in ["lint", "review"] {
+        job.branches.insert(step.to_string(), StepStatus::Running);
+        job.push_step(step, 0);
+    }
+    job.set_step_agent("review", Some("agent-1"), Some("reviewer"));
+
+    assert_eq!(job.agent_branch("agent-1"), Some("review"));
+    assert_eq!(job.step_agent_id("review"), Some("agent-1"));
+    assert_eq!(job.agent_branch("agent-2"), None);
+
+    job.branches.insert("review".to_string(), StepStatus::Completed);
+    assert_eq!(job.agent_branch("agent-1"), None);
+}
+
 proptest! {
     #[test]
     fn step_status_serde_roundtrip(status in arb_step_status()) {
@@ -24,6 +24,7 @@ pub mod id;
 pub mod job;
 pub mod log_paths;
 pub mod metrics;
+pub mod notify;
 pub mod owner;
 pub mod project;
 pub mod records;
@@ -55,10 +56,12 @@ pub use job::{
     StepStatusKind,
 };
 pub use metrics::MetricsHealth;
+pub use notify::{Notification, NotifyKind, NotifySeverity};
 pub use owner::{InvalidOwnerId, OwnerId, OwnerMismatch};
 pub use project::{namespace_to_option, scoped_name, split_scoped_name, Namespace};
 pub use records::{
-    CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, WorkerRecord, Workspace, WorkspaceType,
+    CronCatchup, CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, ThrottleReason,
+    WorkerRecord, WorkerThrottle, Workspace, WorkspaceType,
 };
 pub use target::RunTarget;
 pub use time_fmt::{format_elapsed, format_elapsed_ms};
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Structured notifications.
+//!
+//! A [`Notification`] carries the rendered title and message plus enough
+//! context (project, owner, step, decision) for notifiers to link back to
+//! what triggered it.
+
+use crate::decision::DecisionId;
+use crate::owner::OwnerId;
+use serde::{Deserialize, Serialize};
+
+/// What triggered a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotifyKind {
+    /// A job or agent started (`notify.on_start`)
+    OnStart,
+    /// A job or agent completed (`notify.on_done`)
+    OnDone,
+    /// A job or agent failed (`notify.on_fail`)
+    OnFail,
+    /// An agent escalated to a human and a decision was created
+    Escalation,
+    /// A queue item failed with no retries left
+    DeadLetter,
+    /// A queue worker stopped
+    WorkerStopped,
+}
+
+impl NotifyKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotifyKind::OnStart => "on_start",
+            NotifyKind::OnDone => "on_done",
+            NotifyKind::OnFail => "on_fail",
+            NotifyKind::Escalation => "escalation",
+            NotifyKind::DeadLetter => "dead_letter",
+            NotifyKind::WorkerStopped => "worker_stopped",
+        }
+    }
+
+    /// Severity used when the emitter does not set one.
+    pub fn default_severity(self) -> NotifySeverity {
+        match self {
+            NotifyKind::OnStart | NotifyKind::OnDone => NotifySeverity::Info,
+            NotifyKind::Escalation | NotifyKind::WorkerStopped => NotifySeverity::Warning,
+            NotifyKind::OnFail | NotifyKind::DeadLetter => NotifySeverity::Error,
+        }
+    }
+}
+
+impl std::fmt::Display for NotifyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How urgent a notification is, lowest first.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifySeverity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl NotifySeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NotifySeverity::Info => "info",
+            NotifySeverity::Warning => "warning",
+            NotifySeverity::Error => "error",
+        }
+    }
+}
+
+impl std::fmt::Display for NotifySeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A notification with its triggering context.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Notification {
+    pub kind: NotifyKind,
+    pub severity: NotifySeverity,
+    pub title: String,
+    pub message: String,
+    /// Project the notification belongs to (empty for none)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub project: String,
+    /// Job or crew that triggered the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<OwnerId>,
+    /// Job step the owner was on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+    /// Decision awaiting a human, for escalations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decision_id: Option<DecisionId>,
+}
+
+impl Notification {
+    /// Create a notification with the kind's default severity and no context.
+    pub fn new(kind: NotifyKind, title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            severity: kind.default_severity(),
+            title: title.into(),
+            message: message.into(),
+            project: String::new(),
+            owner: None,
+            step: None,
+            decision_id: None,
+        }
+    }
+
+    pub fn severity(mut self, severity: NotifySeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn project(mut self, project: impl Into<String>) -> Self {
+        self.project = project.into();
+        self
+    }
+
+    pub fn owner(mut self, owner: impl Into<OwnerId>) -> Self {
+        self.owner = Some(owner.into());
+        self
+    }
+
+    pub fn step(mut self, step: Option<&str>) -> Self {
+        self.step = step.map(String::from);
+        self
+    }
+
+    pub fn decision_id(mut self, id: DecisionId) -> Self {
+        self.decision_id = Some(id);
+        self
+    }
+
+    /// CLI command that shows what the notification is about: the decision
+    /// for escalations, otherwise the owning job.
+    pub fn action_command(&self) -> Option<String> {
+        if let Some(id) = &self.decision_id {
+            return Some(format!("oj decision show {}", id));
+        }
+        match self.owner? {
+            OwnerId::Job(id) => Some(format!("oj job show {}", id)),
+            OwnerId::Crew(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "notify_tests.rs"]
+mod tests;
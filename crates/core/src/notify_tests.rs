@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::{CrewId, JobId};
+
+#[yare::parameterized(
+    on_start = { NotifyKind::OnStart, NotifySeverity::Info },
+    on_done = { NotifyKind::OnDone, NotifySeverity::Info },
+    on_fail = { NotifyKind::OnFail, NotifySeverity::Error },
+    escalation = { NotifyKind::Escalation, NotifySeverity::Warning },
+    dead_letter = { NotifyKind::DeadLetter, NotifySeverity::Error },
+    worker_stopped = { NotifyKind::WorkerStopped, NotifySeverity::Warning },
+)]
+fn new_uses_kind_default_severity(kind: NotifyKind, severity: NotifySeverity) {
+    assert_eq!(Notification::new(kind, "t", "m").severity, severity);
+}
+
+#[test]
+fn serializes_context_fields() {
+    let n = Notification::new(NotifyKind::OnFail, "deploy", "build failed")
+        .project("api")
+        .owner(JobId::from_string("job-1"))
+        .step(Some("build"));
+
+    let json = serde_json::to_value(&n).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "kind": "on_fail",
+            "severity": "error",
+            "title": "deploy",
+            "message": "build failed",
+            "project": "api",
+            "owner": "job-1",
+            "step": "build",
+        })
+    );
+    assert_eq!(serde_json::from_value::<Notification>(json).unwrap(), n);
+}
+
+#[test]
+fn empty_context_is_omitted() {
+    let json = serde_json::to_value(Notification::new(NotifyKind::OnStart, "t", "m")).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({ "kind": "on_start", "severity": "info", "title": "t", "message": "m" })
+    );
+}
+
+#[test]
+fn severity_orders_by_urgency() {
+    assert!(NotifySeverity::Info < NotifySeverity::Warning);
+    assert!(NotifySeverity::Warning < NotifySeverity::Error);
+}
+
+#[test]
+fn action_command_prefers_decision() {
+    let job = Notification::new(NotifyKind::OnDone, "t", "m").owner(JobId::from_string("job-1"));
+    assert_eq!(job.action_command().as_deref(), Some("oj job show job-1"));
+
+    let escalation = job.decision_id(DecisionId::from_string("dcn-1"));
+    assert_eq!(escalation.action_command().as_deref(), Some("oj decision show dcn-1"));
+
+    let crew = Notification::new(NotifyKind::OnDone, "t", "m").owner(CrewId::from_string("crw-1"));
+    assert_eq!(crew.action_command(), None);
+}
@@ -70,6 +70,10 @@ pub struct WorkerRecord {
     /// Mapping from owner → item_id for queue item tracking.
     /// Persisted via WorkerDispatched events for restart recovery.
     pub owners: HashMap<String, String>,
+    /// Recent dispatch times (epoch ms), oldest first, so the worker's
+    /// `rate` survives a daemon restart.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dispatched_at_ms: Vec<u64>,
 }
 
 /// Status of a queue item through its lifecycle
@@ -106,6 +110,22 @@ pub struct QueueItem {
     pub pushed_at_ms: u64,
     /// Number of times this item has failed (for retry tracking)
     pub failures: u32,
+    /// Higher runs sooner in queues with `order = "priority"`
+    #[serde(default)]
+    pub priority: i32,
+    /// Workers skip the item until this time (epoch ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_before_ms: Option<u64>,
+    /// When a failed item's automatic retry fires (epoch ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at_ms: Option<u64>,
+}
+
+impl QueueItem {
+    /// Whether the item's `not_before_ms` has passed.
+    pub fn is_eligible(&self, now_ms: u64) -> bool {
+        self.not_before_ms.is_none_or(|t| t <= now_ms)
+    }
 }
 
 /// Runtime-only metadata from the most recent queue poll.
@@ -115,6 +135,51 @@ pub struct QueuePollMeta {
     pub last_polled_at_ms: u64,
 }
 
+/// Runtime-only: a running worker holding back dispatches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkerThrottle {
+    pub reason: ThrottleReason,
+    /// When the worker may dispatch again (epoch ms)
+    pub until_ms: u64,
+}
+
+/// What is holding a worker back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThrottleReason {
+    /// The worker's `rate` is used up
+    Rate,
+    /// Outside the worker's active `window`
+    Window,
+}
+
+impl std::fmt::Display for ThrottleReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThrottleReason::Rate => write!(f, "rate"),
+            ThrottleReason::Window => write!(f, "window"),
+        }
+    }
+}
+
+/// What a cron does about runs it missed while the daemon was down.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CronCatchup {
+    /// Drop missed runs and resume on the regular timer
+    #[default]
+    None,
+    /// Run once on recovery if any runs were missed
+    Once,
+    /// Run every missed run on recovery
+    All,
+}
+
+impl CronCatchup {
+    pub fn is_none(&self) -> bool {
+        *self == CronCatchup::None
+    }
+}
+
 /// Record of a running cron for WAL replay / restart recovery
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CronRecord {
@@ -124,7 +189,17 @@ pub struct CronRecord {
     pub runbook_hash: String,
     /// "running" or "stopped"
     pub status: String,
+    /// Empty for crons that fire on a calendar `schedule`
     pub interval: String,
+    /// Cron expression, for crons that fire on a calendar schedule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// Timezone the schedule is evaluated in (UTC when unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Policy for runs missed while the daemon was down
+    #[serde(default, skip_serializing_if = "CronCatchup::is_none")]
+    pub catchup: CronCatchup,
     pub target: RunTarget,
     /// Epoch ms when the cron was started (timer began)
     pub started_at_ms: u64,
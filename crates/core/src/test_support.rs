@@ -67,6 +67,7 @@ pub fn step_failed_event(job_id: &str, step: &str, error: &str) -> Event {
         job_id: JobId::from_string(job_id),
         step: step.to_string(),
         error: error.to_string(),
+        timed_out: false,
     }
 }
 
@@ -98,6 +99,8 @@ pub fn queue_pushed_event(queue: &str, item_id: &str) -> Event {
             .into_iter()
             .collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     }
 }
@@ -117,6 +120,7 @@ pub fn queue_failed_event(queue: &str, item_id: &str, error: &str) -> Event {
         item_id: item_id.to_string(),
         error: error.to_string(),
         project: String::new(),
+        retry_at_ms: None,
     }
 }
 
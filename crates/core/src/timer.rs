@@ -6,6 +6,7 @@
 //! TimerId uniquely identifies a timer instance used for scheduling delayed
 //! actions such as timeouts, heartbeats, or periodic checks.
 
+use crate::decision::DecisionId;
 use crate::owner::OwnerId;
 use crate::project::scoped_name;
 
@@ -84,10 +85,19 @@ impl TimerId {
         TimerKind::Cooldown { owner: owner.into(), trigger, chain_pos }.to_timer_id()
     }
 
+    pub fn step_timeout(owner: impl Into<OwnerId>, step: &str) -> Self {
+        TimerKind::StepTimeout { owner: owner.into(), step }.to_timer_id()
+    }
+
     pub fn queue_retry(queue: &str, item_id: &str) -> Self {
         TimerKind::QueueRetry { scoped_queue: queue, item_id }.to_timer_id()
     }
 
+    /// Wakes a queue's workers when its earliest delayed item becomes eligible.
+    pub fn queue_delay(queue: &str, project: &str) -> Self {
+        Self::from_string(format!("queue-delay:{}", scoped_name(project, queue)))
+    }
+
     pub fn cron(cron_name: &str, project: &str) -> Self {
         Self::from_string(format!("cron:{}", scoped_name(project, cron_name)))
     }
@@ -96,6 +106,16 @@ impl TimerId {
         Self::from_string(format!("queue-poll:{}", scoped_name(project, worker_name)))
     }
 
+    /// Wakes a throttled worker when its rate limit or active window allows
+    /// dispatching again.
+    pub fn worker_throttle(worker_name: &str, project: &str) -> Self {
+        Self::from_string(format!("worker-throttle:{}", scoped_name(project, worker_name)))
+    }
+
+    pub fn decision_timeout(decision_id: DecisionId) -> Self {
+        TimerKind::DecisionTimeout(decision_id).to_timer_id()
+    }
+
     /// Parse this timer ID into a typed `TimerKind`.
     pub fn kind(&self) -> Option<TimerKind<'_>> {
         TimerKind::parse(self.as_str())
@@ -105,7 +125,8 @@ impl TimerId {
         match self.kind()? {
             TimerKind::Liveness(owner)
             | TimerKind::ExitDeferred(owner)
-            | TimerKind::Cooldown { owner, .. } => Some(owner),
+            | TimerKind::Cooldown { owner, .. }
+            | TimerKind::StepTimeout { owner, .. } => Some(owner),
             _ => None,
         }
     }
@@ -117,9 +138,13 @@ pub enum TimerKind<'a> {
     Liveness(OwnerId),
     ExitDeferred(OwnerId),
     Cooldown { owner: OwnerId, trigger: &'a str, chain_pos: usize },
+    StepTimeout { owner: OwnerId, step: &'a str },
     QueueRetry { scoped_queue: &'a str, item_id: &'a str },
+    QueueDelay { scoped_queue: &'a str },
     Cron { scoped_name: &'a str },
     QueuePoll { scoped_name: &'a str },
+    WorkerThrottle { scoped_name: &'a str },
+    DecisionTimeout(DecisionId),
 }
 
 impl<'a> TimerKind<'a> {
@@ -145,16 +170,35 @@ impl<'a> TimerKind<'a> {
                 chain_pos: chain_pos_str.parse().unwrap_or(0),
             });
         }
+        if let Some(rest) = id.strip_prefix("step-timeout:") {
+            let (owner, step) = parse_owner(rest)?;
+            if step.is_empty() {
+                return None;
+            }
+            return Some(TimerKind::StepTimeout { owner, step });
+        }
         if let Some(rest) = id.strip_prefix("queue-retry:") {
             let (scoped_queue, item_id) = rest.rsplit_once(':')?;
             return Some(TimerKind::QueueRetry { scoped_queue, item_id });
         }
+        if let Some(rest) = id.strip_prefix("queue-delay:") {
+            return Some(TimerKind::QueueDelay { scoped_queue: rest });
+        }
         if let Some(rest) = id.strip_prefix("cron:") {
             return Some(TimerKind::Cron { scoped_name: rest });
         }
         if let Some(rest) = id.strip_prefix("queue-poll:") {
             return Some(TimerKind::QueuePoll { scoped_name: rest });
         }
+        if let Some(rest) = id.strip_prefix("worker-throttle:") {
+            return Some(TimerKind::WorkerThrottle { scoped_name: rest });
+        }
+        if let Some(rest) = id.strip_prefix("decision-timeout:") {
+            if rest.is_empty() || rest.len() > crate::id::ID_MAX_LEN {
+                return None;
+            }
+            return Some(TimerKind::DecisionTimeout(DecisionId::from_string(rest)));
+        }
         None
     }
 
@@ -167,12 +211,24 @@ impl<'a> TimerKind<'a> {
             TimerKind::Cooldown { owner, trigger, chain_pos } => {
                 TimerId::from_string(format!("cooldown:{owner}:{trigger}:{chain_pos}"))
             }
+            TimerKind::StepTimeout { owner, step } => {
+                TimerId::from_string(format!("step-timeout:{owner}:{step}"))
+            }
             TimerKind::QueueRetry { scoped_queue, item_id } => {
                 TimerId::from_string(format!("queue-retry:{scoped_queue}:{item_id}"))
             }
+            TimerKind::QueueDelay { scoped_queue } => {
+                TimerId::from_string(format!("queue-delay:{scoped_queue}"))
+            }
             TimerKind::QueuePoll { scoped_name } => {
                 TimerId::from_string(format!("queue-poll:{scoped_name}"))
             }
+            TimerKind::WorkerThrottle { scoped_name } => {
+                TimerId::from_string(format!("worker-throttle:{scoped_name}"))
+            }
+            TimerKind::DecisionTimeout(id) => {
+                TimerId::from_string(format!("decision-timeout:{id}"))
+            }
         }
     }
 }
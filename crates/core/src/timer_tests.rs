@@ -61,6 +61,10 @@ fn factory_methods_format() {
         TimerId::cooldown(&CrewId::from_string("crw-123"), "idle", 0).as_str(),
         "cooldown:crw-123:idle:0"
     );
+    assert_eq!(
+        TimerId::step_timeout(JobId::from_string("job-123"), "build").as_str(),
+        "step-timeout:job-123:build"
+    );
     assert_eq!(TimerId::queue_retry("bugs", "item-123").as_str(), "queue-retry:bugs:item-123");
     assert_eq!(
         TimerId::queue_retry("myns/bugs", "item-456").as_str(),
@@ -73,6 +77,17 @@ fn factory_methods_format() {
         TimerId::queue_poll("my-worker", "myproject").as_str(),
         "queue-poll:myproject/my-worker"
     );
+    assert_eq!(TimerId::queue_delay("bugs", "").as_str(), "queue-delay:bugs");
+    assert_eq!(TimerId::queue_delay("bugs", "myproject").as_str(), "queue-delay:myproject/bugs");
+    assert_eq!(TimerId::worker_throttle("fixer", "").as_str(), "worker-throttle:fixer");
+    assert_eq!(
+        TimerId::worker_throttle("fixer", "myproject").as_str(),
+        "worker-throttle:myproject/fixer"
+    );
+    assert_eq!(
+        TimerId::decision_timeout(DecisionId::from_string("dcn-123")).as_str(),
+        "decision-timeout:dcn-123"
+    );
 }
 
 #[test]
@@ -97,9 +112,33 @@ fn owner_id_extraction() {
         TimerId::liveness(&CrewId::from_string("crw-456")).owner_id(),
         Some(OwnerId::Crew(CrewId::from_string("crw-456")))
     );
+    assert_eq!(
+        TimerId::step_timeout(JobId::from_string("job-123"), "build").owner_id(),
+        Some(OwnerId::Job(JobId::from_string("job-123")))
+    );
     assert_eq!(TimerId::cron("janitor", "").owner_id(), None);
 }
 
+#[test]
+fn step_timeout_parse() {
+    assert_eq!(
+        TimerKind::parse("step-timeout:job-123:build"),
+        Some(TimerKind::StepTimeout { owner: JobId::from_string("job-123").into(), step: "build" })
+    );
+    assert!(TimerKind::parse("step-timeout:job-123").is_none());
+    assert!(TimerKind::parse("step-timeout:job-123:").is_none());
+}
+
+#[test]
+fn decision_timeout_parse() {
+    assert_eq!(
+        TimerKind::parse("decision-timeout:dcn-123"),
+        Some(TimerKind::DecisionTimeout(DecisionId::from_string("dcn-123")))
+    );
+    assert!(TimerKind::parse("decision-timeout:").is_none());
+    assert_eq!(TimerId::decision_timeout(DecisionId::from_string("dcn-123")).owner_id(), None);
+}
+
 #[test]
 fn kind_unknown_returns_none() {
     assert!(TimerId::from_string("other-timer").kind().is_none());
@@ -123,12 +162,18 @@ fn timer_kind_round_trip_all_factory_methods() {
         TimerId::exit_deferred(&CrewId::from_string("crw-ar1")),
         TimerId::cooldown(&CrewId::from_string("crw-ar1"), "idle", 0),
         TimerId::cooldown(&CrewId::from_string("crw-ar1"), "exit", 5),
+        TimerId::step_timeout(JobId::from_string("job-j1"), "build"),
         TimerId::queue_retry("bugs", "item-1"),
         TimerId::queue_retry("ns/bugs", "item-2"),
         TimerId::cron("janitor", ""),
         TimerId::cron("janitor", "myns"),
         TimerId::queue_poll("worker", ""),
         TimerId::queue_poll("worker", "myns"),
+        TimerId::queue_delay("bugs", ""),
+        TimerId::queue_delay("bugs", "myns"),
+        TimerId::worker_throttle("worker", ""),
+        TimerId::worker_throttle("worker", "myns"),
+        TimerId::decision_timeout(DecisionId::from_string("dcn-d1")),
     ];
 
     for timer_id in &cases {
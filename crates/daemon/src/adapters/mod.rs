@@ -13,7 +13,9 @@ pub use agent::attach_proxy::ws_proxy_bridge_tcp;
 pub use agent::{
     AgentAdapter, AgentAdapterError, AgentConfig, AgentReconnectConfig, RuntimeRouter,
 };
-pub use notify::{notify_adapter, NotifyAdapter};
+pub use notify::{
+    notify_adapter, NotifyAdapter, NotifyDedup, NotifyRouting, NotifySink, WebhookNotifyAdapter,
+};
 pub use workspace::{workspace_adapter, WorkspaceAdapter};
 
 // Test support - only compiled for tests or when explicitly requested
@@ -2,18 +2,12 @@
 
 use super::{NotifyAdapter, NotifyError};
 use async_trait::async_trait;
+use oj_core::Notification;
 use parking_lot::Mutex;
 use std::sync::Arc;
 
-/// Recorded notification
-#[derive(Debug, Clone)]
-pub struct NotifyCall {
-    pub title: String,
-    pub message: String,
-}
-
 struct FakeNotifyState {
-    calls: Vec<NotifyCall>,
+    calls: Vec<Notification>,
 }
 
 /// Fake notification adapter for testing
@@ -34,18 +28,15 @@ impl FakeNotifyAdapter {
     }
 
     /// Get all recorded notifications
-    pub fn calls(&self) -> Vec<NotifyCall> {
+    pub fn calls(&self) -> Vec<Notification> {
         self.inner.lock().calls.clone()
     }
 }
 
 #[async_trait]
 impl NotifyAdapter for FakeNotifyAdapter {
-    async fn notify(&self, title: &str, message: &str) -> Result<(), NotifyError> {
-        self.inner
-            .lock()
-            .calls
-            .push(NotifyCall { title: title.to_string(), message: message.to_string() });
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        self.inner.lock().calls.push(notification.clone());
         Ok(())
     }
 }
@@ -0,0 +1,194 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Minimal HTTP/1.1 client for webhook delivery.
+//!
+//! Sends a single POST per connection (`Connection: close`) over TCP, or TLS
+//! for `https` URLs, and reads the response until the server closes it.
+
+use super::NotifyError;
+use std::collections::BTreeMap;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::{self, pki_types::ServerName};
+
+/// Responses larger than this are truncated; only the status line and a short
+/// error body are ever used.
+const MAX_RESPONSE_BYTES: u64 = 64 * 1024;
+
+/// A parsed `http://` or `https://` URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct HttpUrl {
+    pub tls: bool,
+    /// Host as written, including brackets for IPv6 literals
+    pub host: String,
+    pub port: u16,
+    /// Path and query, always starting with `/`
+    pub path: String,
+}
+
+impl HttpUrl {
+    pub fn parse(url: &str) -> Result<Self, NotifyError> {
+        let invalid =
+            |reason: &str| NotifyError::InvalidUrl { url: url.to_string(), reason: reason.into() };
+        let (tls, rest) = if let Some(rest) = url.strip_prefix("https://") {
+            (true, rest)
+        } else if let Some(rest) = url.strip_prefix("http://") {
+            (false, rest)
+        } else {
+            return Err(invalid("scheme must be http or https"));
+        };
+
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(i) if rest.as_bytes()[i] == b'?' => (&rest[..i], format!("/{}", &rest[i..])),
+            Some(i) => (&rest[..i], rest[i..].to_string()),
+            None => (rest, "/".to_string()),
+        };
+        if authority.contains('@') {
+            return Err(invalid("credentials in the URL are not supported"));
+        }
+
+        // Split off the port, leaving IPv6 literals ("[::1]:8080") intact
+        let port_sep = match authority.rfind(':') {
+            Some(i) if !authority[i..].contains(']') => Some(i),
+            _ => None,
+        };
+        let (host, port) = match port_sep {
+            Some(i) => {
+                let port =
+                    authority[i + 1..].parse::<u16>().map_err(|_| invalid("invalid port"))?;
+                (&authority[..i], port)
+            }
+            None => (authority, if tls { 443 } else { 80 }),
+        };
+        if host.is_empty() {
+            return Err(invalid("missing host"));
+        }
+        Ok(Self { tls, host: host.to_string(), port, path })
+    }
+
+    /// Host without IPv6 brackets, for DNS lookup and TLS server name.
+    fn bare_host(&self) -> &str {
+        self.host.trim_start_matches('[').trim_end_matches(']')
+    }
+
+    /// Value for the `Host` header (port omitted when it is the default).
+    fn host_header(&self) -> String {
+        let default_port = if self.tls { 443 } else { 80 };
+        if self.port == default_port {
+            self.host.clone()
+        } else {
+            format!("{}:{}", self.host, self.port)
+        }
+    }
+}
+
+/// POST a JSON body, returning the response status and body.
+///
+/// `timeout` covers the whole exchange (connect, TLS, write and read).
+pub(super) async fn post_json(
+    url: &HttpUrl,
+    headers: &BTreeMap<String, String>,
+    body: &str,
+    timeout: Duration,
+) -> Result<(u16, String), NotifyError> {
+    let mut request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: oj/{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+        url.path,
+        url.host_header(),
+        env!("CARGO_PKG_VERSION"),
+        body.len()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+
+    tokio::time::timeout(timeout, send(url, &request)).await.map_err(|_| NotifyError::Timeout)?
+}
+
+async fn send(url: &HttpUrl, request: &str) -> Result<(u16, String), NotifyError> {
+    let tcp = TcpStream::connect((url.bare_host(), url.port))
+        .await
+        .map_err(|e| NotifyError::Connect(format!("{}:{}: {}", url.host, url.port, e)))?;
+    if !url.tls {
+        return exchange(tcp, request).await;
+    }
+
+    let server_name = ServerName::try_from(url.bare_host().to_string())
+        .map_err(|e| NotifyError::Tls(format!("invalid server name '{}': {}", url.host, e)))?;
+    let connector = tokio_rustls::TlsConnector::from(tls_config()?);
+    let stream = connector.connect(server_name, tcp).await.map_err(|e| {
+        // Handshake I/O failures are transient; certificate problems are not
+        if e.kind() == std::io::ErrorKind::InvalidData {
+            NotifyError::Tls(e.to_string())
+        } else {
+            NotifyError::Io(e)
+        }
+    })?;
+    exchange(stream, request).await
+}
+
+async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(
+    mut stream: S,
+    request: &str,
+) -> Result<(u16, String), NotifyError> {
+    stream.write_all(request.as_bytes()).await?;
+    stream.flush().await?;
+
+    let mut raw = Vec::new();
+    (&mut stream).take(MAX_RESPONSE_BYTES).read_to_end(&mut raw).await?;
+    parse_response(&raw)
+}
+
+/// Parse the status code and body out of a raw HTTP/1.1 response.
+pub(super) fn parse_response(raw: &[u8]) -> Result<(u16, String), NotifyError> {
+    let text = String::from_utf8_lossy(raw);
+    let status = text
+        .lines()
+        .next()
+        .filter(|line| line.starts_with("HTTP/"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| {
+            NotifyError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "malformed HTTP response",
+            ))
+        })?;
+    let body = text.split_once("\r\n\r\n").map(|(_, b)| b.trim().to_string()).unwrap_or_default();
+    Ok((status, body))
+}
+
+/// Shared TLS client config using the platform's root certificates.
+fn tls_config() -> Result<Arc<rustls::ClientConfig>, NotifyError> {
+    static CONFIG: OnceLock<Result<Arc<rustls::ClientConfig>, String>> = OnceLock::new();
+    CONFIG
+        .get_or_init(|| {
+            let mut roots = rustls::RootCertStore::empty();
+            let native = rustls_native_certs::load_native_certs();
+            for err in &native.errors {
+                tracing::warn!(error = %err, "failed to load a native root certificate");
+            }
+            let (added, _ignored) = roots.add_parsable_certificates(native.certs);
+            if added == 0 {
+                return Err("no root certificates found".to_string());
+            }
+            let provider = Arc::new(rustls::crypto::ring::default_provider());
+            let config = rustls::ClientConfig::builder_with_provider(provider)
+                .with_safe_default_protocol_versions()
+                .map_err(|e| e.to_string())?
+                .with_root_certificates(roots)
+                .with_no_client_auth();
+            Ok(Arc::new(config))
+        })
+        .clone()
+        .map_err(NotifyError::Tls)
+}
+
+#[cfg(test)]
+#[path = "http_tests.rs"]
+mod tests;
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[yare::parameterized(
+    plain = { "http://hooks.local/notify", false, "hooks.local", 80, "/notify" },
+    https_default = { "https://hooks.slack.com/services/T0/B0/X", true, "hooks.slack.com", 443, "/services/T0/B0/X" },
+    explicit_port = { "http://127.0.0.1:8080/hook", false, "127.0.0.1", 8080, "/hook" },
+    no_path = { "http://localhost:9000", false, "localhost", 9000, "/" },
+    query_only = { "http://localhost?token=abc", false, "localhost", 80, "/?token=abc" },
+    ipv6 = { "http://[::1]:8065/hooks/x", false, "[::1]", 8065, "/hooks/x" },
+)]
+fn parses_url(input: &str, tls: bool, host: &str, port: u16, path: &str) {
+    let url = HttpUrl::parse(input).unwrap();
+    assert_eq!(url, HttpUrl { tls, host: host.to_string(), port, path: path.to_string() });
+}
+
+#[yare::parameterized(
+    scheme = { "ftp://example.com/x", "scheme must be http or https" },
+    bare = { "example.com/x", "scheme must be http or https" },
+    bad_port = { "http://example.com:99999/", "invalid port" },
+    no_host = { "http:///path", "missing host" },
+    credentials = { "https://user:pw@example.com/", "credentials" },
+)]
+fn rejects_url(input: &str, expected: &str) {
+    let err = HttpUrl::parse(input).unwrap_err();
+    assert!(matches!(err, NotifyError::InvalidUrl { .. }), "{:?}", err);
+    assert!(err.to_string().contains(expected), "'{}' gave: {}", input, err);
+}
+
+#[test]
+fn host_header_omits_default_port() {
+    assert_eq!(HttpUrl::parse("https://example.com/").unwrap().host_header(), "example.com");
+    assert_eq!(HttpUrl::parse("http://example.com:81/").unwrap().host_header(), "example.com:81");
+    assert_eq!(HttpUrl::parse("http://[::1]:81/").unwrap().bare_host(), "::1");
+}
+
+#[test]
+fn parses_response_status_and_body() {
+    let raw = b"HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nno_team\r\n";
+    assert_eq!(parse_response(raw).unwrap(), (404, "no_team".to_string()));
+}
+
+#[test]
+fn malformed_response_is_io_error() {
+    assert!(matches!(parse_response(b"garbage"), Err(NotifyError::Io(_))));
+    assert!(matches!(parse_response(b""), Err(NotifyError::Io(_))));
+}
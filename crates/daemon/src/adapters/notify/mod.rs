@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Notification adapters
+//!
+//! Desktop notifications for local use, and HTTP webhooks (generic JSON or
+//! Slack/Mattermost-compatible) for headless and Kubernetes deployments.
+//! Per-project routing rules pick which of these each notification goes to.
+
+mod http;
+pub mod routing;
+pub mod webhook;
+
+pub use routing::{NotifyDedup, NotifyRouting, NotifySink};
+pub use webhook::{WebhookConfig, WebhookNotifyAdapter};
+
+use async_trait::async_trait;
+use oj_core::Notification;
+use thiserror::Error;
+
+/// Errors from notify operations
+#[derive(Debug, Error)]
+pub enum NotifyError {
+    #[error("invalid webhook url '{url}': {reason}")]
+    InvalidUrl { url: String, reason: String },
+    #[error("webhook connect failed: {0}")]
+    Connect(String),
+    #[error("webhook TLS error: {0}")]
+    Tls(String),
+    #[error("webhook request failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("webhook request timed out")]
+    Timeout,
+    #[error("webhook returned HTTP {status}: {body}")]
+    Status { status: u16, body: String },
+}
+
+impl NotifyError {
+    /// Whether sending again might succeed: transport failures, timeouts,
+    /// rate limiting (429) and server errors (5xx). Bad URLs and other 4xx
+    /// responses are permanent.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            NotifyError::InvalidUrl { .. } | NotifyError::Tls(_) => false,
+            NotifyError::Connect(_) | NotifyError::Io(_) | NotifyError::Timeout => true,
+            NotifyError::Status { status, .. } => *status == 429 || *status >= 500,
+        }
+    }
+}
+
+/// Adapter for sending notifications
+#[async_trait]
+pub trait NotifyAdapter: Send + Sync + 'static {
+    /// Send a notification and its triggering context
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError>;
+}
+
+/// Desktop notification adapter using notify-rust.
+///
+/// On macOS, `notify-rust` uses `mac-notification-sys` (Cocoa bindings) to send
+/// notifications via the Notification Center. The first notification triggers
+/// `ensure_application_set()` which runs an AppleScript to look up a bundle
+/// identifier. In a daemon context without Automation permissions, that
+/// AppleScript blocks forever. We pre-set the bundle identifier at construction
+/// time to bypass the lookup entirely.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DesktopNotifyAdapter;
+
+impl DesktopNotifyAdapter {
+    pub fn new() -> Self {
+        #[cfg(target_os = "macos")]
+        {
+            // Pre-set the application bundle identifier so mac-notification-sys
+            // skips its NSAppleScript lookup (which blocks forever in daemon
+            // processes that lack Automation permissions).
+            let _ = mac_notification_sys::set_application("com.apple.Terminal");
+        }
+        Self
+    }
+}
+
+#[async_trait]
+impl NotifyAdapter for DesktopNotifyAdapter {
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let title = notification.title.clone();
+        let message = notification.message.clone();
+        #[cfg(all(unix, not(target_os = "macos")))]
+        let urgency = match notification.severity {
+            oj_core::NotifySeverity::Info => notify_rust::Urgency::Normal,
+            oj_core::NotifySeverity::Warning | oj_core::NotifySeverity::Error => {
+                notify_rust::Urgency::Critical
+            }
+        };
+        // notify_rust::Notification::show() is synchronous on macOS.
+        // Fire-and-forget on tokio's bounded blocking thread pool to avoid
+        // blocking the async runtime while capping OS thread count.
+        tokio::task::spawn_blocking(move || {
+            tracing::info!(%title, %message, "sending desktop notification");
+            let mut desktop = notify_rust::Notification::new();
+            desktop.summary(&title).body(&message);
+            #[cfg(all(unix, not(target_os = "macos")))]
+            desktop.urgency(urgency);
+            match desktop.show() {
+                Ok(_) => {
+                    tracing::info!(%title, "desktop notification sent");
+                }
+                Err(e) => {
+                    tracing::warn!(%title, error = %e, "desktop notification failed");
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+/// Create a desktop notification adapter
+pub fn notify_adapter() -> std::sync::Arc<dyn NotifyAdapter> {
+    std::sync::Arc::new(DesktopNotifyAdapter::new())
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
+
+#[cfg(test)]
+mod fake;
+
+#[cfg(test)]
+pub use fake::FakeNotifyAdapter;
@@ -2,16 +2,18 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::*;
+use oj_core::NotifyKind;
 
 #[tokio::test]
 async fn fake_notify_records_calls() {
     let adapter = FakeNotifyAdapter::new();
 
-    adapter.notify("Build", "Job started").await.unwrap();
-    adapter.notify("Build", "Job completed").await.unwrap();
+    adapter.notify(&Notification::new(NotifyKind::OnStart, "Build", "Job started")).await.unwrap();
+    adapter.notify(&Notification::new(NotifyKind::OnDone, "Build", "Job completed")).await.unwrap();
 
     let calls = adapter.calls();
     assert_eq!(calls.len(), 2);
     assert_eq!(calls[0].title, "Build");
     assert_eq!(calls[0].message, "Job started");
+    assert_eq!(calls[1].kind, NotifyKind::OnDone);
 }
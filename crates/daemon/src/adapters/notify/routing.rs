@@ -0,0 +1,366 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Notification routing.
+//!
+//! A project's `.oj/config.toml` names its sinks and lists `[[notify.route]]`
+//! rules mapping notification kinds to sinks, with optional quiet hours and
+//! de-duplication windows. Projects without routes send the original kinds
+//! (start, done, fail, escalation) to their webhook, or the desktop when none
+//! is configured.
+//!
+//! ```toml
+//! [notify.sinks.pager]
+//! url = "https://events.example.com/oj"
+//!
+//! [[notify.route]]
+//! kinds = ["dead_letter"]
+//! sinks = ["pager"]
+//! dedup = "30m"
+//!
+//! [[notify.route]]
+//! kinds = ["on_fail", "escalation"]
+//! sinks = ["webhook"]
+//! quiet_hours = "22:00-07:00"
+//! timezone = "local"
+//! ```
+
+use super::WebhookConfig;
+use crate::engine::parse_duration;
+use chrono::{FixedOffset, Local, TimeZone, Timelike, Utc};
+use oj_core::{Notification, NotifyKind, NotifySeverity};
+use oj_runbook::CronTimezone;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::Duration;
+
+/// Built-in sink for the daemon's own notifier.
+pub const DESKTOP_SINK: &str = "desktop";
+
+/// Sink defined by `[notify.webhook]` or `OJ_NOTIFY_WEBHOOK`.
+pub const WEBHOOK_SINK: &str = "webhook";
+
+/// Kinds delivered when a project has no routes.
+const DEFAULT_KINDS: [NotifyKind; 4] =
+    [NotifyKind::OnStart, NotifyKind::OnDone, NotifyKind::OnFail, NotifyKind::Escalation];
+
+/// Where a notification is delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NotifySink {
+    /// The daemon's notifier (desktop notifications)
+    Desktop,
+    Webhook(WebhookConfig),
+}
+
+/// A daily window, in a timezone, during which a route stays silent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    /// Start and end as minutes after midnight; the window wraps midnight
+    /// when `end` is before `start`
+    start: u32,
+    end: u32,
+    timezone: CronTimezone,
+}
+
+impl QuietHours {
+    /// Parse a range like "22:00-07:00".
+    pub fn parse(range: &str, timezone: CronTimezone) -> Result<Self, String> {
+        let invalid = || format!("invalid quiet_hours '{}': expected \"HH:MM-HH:MM\"", range);
+        let minutes = |s: &str| -> Option<u32> {
+            let (h, m) = s.trim().split_once(':')?;
+            let (h, m): (u32, u32) = (h.parse().ok()?, m.parse().ok()?);
+            (h < 24 && m < 60).then_some(h * 60 + m)
+        };
+        let (start, end) = range.split_once('-').ok_or_else(invalid)?;
+        let (start, end) = (minutes(start).ok_or_else(invalid)?, minutes(end).ok_or_else(invalid)?);
+        if start == end {
+            return Err(format!("quiet_hours '{}' is empty", range));
+        }
+        Ok(Self { start, end, timezone })
+    }
+
+    /// Whether the wall-clock time at `epoch_ms` falls inside the window.
+    pub fn contains(&self, epoch_ms: u64) -> bool {
+        let ms = epoch_ms as i64;
+        let (hour, minute) = match self.timezone {
+            CronTimezone::Utc => wall_clock(&Utc, ms),
+            CronTimezone::Local => wall_clock(&Local, ms),
+            CronTimezone::Fixed(secs) => match FixedOffset::east_opt(secs) {
+                Some(offset) => wall_clock(&offset, ms),
+                None => wall_clock(&Utc, ms),
+            },
+            CronTimezone::Named(tz) => wall_clock(&tz, ms),
+        };
+        let now = hour * 60 + minute;
+        if self.start < self.end {
+            (self.start..self.end).contains(&now)
+        } else {
+            now >= self.start || now < self.end
+        }
+    }
+}
+
+fn wall_clock<Tz: TimeZone>(tz: &Tz, epoch_ms: i64) -> (u32, u32) {
+    match tz.timestamp_millis_opt(epoch_ms).single() {
+        Some(t) => (t.hour(), t.minute()),
+        None => (0, 0),
+    }
+}
+
+/// One `[[notify.route]]` rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyRoute {
+    /// Kinds the route matches (empty for all)
+    pub kinds: Vec<NotifyKind>,
+    pub min_severity: NotifySeverity,
+    pub sinks: Vec<String>,
+    pub quiet_hours: Option<QuietHours>,
+    /// Identical notifications within this window are sent once
+    pub dedup: Option<Duration>,
+}
+
+impl NotifyRoute {
+    fn matches(&self, notification: &Notification) -> bool {
+        (self.kinds.is_empty() || self.kinds.contains(&notification.kind))
+            && notification.severity >= self.min_severity
+    }
+}
+
+/// Route as written in the config file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RouteConfig {
+    #[serde(default)]
+    kinds: Vec<NotifyKind>,
+    #[serde(default)]
+    min_severity: NotifySeverity,
+    sinks: Vec<String>,
+    #[serde(default)]
+    quiet_hours: Option<String>,
+    #[serde(default)]
+    timezone: Option<String>,
+    #[serde(default)]
+    dedup: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ProjectConfigFile {
+    #[serde(default)]
+    notify: Option<NotifySection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotifySection {
+    #[serde(default)]
+    webhook: Option<WebhookConfig>,
+    #[serde(default)]
+    sinks: BTreeMap<String, WebhookConfig>,
+    #[serde(default)]
+    route: Vec<RouteConfig>,
+}
+
+/// Sinks and routes for one project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotifyRouting {
+    sinks: BTreeMap<String, NotifySink>,
+    routes: Vec<NotifyRoute>,
+}
+
+impl NotifyRouting {
+    /// Routing for a project: its `[notify]` config, else the defaults.
+    ///
+    /// The project config is read on every call so edits take effect
+    /// without restarting the daemon.
+    pub fn resolve(project_path: Option<&Path>) -> Self {
+        project_path.and_then(Self::from_project).unwrap_or_else(Self::defaults)
+    }
+
+    /// Read the `[notify]` section from `<project>/.oj/config.toml`.
+    ///
+    /// A missing file or section yields `None`; an invalid one is logged and
+    /// ignored so a typo never silences the default notifier.
+    pub fn from_project(project_path: &Path) -> Option<Self> {
+        let config_path = project_path.join(".oj/config.toml");
+        let content = std::fs::read_to_string(&config_path).ok()?;
+        let parsed = toml::from_str::<ProjectConfigFile>(&content)
+            .map_err(|e| e.message().to_string())
+            .and_then(|file| file.notify.map(Self::from_section).transpose());
+        match parsed {
+            Ok(routing) => routing,
+            Err(e) => {
+                tracing::warn!(
+                    path = %config_path.display(),
+                    error = %e,
+                    "ignoring invalid [notify] config"
+                );
+                None
+            }
+        }
+    }
+
+    /// The daemon-wide webhook from `OJ_NOTIFY_WEBHOOK` if set, else the
+    /// desktop, for the default kinds.
+    pub fn defaults() -> Self {
+        Self::with_webhook(WebhookConfig::from_env(), Vec::new())
+    }
+
+    fn with_webhook(webhook: Option<WebhookConfig>, routes: Vec<NotifyRoute>) -> Self {
+        let mut sinks = BTreeMap::from([(DESKTOP_SINK.to_string(), NotifySink::Desktop)]);
+        let default_sink = match webhook {
+            Some(config) => {
+                sinks.insert(WEBHOOK_SINK.to_string(), NotifySink::Webhook(config));
+                WEBHOOK_SINK
+            }
+            None => DESKTOP_SINK,
+        };
+        let routes = if routes.is_empty() {
+            vec![NotifyRoute {
+                kinds: DEFAULT_KINDS.to_vec(),
+                min_severity: NotifySeverity::Info,
+                sinks: vec![default_sink.to_string()],
+                quiet_hours: None,
+                dedup: None,
+            }]
+        } else {
+            routes
+        };
+        Self { sinks, routes }
+    }
+
+    fn from_section(section: NotifySection) -> Result<Self, String> {
+        if let Some(webhook) = &section.webhook {
+            webhook.validate().map_err(|e| format!("[notify.webhook]: {}", e))?;
+        }
+        let mut routes = Vec::with_capacity(section.route.len());
+        for (i, route) in section.route.into_iter().enumerate() {
+            routes.push(Self::parse_route(route).map_err(|e| format!("route {}: {}", i + 1, e))?);
+        }
+
+        let webhook = section.webhook.or_else(WebhookConfig::from_env);
+        let mut routing = Self::with_webhook(webhook, routes);
+        for (name, config) in section.sinks {
+            if name == DESKTOP_SINK || name == WEBHOOK_SINK {
+                return Err(format!("sink name '{}' is reserved", name));
+            }
+            config.validate().map_err(|e| format!("sink '{}': {}", name, e))?;
+            routing.sinks.insert(name, NotifySink::Webhook(config));
+        }
+        for route in &routing.routes {
+            if let Some(name) = route.sinks.iter().find(|s| !routing.sinks.contains_key(*s)) {
+                return Err(format!("route references unknown sink '{}'", name));
+            }
+        }
+        Ok(routing)
+    }
+
+    fn parse_route(route: RouteConfig) -> Result<NotifyRoute, String> {
+        if route.sinks.is_empty() {
+            return Err("sinks must not be empty".to_string());
+        }
+        let timezone = match &route.timezone {
+            Some(tz) if route.quiet_hours.is_none() => {
+                return Err(format!("timezone '{}' requires quiet_hours", tz));
+            }
+            Some(tz) => CronTimezone::parse(tz)?,
+            None => CronTimezone::default(),
+        };
+        let quiet_hours =
+            route.quiet_hours.as_deref().map(|q| QuietHours::parse(q, timezone)).transpose()?;
+        let dedup = route
+            .dedup
+            .as_deref()
+            .map(|d| parse_duration(d).map_err(|e| format!("dedup: {}", e)))
+            .transpose()?;
+        Ok(NotifyRoute {
+            kinds: route.kinds,
+            min_severity: route.min_severity,
+            sinks: route.sinks,
+            quiet_hours,
+            dedup,
+        })
+    }
+
+    /// Sinks a notification goes to at `epoch_ms`: the union of the sinks of
+    /// every matching route that is outside its quiet hours and has not sent
+    /// the same notification within its dedup window.
+    pub fn route(
+        &self,
+        notification: &Notification,
+        epoch_ms: u64,
+        dedup: &mut NotifyDedup,
+    ) -> Vec<(&str, &NotifySink)> {
+        let mut targets: Vec<(&str, &NotifySink)> = Vec::new();
+        for (index, route) in self.routes.iter().enumerate() {
+            if !route.matches(notification) {
+                continue;
+            }
+            if route.quiet_hours.is_some_and(|q| q.contains(epoch_ms)) {
+                tracing::debug!(
+                    kind = %notification.kind,
+                    title = %notification.title,
+                    route = index + 1,
+                    "notification suppressed by quiet hours"
+                );
+                continue;
+            }
+            if let Some(window) = route.dedup {
+                if !dedup.first_within(index, notification, window, epoch_ms) {
+                    tracing::debug!(
+                        kind = %notification.kind,
+                        title = %notification.title,
+                        route = index + 1,
+                        "duplicate notification suppressed"
+                    );
+                    continue;
+                }
+            }
+            for name in &route.sinks {
+                if let Some((name, sink)) = self.sinks.get_key_value(name) {
+                    if !targets.iter().any(|(n, _)| *n == name) {
+                        targets.push((name, sink));
+                    }
+                }
+            }
+        }
+        targets
+    }
+}
+
+/// Recently routed notifications, for route `dedup` windows.
+#[derive(Debug, Default)]
+pub struct NotifyDedup {
+    /// Expiry (epoch ms) keyed by project, route index, kind, title and message
+    sent: HashMap<(String, usize, NotifyKind, String, String), u64>,
+}
+
+impl NotifyDedup {
+    /// Record `notification` for `route`, returning false if the same one was
+    /// already recorded less than `window` ago.
+    fn first_within(
+        &mut self,
+        route: usize,
+        notification: &Notification,
+        window: Duration,
+        epoch_ms: u64,
+    ) -> bool {
+        self.sent.retain(|_, expires| *expires > epoch_ms);
+        let key = (
+            notification.project.clone(),
+            route,
+            notification.kind,
+            notification.title.clone(),
+            notification.message.clone(),
+        );
+        if self.sent.contains_key(&key) {
+            return false;
+        }
+        self.sent.insert(key, epoch_ms.saturating_add(window.as_millis() as u64));
+        true
+    }
+}
+
+#[cfg(test)]
+#[path = "routing_tests.rs"]
+mod tests;
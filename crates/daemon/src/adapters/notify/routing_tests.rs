@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::adapters::notify::webhook::WebhookFormat;
+
+/// 2026-01-15 12:00:00 UTC
+const NOON_UTC: u64 = 1_768_478_400_000;
+const HOUR_MS: u64 = 3_600_000;
+const MINUTE_MS: u64 = 60_000;
+
+fn project_with_config(content: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join(".oj")).unwrap();
+    std::fs::write(dir.path().join(".oj/config.toml"), content).unwrap();
+    dir
+}
+
+fn routing(content: &str) -> NotifyRouting {
+    let dir = project_with_config(content);
+    NotifyRouting::from_project(dir.path()).unwrap()
+}
+
+fn sink_names(routing: &NotifyRouting, n: &Notification, epoch_ms: u64) -> Vec<String> {
+    routing
+        .route(n, epoch_ms, &mut NotifyDedup::default())
+        .into_iter()
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+fn dead_letter() -> Notification {
+    Notification::new(NotifyKind::DeadLetter, "Dead letter: bugs", "Item 7 failed").project("api")
+}
+
+const ON_CALL: &str = r#"
+[notify.webhook]
+url = "https://chat.example.com/hooks/abc"
+format = "slack"
+
+[notify.sinks.pager]
+url = "https://events.example.com/oj"
+
+[[notify.route]]
+kinds = ["dead_letter"]
+sinks = ["pager", "webhook"]
+
+[[notify.route]]
+kinds = ["on_fail", "escalation", "worker_stopped"]
+sinks = ["webhook"]
+"#;
+
+#[yare::parameterized(
+    dead_letter = { NotifyKind::DeadLetter, &["pager", "webhook"] },
+    on_fail = { NotifyKind::OnFail, &["webhook"] },
+    worker_stopped = { NotifyKind::WorkerStopped, &["webhook"] },
+    on_done = { NotifyKind::OnDone, &[] },
+)]
+fn routes_kinds_to_named_sinks(kind: NotifyKind, expected: &[&str]) {
+    let n = Notification::new(kind, "t", "m");
+    assert_eq!(sink_names(&routing(ON_CALL), &n, NOON_UTC), expected);
+}
+
+#[test]
+fn sinks_are_delivered_once_across_routes() {
+    let routing = routing(
+        r#"
+[notify.sinks.pager]
+url = "https://events.example.com/oj"
+
+[[notify.route]]
+sinks = ["pager", "desktop"]
+
+[[notify.route]]
+kinds = ["on_fail"]
+sinks = ["pager"]
+"#,
+    );
+    let n = Notification::new(NotifyKind::OnFail, "t", "m");
+    assert_eq!(sink_names(&routing, &n, NOON_UTC), ["pager", "desktop"]);
+}
+
+#[test]
+fn named_sink_keeps_webhook_settings() {
+    let routing = routing(ON_CALL);
+    let targets = routing.route(&dead_letter(), NOON_UTC, &mut NotifyDedup::default());
+    assert_eq!(
+        targets[1].1,
+        &NotifySink::Webhook(
+            WebhookConfig::new("https://chat.example.com/hooks/abc").format(WebhookFormat::Slack)
+        )
+    );
+}
+
+#[test]
+fn min_severity_filters_matches() {
+    let routing = routing("[[notify.route]]\nmin_severity = \"warning\"\nsinks = [\"desktop\"]\n");
+    let info = Notification::new(NotifyKind::OnDone, "t", "m");
+    let error = Notification::new(NotifyKind::OnDone, "t", "m").severity(NotifySeverity::Error);
+    assert!(sink_names(&routing, &info, NOON_UTC).is_empty());
+    assert_eq!(sink_names(&routing, &error, NOON_UTC), ["desktop"]);
+}
+
+#[test]
+fn without_routes_sends_default_kinds_to_webhook() {
+    let routing = routing("[notify.webhook]\nurl = \"http://127.0.0.1:9/\"\n");
+    let fail = Notification::new(NotifyKind::OnFail, "t", "m");
+    assert_eq!(sink_names(&routing, &fail, NOON_UTC), ["webhook"]);
+    assert!(sink_names(&routing, &dead_letter(), NOON_UTC).is_empty());
+}
+
+#[test]
+fn defaults_send_default_kinds_to_desktop() {
+    let routing = NotifyRouting::with_webhook(None, Vec::new());
+    let escalation = Notification::new(NotifyKind::Escalation, "t", "m");
+    let stopped = Notification::new(NotifyKind::WorkerStopped, "t", "m");
+    assert_eq!(sink_names(&routing, &escalation, NOON_UTC), ["desktop"]);
+    assert!(sink_names(&routing, &stopped, NOON_UTC).is_empty());
+}
+
+#[yare::parameterized(
+    before_window = { 21, false },
+    start = { 22, true },
+    after_midnight = { 26, true },
+    end = { 31, false },
+)]
+fn quiet_hours_wrap_midnight(hours_after_midnight: u64, quiet: bool) {
+    let routing = routing(
+        "[[notify.route]]\nsinks = [\"desktop\"]\nquiet_hours = \"22:00-07:00\"\ntimezone = \"UTC\"\n",
+    );
+    let midnight = NOON_UTC - 12 * HOUR_MS;
+    let at = midnight + hours_after_midnight * HOUR_MS;
+    let n = Notification::new(NotifyKind::OnFail, "t", "m");
+    assert_eq!(sink_names(&routing, &n, at).is_empty(), quiet);
+}
+
+#[test]
+fn quiet_hours_use_route_timezone() {
+    // 12:00 UTC is 21:00 at +09:00
+    let tokyo = QuietHours::parse("20:30-21:30", CronTimezone::parse("+09:00").unwrap()).unwrap();
+    let utc = QuietHours::parse("20:30-21:30", CronTimezone::Utc).unwrap();
+    assert!(tokyo.contains(NOON_UTC));
+    assert!(!utc.contains(NOON_UTC));
+}
+
+#[test]
+fn quiet_route_does_not_silence_others() {
+    let routing = routing(
+        r#"
+[notify.sinks.pager]
+url = "https://events.example.com/oj"
+
+[[notify.route]]
+kinds = ["on_fail"]
+sinks = ["desktop"]
+quiet_hours = "09:00-17:00"
+
+[[notify.route]]
+kinds = ["dead_letter"]
+sinks = ["pager"]
+"#,
+    );
+    let fail = Notification::new(NotifyKind::OnFail, "t", "m");
+    assert!(sink_names(&routing, &fail, NOON_UTC).is_empty());
+    assert_eq!(sink_names(&routing, &dead_letter(), NOON_UTC), ["pager"]);
+}
+
+#[test]
+fn dedup_suppresses_repeats_within_window() {
+    let routing = routing("[[notify.route]]\nsinks = [\"desktop\"]\ndedup = \"10m\"\n");
+    let mut dedup = NotifyDedup::default();
+    let mut send = |n: &Notification, at: u64| routing.route(n, at, &mut dedup).len();
+
+    assert_eq!(send(&dead_letter(), NOON_UTC), 1);
+    assert_eq!(send(&dead_letter(), NOON_UTC + 5 * MINUTE_MS), 0);
+    let other = dead_letter().project("web");
+    assert_eq!(send(&other, NOON_UTC + 5 * MINUTE_MS), 1);
+    assert_eq!(send(&dead_letter(), NOON_UTC + 10 * MINUTE_MS), 1);
+}
+
+#[test]
+fn routes_without_dedup_always_send() {
+    let routing = routing("[[notify.route]]\nsinks = [\"desktop\"]\n");
+    let mut dedup = NotifyDedup::default();
+    assert_eq!(routing.route(&dead_letter(), NOON_UTC, &mut dedup).len(), 1);
+    assert_eq!(routing.route(&dead_letter(), NOON_UTC, &mut dedup).len(), 1);
+}
+
+#[yare::parameterized(
+    plain = { "09:00-17:30", 540, 1050 },
+    wrapping = { "22:00-07:00", 1320, 420 },
+    spaced = { "22:00 - 07:00", 1320, 420 },
+)]
+fn parses_quiet_hours(input: &str, start: u32, end: u32) {
+    let q = QuietHours::parse(input, CronTimezone::Utc).unwrap();
+    assert_eq!((q.start, q.end), (start, end));
+}
+
+#[yare::parameterized(
+    no_dash = { "22:00" },
+    bad_hour = { "24:00-07:00" },
+    bad_minute = { "22:60-07:00" },
+    no_minutes = { "22-07" },
+    empty = { "08:00-08:00" },
+)]
+fn rejects_quiet_hours(input: &str) {
+    assert!(QuietHours::parse(input, CronTimezone::Utc).is_err());
+}
+
+#[test]
+fn reads_project_webhook_config() {
+    let dir = project_with_config(
+        r#"
+[project]
+name = "api"
+
+[notify.webhook]
+url = "https://chat.example.com/hooks/abc"
+format = "mattermost"
+retries = 5
+headers = { "X-Team" = "platform" }
+"#,
+    );
+
+    let routing = NotifyRouting::from_project(dir.path()).unwrap();
+
+    let Some(NotifySink::Webhook(config)) = routing.sinks.get(WEBHOOK_SINK) else {
+        panic!("expected webhook sink, got {:?}", routing.sinks);
+    };
+    assert_eq!(config.url, "https://chat.example.com/hooks/abc");
+    assert_eq!(config.format, WebhookFormat::Slack);
+    assert_eq!(config.retries, 5);
+    assert_eq!(config.headers.get("X-Team").map(String::as_str), Some("platform"));
+}
+
+#[test]
+fn project_webhook_defaults() {
+    let routing = routing("[notify.webhook]\nurl = \"http://127.0.0.1:9/\"\n");
+    assert_eq!(
+        routing.sinks.get(WEBHOOK_SINK),
+        Some(&NotifySink::Webhook(WebhookConfig::new("http://127.0.0.1:9/")))
+    );
+}
+
+#[yare::parameterized(
+    no_file = { None },
+    no_section = { Some("[project]\nname = \"api\"\n") },
+    bad_url = { Some("[notify.webhook]\nurl = \"ftp://x\"\n") },
+    unknown_key = { Some("[notify.webhook]\nurl = \"http://x/\"\nformt = \"slack\"\n") },
+    bad_format = { Some("[notify.webhook]\nurl = \"http://x/\"\nformat = \"teams\"\n") },
+    bad_header = { Some("[notify.webhook]\nurl = \"http://x/\"\nheaders = { \"A:B\" = \"c\" }\n") },
+    not_toml = { Some("[notify.webhook\n") },
+    unknown_sink = { Some("[[notify.route]]\nsinks = [\"pager\"]\n") },
+    no_sinks = { Some("[[notify.route]]\nsinks = []\n") },
+    reserved_sink = { Some("[notify.sinks.desktop]\nurl = \"http://x/\"\n") },
+    bad_sink_url = { Some("[notify.sinks.pager]\nurl = \"x\"\n") },
+    unknown_kind = { Some("[[notify.route]]\nkinds = [\"on_crash\"]\nsinks = [\"desktop\"]\n") },
+    bad_dedup = { Some("[[notify.route]]\nsinks = [\"desktop\"]\ndedup = \"soon\"\n") },
+    bad_timezone = { Some("[[notify.route]]\nsinks = [\"desktop\"]\nquiet_hours = \"22:00-07:00\"\ntimezone = \"Mars/Olympus\"\n") },
+    timezone_alone = { Some("[[notify.route]]\nsinks = [\"desktop\"]\ntimezone = \"UTC\"\n") },
+    route_typo = { Some("[[notify.routes]]\nsinks = [\"desktop\"]\n") },
+)]
+fn no_project_routing(content: Option<&str>) {
+    let dir = match content {
+        Some(c) => project_with_config(c),
+        None => tempfile::tempdir().unwrap(),
+    };
+    assert_eq!(NotifyRouting::from_project(dir.path()), None);
+}
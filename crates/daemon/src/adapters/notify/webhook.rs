@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! HTTP webhook notifications.
+//!
+//! A project opts in with a `[notify.webhook]` table (or named
+//! `[notify.sinks.*]` tables) in `.oj/config.toml`; projects without one fall
+//! back to the daemon-wide `OJ_NOTIFY_WEBHOOK`, and then to desktop
+//! notifications. Failed deliveries are retried with exponential backoff when
+//! the failure looks transient.
+
+use super::http::{self, HttpUrl};
+use super::{NotifyAdapter, NotifyError};
+use async_trait::async_trait;
+use oj_core::{Notification, NotifySeverity};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Default number of retries after a failed delivery
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Error bodies are cut to this many characters in [`NotifyError::Status`]
+const MAX_ERROR_BODY: usize = 200;
+
+/// Payload shape posted to the webhook.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The notification as JSON, plus an `action` CLI hint when there is one
+    #[default]
+    Json,
+    /// Slack/Mattermost incoming-webhook shape: `text` plus an attachment
+    /// colored by severity with the context as fields
+    #[serde(alias = "mattermost")]
+    Slack,
+}
+
+impl WebhookFormat {
+    /// Parse "json", "slack" or "mattermost".
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Some(WebhookFormat::Json),
+            "slack" | "mattermost" => Some(WebhookFormat::Slack),
+            _ => None,
+        }
+    }
+
+    /// Build the JSON request body for a notification.
+    pub fn payload(self, notification: &Notification) -> serde_json::Value {
+        match self {
+            WebhookFormat::Json => {
+                let mut body = serde_json::to_value(notification).unwrap_or_default();
+                if let (Some(obj), Some(action)) =
+                    (body.as_object_mut(), notification.action_command())
+                {
+                    obj.insert("action".to_string(), action.into());
+                }
+                body
+            }
+            WebhookFormat::Slack => slack_payload(notification),
+        }
+    }
+}
+
+/// Slack/Mattermost message with an attachment carrying the context.
+fn slack_payload(n: &Notification) -> serde_json::Value {
+    let color = match n.severity {
+        NotifySeverity::Info => "#2eb886",
+        NotifySeverity::Warning => "#daa038",
+        NotifySeverity::Error => "#a30200",
+    };
+    let mut fields = Vec::new();
+    let mut field = |title: &str, value: String| {
+        fields.push(serde_json::json!({ "title": title, "value": value, "short": true }));
+    };
+    if !n.project.is_empty() {
+        field("Project", n.project.clone());
+    }
+    if let Some(owner) = &n.owner {
+        field("Owner", owner.to_string());
+    }
+    if let Some(step) = &n.step {
+        field("Step", step.clone());
+    }
+    if let Some(id) = &n.decision_id {
+        field("Decision", id.to_string());
+    }
+
+    let mut attachment = serde_json::json!({
+        "fallback": format!("{}: {}", n.title, n.message),
+        "color": color,
+        "fields": fields,
+    });
+    if let Some(action) = n.action_command() {
+        attachment["footer"] = action.into();
+    }
+    serde_json::json!({
+        "text": format!("*{}*\n{}", n.title, n.message),
+        "attachments": [attachment],
+    })
+}
+
+/// Webhook settings, from a project's `[notify.webhook]` or
+/// `[notify.sinks.<name>]` table, or the daemon's environment.
+///
+/// ```toml
+/// [notify.webhook]
+/// url = "https://hooks.slack.com/services/T000/B000/XXXX"
+/// format = "slack"
+/// retries = 3
+/// headers = { Authorization = "Bearer ..." }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    pub url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Retries after a failed delivery (default 3)
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+    /// Extra request headers, e.g. `Authorization`
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+}
+
+fn default_retries() -> u32 {
+    DEFAULT_RETRIES
+}
+
+impl WebhookConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            format: WebhookFormat::default(),
+            retries: DEFAULT_RETRIES,
+            headers: BTreeMap::new(),
+        }
+    }
+
+    pub fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Daemon-wide webhook from `OJ_NOTIFY_WEBHOOK` and
+    /// `OJ_NOTIFY_WEBHOOK_FORMAT`.
+    pub fn from_env() -> Option<Self> {
+        let url = crate::env::notify_webhook()?;
+        let format = match crate::env::notify_webhook_format() {
+            Some(f) => WebhookFormat::parse(&f).unwrap_or_else(|| {
+                tracing::warn!(format = %f, "unknown OJ_NOTIFY_WEBHOOK_FORMAT, using json");
+                WebhookFormat::Json
+            }),
+            None => WebhookFormat::Json,
+        };
+        Some(Self::new(url).format(format))
+    }
+
+    pub(super) fn validate(&self) -> Result<(), String> {
+        HttpUrl::parse(&self.url).map_err(|e| e.to_string())?;
+        for (name, value) in &self.headers {
+            let bad = |s: &str| s.contains(['\r', '\n']);
+            if name.is_empty() || bad(name) || bad(value) || name.contains(':') {
+                return Err(format!("invalid header '{}'", name));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Notification adapter that POSTs to an HTTP webhook.
+#[derive(Debug, Clone)]
+pub struct WebhookNotifyAdapter {
+    config: WebhookConfig,
+    /// Delay before the first retry; doubles on each further retry
+    retry_delay: Duration,
+    max_retry_delay: Duration,
+    /// Limit for a single delivery attempt
+    timeout: Duration,
+}
+
+impl WebhookNotifyAdapter {
+    pub fn new(config: WebhookConfig) -> Self {
+        Self {
+            config,
+            retry_delay: Duration::from_millis(500),
+            max_retry_delay: Duration::from_secs(10),
+            timeout: Duration::from_secs(10),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = delay;
+        self
+    }
+
+    #[cfg(test)]
+    pub(crate) fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Backoff before retry number `attempt` (0-based).
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_delay.saturating_mul(1u32 << attempt.min(16)).min(self.max_retry_delay)
+    }
+
+    async fn attempt(&self, url: &HttpUrl, body: &str) -> Result<(), NotifyError> {
+        let (status, response) =
+            http::post_json(url, &self.config.headers, body, self.timeout).await?;
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+        Err(NotifyError::Status { status, body: response.chars().take(MAX_ERROR_BODY).collect() })
+    }
+}
+
+#[async_trait]
+impl NotifyAdapter for WebhookNotifyAdapter {
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let url = HttpUrl::parse(&self.config.url)?;
+        let body = self.config.format.payload(notification).to_string();
+        let title = &notification.title;
+
+        let mut attempt = 0;
+        loop {
+            let err = match self.attempt(&url, &body).await {
+                Ok(()) => {
+                    tracing::info!(%title, host = %url.host, "webhook notification sent");
+                    return Ok(());
+                }
+                Err(e) => e,
+            };
+            if attempt >= self.config.retries || !err.is_retryable() {
+                return Err(err);
+            }
+            let delay = self.backoff(attempt);
+            tracing::warn!(
+                %title,
+                attempt = attempt + 1,
+                retry_in_ms = delay.as_millis() as u64,
+                error = %err,
+                "webhook notification failed, retrying"
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "webhook_tests.rs"]
+mod tests;
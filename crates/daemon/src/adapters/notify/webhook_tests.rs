@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{DecisionId, JobId, NotifyKind};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// A request received by the stand-in server.
+#[derive(Debug, Clone)]
+struct Received {
+    request_line: String,
+    headers: Vec<String>,
+    body: serde_json::Value,
+}
+
+/// Local HTTP server that answers each request with the next status in
+/// `statuses` (repeating the last one) and records what it received.
+async fn stand_in(statuses: &[u16]) -> (String, Arc<Mutex<Vec<Received>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hooks/oj", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let statuses = statuses.to_vec();
+
+    let log = Arc::clone(&received);
+    tokio::spawn(async move {
+        for n in 0.. {
+            let Ok((mut stream, _)) = listener.accept().await else { return };
+            let mut reader = BufReader::new(&mut stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).await.unwrap();
+            let mut headers = Vec::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                headers.push(line);
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).await.unwrap();
+            log.lock().push(Received {
+                request_line: request_line.trim_end().to_string(),
+                headers,
+                body: serde_json::from_slice(&body).unwrap(),
+            });
+
+            let status = statuses[n.min(statuses.len() - 1)];
+            let reply = format!("HTTP/1.1 {} Stand-in\r\nContent-Length: 4\r\n\r\nnope", status);
+            stream.write_all(reply.as_bytes()).await.unwrap();
+        }
+    });
+    (url, received)
+}
+
+fn plain() -> Notification {
+    Notification::new(NotifyKind::OnDone, "t", "m")
+}
+
+fn job_failure() -> Notification {
+    Notification::new(NotifyKind::OnFail, "deploy", "Job failed: exit 1")
+        .project("api")
+        .owner(JobId::from_string("job-1"))
+        .step(Some("build"))
+}
+
+fn adapter(config: WebhookConfig) -> WebhookNotifyAdapter {
+    WebhookNotifyAdapter::new(config).retry_delay(Duration::from_millis(1))
+}
+
+#[tokio::test]
+async fn posts_generic_json() {
+    let (url, received) = stand_in(&[200]).await;
+    let mut config = WebhookConfig::new(&url);
+    config.headers.insert("Authorization".into(), "Bearer s3cret".into());
+
+    adapter(config).notify(&job_failure()).await.unwrap();
+
+    let received = received.lock();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].request_line, "POST /hooks/oj HTTP/1.1");
+    assert!(received[0].headers.contains(&"Authorization: Bearer s3cret".to_string()));
+    assert!(received[0].headers.contains(&"Content-Type: application/json".to_string()));
+    assert_eq!(
+        received[0].body,
+        serde_json::json!({
+            "kind": "on_fail",
+            "severity": "error",
+            "title": "deploy",
+            "message": "Job failed: exit 1",
+            "project": "api",
+            "owner": "job-1",
+            "step": "build",
+            "action": "oj job show job-1",
+        })
+    );
+}
+
+#[tokio::test]
+async fn posts_slack_compatible_attachment() {
+    let (url, received) = stand_in(&[200]).await;
+
+    adapter(WebhookConfig::new(&url).format(WebhookFormat::Slack))
+        .notify(&job_failure())
+        .await
+        .unwrap();
+
+    let received = received.lock();
+    let body = &received[0].body;
+    assert_eq!(body["text"], "*deploy*\nJob failed: exit 1");
+    let attachment = &body["attachments"][0];
+    assert_eq!(attachment["color"], "#a30200");
+    assert_eq!(attachment["footer"], "oj job show job-1");
+    let fields: Vec<(&str, &str)> = attachment["fields"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|f| (f["title"].as_str().unwrap_or(""), f["value"].as_str().unwrap_or("")))
+        .collect();
+    assert_eq!(fields, vec![("Project", "api"), ("Owner", "job-1"), ("Step", "build")]);
+}
+
+#[test]
+fn slack_escalation_links_decision() {
+    let n = Notification::new(NotifyKind::Escalation, "Decision needed: fixer", "idle")
+        .decision_id(DecisionId::from_string("dcn-9"));
+
+    let body = WebhookFormat::Slack.payload(&n);
+
+    let attachment = &body["attachments"][0];
+    assert_eq!(attachment["color"], "#daa038");
+    assert_eq!(attachment["footer"], "oj decision show dcn-9");
+    assert_eq!(attachment["fields"][0]["title"], "Decision");
+}
+
+#[test]
+fn json_without_context_has_no_action() {
+    let body = WebhookFormat::Json.payload(&plain());
+    assert_eq!(
+        body,
+        serde_json::json!({ "kind": "on_done", "severity": "info", "title": "t", "message": "m" })
+    );
+}
+
+#[tokio::test]
+async fn retries_transient_failures() {
+    let (url, received) = stand_in(&[503, 429, 204]).await;
+
+    adapter(WebhookConfig::new(&url)).notify(&plain()).await.unwrap();
+
+    assert_eq!(received.lock().len(), 3);
+}
+
+#[tokio::test]
+async fn gives_up_after_retries() {
+    let (url, received) = stand_in(&[502]).await;
+    let config = WebhookConfig { retries: 2, ..WebhookConfig::new(&url) };
+
+    let err = adapter(config).notify(&plain()).await.unwrap_err();
+
+    assert!(matches!(err, NotifyError::Status { status: 502, ref body } if body == "nope"));
+    assert_eq!(received.lock().len(), 3);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let (url, received) = stand_in(&[404]).await;
+
+    let err = adapter(WebhookConfig::new(&url)).notify(&plain()).await.unwrap_err();
+
+    assert!(matches!(err, NotifyError::Status { status: 404, .. }));
+    assert_eq!(received.lock().len(), 1);
+}
+
+#[tokio::test]
+async fn connection_refused_is_connect_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    drop(listener);
+    let config = WebhookConfig { retries: 1, ..WebhookConfig::new(url) };
+
+    let err = adapter(config).notify(&plain()).await.unwrap_err();
+
+    assert!(matches!(err, NotifyError::Connect(_)), "{:?}", err);
+}
+
+#[tokio::test]
+async fn silent_server_times_out() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let config = WebhookConfig { retries: 0, ..WebhookConfig::new(url) };
+
+    let err =
+        adapter(config).timeout(Duration::from_millis(50)).notify(&plain()).await.unwrap_err();
+
+    assert!(matches!(err, NotifyError::Timeout), "{:?}", err);
+    drop(listener);
+}
+
+#[tokio::test]
+async fn invalid_url_fails_without_sending() {
+    let err = adapter(WebhookConfig::new("hooks.local/x")).notify(&plain()).await.unwrap_err();
+    assert!(matches!(err, NotifyError::InvalidUrl { .. }));
+}
+
+#[yare::parameterized(
+    connect = { NotifyError::Connect("refused".into()), true },
+    timeout = { NotifyError::Timeout, true },
+    server = { NotifyError::Status { status: 500, body: String::new() }, true },
+    rate_limited = { NotifyError::Status { status: 429, body: String::new() }, true },
+    not_found = { NotifyError::Status { status: 404, body: String::new() }, false },
+    tls = { NotifyError::Tls("bad certificate".into()), false },
+    url = { NotifyError::InvalidUrl { url: "x".into(), reason: "y".into() }, false },
+)]
+fn retryable_errors(err: NotifyError, expected: bool) {
+    assert_eq!(err.is_retryable(), expected);
+}
+
+#[test]
+fn backoff_doubles_up_to_cap() {
+    let adapter = WebhookNotifyAdapter::new(WebhookConfig::new("http://x/"))
+        .retry_delay(Duration::from_millis(500));
+    assert_eq!(adapter.backoff(0), Duration::from_millis(500));
+    assert_eq!(adapter.backoff(1), Duration::from_secs(1));
+    assert_eq!(adapter.backoff(3), Duration::from_secs(4));
+    assert_eq!(adapter.backoff(10), Duration::from_secs(10));
+}
+
+#[yare::parameterized(
+    json = { "json", Some(WebhookFormat::Json) },
+    slack = { "Slack", Some(WebhookFormat::Slack) },
+    mattermost = { "mattermost", Some(WebhookFormat::Slack) },
+    unknown = { "teams", None },
+)]
+fn format_parsing(input: &str, expected: Option<WebhookFormat>) {
+    assert_eq!(WebhookFormat::parse(input), expected);
+}
@@ -7,8 +7,11 @@
 //! when escalation paths are triggered.
 
 use oj_core::{AgentId, DecisionId, DecisionOption, DecisionSource, Event, OwnerId, QuestionData};
+use oj_runbook::DecisionConfig;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::monitor::parse_duration;
+
 /// Trigger that caused the escalation.
 #[derive(Debug, Clone)]
 pub enum EscalationTrigger {
@@ -53,6 +56,7 @@ pub struct EscalationDecisionBuilder {
     trigger: EscalationTrigger,
     agent_log_tail: Option<String>,
     project: String,
+    config: DecisionConfig,
 }
 
 impl EscalationDecisionBuilder {
@@ -70,6 +74,7 @@ impl EscalationDecisionBuilder {
             trigger,
             agent_log_tail: None,
             project: String::new(),
+            config: DecisionConfig::default(),
         }
     }
 
@@ -84,6 +89,12 @@ impl EscalationDecisionBuilder {
         self
     }
 
+    /// Expire and assign the decision per the runbook's `decision` block, if any.
+    pub fn config(mut self, config: Option<&DecisionConfig>) -> Self {
+        self.config = config.cloned().unwrap_or_default();
+        self
+    }
+
     /// Build the DecisionCreated event and generated decision ID.
     pub fn build(self) -> (DecisionId, Event) {
         let decision_id = DecisionId::new();
@@ -91,6 +102,7 @@ impl EscalationDecisionBuilder {
         let options = self.build_options();
         let created_at_ms =
             SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        let expiry = expire_at(&self.config, &options, created_at_ms);
 
         // Extract questions from Question triggers
         let questions = match &self.trigger {
@@ -108,6 +120,10 @@ impl EscalationDecisionBuilder {
             questions,
             created_at_ms,
             project: self.project,
+            expires_at_ms: expiry.map(|(at, _)| at),
+            default_choice: expiry.map(|(_, choice)| choice),
+            assignee: self.config.assignee,
+            reviewers: self.config.reviewers,
         };
 
         (decision_id, event)
@@ -292,6 +308,33 @@ impl EscalationDecisionBuilder {
     }
 }
 
+/// When a decision with these options expires, and the 1-indexed option it
+/// resolves to.
+///
+/// Prefers the configured `default` label when the decision offers it, then
+/// the recommended option. A decision with neither, or without a `timeout`,
+/// never expires.
+fn expire_at(
+    config: &DecisionConfig,
+    options: &[DecisionOption],
+    created_at_ms: u64,
+) -> Option<(u64, usize)> {
+    let timeout = config.timeout.as_deref()?;
+    let timeout = match parse_duration(timeout) {
+        Ok(d) => d,
+        Err(e) => {
+            tracing::warn!(timeout, error = %e, "invalid decision timeout");
+            return None;
+        }
+    };
+    let configured = config
+        .default
+        .as_deref()
+        .and_then(|label| options.iter().position(|o| o.label.eq_ignore_ascii_case(label)));
+    let index = configured.or_else(|| options.iter().position(|o| o.recommended))?;
+    Some((created_at_ms + timeout.as_millis() as u64, index + 1))
+}
+
 #[cfg(test)]
 #[path = "decision_tests.rs"]
 mod decision_tests;
@@ -433,3 +433,97 @@ proptest! {
         prop_assert_eq!(d.owner, OwnerId::Crew(CrewId::from_string("run-prop")));
     }
 }
+
+fn build_expiring(
+    trigger: EscalationTrigger,
+    default: Option<&str>,
+) -> (Option<u64>, Option<usize>) {
+    let config = DecisionConfig {
+        timeout: Some("4h".to_string()),
+        default: default.map(String::from),
+        ..Default::default()
+    };
+    let (_, event) = EscalationDecisionBuilder::new(
+        JobId::from_string("job-1").into(),
+        "test-job".to_string(),
+        "agent-1".to_string(),
+        trigger,
+    )
+    .config(Some(&config))
+    .build();
+    match event {
+        Event::DecisionCreated { created_at_ms, expires_at_ms, default_choice, .. } => {
+            assert!(expires_at_ms.is_none_or(|at| at == created_at_ms + 4 * 3_600_000));
+            (expires_at_ms, default_choice)
+        }
+        _ => panic!("expected DecisionCreated"),
+    }
+}
+
+fn gate_failed() -> EscalationTrigger {
+    EscalationTrigger::GateFailed {
+        command: "make check".into(),
+        exit_code: 1,
+        stderr: String::new(),
+    }
+}
+
+#[yare::parameterized(
+    recommended = { None, Some(1) },
+    configured = { Some("Skip"), Some(2) },
+    case_insensitive = { Some("cancel"), Some(3) },
+    not_offered_falls_back = { Some("Done"), Some(1) },
+)]
+fn expiry_default_choice(default: Option<&str>, expected: Option<usize>) {
+    let (expires_at_ms, choice) = build_expiring(gate_failed(), default);
+    assert!(expires_at_ms.is_some());
+    assert_eq!(choice, expected);
+}
+
+#[test]
+fn expiry_without_usable_default_never_expires() {
+    // Permission prompts have no recommended option
+    let trigger =
+        EscalationTrigger::Prompt { prompt_type: "permission".into(), last_message: None };
+    assert_eq!(build_expiring(trigger, None), (None, None));
+}
+
+#[test]
+fn decisions_do_not_expire_by_default() {
+    let (_, event) = EscalationDecisionBuilder::new(
+        JobId::from_string("job-1").into(),
+        "test-job".to_string(),
+        "agent-1".to_string(),
+        gate_failed(),
+    )
+    .build();
+    assert!(matches!(
+        event,
+        Event::DecisionCreated { expires_at_ms: None, default_choice: None, assignee: None, .. }
+    ));
+}
+
+#[test]
+fn assignment_without_timeout_never_expires() {
+    let config = DecisionConfig {
+        assignee: Some("alice".to_string()),
+        reviewers: vec!["bob".to_string(), "carol".to_string()],
+        ..Default::default()
+    };
+    let (_, event) = EscalationDecisionBuilder::new(
+        JobId::from_string("job-1").into(),
+        "test-job".to_string(),
+        "agent-1".to_string(),
+        gate_failed(),
+    )
+    .config(Some(&config))
+    .build();
+    match event {
+        Event::DecisionCreated { assignee, reviewers, expires_at_ms, .. } => {
+            assert_eq!(assignee.as_deref(), Some("alice"));
+            assert_eq!(reviewers, ["bob", "carol"]);
+            assert_eq!(expires_at_ms, None);
+        }
+        _ => panic!("expected DecisionCreated"),
+    }
+}
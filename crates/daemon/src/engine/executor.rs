@@ -5,11 +5,13 @@
 
 use crate::adapters::subprocess::{run_with_timeout, QUEUE_COMMAND_TIMEOUT, SHELL_COMMAND_TIMEOUT};
 use crate::adapters::{
-    AgentAdapter, AgentConfig, AgentReconnectConfig, NotifyAdapter, WorkspaceAdapter,
+    AgentAdapter, AgentConfig, AgentReconnectConfig, NotifyAdapter, NotifyDedup, NotifyRouting,
+    NotifySink, WebhookNotifyAdapter, WorkspaceAdapter,
 };
 use crate::engine::{scheduler::Scheduler, RuntimeDeps};
 use crate::storage::MaterializedState;
-use oj_core::{Clock, Effect, Event};
+use oj_core::{Clock, Effect, Event, JobId};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
@@ -33,6 +35,8 @@ pub enum ExecuteError {
 pub struct Executor<C: Clock> {
     pub(crate) agents: Arc<dyn AgentAdapter>,
     notifier: Arc<dyn NotifyAdapter>,
+    /// Recently routed notifications, for routes with a `dedup` window
+    notify_dedup: Mutex<NotifyDedup>,
     state: Arc<Mutex<MaterializedState>>,
     scheduler: Arc<Mutex<Scheduler>>,
     clock: C,
@@ -40,6 +44,61 @@ pub struct Executor<C: Clock> {
     event_tx: mpsc::Sender<Event>,
     /// Workspace filesystem adapter (local or noop for k8s).
     workspace: Arc<dyn WorkspaceAdapter>,
+    /// Running job shell steps, so `KillShell` can stop them
+    shells: ShellProcesses,
+}
+
+/// Process groups of running job shell steps, keyed by job and step.
+///
+/// Each spawn gets a token so that a shell that exits after being killed
+/// (or after its step was re-run) can tell it no longer owns the entry.
+#[derive(Clone, Default)]
+struct ShellProcesses {
+    inner: Arc<Mutex<ShellTable>>,
+}
+
+#[derive(Default)]
+struct ShellTable {
+    next_token: u64,
+    running: HashMap<(JobId, String), (u32, u64)>,
+}
+
+impl ShellProcesses {
+    /// Track a spawned shell; returns the token its exit must present.
+    fn track(&self, job_id: JobId, step: &str, pid: u32) -> u64 {
+        let mut table = self.inner.lock();
+        table.next_token += 1;
+        let token = table.next_token;
+        table.running.insert((job_id, step.to_string()), (pid, token));
+        token
+    }
+
+    /// Stop tracking an exited shell. Returns false when the shell was
+    /// killed (or replaced) in the meantime, i.e. its exit is stale.
+    fn finish(&self, job_id: JobId, step: &str, token: u64) -> bool {
+        let mut table = self.inner.lock();
+        let key = (job_id, step.to_string());
+        match table.running.get(&key) {
+            Some(&(_, t)) if t == token => {
+                table.running.remove(&key);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Stop tracking a shell that is about to be killed.
+    fn take(&self, job_id: JobId, step: &str) -> Option<u32> {
+        self.inner.lock().running.remove(&(job_id, step.to_string())).map(|(pid, _)| pid)
+    }
+}
+
+/// SIGKILL a shell's whole process group (it was spawned as its leader).
+fn kill_process_group(pid: u32) {
+    use nix::sys::signal::{killpg, Signal};
+    if let Err(e) = killpg(nix::unistd::Pid::from_raw(pid as i32), Signal::SIGKILL) {
+        tracing::debug!(pid, error = %e, "shell process group already gone");
+    }
 }
 
 impl<C: Clock> Executor<C> {
@@ -53,11 +112,13 @@ impl<C: Clock> Executor<C> {
         Self {
             agents: deps.agents,
             notifier: deps.notifier,
+            notify_dedup: Mutex::new(NotifyDedup::default()),
             workspace: deps.workspace,
             state: deps.state,
             scheduler,
             clock,
             event_tx,
+            shells: ShellProcesses::default(),
         }
     }
 
@@ -235,6 +296,13 @@ impl<C: Clock> Executor<C> {
                 self.execute_shell(owner, step, command, cwd, env);
                 Ok(None)
             }
+            Effect::KillShell { job_id, step } => {
+                if let Some(pid) = self.shells.take(job_id, &step) {
+                    tracing::info!(%job_id, step, pid, "killing shell command");
+                    kill_process_group(pid);
+                }
+                Ok(None)
+            }
             Effect::PollQueue { worker_name, project, list_command, cwd } => {
                 self.execute_poll_queue(worker_name, project, list_command, cwd);
                 Ok(None)
@@ -250,9 +318,50 @@ impl<C: Clock> Executor<C> {
                 );
                 Ok(None)
             }
-            Effect::Notify { title, message } => {
-                if let Err(e) = self.notifier.notify(&title, &message).await {
-                    tracing::warn!(%title, error = %e, "notification send failed");
+            Effect::Notify { notification } => {
+                let project_path =
+                    self.state.lock().project_path_for_namespace(&notification.project);
+                let routing = NotifyRouting::resolve(project_path.as_deref());
+                let sinks: Vec<(String, NotifySink)> = routing
+                    .route(&notification, self.clock.epoch_ms(), &mut self.notify_dedup.lock())
+                    .into_iter()
+                    .map(|(name, sink)| (name.to_string(), sink.clone()))
+                    .collect();
+                if sinks.is_empty() {
+                    tracing::debug!(
+                        kind = %notification.kind,
+                        title = %notification.title,
+                        "notification not routed to any sink"
+                    );
+                }
+                for (name, sink) in sinks {
+                    match sink {
+                        // Deliver in the background: retries must not stall the event loop
+                        NotifySink::Webhook(config) => {
+                            let webhook = WebhookNotifyAdapter::new(config);
+                            let notification = notification.clone();
+                            tokio::spawn(async move {
+                                if let Err(e) = webhook.notify(&notification).await {
+                                    tracing::warn!(
+                                        project = %notification.project,
+                                        title = %notification.title,
+                                        sink = %name,
+                                        error = %e,
+                                        "webhook notification failed"
+                                    );
+                                }
+                            });
+                        }
+                        NotifySink::Desktop => {
+                            if let Err(e) = self.notifier.notify(&notification).await {
+                                tracing::warn!(
+                                    title = %notification.title,
+                                    error = %e,
+                                    "notification send failed"
+                                );
+                            }
+                        }
+                    }
                 }
                 Ok(None)
             }
@@ -268,6 +377,7 @@ impl<C: Clock> Executor<C> {
         env: std::collections::HashMap<String, String>,
     ) {
         let event_tx = self.event_tx.clone();
+        let shells = self.shells.clone();
         let job_id = match &owner {
             Some(oj_core::OwnerId::Job(id)) => *id,
             _ => oj_core::JobId::from_string(""),
@@ -286,8 +396,43 @@ impl<C: Clock> Executor<C> {
 
             let wrapped = format!("set -euo pipefail\n{command}");
             let mut cmd = tokio::process::Command::new("bash");
+            // Lead a new process group so KillShell also takes out children
             cmd.arg("-c").arg(&wrapped).current_dir(&cwd).envs(&env);
-            let result = run_with_timeout(cmd, SHELL_COMMAND_TIMEOUT, "shell command").await;
+            cmd.stdin(std::process::Stdio::null())
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped())
+                .process_group(0)
+                .kill_on_drop(true);
+            let (result, token) = match cmd.spawn() {
+                Ok(child) => {
+                    let pid = child.id();
+                    let token = pid.map(|pid| shells.track(job_id, &step, pid));
+                    let output =
+                        tokio::time::timeout(SHELL_COMMAND_TIMEOUT, child.wait_with_output()).await;
+                    let result = match output {
+                        Ok(Ok(output)) => Ok(output),
+                        Ok(Err(e)) => Err(format!("shell command failed: {}", e)),
+                        Err(_elapsed) => {
+                            if let Some(pid) = pid {
+                                kill_process_group(pid);
+                            }
+                            Err(format!(
+                                "shell command timed out after {}s",
+                                SHELL_COMMAND_TIMEOUT.as_secs()
+                            ))
+                        }
+                    };
+                    (result, token)
+                }
+                Err(e) => (Err(format!("shell command failed: {}", e)), None),
+            };
+            // A killed shell's step has already moved on: don't report its exit
+            if let Some(token) = token {
+                if !shells.finish(job_id, &step, token) {
+                    tracing::info!(owner = %owner_str, step, "shell command killed");
+                    return;
+                }
+            }
 
             let (exit_code, stdout, stderr) = match result {
                 Ok(output) => {
@@ -4,6 +4,7 @@
 //! Tests for emit, timer, session, notify, execute_all, and accessor effects.
 
 use super::*;
+use oj_core::{Notification, NotifyKind};
 
 #[tokio::test]
 async fn executor_emit_event_effect() {
@@ -98,8 +99,7 @@ async fn notify_effect_delegates_to_adapter() {
     let result = harness
         .executor
         .execute(Effect::Notify {
-            title: "Test Title".to_string(),
-            message: "Test message".to_string(),
+            notification: Notification::new(NotifyKind::OnDone, "Test Title", "Test message"),
         })
         .await;
 
@@ -118,12 +118,16 @@ async fn multiple_notify_effects_recorded() {
 
     harness
         .executor
-        .execute(Effect::Notify { title: "First".to_string(), message: "msg1".to_string() })
+        .execute(Effect::Notify {
+            notification: Notification::new(NotifyKind::OnDone, "First", "msg1"),
+        })
         .await
         .unwrap();
     harness
         .executor
-        .execute(Effect::Notify { title: "Second".to_string(), message: "msg2".to_string() })
+        .execute(Effect::Notify {
+            notification: Notification::new(NotifyKind::OnDone, "Second", "msg2"),
+        })
         .await
         .unwrap();
 
@@ -133,6 +137,51 @@ async fn multiple_notify_effects_recorded() {
     assert_eq!(calls[1].title, "Second");
 }
 
+#[tokio::test]
+async fn notify_passes_context_to_adapter() {
+    let harness = setup().await;
+    let notification = Notification::new(NotifyKind::Escalation, "Decision needed", "stuck")
+        .project("api")
+        .owner(JobId::from_string("job-1"))
+        .step(Some("work"))
+        .decision_id(oj_core::DecisionId::from_string("dcn-1"));
+
+    harness.executor.execute(Effect::Notify { notification: notification.clone() }).await.unwrap();
+
+    assert_eq!(harness.notifier.calls(), vec![notification]);
+}
+
+#[tokio::test]
+async fn notify_uses_project_webhook_when_configured() {
+    let harness = setup().await;
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let project = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(project.path().join(".oj")).unwrap();
+    std::fs::write(
+        project.path().join(".oj/config.toml"),
+        format!("[notify.webhook]\nurl = \"http://{}/hook\"\n", listener.local_addr().unwrap()),
+    )
+    .unwrap();
+    harness
+        .executor
+        .state
+        .lock()
+        .project_paths
+        .insert("api".to_string(), project.path().to_path_buf());
+
+    harness
+        .executor
+        .execute(Effect::Notify {
+            notification: Notification::new(NotifyKind::OnDone, "deploy", "done").project("api"),
+        })
+        .await
+        .unwrap();
+
+    let accepted = tokio::time::timeout(std::time::Duration::from_secs(5), listener.accept()).await;
+    assert!(accepted.is_ok(), "webhook should receive the notification");
+    assert!(harness.notifier.calls().is_empty());
+}
+
 // === execute_all tests ===
 
 #[tokio::test]
@@ -204,7 +253,9 @@ async fn execute_all_mixed_effects() {
             env: HashMap::new(),
             container: None,
         },
-        Effect::Notify { title: "Done".to_string(), message: "mixed test".to_string() },
+        Effect::Notify {
+            notification: Notification::new(NotifyKind::OnDone, "Done", "mixed test"),
+        },
     ];
 
     let inline_events = harness.executor.execute_all(effects).await.unwrap();
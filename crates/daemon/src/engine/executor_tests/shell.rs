@@ -4,6 +4,9 @@
 //! Tests for shell effect execution.
 
 use super::*;
+use crate::engine::test_helpers::{
+    background_sleep_command, process_running, wait_for_exit, wait_for_pid_file,
+};
 
 #[tokio::test]
 async fn shell_effect_runs_command() {
@@ -213,3 +216,31 @@ async fn execute_all_shell_effects_are_async() {
     assert!(matches!(e1, Event::ShellExited { .. }));
     assert!(matches!(e2, Event::ShellExited { .. }));
 }
+
+#[tokio::test]
+async fn kill_shell_kills_process_group() {
+    let mut harness = setup().await;
+    let dir = tempfile::tempdir().unwrap();
+    let pid_file = dir.path().join("pid");
+
+    harness.executor.execute(shell(&background_sleep_command(&pid_file))).await.unwrap();
+    let pid = wait_for_pid_file(&pid_file).await;
+    assert!(process_running(pid));
+
+    let kill = Effect::KillShell { job_id: JobId::from_string("test"), step: "init".to_string() };
+    harness.executor.execute(kill).await.unwrap();
+
+    assert!(wait_for_exit(pid).await, "background child should be killed with its shell");
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    assert!(harness.event_rx.try_recv().is_err(), "killed shell must not report ShellExited");
+}
+
+#[tokio::test]
+async fn kill_shell_without_running_shell_is_noop() {
+    let harness = setup().await;
+
+    let kill = Effect::KillShell { job_id: JobId::from_string("test"), step: "init".to_string() };
+    let event = harness.executor.execute(kill).await.unwrap();
+
+    assert!(event.is_none());
+}
@@ -9,7 +9,10 @@
 //! synchronous.
 
 use crate::engine::RuntimeError;
-use oj_core::{Crew, CrewId, CrewStatus, DecisionId, Effect, Event, Job, JobId, OwnerId, TimerId};
+use oj_core::{
+    Crew, CrewId, CrewStatus, DecisionId, Effect, Event, Job, JobId, Notification, NotifyKind,
+    OwnerId, TimerId,
+};
 use oj_runbook::{AgentDef, Runbook};
 use std::collections::HashMap;
 use std::path::Path;
@@ -53,6 +56,11 @@ pub(crate) trait RunLifecycle {
         None
     }
 
+    /// Parallel branch this run stands for, if it is a `Job::branch_view`.
+    fn branch(&self) -> Option<&str> {
+        None
+    }
+
     /// Agent reference for escalation decisions.
     fn decision_agent_ref(&self) -> String;
 
@@ -120,6 +128,10 @@ impl RunLifecycle for Job {
         Some(&self.step)
     }
 
+    fn branch(&self) -> Option<&str> {
+        self.is_branch_view().then_some(self.step.as_str())
+    }
+
     fn decision_agent_ref(&self) -> String {
         self.agent_id().unwrap_or_default().to_string()
     }
@@ -143,6 +155,7 @@ impl RunLifecycle for Job {
                     message: Some("auto-dismissed: agent became active".to_string()),
                     resolved_at_ms,
                     project: self.project.clone(),
+                    timed_out: false,
                 },
             });
         }
@@ -182,7 +195,14 @@ impl RunLifecycle for Job {
     }
 
     fn resolve_agent_def(&self, runbook: &Runbook) -> Result<AgentDef, RuntimeError> {
-        crate::engine::monitor::get_agent_def(runbook, self).cloned()
+        let mut agent_def = crate::engine::monitor::get_agent_def(runbook, self)?.clone();
+        // The job's decision block fills in whatever the agent leaves unset
+        let job_decision = runbook.get_job(&self.kind).and_then(|j| j.decision.as_ref());
+        agent_def.decision = match agent_def.decision.take() {
+            Some(decision) => Some(decision.or(job_decision)),
+            None => job_decision.cloned(),
+        };
+        Ok(agent_def)
     }
 }
 
@@ -290,21 +310,35 @@ impl RunLifecycle for Crew {
 
 /// Build an on_start notification effect.
 pub(crate) fn notify_on_start(run: &dyn RunLifecycle, agent_def: &AgentDef) -> Option<Effect> {
-    build_notify_effect(run, agent_def, agent_def.notify.on_start.as_ref())
+    build_notify_effect(run, agent_def, NotifyKind::OnStart, agent_def.notify.on_start.as_ref())
 }
 
 /// Build an on_done notification effect.
 pub(crate) fn notify_on_done(run: &dyn RunLifecycle, agent_def: &AgentDef) -> Option<Effect> {
-    build_notify_effect(run, agent_def, agent_def.notify.on_done.as_ref())
+    build_notify_effect(run, agent_def, NotifyKind::OnDone, agent_def.notify.on_done.as_ref())
 }
 
 fn build_notify_effect(
     run: &dyn RunLifecycle,
     agent_def: &AgentDef,
+    kind: NotifyKind,
     message_template: Option<&String>,
 ) -> Option<Effect> {
     let template = message_template?;
     let vars = run.build_notify_vars(agent_def);
     let message = oj_runbook::NotifyConfig::render(template, &vars);
-    Some(Effect::Notify { title: agent_def.name.clone(), message })
+    Some(Effect::Notify { notification: notification(run, kind, agent_def.name.clone(), message) })
+}
+
+/// A notification carrying the run's project, owner and step.
+pub(crate) fn notification(
+    run: &dyn RunLifecycle,
+    kind: NotifyKind,
+    title: String,
+    message: String,
+) -> Notification {
+    Notification::new(kind, title, message)
+        .project(run.project())
+        .owner(run.owner_id())
+        .step(run.step())
 }
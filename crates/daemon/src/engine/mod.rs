@@ -8,6 +8,7 @@ mod agent_logger;
 mod agent_setup;
 pub mod breadcrumb;
 mod decision;
+mod decision_resolve;
 mod error;
 mod executor;
 pub(crate) mod lifecycle;
@@ -25,7 +26,9 @@ mod workspace;
 pub(crate) mod test_helpers;
 
 pub use agent_logger::AgentLogger;
+pub(crate) use decision_resolve::resolution_events;
 pub use error::RuntimeError;
-pub(crate) use monitor::ActionContext;
+pub(crate) use monitor::{parse_duration, ActionContext};
+pub(crate) use runtime::{next_fire_at_ms, CronTrigger};
 pub use runtime::{Runtime, RuntimeConfig, RuntimeDeps};
 pub use usage_metrics::UsageMetricsCollector;
@@ -7,9 +7,9 @@
 //! appropriate actions (nudge, resume, escalate, etc.).
 
 use crate::engine::decision::{EscalationDecisionBuilder, EscalationTrigger};
-use crate::engine::lifecycle::RunLifecycle;
+use crate::engine::lifecycle::{self, RunLifecycle};
 use crate::engine::RuntimeError;
-use oj_core::{AgentError, AgentId, AgentState, Effect, Job, PromptType, QuestionData};
+use oj_core::{AgentError, AgentId, AgentState, Effect, Job, NotifyKind, PromptType, QuestionData};
 use oj_runbook::{ActionConfig, AgentAction, AgentDef, ErrorType, RunDirective, Runbook};
 use std::collections::HashMap;
 use std::time::Duration;
@@ -214,13 +214,19 @@ pub fn build_action_effects_for(
                 escalation_trigger,
             )
             .project(run.project())
+            .config(ctx.agent_def.decision.as_ref())
             .build();
 
             let mut effects = vec![
                 Effect::Emit { event: decision_event },
                 Effect::Notify {
-                    title: format!("Decision needed: {}", run.display_name()),
-                    message: format!("Requires attention ({})", ctx.trigger),
+                    notification: lifecycle::notification(
+                        run,
+                        NotifyKind::Escalation,
+                        format!("Decision needed: {}", run.display_name()),
+                        format!("Requires attention ({})", ctx.trigger),
+                    )
+                    .decision_id(decision_id),
                 },
             ];
 
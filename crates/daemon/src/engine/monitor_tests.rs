@@ -132,6 +132,30 @@ run = "echo hello"
         assert_eq!(agent.name, "worker");
     }
 
+    #[test]
+    fn resolved_agent_def_inherits_job_decision_config() {
+        use crate::engine::lifecycle::RunLifecycle;
+        let toml = r#"
+[job.build]
+decision = { timeout = "4h", assignee = "ops", reviewers = ["bob"] }
+
+[[job.build.step]]
+name = "execute"
+run = { agent = "worker" }
+
+[agent.worker]
+run = 'claude'
+prompt = "Do the task"
+decision = { assignee = "alice" }
+"#;
+        let runbook = parse_runbook(toml).unwrap();
+        let agent = test_job().resolve_agent_def(&runbook).unwrap();
+        let decision = agent.decision.unwrap();
+        assert_eq!(decision.assignee.as_deref(), Some("alice"));
+        assert_eq!(decision.reviewers, ["bob"]);
+        assert_eq!(decision.timeout.as_deref(), Some("4h"));
+    }
+
     #[yare::parameterized(
         missing_job  = { "nonexistent", "execute",    RUNBOOK_WITH_AGENT },
         missing_step = { "build",       "nonexistent", RUNBOOK_WITH_AGENT },
@@ -299,8 +323,14 @@ mod properties {
                 };
                 let has_dc = effects.iter().any(|e| matches!(e, Effect::Emit { event: Event::DecisionCreated { .. } }));
                 prop_assert!(has_dc, "escalate must emit DecisionCreated");
-                let has_n = effects.iter().any(|e| matches!(e, Effect::Notify { .. }));
-                prop_assert!(has_n, "escalate must emit Notify");
+                let has_n = effects.iter().any(|e| matches!(
+                    e,
+                    Effect::Notify { notification }
+                        if notification.kind == oj_core::NotifyKind::Escalation
+                            && notification.decision_id.is_some()
+                            && notification.owner == Some(run.owner_id())
+                ));
+                prop_assert!(has_n, "escalate must emit an escalation Notify with its decision");
                 Ok(())
             };
             let job = test_job();
@@ -421,8 +451,10 @@ mod properties {
             def.notify.on_done = Some(template.clone());
             let effect = lifecycle::notify_on_done(&job, &def);
             prop_assert!(effect.is_some());
-            if let Some(Effect::Notify { title, .. }) = effect {
-                prop_assert_eq!(title, "worker");
+            if let Some(Effect::Notify { notification }) = effect {
+                prop_assert_eq!(notification.title, "worker");
+                prop_assert_eq!(notification.kind, oj_core::NotifyKind::OnDone);
+                prop_assert_eq!(notification.owner, Some(job.owner_id()));
             }
         }
     }
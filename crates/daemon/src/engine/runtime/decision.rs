@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Decision expiry.
+//!
+//! A decision created with `expires_at_ms` arms a `decision-timeout:<id>`
+//! timer. When it fires and nobody has answered, the decision resolves with
+//! its `default_choice` and is marked `timed_out`. A decision resolved or
+//! superseded in the meantime ignores the timer.
+
+use super::Runtime;
+use crate::engine::error::RuntimeError;
+use crate::engine::resolution_events;
+use oj_core::{Clock, DecisionId, Effect, Event, TimerId};
+use std::time::Duration;
+
+impl<C: Clock> Runtime<C> {
+    /// Arm the expiry timer for a decision. Already-expired decisions fire
+    /// immediately.
+    pub(crate) async fn arm_decision_timeout(
+        &self,
+        id: DecisionId,
+        expires_at_ms: u64,
+    ) -> Result<(), RuntimeError> {
+        let now = self.executor.clock().epoch_ms();
+        let duration = Duration::from_millis(expires_at_ms.saturating_sub(now));
+        self.executor
+            .execute(Effect::SetTimer { id: TimerId::decision_timeout(id), duration })
+            .await?;
+        Ok(())
+    }
+
+    /// A decision expired: resolve it with its default option.
+    pub(crate) async fn handle_decision_timeout(
+        &self,
+        id: DecisionId,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let resolved_at_ms = self.executor.clock().epoch_ms();
+        let events = self.lock_state(|state| {
+            let decision = state.decisions.get(id.as_str())?;
+            if decision.is_resolved() {
+                return None;
+            }
+            let choice = decision.default_choice?;
+            Some(resolution_events(state, decision, vec![choice], None, resolved_at_ms, true))
+        });
+        let Some(events) = events else {
+            return Ok(vec![]);
+        };
+
+        tracing::info!(decision = %id, "decision expired, resolving with default option");
+        let effects = events.into_iter().map(|event| Effect::Emit { event }).collect();
+        Ok(self.executor.execute_all(effects).await?)
+    }
+}
@@ -60,13 +60,16 @@ impl<C: Clock> Runtime<C> {
                     return OwnerCtx::Skip;
                 }
 
+                // A parallel branch's agent is handled against that branch
+                if job.is_parallel() {
+                    return match job.agent_branch(agent_id.as_str()) {
+                        Some(branch) => OwnerCtx::Job { job: Box::new(job.branch_view(branch)) },
+                        None => OwnerCtx::Skip,
+                    };
+                }
+
                 // Verify this event is for the current step's agent, not a stale event
-                let current_agent_id = job
-                    .step_history
-                    .iter()
-                    .rfind(|r| r.name == job.step)
-                    .and_then(|r| r.agent_id.as_deref());
-                if current_agent_id != Some(agent_id.as_str()) {
+                if job.step_agent_id(&job.step) != Some(agent_id.as_str()) {
                     return OwnerCtx::Skip;
                 }
 
@@ -328,7 +331,8 @@ impl<C: Clock> Runtime<C> {
         // Resume with coop's --resume flag (coop discovers session ID from JSONL)
         let resume = !all_agent_ids.is_empty();
         let job_id = JobId::from_string(&job.id);
-        let result = self.spawn_agent_with_resume(&job_id, agent_name, &new_inputs, resume).await?;
+        let result =
+            self.spawn_agent_with_resume(&job_id, step, agent_name, &new_inputs, resume).await?;
 
         tracing::info!(job_id = %job.id, kill, resume, "resumed agent with --resume");
         Ok(result)
@@ -66,6 +66,10 @@ fn setup_waiting_job_with_decision(
             context: "test decision".to_string(),
             options: vec![DecisionOption::new("Option A")],
             questions: None,
+            expires_at_ms: None,
+            default_choice: None,
+            assignee: None,
+            reviewers: vec![],
         },
     ];
     ctx.runtime.lock_state_mut(|state| {
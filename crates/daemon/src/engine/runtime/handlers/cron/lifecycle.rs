@@ -8,22 +8,31 @@ use crate::engine::error::RuntimeError;
 use crate::engine::runtime::agent::SpawnAgentParams;
 use crate::engine::runtime::handlers::CreateJobParams;
 use crate::engine::runtime::Runtime;
-use oj_core::{scoped_name, Clock, Effect, Event, JobId, TimerId};
+use oj_core::{
+    scoped_name, split_scoped_name, Clock, CrewId, Effect, Event, JobId, OwnerId, RunTarget,
+    TimerId,
+};
 use std::collections::HashMap;
 
 pub(crate) use super::{CronOnceParams, CronStartedParams};
-use super::{CronShellJobParams, CronState, CronStatus};
+use super::{CronShellJobParams, CronState, CronStatus, CronTrigger};
 
 impl<C: Clock> Runtime<C> {
     pub(crate) async fn handle_cron_started(
         &self,
         params: CronStartedParams<'_>,
     ) -> Result<Vec<Event>, RuntimeError> {
-        let CronStartedParams { cron, project, project_path, runbook_hash, interval, target } =
-            params;
-        let duration = crate::engine::monitor::parse_duration(interval).map_err(|e| {
-            RuntimeError::InvalidFormat(format!("invalid cron interval '{}': {}", interval, e))
-        })?;
+        let CronStartedParams {
+            cron,
+            project,
+            project_path,
+            runbook_hash,
+            interval,
+            schedule,
+            timezone,
+            target,
+        } = params;
+        let trigger = CronTrigger::parse(interval, schedule, timezone)?;
 
         // Read concurrency from the cron definition in the runbook
         let concurrency = self
@@ -36,7 +45,7 @@ impl<C: Clock> Runtime<C> {
         let state = CronState {
             project_path: project_path.to_path_buf(),
             runbook_hash: runbook_hash.to_string(),
-            interval: interval.to_string(),
+            trigger: trigger.clone(),
             target: target.clone(),
             status: CronStatus::Running,
             project: project.to_string(),
@@ -46,21 +55,28 @@ impl<C: Clock> Runtime<C> {
         let cron_key = scoped_name(project, cron);
         {
             let mut crons = self.cron_states.lock();
-            crons.insert(cron_key, state);
+            crons.insert(cron_key.clone(), state);
         }
 
-        // Set the first interval timer
-        let timer_id = TimerId::cron(cron, project);
-        self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
-
+        let timing = match (schedule, timezone) {
+            (Some(schedule), Some(timezone)) => {
+                format!("schedule={}, timezone={}", schedule, timezone)
+            }
+            (Some(schedule), None) => format!("schedule={}", schedule),
+            (None, _) => format!("interval={}", interval),
+        };
         append_cron_log(
             self.logger.log_dir(),
             cron,
             project,
-            &format!("started (interval={}, {})", interval, target.log()),
+            &format!("started ({}, {})", timing, target.log()),
         );
 
-        Ok(vec![])
+        // Set the first timer
+        self.set_cron_timer(cron, project, &trigger).await?;
+
+        // Start runs missed while the daemon was down, if any were queued
+        self.run_cron_catchup(&cron_key).await
     }
 
     pub(crate) async fn handle_cron_stopped(
@@ -75,6 +91,7 @@ impl<C: Clock> Runtime<C> {
                 state.status = CronStatus::Stopped;
             }
         }
+        self.cron_catchup.lock().remove(&cron_key);
 
         // Cancel the timer
         let timer_id = TimerId::cron(cron_name, project);
@@ -85,6 +102,127 @@ impl<C: Clock> Runtime<C> {
         Ok(vec![])
     }
 
+    /// Queue runs a cron missed while the daemon was down.
+    ///
+    /// They start once the cron resumes, as many at a time as its concurrency
+    /// allows, and the rest follow as its runs finish.
+    pub(crate) fn queue_cron_catchup(&self, cron: &str, project: &str, runs: usize) {
+        if runs > 0 {
+            *self.cron_catchup.lock().entry(scoped_name(project, cron)).or_default() += runs;
+        }
+    }
+
+    /// Start the next queued catch-up runs when a cron-owned job or crew finishes.
+    pub(crate) async fn handle_cron_run_finished(
+        &self,
+        owner: OwnerId,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let cron_key = self.lock_state(|state| match owner {
+            OwnerId::Job(id) => state
+                .jobs
+                .get(id.as_str())
+                .and_then(|job| job.cron_name.as_deref().map(|c| scoped_name(&job.project, c))),
+            OwnerId::Crew(id) => state.crew.get(id.as_str()).and_then(|crew| {
+                crew.command_name.strip_prefix("cron:").map(|c| scoped_name(&crew.project, c))
+            }),
+        });
+        match cron_key {
+            Some(cron_key) => self.run_cron_catchup(&cron_key).await,
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Start queued catch-up runs for a running cron while it is below its
+    /// concurrency limit, using the same limits as a timer fire.
+    async fn run_cron_catchup(&self, cron_key: &str) -> Result<Vec<Event>, RuntimeError> {
+        let (_, cron) = split_scoped_name(cron_key);
+        let mut result_events = Vec::new();
+
+        loop {
+            if self.cron_catchup.lock().get(cron_key).copied().unwrap_or(0) == 0 {
+                break;
+            }
+            let (project, project_path, runbook_hash, target, concurrency) = {
+                let crons = self.cron_states.lock();
+                match crons.get(cron_key) {
+                    Some(s) if s.status == CronStatus::Running => (
+                        s.project.clone(),
+                        s.project_path.clone(),
+                        s.runbook_hash.clone(),
+                        s.target.clone(),
+                        s.concurrency,
+                    ),
+                    _ => break,
+                }
+            };
+
+            let (active, max) = match &target {
+                RunTarget::Agent(agent_name) => {
+                    let max = self
+                        .cached_runbook(&runbook_hash)?
+                        .get_agent(agent_name)
+                        .and_then(|a| a.max_concurrency);
+                    match max {
+                        Some(max) => {
+                            (self.count_running_agents(agent_name, &project), max as usize)
+                        }
+                        None => (0, usize::MAX),
+                    }
+                }
+                RunTarget::Job(_) | RunTarget::Shell(_) => {
+                    (self.count_active_cron_jobs(cron, &project), concurrency as usize)
+                }
+            };
+            if active >= max {
+                let waiting = self.cron_catchup.lock().get(cron_key).copied().unwrap_or(0);
+                append_cron_log(
+                    self.logger.log_dir(),
+                    cron,
+                    &project,
+                    &format!(
+                        "catch-up: {} run(s) waiting at max concurrency ({}/{})",
+                        waiting, active, max
+                    ),
+                );
+                break;
+            }
+
+            {
+                let mut catchup = self.cron_catchup.lock();
+                if let Some(waiting) = catchup.get_mut(cron_key) {
+                    *waiting -= 1;
+                    if *waiting == 0 {
+                        catchup.remove(cron_key);
+                    }
+                }
+            }
+
+            let owner: OwnerId = match &target {
+                RunTarget::Agent(_) => CrewId::new().into(),
+                RunTarget::Job(_) | RunTarget::Shell(_) => JobId::new().into(),
+            };
+            append_cron_log(
+                self.logger.log_dir(),
+                cron,
+                &project,
+                &format!("catch-up: triggered missed run ({})", owner),
+            );
+            result_events.extend(
+                self.handle_cron_once(CronOnceParams {
+                    cron,
+                    owner: &owner,
+                    runbook_hash: &runbook_hash,
+                    target: &target,
+                    project: &project,
+                    project_path: &project_path,
+                })
+                .await?,
+            );
+        }
+
+        Ok(result_events)
+    }
+
     /// Handle a one-shot cron execution: create and start the job/agent immediately.
     pub(crate) async fn handle_cron_once(
         &self,
@@ -95,7 +233,7 @@ impl<C: Clock> Runtime<C> {
         let mut result_events = Vec::new();
 
         match target {
-            oj_core::RunTarget::Shell(cmd) => {
+            RunTarget::Shell(cmd) => {
                 let job_id = owner.try_job()?;
 
                 // Idempotency guard
@@ -117,7 +255,7 @@ impl<C: Clock> Runtime<C> {
                     .await?,
                 );
             }
-            oj_core::RunTarget::Agent(agent_name) => {
+            RunTarget::Agent(agent_name) => {
                 let agent_def = runbook
                     .get_agent(agent_name)
                     .ok_or_else(|| RuntimeError::AgentNotFound(agent_name.to_string()))?
@@ -173,7 +311,7 @@ impl<C: Clock> Runtime<C> {
                         .await?,
                 );
             }
-            oj_core::RunTarget::Job(job_name) => {
+            RunTarget::Job(job_name) => {
                 let job_id = owner.as_job().cloned().unwrap_or_else(JobId::new);
 
                 let display_name = oj_runbook::job_display_name(job_name, job_id.short(8), project);
@@ -6,17 +6,22 @@
 mod lifecycle;
 mod timer;
 
+use crate::engine::error::RuntimeError;
 use crate::engine::time_fmt::format_utc_now;
 use oj_core::log_paths::cron_log_path;
 use oj_core::{scoped_name, JobId, OwnerId, RunTarget};
+use oj_runbook::{CronExpr, CronTimezone};
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub(crate) use timer::next_fire_at_ms;
 
 /// In-memory state for a running cron
 pub(crate) struct CronState {
     pub project_path: PathBuf,
     pub runbook_hash: String,
-    pub interval: String,
+    pub trigger: CronTrigger,
     pub target: RunTarget,
     pub status: CronStatus,
     pub project: String,
@@ -30,6 +35,39 @@ pub(crate) enum CronStatus {
     Stopped,
 }
 
+/// When a cron fires: every `interval`, or whenever its calendar `schedule`
+/// matches.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum CronTrigger {
+    Interval(Duration),
+    Schedule { expr: CronExpr, timezone: CronTimezone },
+}
+
+impl CronTrigger {
+    /// Build a trigger from the fields of a `CronStarted` event or `CronRecord`.
+    /// A schedule takes precedence over the interval.
+    pub(crate) fn parse(
+        interval: &str,
+        schedule: Option<&str>,
+        timezone: Option<&str>,
+    ) -> Result<Self, RuntimeError> {
+        let Some(schedule) = schedule else {
+            let duration = crate::engine::monitor::parse_duration(interval).map_err(|e| {
+                RuntimeError::InvalidFormat(format!("invalid cron interval '{}': {}", interval, e))
+            })?;
+            return Ok(CronTrigger::Interval(duration));
+        };
+        let expr = CronExpr::parse(schedule).map_err(|e| {
+            RuntimeError::InvalidFormat(format!("invalid cron schedule '{}': {}", schedule, e))
+        })?;
+        let timezone = timezone
+            .map(CronTimezone::parse)
+            .transpose()
+            .map_err(|e| RuntimeError::InvalidFormat(format!("invalid cron timezone: {}", e)))?;
+        Ok(CronTrigger::Schedule { expr, timezone: timezone.unwrap_or_default() })
+    }
+}
+
 /// Append a timestamped line to the cron log file.
 ///
 /// Creates the `{logs_dir}/cron/` directory on first write.
@@ -53,6 +91,8 @@ pub(crate) struct CronStartedParams<'a> {
     pub project_path: &'a Path,
     pub runbook_hash: &'a str,
     pub interval: &'a str,
+    pub schedule: Option<&'a str>,
+    pub timezone: Option<&'a str>,
     pub target: &'a RunTarget,
 }
 
@@ -1,17 +1,46 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Cron timer firing, shell job creation, and runbook refresh
+//! Cron timer firing, next-fire computation, shell job creation, and runbook
+//! refresh
 
-use super::{append_cron_log, CronShellJobParams, CronStatus};
+use super::{append_cron_log, CronShellJobParams, CronStatus, CronTrigger};
 use crate::engine::error::RuntimeError;
 use crate::engine::runtime::agent::SpawnAgentParams;
 use crate::engine::runtime::handlers::CreateJobParams;
 use crate::engine::runtime::Runtime;
+use chrono::{DateTime, Datelike, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
 use oj_core::{split_scoped_name, Clock, Effect, Event, JobId, RunTarget, TimerId};
+use oj_runbook::{CronExpr, CronTimezone};
 use std::collections::HashMap;
+use std::time::Duration;
+
+/// How far ahead to search for a schedule's next fire before giving up
+const SEARCH_YEARS: i32 = 5;
 
 impl<C: Clock> Runtime<C> {
+    /// Arm the cron's timer for its next fire.
+    pub(super) async fn set_cron_timer(
+        &self,
+        cron_name: &str,
+        project: &str,
+        trigger: &CronTrigger,
+    ) -> Result<(), RuntimeError> {
+        let now_ms = self.executor.clock().epoch_ms();
+        let Some(duration) = trigger.delay_from(now_ms) else {
+            append_cron_log(
+                self.logger.log_dir(),
+                cron_name,
+                project,
+                "schedule has no upcoming fire time; timer not set",
+            );
+            return Ok(());
+        };
+        let timer_id = TimerId::cron(cron_name, project);
+        self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+        Ok(())
+    }
+
     /// Handle a cron timer firing: spawn job/agent and reschedule timer.
     pub(crate) async fn handle_cron_timer_fired(
         &self,
@@ -22,14 +51,14 @@ impl<C: Clock> Runtime<C> {
         let cron_key = rest;
         let (_, cron_name) = split_scoped_name(cron_key);
 
-        let (project_path, runbook_hash, target, interval, project, concurrency) = {
+        let (project_path, runbook_hash, target, trigger, project, concurrency) = {
             let crons = self.cron_states.lock();
             match crons.get(cron_key) {
                 Some(s) if s.status == CronStatus::Running => (
                     s.project_path.clone(),
                     s.runbook_hash.clone(),
                     s.target.clone(),
-                    s.interval.clone(),
+                    s.trigger.clone(),
                     s.project.clone(),
                     s.concurrency,
                 ),
@@ -80,15 +109,7 @@ impl<C: Clock> Runtime<C> {
                         ),
                     );
                     // Reschedule timer but don't spawn
-                    let duration =
-                        crate::engine::monitor::parse_duration(&interval).map_err(|e| {
-                            RuntimeError::InvalidFormat(format!(
-                                "invalid cron interval '{}': {}",
-                                interval, e
-                            ))
-                        })?;
-                    let timer_id = TimerId::cron(cron_name, &project);
-                    self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+                    self.set_cron_timer(cron_name, &project, &trigger).await?;
                     return Ok(result_events);
                 }
 
@@ -157,15 +178,7 @@ impl<C: Clock> Runtime<C> {
                             ),
                         );
                         // Reschedule timer but don't spawn
-                        let duration =
-                            crate::engine::monitor::parse_duration(&interval).map_err(|e| {
-                                RuntimeError::InvalidFormat(format!(
-                                    "invalid cron interval '{}': {}",
-                                    interval, e
-                                ))
-                            })?;
-                        let timer_id = TimerId::cron(cron_name, &project);
-                        self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+                        self.set_cron_timer(cron_name, &project, &trigger).await?;
                         return Ok(result_events);
                     }
                 }
@@ -232,15 +245,7 @@ impl<C: Clock> Runtime<C> {
                         &format!("skip: shell at max concurrency ({}/{})", active, concurrency),
                     );
                     // Reschedule timer but don't spawn
-                    let duration =
-                        crate::engine::monitor::parse_duration(&interval).map_err(|e| {
-                            RuntimeError::InvalidFormat(format!(
-                                "invalid cron interval '{}': {}",
-                                interval, e
-                            ))
-                        })?;
-                    let timer_id = TimerId::cron(cron_name, &project);
-                    self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+                    self.set_cron_timer(cron_name, &project, &trigger).await?;
                     return Ok(result_events);
                 }
 
@@ -270,12 +275,8 @@ impl<C: Clock> Runtime<C> {
             }
         }
 
-        // Reschedule timer for next interval
-        let duration = crate::engine::monitor::parse_duration(&interval).map_err(|e| {
-            RuntimeError::InvalidFormat(format!("invalid cron interval '{}': {}", interval, e))
-        })?;
-        let timer_id = TimerId::cron(cron_name, &project);
-        self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+        // Reschedule timer for the next fire
+        self.set_cron_timer(cron_name, &project, &trigger).await?;
 
         Ok(result_events)
     }
@@ -351,3 +352,104 @@ impl<C: Clock> Runtime<C> {
         Ok(result_events)
     }
 }
+
+impl CronTrigger {
+    /// Time from `now_ms` until the next fire. `None` if a schedule never
+    /// fires again.
+    pub(crate) fn delay_from(&self, now_ms: u64) -> Option<Duration> {
+        match self {
+            CronTrigger::Interval(duration) => Some(*duration),
+            CronTrigger::Schedule { expr, timezone } => {
+                let next_ms = next_fire_at_ms(expr, *timezone, now_ms)?;
+                Some(Duration::from_millis(next_ms.saturating_sub(now_ms)))
+            }
+        }
+    }
+
+    /// Count the fires in `(from_ms, to_ms]`, stopping at `limit`.
+    pub(crate) fn fires_between(&self, from_ms: u64, to_ms: u64, limit: usize) -> usize {
+        match self {
+            CronTrigger::Interval(duration) => {
+                let interval_ms = duration.as_millis() as u64;
+                if interval_ms == 0 {
+                    return 0;
+                }
+                let fires = to_ms.saturating_sub(from_ms) / interval_ms;
+                usize::try_from(fires).unwrap_or(usize::MAX).min(limit)
+            }
+            CronTrigger::Schedule { expr, timezone } => {
+                let mut count = 0;
+                let mut at_ms = from_ms;
+                while count < limit {
+                    match next_fire_at_ms(expr, *timezone, at_ms) {
+                        Some(next_ms) if next_ms <= to_ms => {
+                            count += 1;
+                            at_ms = next_ms;
+                        }
+                        _ => break,
+                    }
+                }
+                count
+            }
+        }
+    }
+}
+
+/// The first time strictly after `after_ms` at which the cron expression
+/// matches, evaluated on the wall clock of `timezone`.
+///
+/// Wall-clock times skipped by a DST change never fire; times repeated by one
+/// fire only on their first occurrence.
+pub(crate) fn next_fire_at_ms(
+    expr: &CronExpr,
+    timezone: CronTimezone,
+    after_ms: u64,
+) -> Option<u64> {
+    let after = Utc.timestamp_millis_opt(i64::try_from(after_ms).ok()?).single()?;
+    let next = match timezone {
+        CronTimezone::Utc => next_fire_in(expr, &Utc, after),
+        CronTimezone::Local => next_fire_in(expr, &Local, after),
+        CronTimezone::Fixed(secs) => {
+            next_fire_in(expr, &chrono::FixedOffset::east_opt(secs)?, after)
+        }
+        CronTimezone::Named(tz) => next_fire_in(expr, &tz, after),
+    }?;
+    u64::try_from(next.timestamp_millis()).ok()
+}
+
+fn next_fire_in<Tz: TimeZone>(
+    expr: &CronExpr,
+    tz: &Tz,
+    after: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let start = after.with_timezone(tz).naive_local().with_nanosecond(0)?;
+    let limit = start.year() + SEARCH_YEARS;
+    let mut t = start + chrono::Duration::seconds(1);
+
+    // Advance the coarsest mismatched field first, resetting finer ones
+    while t.year() <= limit {
+        if !expr.matches_month(t.month()) {
+            t = start_of_next_month(t.date())?;
+        } else if !expr.matches_day(t.day(), t.weekday().num_days_from_sunday()) {
+            t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+        } else if !expr.matches_hour(t.hour()) {
+            t = t.date().and_hms_opt(t.hour(), 0, 0)? + chrono::Duration::hours(1);
+        } else if !expr.matches_minute(t.minute()) {
+            t = t.date().and_hms_opt(t.hour(), t.minute(), 0)? + chrono::Duration::minutes(1);
+        } else if !expr.matches_second(t.second()) {
+            t += chrono::Duration::seconds(1);
+        } else {
+            match tz.from_local_datetime(&t).earliest().map(|dt| dt.with_timezone(&Utc)) {
+                Some(fire) if fire > after => return Some(fire),
+                _ => t += chrono::Duration::seconds(1),
+            }
+        }
+    }
+    None
+}
+
+fn start_of_next_month(date: NaiveDate) -> Option<NaiveDateTime> {
+    let (year, month) =
+        if date.month() == 12 { (date.year() + 1, 1) } else { (date.year(), date.month() + 1) };
+    NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)
+}
@@ -5,7 +5,7 @@
 
 use super::super::Runtime;
 use crate::engine::error::RuntimeError;
-use oj_core::{Clock, Effect, Event, JobId};
+use oj_core::{Clock, Effect, Event, JobId, Notification, NotifyKind};
 use oj_runbook::{NotifyConfig, Runbook};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -337,9 +337,12 @@ impl<C: Clock> Runtime<C> {
             notify_vars.insert("name".to_string(), job_name.clone());
 
             let message = NotifyConfig::render(template, &notify_vars);
-            if let Some(event) =
-                self.executor.execute(Effect::Notify { title: job_name.clone(), message }).await?
-            {
+            let notification = Notification::new(NotifyKind::OnStart, job_name.clone(), message)
+                .project(project.clone())
+                .owner(job_id)
+                .step(Some(initial_step.as_str()));
+            let effect = Effect::Notify { notification };
+            if let Some(event) = self.executor.execute(effect).await? {
                 result_events.push(event);
             }
         }
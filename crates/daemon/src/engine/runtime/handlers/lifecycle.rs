@@ -24,13 +24,18 @@ impl<C: Clock> Runtime<C> {
         let is_failed = job.step == "failed";
         let is_suspended = job.step == "suspended";
 
+        // A fanned-out job resumes its branches that wait on a decision
+        if job.is_parallel() && !is_failed && !is_suspended {
+            return self.resume_branches(&job, message, vars, kill).await;
+        }
+
         // If job is in terminal "failed" or "suspended" state, find the last failed step
         // from history so we can reset the job to that step for retry.
         let resume_step = if is_failed || is_suspended {
             job.step_history
                 .iter()
                 .rev()
-                .find(|r| matches!(r.outcome, StepOutcome::Failed(_)))
+                .find(|r| matches!(r.outcome, StepOutcome::Failed(_) | StepOutcome::TimedOut(_)))
                 .map(|r| r.name.clone())
                 .ok_or_else(|| {
                     RuntimeError::InvalidRequest("no failed step found in history".into())
@@ -191,8 +196,9 @@ impl<C: Clock> Runtime<C> {
     ) -> Result<Vec<Event>, RuntimeError> {
         let job = self.require_job(job_id.as_str())?;
 
-        // Verify we're in the expected step
-        if job.step != step {
+        // Verify we're in the expected step (or an active parallel branch)
+        let is_branch = job.is_branch_active(step);
+        if !is_branch && (job.step != step || job.is_parallel()) {
             tracing::warn!(
                 job_id = %job_id,
                 expected = step,
@@ -216,6 +222,9 @@ impl<C: Clock> Runtime<C> {
                 step,
                 &format!("shell completed (exit {})", exit_code),
             );
+            if is_branch {
+                return self.advance_branch(&job, step).await;
+            }
             self.advance_job(&job).await
         } else {
             self.logger.append(
@@ -223,7 +232,11 @@ impl<C: Clock> Runtime<C> {
                 step,
                 &format!("shell failed (exit {})", exit_code),
             );
-            self.fail_job(&job, &format!("shell exit code: {}", exit_code)).await
+            let error = format!("shell exit code: {}", exit_code);
+            if is_branch {
+                return self.fail_branch(&job, step, &error, false).await;
+            }
+            self.fail_job(&job, &error).await
         }
     }
 
@@ -363,9 +376,21 @@ impl<C: Clock> Runtime<C> {
     /// completes. Idempotent: no-op if the job/crew is already terminal.
     pub(crate) async fn handle_agent_spawned(
         &self,
+        agent_id: &AgentId,
         owner: &OwnerId,
     ) -> Result<Vec<Event>, RuntimeError> {
-        let Some(run) = self.get_active_run(owner) else {
+        // A parallel branch's agent notifies as its branch
+        let branch = match owner {
+            OwnerId::Job(job_id) => self
+                .get_active_job(job_id.as_str())
+                .and_then(|job| job.agent_branch(agent_id.as_str()).map(str::to_string)),
+            OwnerId::Crew(_) => None,
+        };
+        let run = match branch {
+            Some(branch) => self.get_branch_run(owner, &branch),
+            None => self.get_active_run(owner),
+        };
+        let Some(run) = run else {
             // For jobs: terminal (e.g. cancelled during spawn) — kill the orphan agent
             if let OwnerId::Job(job_id) = owner {
                 if let Some(job) = self.get_job(job_id.as_str()) {
@@ -417,7 +442,8 @@ impl<C: Clock> Runtime<C> {
 
     /// Handle AgentSpawnFailed: background agent spawn task failed.
     ///
-    /// For job-owned agents: deregisters the agent mapping and fails the job.
+    /// For job-owned agents: deregisters the agent mapping and fails the job
+    /// (or the parallel branch the agent was spawned for).
     /// For crew: emits CrewUpdated::Failed.
     pub(crate) async fn handle_agent_spawn_failed(
         &self,
@@ -437,11 +463,16 @@ impl<C: Clock> Runtime<C> {
                     return Ok(vec![]);
                 };
 
+                let step = job.agent_branch(agent_id.as_str()).unwrap_or(&job.step);
                 self.logger.append(
                     job_id.as_str(),
-                    &job.step,
+                    step,
                     &format!("agent spawn failed: {}", reason),
                 );
+                if job.is_branch_active(step) {
+                    let step = step.to_string();
+                    return self.fail_branch(&job, &step, reason, false).await;
+                }
                 self.fail_job(&job, reason).await
             }
             OwnerId::Crew(crew_id) => {
@@ -464,7 +495,7 @@ impl<C: Clock> Runtime<C> {
     /// It cleans up all associated resources:
     /// - Cancels all job-scoped timers
     /// - Deregisters agent→job mappings
-    /// - Kills any running agents/sessions
+    /// - Kills any running agents/sessions and shell commands
     /// - Deletes associated workspaces
     ///
     /// All cleanup is best-effort: errors are logged but don't fail the deletion.
@@ -488,6 +519,7 @@ impl<C: Clock> Runtime<C> {
             sched.cancel_timer(&format!("idle-grace{}", timer_prefix));
             // Cancel any cooldown timers (dynamic suffixes like cooldown:abc123:exit:0)
             sched.cancel_timers_with_prefix(&format!("cooldown:{}", job_id.as_str()));
+            sched.cancel_timers_with_prefix(&format!("step-timeout:{}", job_id.as_str()));
         }
 
         // The following cleanup depends on having job info
@@ -515,6 +547,16 @@ impl<C: Clock> Runtime<C> {
         // 5. Capture terminal + session log before killing session
         self.capture_before_kill_job(&job).await;
 
+        // Kill any shell command the job's step or branches are running
+        let mut steps = job.active_branches();
+        steps.push(&job.step);
+        for step in steps {
+            let _ = self
+                .executor
+                .execute(Effect::KillShell { job_id: *job_id, step: step.to_string() })
+                .await;
+        }
+
         // 6. Delete workspace if one exists
         let ws_id = job.workspace_id.or_else(|| {
             self.lock_state(|s| {
@@ -17,7 +17,7 @@ use self::command::HandleCommandParams;
 use self::cron::{CronOnceParams, CronStartedParams};
 use super::Runtime;
 use crate::engine::error::RuntimeError;
-use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event};
+use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event, Notification, NotifyKind};
 
 impl<C: Clock> Runtime<C> {
     /// Handle an incoming event and return any produced events
@@ -126,13 +126,25 @@ impl<C: Clock> Runtime<C> {
             }
 
             // -- cron events --
-            Event::CronStarted { cron, project_path, runbook_hash, interval, target, project } => {
+            Event::CronStarted {
+                cron,
+                project_path,
+                runbook_hash,
+                interval,
+                schedule,
+                timezone,
+                target,
+                project,
+                ..
+            } => {
                 result_events.extend(
                     self.handle_cron_started(CronStartedParams {
                         cron,
                         project_path,
                         runbook_hash,
                         interval,
+                        schedule: schedule.as_deref(),
+                        timezone: timezone.as_deref(),
                         target,
                         project,
                     })
@@ -210,6 +222,12 @@ impl<C: Clock> Runtime<C> {
                     || step == "suspended" =>
             {
                 result_events.extend(self.check_worker_job_complete(id, step).await?);
+                result_events.extend(self.handle_cron_run_finished((*id).into()).await?);
+            }
+
+            // Cron crew finished -> start its next queued catch-up run
+            Event::CrewUpdated { id, status, .. } if status.is_terminal() => {
+                result_events.extend(self.handle_cron_run_finished((*id).into()).await?);
             }
 
             // Queue pushed -> wake workers watching this queue
@@ -275,21 +293,33 @@ impl<C: Clock> Runtime<C> {
                 let scoped = scoped_name(project, queue);
                 self.queue_logger.append(&scoped, item_id, "completed");
             }
-            Event::QueueFailed { queue, item_id, error, project } => {
+            Event::QueueFailed { queue, item_id, error, project, retry_at_ms } => {
                 let scoped = scoped_name(project, queue);
-                self.queue_logger.append(&scoped, item_id, &format!("failed error=\"{}\"", error));
+                let mut line = format!("failed error=\"{}\"", error);
+                if let Some(retry_at_ms) = retry_at_ms {
+                    line.push_str(&format!(" retry_at_ms={}", retry_at_ms));
+                }
+                self.queue_logger.append(&scoped, item_id, &line);
             }
             Event::QueueDropped { queue, item_id, project } => {
                 let scoped = scoped_name(project, queue);
                 self.queue_logger.append(&scoped, item_id, "dropped");
             }
-            Event::QueueRetry { queue, item_id, project } => {
+            Event::QueueRetry { queue, item_id, project, scheduled } => {
                 let scoped = scoped_name(project, queue);
-                self.queue_logger.append(&scoped, item_id, "retried");
+                let line = if *scheduled { "retried (scheduled)" } else { "retried" };
+                self.queue_logger.append(&scoped, item_id, line);
             }
             Event::QueueDead { queue, item_id, project } => {
                 let scoped = scoped_name(project, queue);
                 self.queue_logger.append(&scoped, item_id, "dead");
+                let notification = Notification::new(
+                    NotifyKind::DeadLetter,
+                    format!("Dead letter: {}", queue),
+                    format!("Item {} in queue {} failed with no retries left", item_id, queue),
+                )
+                .project(project.clone());
+                result_events.extend(self.executor.execute(Effect::Notify { notification }).await?);
             }
 
             // Populate in-process runbook cache so subsequent WorkerStarted
@@ -315,8 +345,8 @@ impl<C: Clock> Runtime<C> {
                 result_events.extend(self.handle_workspace_failed(id, reason).await?);
             }
 
-            Event::AgentSpawned { owner, .. } => {
-                result_events.extend(self.handle_agent_spawned(owner).await?);
+            Event::AgentSpawned { id: agent_id, owner, .. } => {
+                result_events.extend(self.handle_agent_spawned(agent_id, owner).await?);
             }
 
             Event::AgentSpawnFailed { id: agent_id, owner, reason } => {
@@ -328,6 +358,10 @@ impl<C: Clock> Runtime<C> {
                 result_events.extend(self.handle_job_created(id).await?);
             }
 
+            Event::DecisionCreated { id, expires_at_ms: Some(expires_at_ms), .. } => {
+                self.arm_decision_timeout(*id, *expires_at_ms).await?;
+            }
+
             Event::CrewResume { id, message, kill } => {
                 result_events.extend(self.handle_crew_resume(id, message.as_deref(), *kill).await?);
             }
@@ -336,6 +370,7 @@ impl<C: Clock> Runtime<C> {
             Event::Shutdown
             | Event::Custom
             | Event::JobAdvanced { .. }
+            | Event::JobForked { .. }
             | Event::StepStarted { .. }
             | Event::StepWaiting { .. }
             | Event::StepCompleted { .. }
@@ -347,6 +382,7 @@ impl<C: Clock> Runtime<C> {
             | Event::JobCancelling { .. }
             | Event::JobSuspending { .. }
             | Event::JobUpdated { .. }
+            | Event::JobArchived { .. }
             | Event::WorkerDispatched { .. }
             | Event::CronFired { .. }
             | Event::CronDeleted { .. }
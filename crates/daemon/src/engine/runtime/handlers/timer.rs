@@ -5,6 +5,7 @@
 
 use super::super::Runtime;
 use crate::engine::error::RuntimeError;
+use crate::engine::lifecycle::RunLifecycle;
 use crate::engine::monitor::MonitorState;
 use crate::engine::ActionContext;
 use oj_core::{
@@ -21,36 +22,49 @@ impl<C: Clock> Runtime<C> {
             Some(TimerKind::Cooldown { owner, trigger, chain_pos }) => {
                 self.handle_owner_cooldown(owner, trigger, chain_pos).await
             }
+            Some(TimerKind::StepTimeout { owner, step }) => {
+                self.handle_step_timeout(owner, step).await
+            }
             Some(TimerKind::QueueRetry { scoped_queue, item_id }) => {
                 self.handle_queue_retry_timer(scoped_queue, item_id).await
             }
+            Some(TimerKind::QueueDelay { scoped_queue }) => {
+                self.handle_queue_delay_timer(scoped_queue).await
+            }
             Some(TimerKind::Cron { scoped_name }) => {
                 self.handle_cron_timer_fired(scoped_name).await
             }
             Some(TimerKind::QueuePoll { scoped_name }) => {
                 self.handle_queue_poll_timer(scoped_name).await
             }
+            Some(TimerKind::WorkerThrottle { scoped_name }) => {
+                self.handle_worker_wake(scoped_name).await
+            }
+            Some(TimerKind::DecisionTimeout(id)) => self.handle_decision_timeout(id).await,
             None => Ok(vec![]),
         }
     }
 
-    /// Periodic liveness check. Checks if the agent is alive.
+    /// Periodic liveness check. Checks if the owner's agents are alive.
     async fn handle_owner_liveness(&self, owner: OwnerId) -> Result<Vec<Event>, RuntimeError> {
-        let agent_id = match self.get_owner_active_agent(&owner) {
-            Some(id) => id,
-            None => return Ok(vec![]),
-        };
-
-        let is_running = self.executor.agents.is_alive(&agent_id).await;
+        let (mut any_alive, mut any_dead) = (false, false);
+        for agent_id in self.get_owner_active_agents(&owner) {
+            if self.executor.agents.is_alive(&agent_id).await {
+                any_alive = true;
+            } else {
+                any_dead = true;
+            }
+        }
 
-        if is_running {
+        if any_alive {
             self.executor
                 .execute(Effect::SetTimer {
                     id: TimerId::liveness(owner),
                     duration: crate::engine::spawn::LIVENESS_INTERVAL,
                 })
                 .await?;
-        } else {
+        }
+        if any_dead {
             tracing::info!(%owner, "agent process dead, scheduling deferred exit");
             self.executor
                 .execute(Effect::SetTimer {
@@ -63,11 +77,34 @@ impl<C: Clock> Runtime<C> {
     }
 
     /// Deferred exit handler (5s after liveness detected death).
-    /// Reads final session log to determine exit reason.
+    ///
+    /// A fanned-out job handles each agent branch whose agent has died.
     async fn handle_owner_exit_deferred(&self, owner: OwnerId) -> Result<Vec<Event>, RuntimeError> {
-        let Some(run) = self.get_active_run(&owner) else {
-            return Ok(vec![]);
-        };
+        let mut result_events = Vec::new();
+        for run in self.get_active_runs(&owner) {
+            let Some(branch) = run.branch() else {
+                result_events.extend(self.handle_run_exit_deferred(run.as_ref()).await?);
+                continue;
+            };
+            // An earlier branch's exit may have advanced or failed the job
+            let Some(run) = self.get_branch_run(&owner, branch) else {
+                continue;
+            };
+            if let Some(agent_id) = run.agent_id().map(AgentId::from_string) {
+                if self.executor.agents.is_alive(&agent_id).await {
+                    continue;
+                }
+            }
+            result_events.extend(self.handle_run_exit_deferred(run.as_ref()).await?);
+        }
+        Ok(result_events)
+    }
+
+    /// Read a run's final session log to determine its exit reason.
+    async fn handle_run_exit_deferred(
+        &self,
+        run: &dyn RunLifecycle,
+    ) -> Result<Vec<Event>, RuntimeError> {
         let agent_id = run.agent_id().map(AgentId::from_string);
 
         if agent_id.is_none() {
@@ -91,17 +128,28 @@ impl<C: Clock> Runtime<C> {
         let Ok(agent_def) = run.resolve_agent_def(&runbook) else {
             return Ok(vec![]);
         };
-        self.handle_monitor_state_for(run.as_ref(), &agent_def, monitor_state).await
+        self.handle_monitor_state_for(run, &agent_def, monitor_state).await
     }
 
     /// Cooldown timer handler — re-trigger the action after cooldown expires.
+    ///
+    /// Branch cooldowns carry their branch in the trigger: `<branch>/<trigger>`.
     async fn handle_owner_cooldown(
         &self,
         owner: OwnerId,
         trigger: &str,
         chain_pos: usize,
     ) -> Result<Vec<Event>, RuntimeError> {
-        let Some(run) = self.get_active_run(&owner) else {
+        let (run, trigger) = match trigger.split_once('/') {
+            Some((branch, trigger)) => (self.get_branch_run(&owner, branch), trigger),
+            None => {
+                // A fanned-out job's cooldowns always belong to a branch
+                let fanned_out = matches!(&owner, OwnerId::Job(id)
+                    if self.get_active_job(id.as_str()).is_some_and(|j| j.is_parallel()));
+                (self.get_active_run(&owner).filter(|_| !fanned_out), trigger)
+            }
+        };
+        let Some(run) = run else {
             return Ok(vec![]);
         };
 
@@ -139,10 +187,13 @@ impl<C: Clock> Runtime<C> {
         self.execute_action_with_attempts_for(run.as_ref(), &ctx).await
     }
 
-    /// Get agent_id for a non-terminal owner. Returns None if owner is missing,
-    /// terminal, or has no agent.
-    fn get_owner_active_agent(&self, owner: &OwnerId) -> Option<AgentId> {
-        self.get_active_run(owner)?.agent_id().map(AgentId::from_string)
+    /// Get the agent_ids of a non-terminal owner's active runs. Empty if the
+    /// owner is missing, terminal, or has no agent.
+    fn get_owner_active_agents(&self, owner: &OwnerId) -> Vec<AgentId> {
+        self.get_active_runs(owner)
+            .iter()
+            .filter_map(|run| run.agent_id().map(AgentId::from_string))
+            .collect()
     }
 
     /// Handle queue retry timer expiry — move item back to Pending and wake workers.
@@ -168,11 +219,33 @@ impl<C: Clock> Runtime<C> {
                     queue: queue_name.clone(),
                     item_id: item_id.to_string(),
                     project: project.clone(),
+                    scheduled: true,
                 },
             }])
             .await?;
+        result_events.extend(self.wake_queue_workers(&queue_name, &project).await?);
+
+        Ok(result_events)
+    }
+
+    /// Handle queue delay timer expiry — a delayed item is now eligible, so
+    /// wake workers. Their poll re-arms the timer for any later items.
+    async fn handle_queue_delay_timer(
+        &self,
+        scoped_queue: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let (project, queue_name) = split_scoped_name(scoped_queue);
+        tracing::info!(queue = queue_name, project, "queue delay timer fired");
+        self.wake_queue_workers(queue_name, project).await
+    }
 
-        // Wake workers attached to this queue
+    /// Wake workers attached to a queue.
+    async fn wake_queue_workers(
+        &self,
+        queue_name: &str,
+        project: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let mut result_events = Vec::new();
         let worker_names: Vec<String> = {
             let workers = self.worker_states.lock();
             workers
@@ -194,7 +267,10 @@ impl<C: Clock> Runtime<C> {
             result_events.extend(
                 self.executor
                     .execute_all(vec![Effect::Emit {
-                        event: Event::WorkerWake { worker: bare_name, project: project.clone() },
+                        event: Event::WorkerWake {
+                            worker: bare_name,
+                            project: project.to_string(),
+                        },
                     }])
                     .await?,
             );
@@ -6,9 +6,8 @@
 use super::WorkerStatus;
 use crate::engine::error::RuntimeError;
 use crate::engine::runtime::Runtime;
-use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event, JobId, OwnerId, TimerId};
+use oj_core::{split_scoped_name, Clock, Effect, Event, JobId, OwnerId};
 use oj_runbook::QueueType;
-use std::time::Duration;
 
 impl<C: Clock> Runtime<C> {
     /// Check if a completed job belongs to a worker and trigger re-poll if so.
@@ -91,72 +90,31 @@ impl<C: Clock> Runtime<C> {
             // be retried when the job is resumed.
             if queue_type == QueueType::Persisted && terminal_step != "suspended" {
                 if let Some(ref item_id) = item_id {
-                    let queue_event = if terminal_step == "done" {
-                        Event::QueueCompleted {
-                            queue: queue_name.clone(),
-                            item_id: item_id.clone(),
-                            project: worker_namespace.clone(),
-                        }
+                    if terminal_step == "done" {
+                        result_events.extend(
+                            self.executor
+                                .execute_all(vec![Effect::Emit {
+                                    event: Event::QueueCompleted {
+                                        queue: queue_name.clone(),
+                                        item_id: item_id.clone(),
+                                        project: worker_namespace.clone(),
+                                    },
+                                }])
+                                .await?,
+                        );
                     } else {
-                        Event::QueueFailed {
-                            queue: queue_name.clone(),
-                            item_id: item_id.clone(),
-                            error: format!("job reached '{}'", terminal_step),
-                            project: worker_namespace.clone(),
-                        }
-                    };
-                    result_events.extend(
-                        self.executor
-                            .execute_all(vec![Effect::Emit { event: queue_event }])
-                            .await?,
-                    );
-
-                    // Retry-or-dead logic: after QueueFailed is applied, check retry config
-                    if terminal_step != "done" {
-                        let scoped_queue = scoped_name(&worker_namespace, &queue_name);
-
-                        // Read failures from state (QueueFailed already incremented it)
-                        let failures = self.lock_state(|state| {
-                            state
-                                .queue_items
-                                .get(&scoped_queue)
-                                .and_then(|items| {
-                                    items.iter().find(|i| i.id == *item_id).map(|i| i.failures)
-                                })
-                                .unwrap_or(0)
-                        });
-
-                        // Look up retry config from the runbook
                         let runbook = self.cached_runbook(&runbook_hash)?;
-                        let retry_config =
-                            runbook.get_queue(&queue_name).and_then(|q| q.retry.as_ref());
-
-                        let max_attempts = retry_config.map(|r| r.attempts).unwrap_or(0);
-
-                        if max_attempts > 0 && failures < max_attempts {
-                            // Schedule retry after cooldown
-                            let cooldown_str =
-                                retry_config.map(|r| r.cooldown.as_str()).unwrap_or("0s");
-                            let duration = crate::engine::monitor::parse_duration(cooldown_str)
-                                .unwrap_or(Duration::ZERO);
-                            let timer_id = TimerId::queue_retry(&scoped_queue, item_id);
-                            self.executor
-                                .execute(Effect::SetTimer { id: timer_id, duration })
-                                .await?;
-                        } else {
-                            // Mark as dead
-                            result_events.extend(
-                                self.executor
-                                    .execute_all(vec![Effect::Emit {
-                                        event: Event::QueueDead {
-                                            queue: queue_name.clone(),
-                                            item_id: item_id.clone(),
-                                            project: worker_namespace.clone(),
-                                        },
-                                    }])
-                                    .await?,
-                            );
-                        }
+                        let retry = runbook.get_queue(&queue_name).and_then(|q| q.retry.as_ref());
+                        result_events.extend(
+                            self.fail_queue_item(
+                                &queue_name,
+                                &worker_namespace,
+                                item_id,
+                                format!("job reached '{}'", terminal_step),
+                                retry,
+                            )
+                            .await?,
+                        );
                     }
                 }
             }
@@ -191,11 +149,10 @@ impl<C: Clock> Runtime<C> {
                         }
                     }
                     QueueType::Persisted => {
-                        result_events.extend(self.poll_persisted_queue(
-                            &worker_key,
-                            &queue_name,
-                            &worker_namespace,
-                        )?);
+                        result_events.extend(
+                            self.poll_persisted_queue(&worker_key, &queue_name, &worker_namespace)
+                                .await?,
+                        );
                     }
                 }
             }
@@ -3,13 +3,15 @@
 
 //! Queue item dispatch: take items from queue and create jobs
 
+use super::throttle::WorkerLimits;
 use super::WorkerStatus;
 use crate::engine::error::RuntimeError;
 use crate::engine::runtime::handlers::CreateJobParams;
 use crate::engine::runtime::Runtime;
 use crate::storage::{QueueItemStatus, QueuePollMeta};
 use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event, JobId, OwnerId};
-use oj_runbook::QueueType;
+use oj_runbook::{QueueOrder, QueueType};
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
@@ -29,7 +31,32 @@ impl<C: Clock> Runtime<C> {
             result_events.push(loaded_event);
         }
 
-        let (queue_type, take_template, cwd, available_slots, queue_name, worker_namespace) = {
+        // Hold back while the worker's rate is used up or its window is closed
+        let now_ms = self.executor.clock().epoch_ms();
+        let (limits, budget) = {
+            let mut workers = self.worker_states.lock();
+            let state = match workers.get_mut(worker_key) {
+                Some(s) if s.status != WorkerStatus::Stopped => s,
+                _ => return Ok(result_events),
+            };
+            let runbook = self.cached_runbook(&state.runbook_hash)?;
+            let limits =
+                runbook.get_worker(bare_name).map(WorkerLimits::from_def).unwrap_or_default();
+            let budget = limits.check(&mut state.dispatched_at_ms, now_ms);
+            (limits, budget)
+        };
+        let rate_budget = match budget {
+            Ok(remaining) => {
+                self.unthrottle_worker(worker_key);
+                remaining
+            }
+            Err(throttle) => {
+                self.throttle_worker(worker_key, throttle).await?;
+                return Ok(result_events);
+            }
+        };
+
+        let (queue_type, take_template, order, cwd, available_slots, queue_name, worker_namespace) = {
             let mut workers = self.worker_states.lock();
             let state = match workers.get_mut(worker_key) {
                 Some(s) if s.status != WorkerStatus::Stopped => s,
@@ -38,6 +65,7 @@ impl<C: Clock> Runtime<C> {
 
             let active = state.active.len() as u32 + state.pending_takes;
             let available = state.concurrency.saturating_sub(active);
+            let available = rate_budget.map_or(available, |r| available.min(r));
             if available == 0 || items.is_empty() {
                 self.worker_logger
                     .append(worker_key, &format!("idle (active={}/{})", active, state.concurrency));
@@ -57,6 +85,7 @@ impl<C: Clock> Runtime<C> {
             (
                 queue_type,
                 queue_def.take.clone(),
+                queue_def.order,
                 state.project_path.clone(),
                 available as usize,
                 state.queue_name.clone(),
@@ -68,7 +97,7 @@ impl<C: Clock> Runtime<C> {
         let scoped_key = scoped_name(&worker_namespace, &queue_name);
         self.lock_state_mut(|s| {
             s.poll_meta.insert(
-                scoped_key,
+                scoped_key.clone(),
                 QueuePollMeta {
                     last_item_count: items.len(),
                     last_polled_at_ms: self.executor.clock().epoch_ms(),
@@ -76,8 +105,25 @@ impl<C: Clock> Runtime<C> {
             )
         });
 
+        // Persisted items are polled in push order; their priority is in state
+        let items = match queue_type {
+            QueueType::External => ordered_items(items, order, json_item_priority),
+            QueueType::Persisted => {
+                let priorities: HashMap<String, i32> = self.lock_state(|state| {
+                    state
+                        .queue_items
+                        .get(&scoped_key)
+                        .map(|items| items.iter().map(|i| (i.id.clone(), i.priority)).collect())
+                        .unwrap_or_default()
+                });
+                ordered_items(items, order, |item| {
+                    priorities.get(&json_item_id(item)).copied().unwrap_or_default()
+                })
+            }
+        };
+
         let mut dispatched_count = 0;
-        for item in items.iter() {
+        for item in items {
             if dispatched_count >= available_slots {
                 break;
             }
@@ -140,20 +186,22 @@ impl<C: Clock> Runtime<C> {
                             item: item.clone(),
                         })
                         .await?;
+                    self.record_worker_dispatch(worker_key, &limits, now_ms);
                     dispatched_count += 1;
                 }
                 QueueType::Persisted => {
                     // Guard against stale WorkerPolled events: if multiple
                     // polls run before any dispatches are processed, their payloads
                     // overlap. Skip items that are no longer Pending to avoid
-                    // creating duplicate jobs for the same queue item.
+                    // creating duplicate jobs for the same queue item. Delayed
+                    // items are skipped too; the queue delay timer wakes us.
                     let scoped_queue = scoped_name(&worker_namespace, &queue_name);
                     let still_pending = self.lock_state(|state| {
                         state
                             .queue_items
                             .get(&scoped_queue)
                             .and_then(|items| items.iter().find(|i| i.id == item_id))
-                            .map(|i| i.status == QueueItemStatus::Pending)
+                            .map(|i| i.status == QueueItemStatus::Pending && i.is_eligible(now_ms))
                             .unwrap_or(false)
                     });
                     if !still_pending {
@@ -176,11 +224,23 @@ impl<C: Clock> Runtime<C> {
 
                     // Dispatch job immediately for persisted queues
                     result_events.extend(self.dispatch_queue_item(worker_key, item).await?);
+                    self.record_worker_dispatch(worker_key, &limits, now_ms);
                     dispatched_count += 1;
                 }
             }
         }
 
+        // Report the worker as throttled as soon as it uses up its rate
+        if dispatched_count > 0 && rate_budget.is_some() {
+            let budget = {
+                let mut workers = self.worker_states.lock();
+                workers.get_mut(worker_key).map(|s| limits.check(&mut s.dispatched_at_ms, now_ms))
+            };
+            if let Some(Err(throttle)) = budget {
+                self.throttle_worker(worker_key, throttle).await?;
+            }
+        }
+
         Ok(result_events)
     }
 
@@ -359,6 +419,37 @@ impl<C: Clock> Runtime<C> {
     }
 }
 
+/// Arrange polled items in the order the queue's workers take them.
+///
+/// Items arrive oldest first: in push order for persisted queues, and in
+/// the `list` command's order for external ones.
+fn ordered_items(
+    items: &[serde_json::Value],
+    order: QueueOrder,
+    priority: impl Fn(&serde_json::Value) -> i32,
+) -> Vec<&serde_json::Value> {
+    let mut ordered: Vec<_> = items.iter().collect();
+    match order {
+        QueueOrder::Fifo => {}
+        QueueOrder::Lifo => ordered.reverse(),
+        // Stable, so equal priorities stay oldest first
+        QueueOrder::Priority => ordered.sort_by_cached_key(|item| Reverse(priority(item))),
+    }
+    ordered
+}
+
+/// Priority of an external queue item, from its `priority` field as a
+/// number or numeric string.
+fn json_item_priority(item: &serde_json::Value) -> i32 {
+    match item.get("priority") {
+        Some(serde_json::Value::Number(n)) => {
+            n.as_i64().map(|n| n.clamp(i32::MIN.into(), i32::MAX.into()) as i32).unwrap_or(0)
+        }
+        Some(serde_json::Value::String(s)) => s.trim().parse().unwrap_or(0),
+        _ => 0,
+    }
+}
+
 /// Extract a dedup identifier from a queue item.
 ///
 /// Tries `id` then `number`, falling back to a content-based fingerprint
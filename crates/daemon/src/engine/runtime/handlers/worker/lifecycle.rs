@@ -7,9 +7,12 @@ use super::{WorkerState, WorkerStatus};
 use crate::engine::error::RuntimeError;
 use crate::engine::runtime::Runtime;
 use crate::storage::QueueItemStatus;
-use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event, JobId, OwnerId, TimerId};
+use oj_core::{
+    scoped_name, split_scoped_name, Clock, Effect, Event, JobId, Notification, NotifyKind, OwnerId,
+    TimerId,
+};
 use oj_runbook::QueueType;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::Path;
 use std::time::Duration;
 
@@ -72,36 +75,53 @@ impl<C: Clock> Runtime<C> {
         let queue_type = queue_def.queue_type;
 
         // Restore active jobs from persisted state (survives daemon restart)
-        let (persisted_active, persisted_item_map, persisted_inflight) = self.lock_state(|state| {
-            let scoped = scoped_name(project, worker_name);
-            let record = state.workers.get(&scoped);
-
-            let active: HashSet<OwnerId> = record
-                .map(|w| w.active.iter().filter_map(|s| OwnerId::parse(s).ok()).collect())
-                .unwrap_or_default();
-
-            // Restore owner→item map from persisted WorkerRecord
-            let item_map: HashMap<OwnerId, String> = record
-                .map(|w| {
-                    w.owners
-                        .iter()
-                        .filter_map(|(owner_str, item_id)| {
-                            Some((OwnerId::parse(owner_str).ok()?, item_id.clone()))
-                        })
-                        .collect()
-                })
-                .unwrap_or_default();
-
-            // For external queues, restore inflight item IDs so overlapping
-            // polls after restart don't re-dispatch already-active items.
-            let inflight: HashSet<String> = if queue_type == QueueType::External {
-                item_map.values().cloned().collect()
-            } else {
-                HashSet::new()
-            };
+        let (persisted_active, persisted_item_map, persisted_inflight, persisted_dispatches) = self
+            .lock_state(|state| {
+                let scoped = scoped_name(project, worker_name);
+                let record = state.workers.get(&scoped);
+
+                let active: HashSet<OwnerId> = record
+                    .map(|w| w.active.iter().filter_map(|s| OwnerId::parse(s).ok()).collect())
+                    .unwrap_or_default();
+
+                // Restore owner→item map from persisted WorkerRecord
+                let item_map: HashMap<OwnerId, String> = record
+                    .map(|w| {
+                        w.owners
+                            .iter()
+                            .filter_map(|(owner_str, item_id)| {
+                                Some((OwnerId::parse(owner_str).ok()?, item_id.clone()))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                // For external queues, restore inflight item IDs so overlapping
+                // polls after restart don't re-dispatch already-active items.
+                let inflight: HashSet<String> = if queue_type == QueueType::External {
+                    item_map.values().cloned().collect()
+                } else {
+                    HashSet::new()
+                };
+
+                let dispatches: VecDeque<u64> = record
+                    .map(|w| w.dispatched_at_ms.iter().copied().collect())
+                    .unwrap_or_default();
+
+                (active, item_map, inflight, dispatches)
+            });
 
-            (active, item_map, inflight)
-        });
+        // Keep dispatch history across a stop/start so a restart can't reset
+        // the worker's rate. The in-memory history also counts external takes
+        // that failed; after a daemon restart only the persisted dispatches
+        // are left.
+        let dispatched_at_ms = {
+            let workers = self.worker_states.lock();
+            workers
+                .get(&worker_key)
+                .map(|s| s.dispatched_at_ms.clone())
+                .unwrap_or(persisted_dispatches)
+        };
 
         // Store worker state
         let poll_interval = queue_def.poll.clone();
@@ -119,6 +139,7 @@ impl<C: Clock> Runtime<C> {
             poll_interval: poll_interval.clone(),
             pending_takes: 0,
             inflight_items: persisted_inflight,
+            dispatched_at_ms,
         };
 
         {
@@ -174,11 +195,10 @@ impl<C: Clock> Runtime<C> {
                 Ok(result_events)
             }
             QueueType::Persisted => {
-                result_events.extend(self.poll_persisted_queue(
-                    &worker_key,
-                    &worker_def.source.queue,
-                    project,
-                )?);
+                result_events.extend(
+                    self.poll_persisted_queue(&worker_key, &worker_def.source.queue, project)
+                        .await?,
+                );
                 Ok(result_events)
             }
         }
@@ -188,24 +208,38 @@ impl<C: Clock> Runtime<C> {
         &self,
         worker_key: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
-        let (bare_name, project) = {
+        let (bare_name, project, was_running) = {
             let mut workers = self.worker_states.lock();
             if let Some(state) = workers.get_mut(worker_key) {
                 self.worker_logger.append(worker_key, "stopped");
+                let was_running = state.status == WorkerStatus::Running;
                 state.status = WorkerStatus::Stopped;
                 state.pending_takes = 0;
                 state.inflight_items.clear();
                 let (_, bare) = split_scoped_name(worker_key);
-                (bare.to_string(), state.project.clone())
+                (bare.to_string(), state.project.clone(), was_running)
             } else {
                 let (_, bare) = split_scoped_name(worker_key);
-                (bare.to_string(), String::new())
+                (bare.to_string(), String::new(), false)
             }
         };
 
-        // Cancel poll timer if it was set (no-op if timer doesn't exist)
+        // Cancel poll and throttle timers if they were set (no-op if they don't exist)
         let timer_id = TimerId::queue_poll(&bare_name, &project);
         self.executor.execute(Effect::CancelTimer { id: timer_id }).await?;
+        let timer_id = TimerId::worker_throttle(&bare_name, &project);
+        self.executor.execute(Effect::CancelTimer { id: timer_id }).await?;
+        self.unthrottle_worker(worker_key);
+
+        if was_running {
+            let notification = Notification::new(
+                NotifyKind::WorkerStopped,
+                format!("Worker stopped: {}", bare_name),
+                format!("Worker {} stopped and will not take new items", bare_name),
+            )
+            .project(project);
+            self.executor.execute(Effect::Notify { notification }).await?;
+        }
 
         Ok(vec![])
     }
@@ -408,47 +442,36 @@ impl<C: Clock> Runtime<C> {
                 "reconciling orphaned queue item (no job)"
             );
 
-            self.executor
-                .execute_all(vec![Effect::Emit {
-                    event: Event::QueueFailed {
-                        queue: queue_name.clone(),
-                        item_id: item_id.clone(),
-                        error: "job lost during daemon recovery".to_string(),
-                        project: project.to_string(),
-                    },
-                }])
-                .await?;
-
-            // Apply retry-or-dead logic
-            let failures = self.lock_state(|state| {
-                state
-                    .queue_items
-                    .get(&scoped_queue)
-                    .and_then(|items| items.iter().find(|i| i.id == item_id))
-                    .map(|i| i.failures)
-                    .unwrap_or(0)
-            });
-
-            let retry_config = runbook.get_queue(&queue_name).and_then(|q| q.retry.as_ref());
-            let max_attempts = retry_config.map(|r| r.attempts).unwrap_or(0);
+            let retry = runbook.get_queue(&queue_name).and_then(|q| q.retry.as_ref());
+            self.fail_queue_item(
+                &queue_name,
+                project,
+                &item_id,
+                "job lost during daemon recovery".to_string(),
+                retry,
+            )
+            .await?;
+        }
 
-            if max_attempts > 0 && failures < max_attempts {
-                let cooldown_str = retry_config.map(|r| r.cooldown.as_str()).unwrap_or("0s");
-                let duration =
-                    crate::engine::monitor::parse_duration(cooldown_str).unwrap_or(Duration::ZERO);
-                let timer_id = TimerId::queue_retry(&scoped_queue, &item_id);
-                self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
-            } else {
-                self.executor
-                    .execute_all(vec![Effect::Emit {
-                        event: Event::QueueDead {
-                            queue: queue_name.clone(),
-                            item_id,
-                            project: project.to_string(),
-                        },
-                    }])
-                    .await?;
-            }
+        // 3. Re-arm retry timers lost with the previous daemon
+        let now_ms = self.executor.clock().epoch_ms();
+        let scheduled_retries: Vec<(String, u64)> = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(&scoped_queue)
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter(|i| i.status == QueueItemStatus::Failed)
+                        .filter_map(|i| Some((i.id.clone(), i.retry_at_ms?)))
+                        .collect()
+                })
+                .unwrap_or_default()
+        });
+        for (item_id, retry_at_ms) in scheduled_retries {
+            let id = TimerId::queue_retry(&scoped_queue, &item_id);
+            let duration = Duration::from_millis(retry_at_ms.saturating_sub(now_ms));
+            self.executor.execute(Effect::SetTimer { id, duration }).await?;
         }
 
         Ok(())
@@ -7,10 +7,12 @@ mod completion;
 mod dispatch;
 mod lifecycle;
 mod polling;
+mod retry;
+mod throttle;
 
 use oj_core::OwnerId;
 use oj_runbook::QueueType;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
 
 /// In-memory state for a running worker
@@ -35,6 +37,9 @@ pub(crate) struct WorkerState {
     /// Item IDs that are in-flight (pending take or active job) for external queues.
     /// Prevents duplicate dispatches when overlapping polls return the same items.
     pub inflight_items: HashSet<String>,
+    /// Recent dispatch times (epoch ms), oldest first, counted against the
+    /// worker's `rate`
+    pub dispatched_at_ms: VecDeque<u64>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,6 +9,7 @@ use crate::engine::runtime::Runtime;
 use crate::storage::{QueueItemStatus, QueuePollMeta};
 use oj_core::{scoped_name, split_scoped_name, Clock, Effect, Event, TimerId};
 use oj_runbook::QueueType;
+use std::time::Duration;
 
 impl<C: Clock> Runtime<C> {
     pub(crate) async fn handle_worker_wake(
@@ -83,11 +84,9 @@ impl<C: Clock> Runtime<C> {
                 result_events.extend(self.executor.execute_all(vec![poll_effect]).await?);
             }
             QueueType::Persisted => {
-                result_events.extend(self.poll_persisted_queue(
-                    worker_key,
-                    &queue_name,
-                    &worker_namespace,
-                )?);
+                result_events.extend(
+                    self.poll_persisted_queue(worker_key, &queue_name, &worker_namespace).await?,
+                );
             }
         }
 
@@ -95,35 +94,51 @@ impl<C: Clock> Runtime<C> {
     }
 
     /// Read pending items from MaterializedState and synthesize a WorkerPolled event.
-    pub(super) fn poll_persisted_queue(
+    ///
+    /// Delayed items are left out; the queue delay timer is armed to wake
+    /// workers when the earliest of them becomes eligible.
+    pub(super) async fn poll_persisted_queue(
         &self,
         worker_key: &str,
         queue_name: &str,
         project: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
         let key = scoped_name(project, queue_name);
-        let (total, items): (usize, Vec<serde_json::Value>) = self.lock_state(|state| match state
-            .queue_items
-            .get(&key)
-        {
-            Some(queue_items) => {
-                let total = queue_items.len();
-                let pending: Vec<_> = queue_items
-                    .iter()
-                    .filter(|item| item.status == QueueItemStatus::Pending)
-                    .map(|item| {
-                        let mut obj = serde_json::Map::new();
-                        obj.insert("id".to_string(), serde_json::Value::String(item.id.clone()));
-                        for (k, v) in &item.data {
-                            obj.insert(k.clone(), serde_json::Value::String(v.clone()));
-                        }
-                        serde_json::Value::Object(obj)
-                    })
-                    .collect();
-                (total, pending)
-            }
-            None => (0, Vec::new()),
-        });
+        let now_ms = self.executor.clock().epoch_ms();
+        let (total, items, next_eligible_ms): (usize, Vec<serde_json::Value>, Option<u64>) = self
+            .lock_state(|state| match state.queue_items.get(&key) {
+                Some(queue_items) => {
+                    let total = queue_items.len();
+                    let (pending, delayed): (Vec<_>, Vec<_>) = queue_items
+                        .iter()
+                        .filter(|item| item.status == QueueItemStatus::Pending)
+                        .partition(|item| item.is_eligible(now_ms));
+                    let next_eligible_ms =
+                        delayed.iter().filter_map(|item| item.not_before_ms).min();
+                    let pending: Vec<_> = pending
+                        .into_iter()
+                        .map(|item| {
+                            let mut obj = serde_json::Map::new();
+                            obj.insert(
+                                "id".to_string(),
+                                serde_json::Value::String(item.id.clone()),
+                            );
+                            for (k, v) in &item.data {
+                                obj.insert(k.clone(), serde_json::Value::String(v.clone()));
+                            }
+                            serde_json::Value::Object(obj)
+                        })
+                        .collect();
+                    (total, pending, next_eligible_ms)
+                }
+                None => (0, Vec::new(), None),
+            });
+
+        if let Some(not_before_ms) = next_eligible_ms {
+            let duration = Duration::from_millis(not_before_ms.saturating_sub(now_ms));
+            let timer_id = TimerId::queue_delay(queue_name, project);
+            self.executor.execute(Effect::SetTimer { id: timer_id, duration }).await?;
+        }
 
         tracing::info!(
             worker = worker_key,
@@ -7,7 +7,7 @@ use crate::engine::test_helpers::{
     load_runbook_hash, setup_with_runbook, worker_started, TestContext,
 };
 use crate::storage::QueueItemStatus;
-use oj_core::{Event, JobId, OwnerId, TimerId};
+use oj_core::{Clock, Event, JobId, OwnerId, TimerId};
 use std::collections::HashMap;
 
 const PERSISTED_RUNBOOK: &str = r#"
@@ -60,6 +60,35 @@ source = { queue = "bugs" }
 concurrency = 2
 "#;
 
+const BACKOFF_RUNBOOK: &str = r#"
+[job.build]
+input  = ["name"]
+
+[[job.build.step]]
+name = "init"
+run = "echo init"
+on_done = { step = "done" }
+
+[[job.build.step]]
+name = "done"
+run = "echo done"
+
+[queue.bugs]
+type = "persisted"
+vars = ["title"]
+
+[queue.bugs.retry]
+attempts = 5
+cooldown = "10s"
+backoff = "exponential"
+max_cooldown = "30s"
+
+[worker.fixer]
+run = { job = "build" }
+source = { queue = "bugs" }
+concurrency = 2
+"#;
+
 /// Start a worker by sending WorkerStarted through handle_event (triggers reconciliation).
 async fn start_worker(ctx: &TestContext, runbook: &str, project: &str) {
     let hash = load_runbook_hash(ctx, runbook);
@@ -86,6 +115,8 @@ fn setup_orphaned_item(
             item_id: item_id.to_string(),
             data: HashMap::from([("title".to_string(), "item".to_string())]),
             pushed_at_ms: 1000,
+            priority: 0,
+            not_before_ms: None,
             project: project.to_string(),
         });
         if failure_cycles > 0 {
@@ -95,6 +126,7 @@ fn setup_orphaned_item(
                     item_id: item_id.to_string(),
                     error: "prior failure".to_string(),
                     project: project.to_string(),
+                    retry_at_ms: None,
                 });
                 state.apply_event(&Event::QueueTaken {
                     queue: "bugs".to_string(),
@@ -115,6 +147,13 @@ fn setup_orphaned_item(
     });
 }
 
+/// Get the scheduled retry time of a queue item.
+fn queue_item_retry_at(ctx: &TestContext, item_id: &str) -> Option<u64> {
+    ctx.runtime.lock_state(|state| {
+        state.queue_items.get("bugs")?.iter().find(|i| i.id == item_id)?.retry_at_ms
+    })
+}
+
 /// Get the status of a queue item.
 fn queue_item_status(ctx: &TestContext, item_id: &str, project: &str) -> Option<QueueItemStatus> {
     let scoped_queue =
@@ -141,6 +180,8 @@ async fn reconcile_recovers_item_mapping_from_persisted_record() {
             item_id: "item-orphan".to_string(),
             data: HashMap::from([("title".to_string(), "item".to_string())]),
             pushed_at_ms: 1000,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
         state.apply_event(&Event::QueueTaken {
@@ -226,3 +267,75 @@ async fn reconcile_orphaned_item_with_retry_schedules_retry() {
         timer_ids
     );
 }
+
+#[yare::parameterized(
+    first_failure  = { 0, 10_000 },
+    second_failure = { 1, 20_000 },
+    capped         = { 3, 30_000 },
+)]
+fn reconcile_orphaned_item_backs_off(failure_cycles: usize, delay_ms: u64) {
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        let ctx = setup_with_runbook(BACKOFF_RUNBOOK).await;
+        let hash = load_runbook_hash(&ctx, BACKOFF_RUNBOOK);
+        setup_orphaned_item(&ctx, &hash, "item-flaky", "", failure_cycles);
+        start_worker(&ctx, BACKOFF_RUNBOOK, "").await;
+
+        assert_eq!(queue_item_status(&ctx, "item-flaky", ""), Some(QueueItemStatus::Failed));
+        assert_eq!(queue_item_retry_at(&ctx, "item-flaky"), Some(ctx.clock.epoch_ms() + delay_ms));
+
+        let deadline = ctx.runtime.executor.scheduler().lock().next_deadline().unwrap();
+        assert_eq!(deadline - ctx.clock.now(), std::time::Duration::from_millis(delay_ms));
+    });
+}
+
+#[tokio::test]
+async fn reconcile_rearms_scheduled_retry() {
+    let ctx = setup_with_runbook(RETRY_RUNBOOK).await;
+    let hash = load_runbook_hash(&ctx, RETRY_RUNBOOK);
+    let retry_at_ms = ctx.clock.epoch_ms() + 5_000;
+    ctx.runtime.lock_state_mut(|state| {
+        state.apply_event(&Event::QueuePushed {
+            queue: "bugs".to_string(),
+            item_id: "item-waiting".to_string(),
+            data: HashMap::from([("title".to_string(), "item".to_string())]),
+            pushed_at_ms: 1000,
+            priority: 0,
+            not_before_ms: None,
+            project: String::new(),
+        });
+        state.apply_event(&Event::QueueFailed {
+            queue: "bugs".to_string(),
+            item_id: "item-waiting".to_string(),
+            error: "before restart".to_string(),
+            project: String::new(),
+            retry_at_ms: Some(retry_at_ms),
+        });
+        state.apply_event(&worker_started("fixer", &ctx.project_path, &hash, "bugs", 2, ""));
+    });
+
+    start_worker(&ctx, RETRY_RUNBOOK, "").await;
+
+    let deadline = ctx.runtime.executor.scheduler().lock().next_deadline().unwrap();
+    assert_eq!(deadline - ctx.clock.now(), std::time::Duration::from_secs(5));
+    let retry_timer = TimerId::queue_retry("bugs", "item-waiting");
+    assert!(ctx.pending_timer_ids().iter().any(|id| id == retry_timer.as_str()));
+}
+
+#[tokio::test]
+async fn retry_timer_keeps_failure_count() {
+    let ctx = setup_with_runbook(BACKOFF_RUNBOOK).await;
+    let hash = load_runbook_hash(&ctx, BACKOFF_RUNBOOK);
+    setup_orphaned_item(&ctx, &hash, "item-flaky", "", 1);
+    start_worker(&ctx, BACKOFF_RUNBOOK, "").await;
+
+    ctx.runtime
+        .handle_event(Event::TimerStart { id: TimerId::queue_retry("bugs", "item-flaky") })
+        .await
+        .unwrap();
+
+    let failures = ctx.runtime.lock_state(|state| {
+        state.queue_items["bugs"].iter().find(|i| i.id == "item-flaky").unwrap().failures
+    });
+    assert_eq!(failures, 2, "a timer retry must not reset the backoff");
+    assert_eq!(queue_item_retry_at(&ctx, "item-flaky"), None);
+}
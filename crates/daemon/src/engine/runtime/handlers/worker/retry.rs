@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Retry-or-dead handling for failed persisted queue items
+
+use crate::engine::error::RuntimeError;
+use crate::engine::monitor::parse_duration;
+use crate::engine::runtime::Runtime;
+use oj_core::{scoped_name, Clock, Effect, Event, TimerId};
+use oj_runbook::{Backoff, RetryConfig};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+
+/// Doublings after which exponential backoff stops growing, so a queue
+/// without `max_cooldown` still gets a finite timer.
+const MAX_DOUBLINGS: u32 = 20;
+
+impl<C: Clock> Runtime<C> {
+    /// Fail a persisted queue item, then schedule its retry or mark it dead
+    /// according to the queue's `retry` config.
+    pub(super) async fn fail_queue_item(
+        &self,
+        queue_name: &str,
+        project: &str,
+        item_id: &str,
+        error: String,
+        retry: Option<&RetryConfig>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let scoped_queue = scoped_name(project, queue_name);
+
+        // Failure count once this failure is applied
+        let failures = self.lock_state(|state| {
+            state
+                .queue_items
+                .get(&scoped_queue)
+                .and_then(|items| items.iter().find(|i| i.id == item_id))
+                .map(|i| i.failures)
+                .unwrap_or(0)
+                .saturating_add(1)
+        });
+
+        let delay = retry
+            .filter(|r| r.attempts > 0 && failures < r.attempts)
+            .map(|r| retry_delay(r, failures, item_id));
+        let retry_at_ms =
+            delay.map(|d| self.executor.clock().epoch_ms().saturating_add(d.as_millis() as u64));
+
+        let mut events = self
+            .executor
+            .execute_all(vec![Effect::Emit {
+                event: Event::QueueFailed {
+                    queue: queue_name.to_string(),
+                    item_id: item_id.to_string(),
+                    error,
+                    project: project.to_string(),
+                    retry_at_ms,
+                },
+            }])
+            .await?;
+
+        match delay {
+            Some(duration) => {
+                let id = TimerId::queue_retry(&scoped_queue, item_id);
+                self.executor.execute(Effect::SetTimer { id, duration }).await?;
+            }
+            None => {
+                events.extend(
+                    self.executor
+                        .execute_all(vec![Effect::Emit {
+                            event: Event::QueueDead {
+                                queue: queue_name.to_string(),
+                                item_id: item_id.to_string(),
+                                project: project.to_string(),
+                            },
+                        }])
+                        .await?,
+                );
+            }
+        }
+
+        Ok(events)
+    }
+}
+
+/// Delay before retrying an item that has now failed `failures` times.
+///
+/// Starts from `cooldown`, doubles per extra failure under exponential
+/// backoff, is capped at `max_cooldown`, then loses up to `jitter` of itself.
+pub(crate) fn retry_delay(retry: &RetryConfig, failures: u32, item_id: &str) -> Duration {
+    let cooldown = parse_duration(&retry.cooldown).unwrap_or(Duration::ZERO);
+    let mut delay = match retry.backoff {
+        Backoff::Fixed => cooldown,
+        Backoff::Exponential => {
+            let doublings = failures.saturating_sub(1).min(MAX_DOUBLINGS);
+            cooldown.saturating_mul(2u32.pow(doublings))
+        }
+    };
+    if let Some(max) = retry.max_cooldown.as_deref().and_then(|m| parse_duration(m).ok()) {
+        delay = delay.min(max);
+    }
+    if retry.jitter > 0.0 {
+        delay = delay.mul_f64(1.0 - retry.jitter * jitter_sample(item_id, failures));
+    }
+    delay
+}
+
+/// Sample in `[0, 1)` fixed per item and attempt: items that failed together
+/// spread out, while the same failure always gets the same delay.
+fn jitter_sample(item_id: &str, failures: u32) -> f64 {
+    let mut hasher = DefaultHasher::new();
+    (item_id, failures).hash(&mut hasher);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+#[path = "retry_tests.rs"]
+mod tests;
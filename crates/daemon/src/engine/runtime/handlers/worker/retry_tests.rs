@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn retry(backoff: Backoff, max_cooldown: Option<&str>, jitter: f64) -> RetryConfig {
+    RetryConfig {
+        attempts: 10,
+        cooldown: "10s".to_string(),
+        backoff,
+        max_cooldown: max_cooldown.map(String::from),
+        jitter,
+    }
+}
+
+#[yare::parameterized(
+    fixed_first       = { Backoff::Fixed,       None,        1,  10 },
+    fixed_later       = { Backoff::Fixed,       None,        4,  10 },
+    exponential_first = { Backoff::Exponential, None,        1,  10 },
+    exponential_third = { Backoff::Exponential, None,        3,  40 },
+    exponential_capped = { Backoff::Exponential, Some("1m"), 5,  60 },
+    fixed_capped      = { Backoff::Fixed,       Some("5s"),  1,  5 },
+)]
+fn delay_without_jitter(backoff: Backoff, max_cooldown: Option<&str>, failures: u32, secs: u64) {
+    assert_eq!(
+        retry_delay(&retry(backoff, max_cooldown, 0.0), failures, "item-1"),
+        Duration::from_secs(secs)
+    );
+}
+
+#[test]
+fn exponential_growth_stays_finite() {
+    let delay = retry_delay(&retry(Backoff::Exponential, None, 0.0), u32::MAX, "item-1");
+    assert_eq!(delay, Duration::from_secs(10) * 2u32.pow(MAX_DOUBLINGS));
+}
+
+#[test]
+fn jitter_shortens_delay_within_bounds() {
+    let config = retry(Backoff::Fixed, None, 0.5);
+    for failures in 1..50 {
+        let delay = retry_delay(&config, failures, "item-1");
+        assert!(delay >= Duration::from_secs(5) && delay <= Duration::from_secs(10), "{delay:?}");
+    }
+}
+
+#[test]
+fn jitter_is_stable_per_item_and_spread_across_items() {
+    let config = retry(Backoff::Fixed, None, 1.0);
+    assert_eq!(retry_delay(&config, 2, "item-1"), retry_delay(&config, 2, "item-1"));
+
+    let delays: std::collections::HashSet<Duration> =
+        (0..20).map(|i| retry_delay(&config, 2, &format!("item-{i}"))).collect();
+    assert!(delays.len() > 1, "items that fail together should not retry together");
+}
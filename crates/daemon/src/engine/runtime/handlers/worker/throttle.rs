@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Worker throughput caps (`rate`) and active windows (`window`)
+
+use crate::engine::error::RuntimeError;
+use crate::engine::monitor::parse_duration;
+use crate::engine::next_fire_at_ms;
+use crate::engine::runtime::Runtime;
+use chrono::{FixedOffset, Local, TimeZone, Timelike, Utc};
+use oj_core::{split_scoped_name, Clock, Effect, ThrottleReason, TimerId, WorkerThrottle};
+use oj_runbook::{ActiveWindow, CronExpr, CronTimezone, WorkerDef, WorkerRate};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// A worker's parsed `rate` and `window`. Both were validated when the
+/// runbook was parsed.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WorkerLimits {
+    rate: Option<(u32, Duration)>,
+    window: Option<(ActiveWindow, CronTimezone)>,
+}
+
+impl WorkerLimits {
+    pub(crate) fn from_def(def: &WorkerDef) -> Self {
+        let rate = def.rate.as_deref().and_then(|r| WorkerRate::parse(r).ok()).and_then(|r| {
+            let per = parse_duration(&r.per).ok()?;
+            Some((r.limit, per))
+        });
+        let window = def.window.as_deref().and_then(|w| ActiveWindow::parse(w).ok()).map(|w| {
+            let timezone = def
+                .timezone
+                .as_deref()
+                .and_then(|tz| CronTimezone::parse(tz).ok())
+                .unwrap_or_default();
+            (w, timezone)
+        });
+        WorkerLimits { rate, window }
+    }
+
+    /// How many more items the worker may dispatch right now (`None` for no
+    /// cap), or what is holding it back.
+    ///
+    /// `dispatched` holds recent dispatch times (epoch ms), oldest first;
+    /// entries older than the rate period are dropped.
+    pub(crate) fn check(
+        &self,
+        dispatched: &mut VecDeque<u64>,
+        now_ms: u64,
+    ) -> Result<Option<u32>, WorkerThrottle> {
+        if let Some((window, timezone)) = self.window {
+            if let Some(until_ms) = window_opens_at_ms(window, timezone, now_ms) {
+                return Err(WorkerThrottle { reason: ThrottleReason::Window, until_ms });
+            }
+        }
+        let Some((limit, per)) = self.rate else {
+            return Ok(None);
+        };
+        let per_ms = per.as_millis() as u64;
+        while dispatched.front().is_some_and(|&t| t.saturating_add(per_ms) <= now_ms) {
+            dispatched.pop_front();
+        }
+        match dispatched.front() {
+            Some(&oldest) if dispatched.len() as u32 >= limit => Err(WorkerThrottle {
+                reason: ThrottleReason::Rate,
+                until_ms: oldest.saturating_add(per_ms),
+            }),
+            _ => Ok(Some(limit - dispatched.len() as u32)),
+        }
+    }
+}
+
+/// When a closed window next opens (epoch ms), or `None` while it is open.
+fn window_opens_at_ms(window: ActiveWindow, timezone: CronTimezone, now_ms: u64) -> Option<u64> {
+    let now = Utc.timestamp_millis_opt(i64::try_from(now_ms).ok()?).single()?;
+    let time = match timezone {
+        CronTimezone::Utc => now.time(),
+        CronTimezone::Local => now.with_timezone(&Local).time(),
+        CronTimezone::Fixed(secs) => now.with_timezone(&FixedOffset::east_opt(secs)?).time(),
+        CronTimezone::Named(tz) => now.with_timezone(&tz).time(),
+    };
+    if window.contains(time.hour() * 60 + time.minute()) {
+        return None;
+    }
+    // The opening time as a daily cron, so DST is handled the same way
+    let opening = format!("{} {} * * *", window.start % 60, window.start / 60);
+    let expr = CronExpr::parse(&opening).ok()?;
+    // Retry in a minute if no opening is found, rather than dispatching
+    Some(next_fire_at_ms(&expr, timezone, now_ms).unwrap_or(now_ms + 60_000))
+}
+
+impl<C: Clock> Runtime<C> {
+    /// Record a worker as throttled and arm the timer that wakes it when it
+    /// may dispatch again.
+    pub(super) async fn throttle_worker(
+        &self,
+        worker_key: &str,
+        throttle: WorkerThrottle,
+    ) -> Result<(), RuntimeError> {
+        let now_ms = self.executor.clock().epoch_ms();
+        let previous = self.lock_state_mut(|state| {
+            state.worker_throttles.insert(worker_key.to_string(), throttle)
+        });
+        if previous != Some(throttle) {
+            self.worker_logger.append(
+                worker_key,
+                &format!(
+                    "throttled by {} for {}s",
+                    throttle.reason,
+                    throttle.until_ms.saturating_sub(now_ms).div_ceil(1000)
+                ),
+            );
+        }
+
+        let (project, bare_name) = split_scoped_name(worker_key);
+        let id = TimerId::worker_throttle(bare_name, project);
+        let duration = Duration::from_millis(throttle.until_ms.saturating_sub(now_ms));
+        self.executor.execute(Effect::SetTimer { id, duration }).await?;
+        Ok(())
+    }
+
+    /// Count a dispatch against the worker's rate, if it has one.
+    pub(super) fn record_worker_dispatch(
+        &self,
+        worker_key: &str,
+        limits: &WorkerLimits,
+        now_ms: u64,
+    ) {
+        if limits.rate.is_none() {
+            return;
+        }
+        if let Some(state) = self.worker_states.lock().get_mut(worker_key) {
+            state.dispatched_at_ms.push_back(now_ms);
+        }
+    }
+
+    /// Clear a worker's throttled state once it may dispatch again.
+    pub(super) fn unthrottle_worker(&self, worker_key: &str) {
+        self.lock_state_mut(|state| state.worker_throttles.remove(worker_key));
+    }
+}
+
+#[cfg(test)]
+#[path = "throttle_tests.rs"]
+mod tests;
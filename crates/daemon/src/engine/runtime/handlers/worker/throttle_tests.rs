@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+/// 2026-01-01T00:00:00Z
+const MIDNIGHT_MS: u64 = 1_767_225_600_000;
+const HOUR_MS: u64 = 3_600_000;
+
+fn rate(limit: u32, per_secs: u64) -> WorkerLimits {
+    WorkerLimits { rate: Some((limit, Duration::from_secs(per_secs))), window: None }
+}
+
+fn window(spec: &str, timezone: CronTimezone) -> WorkerLimits {
+    WorkerLimits { rate: None, window: Some((ActiveWindow::parse(spec).unwrap(), timezone)) }
+}
+
+#[test]
+fn no_limits_never_throttle() {
+    let mut dispatched = VecDeque::from(vec![MIDNIGHT_MS; 100]);
+    assert_eq!(WorkerLimits::default().check(&mut dispatched, MIDNIGHT_MS), Ok(None));
+}
+
+#[test]
+fn rate_reports_remaining_budget() {
+    let mut dispatched = VecDeque::from(vec![MIDNIGHT_MS]);
+    assert_eq!(rate(3, 3600).check(&mut dispatched, MIDNIGHT_MS), Ok(Some(2)));
+}
+
+#[test]
+fn rate_throttles_until_oldest_dispatch_expires() {
+    let mut dispatched = VecDeque::from(vec![MIDNIGHT_MS, MIDNIGHT_MS + 10, MIDNIGHT_MS + 20]);
+    assert_eq!(
+        rate(3, 3600).check(&mut dispatched, MIDNIGHT_MS + 1000),
+        Err(WorkerThrottle { reason: ThrottleReason::Rate, until_ms: MIDNIGHT_MS + HOUR_MS })
+    );
+}
+
+#[test]
+fn rate_drops_expired_dispatches() {
+    let mut dispatched = VecDeque::from(vec![MIDNIGHT_MS, MIDNIGHT_MS + 10, MIDNIGHT_MS + HOUR_MS]);
+    assert_eq!(rate(3, 3600).check(&mut dispatched, MIDNIGHT_MS + HOUR_MS + 10), Ok(Some(2)));
+    assert_eq!(dispatched, VecDeque::from(vec![MIDNIGHT_MS + HOUR_MS]));
+}
+
+#[yare::parameterized(
+    inside          = { "08:00-20:00", 12 },
+    at_start        = { "08:00-20:00", 8 },
+    overnight_late  = { "22:00-06:00", 23 },
+    overnight_early = { "22:00-06:00", 3 },
+)]
+fn window_open(spec: &str, hour: u64) {
+    let mut dispatched = VecDeque::new();
+    let now_ms = MIDNIGHT_MS + hour * HOUR_MS;
+    assert_eq!(window(spec, CronTimezone::Utc).check(&mut dispatched, now_ms), Ok(None));
+}
+
+#[yare::parameterized(
+    before_start   = { "08:00-20:00", 6,  8 },
+    at_end         = { "08:00-20:00", 20, 32 },
+    overnight_gap  = { "22:00-06:00", 12, 22 },
+)]
+fn window_closed_until_next_opening(spec: &str, hour: u64, opens_hour: u64) {
+    let mut dispatched = VecDeque::new();
+    let now_ms = MIDNIGHT_MS + hour * HOUR_MS;
+    assert_eq!(
+        window(spec, CronTimezone::Utc).check(&mut dispatched, now_ms),
+        Err(WorkerThrottle {
+            reason: ThrottleReason::Window,
+            until_ms: MIDNIGHT_MS + opens_hour * HOUR_MS,
+        })
+    );
+}
+
+#[test]
+fn window_uses_fixed_offset() {
+    // 06:00 UTC is 08:00 at +02:00
+    let mut dispatched = VecDeque::new();
+    let limits = window("08:00-20:00", CronTimezone::Fixed(2 * 3600));
+    assert_eq!(limits.check(&mut dispatched, MIDNIGHT_MS + 6 * HOUR_MS), Ok(None));
+    assert_eq!(
+        limits.check(&mut dispatched, MIDNIGHT_MS + 19 * HOUR_MS),
+        Err(WorkerThrottle {
+            reason: ThrottleReason::Window,
+            until_ms: MIDNIGHT_MS + 30 * HOUR_MS,
+        })
+    );
+}
+
+#[test]
+fn window_follows_named_timezone_dst() {
+    // 2026-03-08 is New York's spring-forward day
+    let mut dispatched = VecDeque::new();
+    let limits = window("08:00-20:00", CronTimezone::parse("America/New_York").unwrap());
+    let mar_8_06_utc = 1_772_949_600_000;
+    // 01:00 EST; the window opens at 08:00 EDT, 12:00 UTC, not 13:00
+    assert_eq!(
+        limits.check(&mut dispatched, mar_8_06_utc),
+        Err(WorkerThrottle {
+            reason: ThrottleReason::Window,
+            until_ms: mar_8_06_utc + 6 * HOUR_MS
+        })
+    );
+    // 12:30 UTC is 08:30 EDT
+    assert_eq!(limits.check(&mut dispatched, mar_8_06_utc + 6 * HOUR_MS + HOUR_MS / 2), Ok(None));
+}
+
+#[test]
+fn closed_window_wins_over_rate() {
+    let mut limits = window("08:00-20:00", CronTimezone::Utc);
+    limits.rate = Some((1, Duration::from_secs(3600)));
+    let mut dispatched = VecDeque::from(vec![MIDNIGHT_MS]);
+    let result = limits.check(&mut dispatched, MIDNIGHT_MS + 1000);
+    assert_eq!(result.map_err(|t| t.reason), Err(ThrottleReason::Window));
+}
+
+#[test]
+fn from_def_reads_rate_window_and_timezone() {
+    let def: WorkerDef = serde_json::from_value(serde_json::json!({
+        "name": "fixer",
+        "source": { "queue": "bugs" },
+        "run": { "job": "fix" },
+        "rate": "10/h",
+        "window": "08:00-20:00",
+        "timezone": "+02:00",
+    }))
+    .unwrap();
+    let limits = WorkerLimits::from_def(&def);
+    assert_eq!(limits.rate, Some((10, Duration::from_secs(3600))));
+    let (window, timezone) = limits.window.unwrap();
+    assert_eq!((window.start, window.end), (8 * 60, 20 * 60));
+    assert_eq!(timezone, CronTimezone::Fixed(2 * 3600));
+}
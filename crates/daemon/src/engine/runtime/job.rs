@@ -6,10 +6,11 @@
 use super::Runtime;
 use crate::engine::error::RuntimeError;
 use crate::engine::steps;
-use oj_core::{Clock, Effect, Event, Job, JobId, TimerId};
-use oj_runbook::{NotifyConfig, RunDirective};
+use oj_core::{Clock, Effect, Event, Job, JobId, Notification, NotifyKind, TimerId};
+use oj_runbook::{NotifyConfig, RunDirective, StepTransition};
 use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 
 impl<C: Clock> Runtime<C> {
     pub(crate) async fn start_step(
@@ -45,9 +46,8 @@ impl<C: Clock> Runtime<C> {
             self.breadcrumb.delete(&job.id);
 
             // Emit on_fail notification for the terminal failure
-            result_events.extend(
-                self.emit_notify(&job, &job_def.notify, job_def.notify.on_fail.as_ref()).await?,
-            );
+            result_events
+                .extend(self.emit_notify(&job, &job_def.notify, NotifyKind::OnFail).await?);
 
             return Ok(result_events);
         }
@@ -62,6 +62,7 @@ impl<C: Clock> Runtime<C> {
         let effects = steps::step_start_effects(job_id, step_name);
         result_events.extend(self.executor.execute_all(effects).await?);
         self.logger.append(job_id.as_str(), step_name, "step started");
+        self.arm_step_timeout(job_id, job_def, step_name, Duration::ZERO).await?;
 
         // Write breadcrumb after step status change (captures agent info)
         if let Some(job) = self.get_job(job_id.as_str()) {
@@ -103,7 +104,7 @@ impl<C: Clock> Runtime<C> {
             }
 
             RunDirective::Agent { agent, .. } => {
-                result_events.extend(self.spawn_agent(job_id, agent, input).await?);
+                result_events.extend(self.spawn_agent(job_id, step_name, agent, input).await?);
             }
 
             RunDirective::Job { job } => {
@@ -150,8 +151,8 @@ impl<C: Clock> Runtime<C> {
 
         let mut result_events = Vec::new();
 
-        match next_transition {
-            Some(transition) => {
+        match next_transition.as_ref().map(|t| t.as_slice()) {
+            Some([transition]) => {
                 let next_step = transition.step_name();
                 self.logger.append(&job.id, &job.step, &format!("advancing to {}", next_step));
                 let effects = steps::step_transition_effects(job, next_step);
@@ -168,46 +169,65 @@ impl<C: Clock> Runtime<C> {
                     );
                 }
             }
-            None => {
-                let job_on_done = job_def.as_ref().and_then(|p| p.on_done.clone());
-                if let Some(ref on_done) = job_on_done {
-                    let on_done_step = on_done.step_name();
-                    if job.step != on_done_step {
-                        // Job-level on_done: route to that step instead of completing
-                        self.logger.append(
-                            &job.id,
-                            &job.step,
-                            &format!("job on_done: advancing to {}", on_done_step),
-                        );
-                        let effects = steps::step_transition_effects(job, on_done_step);
-                        result_events.extend(self.executor.execute_all(effects).await?);
-                        result_events.extend(
-                            self.start_step(&job_id, on_done_step, &job.vars, job.execution_dir())
-                                .await?,
-                        );
-                    } else {
-                        // Already at on_done target; complete normally
-                        let effects = steps::step_transition_effects(job, "done");
-                        result_events.extend(self.executor.execute_all(effects).await?);
-                        result_events.extend(self.complete_job(job).await?);
-                    }
-                } else if job.failing {
-                    // On-fail cleanup step completed; go to terminal "failed"
-                    result_events.extend(self.terminate_failed_job(job).await?);
-                } else if job.cancelling {
-                    // Cancel cleanup step completed; go to terminal "cancelled"
-                    result_events.extend(self.terminate_cancelled_job(job).await?);
-                } else {
-                    let effects = steps::step_transition_effects(job, "done");
-                    result_events.extend(self.executor.execute_all(effects).await?);
-                    result_events.extend(self.complete_job(job).await?);
-                }
+            Some(targets) if !targets.is_empty() => {
+                let steps = targets.iter().map(|t| t.step_name().to_string()).collect();
+                result_events.extend(self.fork_job(job, steps).await?);
+            }
+            _ => {
+                result_events.extend(self.finish_job(job).await?);
             }
         }
 
         Ok(result_events)
     }
 
+    /// Finish a job whose last step (or last parallel branch) has completed.
+    ///
+    /// Routes to the job-level on_done if configured, terminates a job that
+    /// was running on_fail/on_cancel cleanup, or otherwise completes it.
+    pub(crate) async fn finish_job(&self, job: &Job) -> Result<Vec<Event>, RuntimeError> {
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let job_def = runbook.get_job(&job.kind);
+        let job_id = JobId::from_string(&job.id);
+
+        let mut result_events = Vec::new();
+
+        let job_on_done = job_def.as_ref().and_then(|p| p.on_done.clone());
+        if let Some(ref on_done) = job_on_done {
+            let on_done_step = on_done.step_name();
+            if job.step != on_done_step {
+                // Job-level on_done: route to that step instead of completing
+                self.logger.append(
+                    &job.id,
+                    &job.step,
+                    &format!("job on_done: advancing to {}", on_done_step),
+                );
+                let effects = steps::step_transition_effects(job, on_done_step);
+                result_events.extend(self.executor.execute_all(effects).await?);
+                result_events.extend(
+                    self.start_step(&job_id, on_done_step, &job.vars, job.execution_dir()).await?,
+                );
+            } else {
+                // Already at on_done target; complete normally
+                let effects = steps::step_transition_effects(job, "done");
+                result_events.extend(self.executor.execute_all(effects).await?);
+                result_events.extend(self.complete_job(job).await?);
+            }
+        } else if job.failing {
+            // On-fail cleanup step completed; go to terminal "failed"
+            result_events.extend(self.terminate_failed_job(job).await?);
+        } else if job.cancelling {
+            // Cancel cleanup step completed; go to terminal "cancelled"
+            result_events.extend(self.terminate_cancelled_job(job).await?);
+        } else {
+            let effects = steps::step_transition_effects(job, "done");
+            result_events.extend(self.executor.execute_all(effects).await?);
+            result_events.extend(self.complete_job(job).await?);
+        }
+
+        Ok(result_events)
+    }
+
     pub(crate) async fn fail_job(
         &self,
         job: &Job,
@@ -218,13 +238,26 @@ impl<C: Clock> Runtime<C> {
         }
 
         let runbook = self.cached_runbook(&job.runbook_hash)?;
-        let job_def = runbook.get_job(&job.kind);
-        let current_step_def = job_def.as_ref().and_then(|p| p.get_step(&job.step));
-        let on_fail = current_step_def.and_then(|p| p.on_fail.as_ref());
-        let job_id = JobId::from_string(&job.id);
+        let on_fail =
+            runbook.get_job(&job.kind).and_then(|p| p.get_step(&job.step)?.on_fail.clone());
 
         self.logger.append(&job.id, &job.step, &format!("job failed: {}", error));
 
+        self.route_failure(job, on_fail.as_ref(), error).await
+    }
+
+    /// Route a failed job: to the failing step's on_fail target, else the
+    /// job-level on_fail, else terminal failure.
+    pub(crate) async fn route_failure(
+        &self,
+        job: &Job,
+        on_fail: Option<&StepTransition>,
+        error: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let job_def = runbook.get_job(&job.kind);
+        let job_id = JobId::from_string(&job.id);
+
         let mut result_events = Vec::new();
 
         if let Some(on_fail) = on_fail {
@@ -279,9 +312,8 @@ impl<C: Clock> Runtime<C> {
 
             // Emit on_fail notification only on terminal failure (not on_fail transition)
             if let Some(job_def) = job_def.as_ref() {
-                result_events.extend(
-                    self.emit_notify(job, &job_def.notify, job_def.notify.on_fail.as_ref()).await?,
-                );
+                result_events
+                    .extend(self.emit_notify(job, &job_def.notify, NotifyKind::OnFail).await?);
             }
         }
 
@@ -305,23 +337,29 @@ impl<C: Clock> Runtime<C> {
         // Emit on_done notification if configured
         if let Ok(runbook) = self.cached_runbook(&job.runbook_hash) {
             if let Some(job_def) = runbook.get_job(&job.kind) {
-                result_events.extend(
-                    self.emit_notify(job, &job_def.notify, job_def.notify.on_done.as_ref()).await?,
-                );
+                result_events
+                    .extend(self.emit_notify(job, &job_def.notify, NotifyKind::OnDone).await?);
             }
         }
 
         Ok(result_events)
     }
 
-    /// Emit a notification effect if a notify message template is configured.
+    /// Emit a notification effect if the job's notify block has a message
+    /// template for this kind.
     pub(crate) async fn emit_notify(
         &self,
         job: &Job,
         notify: &NotifyConfig,
-        message_template: Option<&String>,
+        kind: NotifyKind,
     ) -> Result<Vec<Event>, RuntimeError> {
-        if let Some(template) = message_template {
+        let template = match kind {
+            NotifyKind::OnStart => notify.on_start.as_ref(),
+            NotifyKind::OnDone => notify.on_done.as_ref(),
+            NotifyKind::OnFail => notify.on_fail.as_ref(),
+            NotifyKind::Escalation | NotifyKind::DeadLetter | NotifyKind::WorkerStopped => None,
+        };
+        if let Some(template) = template {
             let mut vars = crate::engine::vars::namespace_vars(&job.vars);
             vars.insert("job_id".to_string(), job.id.clone());
             vars.insert("name".to_string(), job.name.clone());
@@ -330,11 +368,13 @@ impl<C: Clock> Runtime<C> {
             }
 
             let message = NotifyConfig::render(template, &vars);
-            let event =
-                self.executor.execute(Effect::Notify { title: job.name.clone(), message }).await?;
+            let notification = Notification::new(kind, job.name.clone(), message)
+                .project(job.project.clone())
+                .owner(JobId::from_string(&job.id))
+                .step(Some(job.step.as_str()));
+            let event = self.executor.execute(Effect::Notify { notification }).await?;
             return Ok(event.into_iter().collect());
         }
-        let _ = notify; // silence unused warning when no template
         Ok(vec![])
     }
 
@@ -359,6 +399,7 @@ impl<C: Clock> Runtime<C> {
         if self.is_agent_step(job) {
             self.finalize_agent_step(job).await?;
         }
+        self.stop_branches(job).await?;
 
         // Go directly to suspended terminal — no cleanup step routing
         let effects = steps::suspension_effects(job);
@@ -391,6 +432,7 @@ impl<C: Clock> Runtime<C> {
         if self.is_agent_step(job) {
             self.finalize_agent_step(job).await?;
         }
+        self.stop_branches(job).await?;
 
         let runbook = self.cached_runbook(&job.runbook_hash)?;
         let job_def = runbook.get_job(&job.kind);
@@ -440,7 +482,14 @@ impl<C: Clock> Runtime<C> {
     }
 
     /// Whether the job's current step is an agent step.
-    fn is_agent_step(&self, job: &Job) -> bool {
+    ///
+    /// False while the job is fanned out (the forking step's agent was
+    /// already finalized when the job advanced into its branches), unless
+    /// `job` is a branch view of an agent branch.
+    pub(crate) fn is_agent_step(&self, job: &Job) -> bool {
+        if job.is_parallel() && !job.is_branch_view() {
+            return false;
+        }
         self.cached_runbook(&job.runbook_hash)
             .ok()
             .and_then(|rb| rb.get_job(&job.kind)?.get_step(&job.step).cloned())
@@ -484,9 +533,8 @@ impl<C: Clock> Runtime<C> {
         // Emit on_fail notification on terminal failure
         if let Ok(runbook) = self.cached_runbook(&job.runbook_hash) {
             if let Some(job_def) = runbook.get_job(&job.kind) {
-                result_events.extend(
-                    self.emit_notify(job, &job_def.notify, job_def.notify.on_fail.as_ref()).await?,
-                );
+                result_events
+                    .extend(self.emit_notify(job, &job_def.notify, NotifyKind::OnFail).await?);
             }
         }
 
@@ -506,10 +554,17 @@ impl<C: Clock> Runtime<C> {
 
     /// Clean up when leaving an agent step: cancel timers, deregister agent
     /// mapping, capture terminal output, and kill the agent process.
-    async fn finalize_agent_step(&self, job: &Job) -> Result<(), RuntimeError> {
+    ///
+    /// For a branch view the job's monitoring timers are left alone: they
+    /// are shared with the other branches' agents.
+    pub(crate) async fn finalize_agent_step(&self, job: &Job) -> Result<(), RuntimeError> {
         let job_id = JobId::from_string(&job.id);
-        self.executor.execute(Effect::CancelTimer { id: TimerId::liveness(job_id) }).await?;
-        self.executor.execute(Effect::CancelTimer { id: TimerId::exit_deferred(job_id) }).await?;
+        if !job.is_branch_view() {
+            self.executor.execute(Effect::CancelTimer { id: TimerId::liveness(job_id) }).await?;
+            self.executor
+                .execute(Effect::CancelTimer { id: TimerId::exit_deferred(job_id) })
+                .await?;
+        }
 
         if let Some(agent_id) =
             job.step_history.iter().rfind(|r| r.name == job.step).and_then(|r| r.agent_id.as_ref())
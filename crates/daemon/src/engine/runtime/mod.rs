@@ -4,11 +4,16 @@
 //! Runtime for the Odd Jobs engine
 
 pub(crate) mod agent;
+mod decision;
 mod gate;
 mod handlers;
 mod job;
 mod monitor;
+mod parallel;
 mod signal;
+mod timeout;
+
+pub(crate) use handlers::cron::{next_fire_at_ms, CronTrigger};
 
 use crate::adapters::{AgentAdapter, NotifyAdapter, WorkspaceAdapter};
 use crate::engine::{
@@ -23,7 +28,7 @@ use handlers::worker::WorkerState;
 #[cfg(test)]
 use handlers::worker::WorkerStatus;
 use oj_core::actions::ActionTracker;
-use oj_core::{AgentId, Clock, Crew, Job, OwnerId};
+use oj_core::{AgentId, Clock, Crew, Job, OwnerId, StepStatus};
 use oj_runbook::Runbook;
 
 use crate::storage::MaterializedState;
@@ -36,7 +41,7 @@ use parking_lot::Mutex;
 use tokio::sync::mpsc;
 
 #[cfg(test)]
-use oj_core::{Event, StepStatus};
+use oj_core::Event;
 
 /// Runtime path configuration
 pub struct RuntimeConfig {
@@ -73,6 +78,8 @@ pub struct Runtime<C: Clock> {
     pub(crate) runbook_cache: Mutex<HashMap<String, Runbook>>,
     pub(crate) worker_states: Mutex<HashMap<String, WorkerState>>,
     pub(crate) cron_states: Mutex<HashMap<String, CronState>>,
+    /// Missed runs per cron (scoped key) still waiting to start after a restart
+    pub(crate) cron_catchup: Mutex<HashMap<String, usize>>,
 }
 
 impl<C: Clock> Runtime<C> {
@@ -94,6 +101,7 @@ impl<C: Clock> Runtime<C> {
             runbook_cache: Mutex::new(HashMap::new()),
             worker_states: Mutex::new(HashMap::new()),
             cron_states: Mutex::new(HashMap::new()),
+            cron_catchup: Mutex::new(HashMap::new()),
         }
     }
 
@@ -193,6 +201,46 @@ impl<C: Clock> Runtime<C> {
         }
     }
 
+    /// Resolve the runs whose agents an owner's liveness timer watches: one
+    /// branch view per running agent branch of a fanned-out job, otherwise
+    /// the owner's active run.
+    pub(crate) fn get_active_runs(
+        &self,
+        owner: &OwnerId,
+    ) -> Vec<Box<dyn crate::engine::lifecycle::RunLifecycle>> {
+        if let OwnerId::Job(job_id) = owner {
+            if let Some(job) = self.get_active_job(job_id.as_str()).filter(|j| j.is_parallel()) {
+                return job
+                    .active_branches()
+                    .into_iter()
+                    .filter(|b| job.branches[*b] == StepStatus::Running)
+                    .filter(|b| job.step_agent_id(b).is_some())
+                    .map(|b| {
+                        Box::new(job.branch_view(b))
+                            as Box<dyn crate::engine::lifecycle::RunLifecycle>
+                    })
+                    .collect();
+            }
+        }
+        self.get_active_run(owner).into_iter().collect()
+    }
+
+    /// Resolve an active branch of a job owner as a run, if it is still active.
+    pub(crate) fn get_branch_run(
+        &self,
+        owner: &OwnerId,
+        branch: &str,
+    ) -> Option<Box<dyn crate::engine::lifecycle::RunLifecycle>> {
+        let OwnerId::Job(job_id) = owner else {
+            return None;
+        };
+        let job = self.get_active_job(job_id.as_str())?;
+        if !job.is_branch_active(branch) {
+            return None;
+        }
+        Some(Box::new(job.branch_view(branch)))
+    }
+
     /// Look up the owner of an agent.
     pub(crate) fn get_agent_owner(&self, agent_id: &AgentId) -> Option<OwnerId> {
         self.agent_owners.lock().get(agent_id).cloned()
@@ -232,16 +280,19 @@ impl<C: Clock> Runtime<C> {
         self.with_run_mut(owner, |e| *e.last_nudge_at = Some(epoch_ms));
     }
 
-    /// Look up the source of a pending (unresolved) decision for an owner.
+    /// Look up the source of a pending (unresolved) decision for an owner,
+    /// optionally only one raised by the given agent.
     /// Returns None if no pending decision exists.
     pub(crate) fn pending_decision_source(
         &self,
         owner: &OwnerId,
+        agent_id: Option<&str>,
     ) -> Option<(oj_core::DecisionId, oj_core::DecisionSource)> {
         self.lock_state(|state| {
             state
                 .decisions
                 .values()
+                .filter(|d| agent_id.is_none_or(|id| d.agent_id.as_str() == id))
                 .find(|d| d.owner == *owner && !d.is_resolved())
                 .map(|d| (d.id, d.source.clone()))
         })
@@ -447,7 +498,7 @@ impl<C: Clock> Runtime<C> {
 #[cfg(test)]
 impl<C: Clock> Runtime<C> {
     pub fn jobs(&self) -> HashMap<String, Job> {
-        self.lock_state(|state| state.jobs.clone())
+        self.lock_state(|state| (*state.jobs).clone())
     }
 }
 
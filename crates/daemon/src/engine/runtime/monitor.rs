@@ -9,8 +9,8 @@ use crate::engine::lifecycle::RunLifecycle;
 use crate::engine::monitor::{self, ActionEffects, MonitorState};
 use crate::engine::ActionContext;
 use oj_core::{
-    AgentId, Clock, CrewId, CrewStatus, DecisionId, Effect, Event, Job, JobId, OwnerId, PromptType,
-    TimerId,
+    AgentId, Clock, CrewId, CrewStatus, DecisionId, Effect, Event, Job, JobId, NotifyKind, OwnerId,
+    PromptType, TimerId,
 };
 use std::collections::HashMap;
 
@@ -67,18 +67,22 @@ impl<C: Clock> Runtime<C> {
         Ok(())
     }
 
+    /// Spawn the agent for a job step (the job's current step, or one of
+    /// its parallel branches).
     pub(crate) async fn spawn_agent(
         &self,
         job_id: &JobId,
+        step: &str,
         agent_name: &str,
         input: &HashMap<String, String>,
     ) -> Result<Vec<Event>, RuntimeError> {
-        self.spawn_agent_with_resume(job_id, agent_name, input, false).await
+        self.spawn_agent_with_resume(job_id, step, agent_name, input, false).await
     }
 
     pub(crate) async fn spawn_agent_with_resume(
         &self,
         job_id: &JobId,
+        step: &str,
         agent_name: &str,
         input: &HashMap<String, String>,
         resume: bool,
@@ -115,7 +119,7 @@ impl<C: Clock> Runtime<C> {
         if let Some(cmd) = command {
             self.logger.append(
                 job_id.as_str(),
-                &job.step,
+                step,
                 &format!("agent spawned: {} ({})", agent_name, cmd),
             );
         }
@@ -128,14 +132,14 @@ impl<C: Clock> Runtime<C> {
             effects.push(Effect::Emit {
                 event: Event::StepStarted {
                     job_id: *job_id,
-                    step: job.step.clone(),
+                    step: step.to_string(),
                     agent_id: Some(aid),
                     agent_name: Some(agent_name.to_string()),
                 },
             });
 
             // Log pointer to agent log in job log
-            self.logger.append_agent_pointer(job_id.as_str(), &job.step, aid.as_str());
+            self.logger.append_agent_pointer(job_id.as_str(), step, aid.as_str());
         }
 
         let result_events = self.executor.execute_all(effects).await?;
@@ -210,6 +214,7 @@ impl<C: Clock> Runtime<C> {
                                     ),
                                     resolved_at_ms,
                                     project,
+                                    timed_out: false,
                                 },
                             });
                         }
@@ -312,8 +317,12 @@ impl<C: Clock> Runtime<C> {
         //
         // The entity snapshot's is_waiting() may be stale (apply_event overwrites
         // step_status before handle_event runs), so we check the decision table directly.
+        // A parallel branch only defers to decisions raised by its own agent.
         let owner = run.owner_id();
-        if let Some((decision_id, decision_source)) = self.pending_decision_source(&owner) {
+        let branch_agent = run.branch().and(run.agent_id());
+        if let Some((decision_id, decision_source)) =
+            self.pending_decision_source(&owner, branch_agent)
+        {
             let is_dead_trigger = trigger == "exit";
             if is_dead_trigger && decision_source.is_alive_agent_source() {
                 // Agent died while an alive decision was pending — dismiss it.
@@ -332,6 +341,7 @@ impl<C: Clock> Runtime<C> {
                             message: Some("auto-dismissed: agent exited".to_string()),
                             resolved_at_ms,
                             project: run.project().to_string(),
+                            timed_out: false,
                         },
                     })
                     .await?;
@@ -364,8 +374,14 @@ impl<C: Clock> Runtime<C> {
         let attempts = ctx.action_config.attempts();
         let owner = run.owner_id();
 
+        // Parallel branches keep their own attempt counts and cooldowns
+        let attempt_key = match run.branch() {
+            Some(branch) => format!("{}/{}", branch, ctx.trigger),
+            None => ctx.trigger.to_string(),
+        };
+
         // Increment attempt count and get new value
-        let attempt_num = self.increment_run_attempt(&owner, ctx.trigger, ctx.chain_pos);
+        let attempt_num = self.increment_run_attempt(&owner, &attempt_key, ctx.chain_pos);
 
         // Check if attempts exhausted (compare against attempt count BEFORE this attempt)
         if attempts.is_exhausted(attempt_num - 1) {
@@ -403,7 +419,7 @@ impl<C: Clock> Runtime<C> {
                         cooldown_str, e
                     ))
                 })?;
-                let timer_id = TimerId::cooldown(owner, ctx.trigger, ctx.chain_pos);
+                let timer_id = TimerId::cooldown(owner, &attempt_key, ctx.chain_pos);
 
                 tracing::info!(
                     entity_id = %run.log_id(),
@@ -460,7 +476,14 @@ impl<C: Clock> Runtime<C> {
                 if let Some(template) = agent_def.notify.on_fail.as_ref() {
                     let message = oj_runbook::NotifyConfig::render(template, &vars);
                     self.executor
-                        .execute(Effect::Notify { title: agent_def.name.clone(), message })
+                        .execute(Effect::Notify {
+                            notification: crate::engine::lifecycle::notification(
+                                run,
+                                NotifyKind::OnFail,
+                                agent_def.name.clone(),
+                                message,
+                            ),
+                        })
                         .await?;
                 }
                 self.fail_run(run, &error).await
@@ -476,7 +499,16 @@ impl<C: Clock> Runtime<C> {
     /// Advance (complete) an entity — Job advances to next step, Crew completes.
     async fn advance_run(&self, run: &dyn RunLifecycle) -> Result<Vec<Event>, RuntimeError> {
         match run.owner_id() {
-            OwnerId::Job(job_id) => self.advance_job(&self.require_job(job_id.as_str())?).await,
+            OwnerId::Job(job_id) => {
+                let Some(job) = self.require_run_job(run, &job_id)? else {
+                    return Ok(vec![]);
+                };
+                if job.is_branch_view() {
+                    self.finalize_agent_step(&job).await?;
+                    return self.advance_branch(&job, &job.step).await;
+                }
+                self.advance_job(&job).await
+            }
             OwnerId::Crew(run_id) => {
                 let run = self.require_crew(run_id.as_str())?;
                 self.terminate_crew(&run, CrewStatus::Completed, None).await
@@ -491,7 +523,15 @@ impl<C: Clock> Runtime<C> {
         error: &str,
     ) -> Result<Vec<Event>, RuntimeError> {
         match run.owner_id() {
-            OwnerId::Job(job_id) => self.fail_job(&self.require_job(job_id.as_str())?, error).await,
+            OwnerId::Job(job_id) => {
+                let Some(job) = self.require_run_job(run, &job_id)? else {
+                    return Ok(vec![]);
+                };
+                if job.is_branch_view() {
+                    return self.fail_branch(&job, &job.step, error, false).await;
+                }
+                self.fail_job(&job, error).await
+            }
             OwnerId::Crew(run_id) => {
                 let run = self.require_crew(run_id.as_str())?;
                 self.terminate_crew(&run, CrewStatus::Failed, Some(error.to_string())).await
@@ -511,7 +551,9 @@ impl<C: Clock> Runtime<C> {
     ) -> Result<Vec<Event>, RuntimeError> {
         match run.owner_id() {
             OwnerId::Job(job_id) => {
-                let job = self.require_job(job_id.as_str())?;
+                let Some(job) = self.require_run_job(run, &job_id)? else {
+                    return Ok(vec![]);
+                };
                 let agent_id = kill_agent.map(AgentId::from_string);
                 self.kill_and_resume(&job, agent_id, agent_name, input, resume).await
             }
@@ -594,6 +636,7 @@ impl<C: Clock> Runtime<C> {
                     EscalationTrigger::GateFailed { command: command.clone(), exit_code, stderr },
                 )
                 .project(run.project())
+                .config(agent_def.decision.as_ref())
                 .build();
 
                 let mut effects = vec![Effect::Emit { event: decision_event }];
@@ -619,7 +662,23 @@ impl<C: Clock> Runtime<C> {
             self.executor.execute(Effect::KillAgent { agent_id: aid }).await?;
         }
         let job_id = JobId::from_string(&job.id);
-        self.spawn_agent_with_resume(&job_id, agent_name, input, resume).await
+        self.spawn_agent_with_resume(&job_id, &job.step, agent_name, input, resume).await
+    }
+
+    /// Refetch a run's job: as a branch view when the run is one of the
+    /// job's active parallel branches. `None` once a branch run's branch
+    /// has finished or been cancelled.
+    fn require_run_job(
+        &self,
+        run: &dyn RunLifecycle,
+        job_id: &JobId,
+    ) -> Result<Option<Job>, RuntimeError> {
+        let job = self.require_job(job_id.as_str())?;
+        Ok(match run.step() {
+            Some(step) if job.is_branch_active(step) => Some(job.branch_view(step)),
+            _ if run.branch().is_some() => None,
+            _ => Some(job),
+        })
     }
 
     /// Log to job activity log if entity is a Job (no-op for crew).
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel fan-out / fan-in step execution.
+//!
+//! A step whose `on_done` lists several steps forks the job into branches
+//! tracked in `Job::branches`. Steps with `after = [...]` join them: once all
+//! prerequisites complete, the join starts. If it is the only thing left to
+//! run, the job collapses back to sequential execution at the join step.
+
+use super::Runtime;
+use crate::engine::error::RuntimeError;
+use crate::engine::steps;
+use oj_core::{Clock, Effect, Event, Job, JobId, StepStatus};
+use std::collections::{HashMap, HashSet};
+
+impl<C: Clock> Runtime<C> {
+    /// Fork a job into parallel branches and start each one.
+    pub(crate) async fn fork_job(
+        &self,
+        job: &Job,
+        steps: Vec<String>,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let job_id = JobId::from_string(&job.id);
+        self.logger.append(&job.id, &job.step, &format!("forking into {}", steps.join(", ")));
+
+        let mut result_events = Vec::new();
+        result_events.extend(
+            self.executor
+                .execute(Effect::Emit {
+                    event: Event::JobForked { id: job_id, steps: steps.clone() },
+                })
+                .await?,
+        );
+        for step in &steps {
+            result_events.extend(self.start_branch(&job_id, step).await?);
+        }
+        Ok(result_events)
+    }
+
+    /// Start a single branch.
+    async fn start_branch(&self, job_id: &JobId, step: &str) -> Result<Vec<Event>, RuntimeError> {
+        let job = self.require_job(job_id.as_str())?;
+        // An earlier sibling may already have failed and cancelled this branch
+        if !job.is_branch_active(step) {
+            return Ok(vec![]);
+        }
+        self.start_step(job_id, step, &job.vars, job.execution_dir()).await
+    }
+
+    /// Stop whatever a branch is running: its agent, or its shell command
+    /// and any processes that command started.
+    async fn stop_branch(&self, job: &Job, step: &str) -> Result<(), RuntimeError> {
+        let view = job.branch_view(step);
+        if self.is_agent_step(&view) {
+            self.finalize_agent_step(&view).await
+        } else {
+            let job_id = JobId::from_string(&job.id);
+            self.executor.execute(Effect::KillShell { job_id, step: step.to_string() }).await?;
+            Ok(())
+        }
+    }
+
+    /// Stop every active branch of a fanned-out job (no-op otherwise).
+    pub(crate) async fn stop_branches(&self, job: &Job) -> Result<(), RuntimeError> {
+        for step in job.active_branches() {
+            self.stop_branch(job, step).await?;
+        }
+        Ok(())
+    }
+
+    /// A branch completed: follow its on_done (as further branches), then
+    /// start any joins whose prerequisites are now all complete.
+    pub(crate) async fn advance_branch(
+        &self,
+        job: &Job,
+        step: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let job_id = JobId::from_string(&job.id);
+        let mut result_events = Vec::new();
+        result_events.extend(
+            self.executor
+                .execute(Effect::Emit {
+                    event: Event::StepCompleted { job_id, step: step.to_string() },
+                })
+                .await?,
+        );
+
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let next: Vec<String> = runbook
+            .get_job(&job.kind)
+            .and_then(|p| p.get_step(step)?.on_done.as_ref().map(|t| t.step_names()))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| !job.branches.contains_key(s))
+            .collect();
+        if !next.is_empty() {
+            self.logger.append(&job.id, step, &format!("advancing to {}", next.join(", ")));
+            result_events.extend(self.fork_job(job, next).await?);
+        }
+
+        result_events.extend(self.join_branches(&job_id).await?);
+        Ok(result_events)
+    }
+
+    /// Start ready joins, or finish the job once every branch has completed.
+    async fn join_branches(&self, job_id: &JobId) -> Result<Vec<Event>, RuntimeError> {
+        let job = self.require_job(job_id.as_str())?;
+        if !job.is_parallel() {
+            return Ok(vec![]);
+        }
+
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let job_def = runbook
+            .get_job(&job.kind)
+            .ok_or_else(|| RuntimeError::JobDefNotFound(job.kind.clone()))?;
+
+        let completed: HashSet<&str> = job
+            .branches
+            .iter()
+            .filter(|(_, status)| **status == StepStatus::Completed)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let ready: Vec<String> = job_def
+            .ready_joins(&completed, |s| job.branches.contains_key(s))
+            .into_iter()
+            .map(|s| s.name.clone())
+            .collect();
+        let running = job.branches.keys().any(|s| job.is_branch_active(s));
+
+        match ready.as_slice() {
+            [] if running => Ok(vec![]),
+            [] => {
+                self.logger.append(&job.id, &job.step, "all branches completed");
+                self.finish_job(&job).await
+            }
+            [join] if !running => {
+                self.logger.append(&job.id, &job.step, &format!("joined: advancing to {}", join));
+                let effects = steps::step_transition_effects(&job, join);
+                let mut result_events = self.executor.execute_all(effects).await?;
+                result_events
+                    .extend(self.start_step(job_id, join, &job.vars, job.execution_dir()).await?);
+                Ok(result_events)
+            }
+            _ => self.fork_job(&job, ready).await,
+        }
+    }
+
+    /// A branch failed: stop it and cancel its running siblings, then route
+    /// the failure through the failing step's on_fail (falling back to the
+    /// job's on_fail). A branch that timed out routes through its on_timeout
+    /// first.
+    pub(crate) async fn fail_branch(
+        &self,
+        job: &Job,
+        step: &str,
+        error: &str,
+        timed_out: bool,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let job_id = JobId::from_string(&job.id);
+        self.logger.append(&job.id, step, &format!("branch failed: {}", error));
+        self.stop_branches(job).await?;
+
+        let mut effects: Vec<Effect> = job
+            .active_branches()
+            .into_iter()
+            .filter(|s| *s != step)
+            .map(|s| Effect::Emit {
+                event: Event::StepFailed {
+                    job_id,
+                    step: s.to_string(),
+                    error: "cancelled".to_string(),
+                    timed_out: false,
+                },
+            })
+            .collect();
+        effects.push(Effect::Emit {
+            event: Event::StepFailed {
+                job_id,
+                step: step.to_string(),
+                error: error.to_string(),
+                timed_out,
+            },
+        });
+        let mut result_events = self.executor.execute_all(effects).await?;
+
+        let job = self.require_job(job_id.as_str())?;
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let step_def = runbook.get_job(&job.kind).and_then(|p| p.get_step(step));
+        let on_fail = step_def.and_then(|s| {
+            let on_timeout = if timed_out { s.on_timeout.as_ref() } else { None };
+            on_timeout.or(s.on_fail.as_ref())
+        });
+        result_events.extend(self.route_failure(&job, on_fail, error).await?);
+        Ok(result_events)
+    }
+
+    /// Resume a fanned-out job: nudge (or respawn) the agent of each branch
+    /// waiting on a decision.
+    pub(crate) async fn resume_branches(
+        &self,
+        job: &Job,
+        message: Option<&str>,
+        vars: &HashMap<String, String>,
+        kill: bool,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let job_def = runbook
+            .get_job(&job.kind)
+            .ok_or_else(|| RuntimeError::JobDefNotFound(job.kind.clone()))?;
+        let waiting: Vec<(&str, &str)> = job
+            .active_branches()
+            .into_iter()
+            .filter(|b| job.branches[*b].is_waiting())
+            .filter_map(|b| Some((b, job_def.get_step(b)?.agent_name()?)))
+            .collect();
+        if waiting.is_empty() {
+            return Err(RuntimeError::InvalidRequest("no parallel branch is waiting".into()));
+        }
+
+        // Persist var updates if any
+        if !vars.is_empty() {
+            self.executor
+                .execute(Effect::Emit {
+                    event: Event::JobUpdated {
+                        id: JobId::from_string(&job.id),
+                        vars: vars.clone(),
+                    },
+                })
+                .await?;
+        }
+        let merged_inputs: HashMap<String, String> =
+            job.vars.iter().map(|(k, v)| (k.clone(), v.clone())).chain(vars.clone()).collect();
+        let message = message.unwrap_or("Please continue with the task.");
+
+        let mut result_events = Vec::new();
+        for (branch, agent_name) in waiting {
+            let view = job.branch_view(branch);
+            result_events.extend(
+                self.handle_agent_resume(&view, branch, agent_name, message, &merged_inputs, kill)
+                    .await?,
+            );
+        }
+        Ok(result_events)
+    }
+}
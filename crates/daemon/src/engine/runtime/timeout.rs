@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Step timeouts.
+//!
+//! `start_step` arms a `step-timeout:<job>:<step>` timer when the step (or its
+//! job) sets `timeout`. Re-entering a step re-arms the timer, so a timer that
+//! fires for the step the job is on always belongs to the current visit. A
+//! timer for a step the job has since left is ignored. Timers live in memory,
+//! so reconciliation re-arms them after a daemon restart.
+
+use super::Runtime;
+use crate::engine::error::RuntimeError;
+use crate::engine::monitor::parse_duration;
+use oj_core::{Clock, Effect, Event, Job, JobId, OwnerId, StepStatus, TimerId};
+use oj_runbook::JobDef;
+use std::time::Duration;
+
+impl<C: Clock> Runtime<C> {
+    /// Arm the timeout timer for a step, if it has one, for what is left of
+    /// it after `elapsed`. A step already past its timeout fires right away.
+    pub(crate) async fn arm_step_timeout(
+        &self,
+        job_id: &JobId,
+        job_def: &JobDef,
+        step: &str,
+        elapsed: Duration,
+    ) -> Result<(), RuntimeError> {
+        let Some(timeout) = job_def.step_timeout(step) else {
+            return Ok(());
+        };
+        let duration = match parse_duration(timeout) {
+            Ok(d) => d,
+            Err(e) => {
+                tracing::warn!(%job_id, step, error = %e, "invalid step timeout");
+                return Ok(());
+            }
+        };
+        let duration = duration.saturating_sub(elapsed);
+        self.executor
+            .execute(Effect::SetTimer { id: TimerId::step_timeout(*job_id, step), duration })
+            .await?;
+        Ok(())
+    }
+
+    /// A step ran past its timeout: stop its agent or shell command, fail it
+    /// as timed out and route through the step's `on_timeout`, falling back
+    /// to `on_fail`.
+    pub(crate) async fn handle_step_timeout(
+        &self,
+        owner: OwnerId,
+        step: &str,
+    ) -> Result<Vec<Event>, RuntimeError> {
+        let OwnerId::Job(job_id) = owner else {
+            return Ok(vec![]);
+        };
+        let Some(job) = self.get_job(job_id.as_str()) else {
+            return Ok(vec![]);
+        };
+        if job.is_terminal() {
+            return Ok(vec![]);
+        }
+
+        let runbook = self.cached_runbook(&job.runbook_hash)?;
+        let Some(job_def) = runbook.get_job(&job.kind) else {
+            return Ok(vec![]);
+        };
+        let error = format!("timed out after {}", job_def.step_timeout(step).unwrap_or_default());
+
+        if job.is_branch_active(step) {
+            return self.fail_branch(&job, step, &error, true).await;
+        }
+        if !is_in_step(&job, step) {
+            return Ok(vec![]);
+        }
+
+        tracing::info!(job_id = %job.id, step, "step timed out");
+        self.logger.append(&job.id, step, &format!("step {}", error));
+        let mut effects = Vec::new();
+        if self.is_agent_step(&job) {
+            self.finalize_agent_step(&job).await?;
+        } else {
+            effects.push(Effect::KillShell { job_id, step: step.to_string() });
+        }
+
+        // Record the timeout first so the step's outcome is TimedOut; the
+        // failure routing below then transitions from an already-failed step.
+        effects.push(Effect::Emit {
+            event: Event::StepFailed {
+                job_id,
+                step: step.to_string(),
+                error: error.clone(),
+                timed_out: true,
+            },
+        });
+        let mut result_events = self.executor.execute_all(effects).await?;
+
+        let job = self.require_job(job_id.as_str())?;
+        let step_def = job_def.get_step(step);
+        let on_timeout = step_def.and_then(|s| s.on_timeout.as_ref().or(s.on_fail.as_ref()));
+        result_events.extend(self.route_failure(&job, on_timeout, &error).await?);
+        Ok(result_events)
+    }
+}
+
+/// Whether the job is still running (or waiting in) the given sequential step.
+fn is_in_step(job: &Job, step: &str) -> bool {
+    job.step == step
+        && !job.is_parallel()
+        && matches!(job.step_status, StepStatus::Running | StepStatus::Waiting(_))
+}
@@ -4,7 +4,7 @@
 //! Cron-related runtime tests
 
 use super::*;
-use crate::engine::runtime::handlers::cron::CronStatus;
+use crate::engine::runtime::handlers::cron::{CronStatus, CronTrigger};
 use oj_core::RunTarget;
 
 fn cron_runbook_with_hash() -> (String, serde_json::Value, String) {
@@ -89,6 +89,9 @@ async fn cron_start_sets_timer() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -101,7 +104,7 @@ async fn cron_start_sets_timer() {
         let state = crons.get("janitor").expect("cron state should exist");
         assert_eq!(state.status, CronStatus::Running);
         assert!(matches!(state.target, RunTarget::Job(ref p) if p == "cleanup"));
-        assert_eq!(state.interval, "30m");
+        assert_eq!(state.trigger, CronTrigger::Interval(std::time::Duration::from_secs(30 * 60)));
     }
 
     // Timer should have been set
@@ -142,6 +145,9 @@ async fn cron_stop_cancels_timer() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -192,6 +198,9 @@ async fn cron_timer_fired_creates_job() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -235,6 +244,9 @@ async fn cron_timer_fired_reloads_runbook() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -333,6 +345,9 @@ async fn cron_timer_fired_reschedules_timer() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -368,6 +383,9 @@ async fn cron_timer_fired_when_stopped_is_noop() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: String::new(),
         })
@@ -411,6 +429,9 @@ async fn cron_start_with_namespace() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::job("cleanup"),
             project: "myproject".to_string(),
         })
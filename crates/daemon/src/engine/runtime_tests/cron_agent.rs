@@ -67,6 +67,9 @@ async fn cron_start_agent_sets_timer() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::agent("doctor"),
             project: String::new(),
         })
@@ -107,6 +110,9 @@ async fn cron_timer_fires_agent() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::agent("doctor"),
             project: String::new(),
         })
@@ -178,6 +184,9 @@ async fn cron_agent_concurrency_skip() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::agent("doctor"),
             project: String::new(),
         })
@@ -261,6 +270,9 @@ async fn cron_agent_concurrency_respawns_after_complete() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: RunTarget::agent("doctor"),
             project: String::new(),
         })
@@ -59,6 +59,9 @@ async fn cron_job_concurrency_skip() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("deploy"),
             project: String::new(),
         })
@@ -152,6 +155,9 @@ async fn cron_job_concurrency_respawns_after_complete() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("deploy"),
             project: String::new(),
         })
@@ -220,6 +226,9 @@ async fn cron_job_concurrency_default_singleton() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("deploy"),
             project: String::new(),
         })
@@ -285,6 +294,9 @@ async fn cron_job_concurrency_allows_multiple() {
             project_path: ctx.project_path.clone(),
             runbook_hash: runbook_hash.clone(),
             interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("deploy"),
             project: String::new(),
         })
@@ -308,3 +320,110 @@ async fn cron_job_concurrency_allows_multiple() {
     let has_cron_fired = events.iter().any(|e| matches!(e, Event::CronFired { .. }));
     assert!(has_cron_fired, "CronFired should be emitted for successful spawn");
 }
+
+// ---- Test 21: cron_catchup_runs_sequentially_at_concurrency_one ----
+
+fn new_job_ids(events: &[Event]) -> Vec<JobId> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::JobCreated { id, .. } => Some(*id),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn cron_catchup_runs_sequentially_at_concurrency_one() {
+    let runbook = test_runbook_cron_job(
+        "deployer",
+        "deploy",
+        "interval = \"10m\"\nconcurrency = 1\ncatchup = \"all\"",
+        &[("run", "echo deploying", "")],
+    );
+    let ctx = setup_with_runbook(&runbook).await;
+    let (runbook_json, runbook_hash) = hash_runbook(&runbook);
+
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+
+    // Three runs were missed while the daemon was down
+    ctx.runtime.queue_cron_catchup("deployer", "", 3);
+
+    // Resuming the cron starts only as many as its concurrency allows
+    let events = ctx
+        .runtime
+        .handle_event(Event::CronStarted {
+            cron: "deployer".to_string(),
+            project_path: ctx.project_path.clone(),
+            runbook_hash: runbook_hash.clone(),
+            interval: "10m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::All,
+            target: oj_core::RunTarget::job("deploy"),
+            project: String::new(),
+        })
+        .await
+        .unwrap();
+
+    let mut started = new_job_ids(&events);
+    assert_eq!(started.len(), 1, "concurrency = 1 should start one catch-up run");
+    assert_eq!(ctx.runtime.count_active_cron_jobs("deployer", ""), 1);
+
+    // Each finished run lets the next missed run start
+    for remaining in [1, 0] {
+        let done = Event::JobAdvanced { id: started[0], step: "done".to_string() };
+        ctx.runtime.executor.execute(oj_core::Effect::Emit { event: done.clone() }).await.unwrap();
+        let events = ctx.runtime.handle_event(done).await.unwrap();
+
+        started = new_job_ids(&events);
+        assert_eq!(started.len(), 1, "one run should start per finished run");
+        assert_eq!(ctx.runtime.count_active_cron_jobs("deployer", ""), 1);
+        assert_eq!(
+            ctx.runtime.cron_catchup.lock().get("deployer").copied().unwrap_or(0),
+            remaining
+        );
+    }
+
+    // Nothing left to catch up
+    let done = Event::JobAdvanced { id: started[0], step: "done".to_string() };
+    ctx.runtime.executor.execute(oj_core::Effect::Emit { event: done.clone() }).await.unwrap();
+    let events = ctx.runtime.handle_event(done).await.unwrap();
+    assert!(new_job_ids(&events).is_empty());
+}
+
+// ---- Test 22: cron_catchup_agent_uses_crew_owner ----
+
+#[tokio::test]
+async fn cron_catchup_agent_uses_crew_owner() {
+    let runbook = test_runbook_cron_agent("interval = \"30m\"\ncatchup = \"once\"", "");
+    let ctx = setup_with_runbook(&runbook).await;
+    let (runbook_json, runbook_hash) = hash_runbook(&runbook);
+
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+
+    ctx.runtime.queue_cron_catchup("health_check", "", 1);
+
+    let events = ctx
+        .runtime
+        .handle_event(Event::CronStarted {
+            cron: "health_check".to_string(),
+            project_path: ctx.project_path.clone(),
+            runbook_hash: runbook_hash.clone(),
+            interval: "30m".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::Once,
+            target: oj_core::RunTarget::agent("doctor"),
+            project: String::new(),
+        })
+        .await
+        .unwrap();
+
+    let owner = events.iter().find_map(|e| match e {
+        Event::CronFired { owner, .. } => Some(*owner),
+        _ => None,
+    });
+    assert!(matches!(owner, Some(oj_core::OwnerId::Crew(_))), "owner: {:?}", owner);
+    assert!(ctx.runtime.cron_catchup.lock().is_empty());
+}
@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Calendar-schedule cron tests
+
+use super::cron::load_runbook;
+use super::*;
+use crate::engine::{next_fire_at_ms, CronTrigger};
+use oj_core::{RunTarget, TimerId};
+use oj_runbook::{CronExpr, CronTimezone};
+use std::time::Duration;
+
+/// Monday 2026-03-02 08:30:00 UTC
+const MON_0830: u64 = 1_772_440_200_000;
+/// Monday 2026-03-02 09:00:00 UTC
+const MON_0900: u64 = 1_772_442_000_000;
+const HOUR_MS: u64 = 3_600_000;
+
+fn next(expr: &str, timezone: CronTimezone, after_ms: u64) -> Option<u64> {
+    next_fire_at_ms(&CronExpr::parse(expr).unwrap(), timezone, after_ms)
+}
+
+fn schedule_runbook(cron_cfg: &str) -> (String, serde_json::Value, String) {
+    let runbook = test_runbook_cron_job(
+        "standup",
+        "report",
+        cron_cfg,
+        &[("post", "echo posting", "on_done = { step = \"done\" }"), ("done", "echo done", "")],
+    );
+    let (json, hash) = hash_runbook(&runbook);
+    (runbook, json, hash)
+}
+
+async fn start_schedule_cron(
+    ctx: &TestContext,
+    runbook_hash: &str,
+    schedule: &str,
+    timezone: Option<&str>,
+) {
+    ctx.runtime
+        .handle_event(Event::CronStarted {
+            cron: "standup".to_string(),
+            project_path: ctx.project_path.clone(),
+            runbook_hash: runbook_hash.to_string(),
+            interval: String::new(),
+            schedule: Some(schedule.to_string()),
+            timezone: timezone.map(String::from),
+            catchup: oj_core::CronCatchup::None,
+            target: RunTarget::job("report"),
+            project: String::new(),
+        })
+        .await
+        .unwrap();
+}
+
+/// Time until the cron timer fires, per the scheduler.
+fn cron_timer_delay(ctx: &TestContext) -> Duration {
+    let scheduler = ctx.runtime.executor.scheduler();
+    let deadline = scheduler.lock().next_deadline().expect("cron timer should be set");
+    deadline.saturating_duration_since(ctx.clock.now())
+}
+
+#[yare::parameterized(
+    same_day = { "0 9 * * *", MON_0830, MON_0900 },
+    strictly_after = { "0 9 * * *", MON_0900, MON_0900 + 24 * HOUR_MS },
+    weekdays_skip_weekend = { "0 9 * * MON-FRI", MON_0900 - 3 * 24 * HOUR_MS + HOUR_MS, MON_0900 },
+    every_15_minutes = { "*/15 * * * *", MON_0830 + 1, MON_0830 + HOUR_MS / 4 },
+    seconds_field = { "30 * * * * *", MON_0830, MON_0830 + 30_000 },
+    first_of_month = { "@monthly", MON_0830, 1_772_323_200_000 + 31 * 24 * HOUR_MS },
+)]
+fn next_fire_utc(expr: &str, after: u64, expected: u64) {
+    assert_eq!(next(expr, CronTimezone::Utc, after), Some(expected));
+}
+
+#[test]
+fn next_fire_fixed_offset() {
+    // 08:30 UTC is 14:00 at +05:30, so 09:00 local is tomorrow at 03:30 UTC
+    let tz = CronTimezone::Fixed(5 * 3600 + 1800);
+    assert_eq!(next("0 9 * * *", tz, MON_0830), Some(MON_0900 + 18 * HOUR_MS + HOUR_MS / 2));
+}
+
+/// Around the 2026 New York DST changes (2026-03-08 and 2026-11-01).
+#[yare::parameterized(
+    // 09:00 EST is 14:00 UTC; the next 09:00 is EDT, 13:00 UTC
+    spring_forward_day = { "0 9 * * *", 1_772_892_000_000, 1_772_974_800_000 },
+    // 02:30 doesn't exist on 2026-03-08, so the next is 02:30 EDT on the 9th
+    spring_gap_skipped = { "30 2 * * *", 1_772_868_600_000, 1_773_037_800_000 },
+    // 01:30 happens twice on 2026-11-01; the first is 01:30 EDT, 05:30 UTC
+    fall_back_first = { "30 1 * * *", 1_793_505_600_000, 1_793_511_000_000 },
+    // and the repeat is skipped: next is 01:30 EST on the 2nd, 06:30 UTC
+    fall_back_repeat = { "30 1 * * *", 1_793_511_000_000, 1_793_601_000_000 },
+)]
+fn next_fire_named_timezone_follows_dst(expr: &str, after: u64, expected: u64) {
+    let tz = CronTimezone::parse("America/New_York").unwrap();
+    assert_eq!(next(expr, tz, after), Some(expected));
+}
+
+#[test]
+fn next_fire_leap_day() {
+    // From 2026-03-02, the next Feb 29 is in 2028
+    let feb_29_2028 = 1_835_395_200_000;
+    assert_eq!(next("0 0 29 2 *", CronTimezone::Utc, MON_0830), Some(feb_29_2028));
+}
+
+#[tokio::test]
+async fn schedule_start_sets_timer_to_next_match() {
+    let (runbook, runbook_json, runbook_hash) = schedule_runbook("schedule = \"0 9 * * *\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+    ctx.clock.set_epoch_ms(MON_0830);
+
+    start_schedule_cron(&ctx, &runbook_hash, "0 9 * * *", None).await;
+
+    assert_eq!(cron_timer_delay(&ctx), Duration::from_secs(30 * 60));
+}
+
+#[tokio::test]
+async fn schedule_start_honours_timezone() {
+    let cfg = "schedule = \"0 9 * * *\"\ntimezone = \"-05:00\"";
+    let (runbook, runbook_json, runbook_hash) = schedule_runbook(cfg);
+    let ctx = setup_with_runbook(&runbook).await;
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+    ctx.clock.set_epoch_ms(MON_0830);
+
+    start_schedule_cron(&ctx, &runbook_hash, "0 9 * * *", Some("-05:00")).await;
+
+    // 09:00 at -05:00 is 14:00 UTC
+    assert_eq!(cron_timer_delay(&ctx), Duration::from_secs(5 * 3600 + 30 * 60));
+}
+
+#[tokio::test]
+async fn schedule_fire_runs_job_and_reschedules() {
+    let (runbook, runbook_json, runbook_hash) = schedule_runbook("schedule = \"0 9 * * MON-FRI\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    load_runbook(&ctx, &runbook_json, &runbook_hash).await;
+    ctx.clock.set_epoch_ms(MON_0830);
+    start_schedule_cron(&ctx, &runbook_hash, "0 9 * * MON-FRI", None).await;
+
+    ctx.clock.advance(Duration::from_secs(30 * 60));
+    ctx.runtime.handle_event(Event::TimerStart { id: TimerId::cron("standup", "") }).await.unwrap();
+
+    let jobs = ctx.runtime.jobs();
+    assert!(jobs.values().any(|j| j.kind == "report"), "scheduled fire should create a job");
+    assert_eq!(cron_timer_delay(&ctx), Duration::from_secs(24 * 3600));
+}
+
+#[yare::parameterized(
+    interval_three = { CronTrigger::Interval(Duration::from_secs(3600)), 0, 3 * HOUR_MS + 1, 10, 3 },
+    interval_limit = { CronTrigger::Interval(Duration::from_secs(60)), 0, HOUR_MS, 5, 5 },
+    interval_none = { CronTrigger::Interval(Duration::from_secs(3600)), 0, HOUR_MS - 1, 10, 0 },
+)]
+fn fires_between_interval(trigger: CronTrigger, from: u64, to: u64, limit: usize, expected: usize) {
+    assert_eq!(trigger.fires_between(from, to, limit), expected);
+}
+
+#[test]
+fn fires_between_schedule() {
+    let trigger = CronTrigger::Schedule {
+        expr: CronExpr::parse("0 9 * * MON-FRI").unwrap(),
+        timezone: CronTimezone::Utc,
+    };
+    // Friday 10:00 to the following Tuesday 09:00: Monday and Tuesday fire
+    let fri_1000 = MON_0900 - 3 * 24 * HOUR_MS + HOUR_MS;
+    let tue_0900 = MON_0900 + 24 * HOUR_MS;
+    assert_eq!(trigger.fires_between(fri_1000, tue_0900, 10), 2);
+    assert_eq!(trigger.fires_between(fri_1000, tue_0900, 1), 1);
+    assert_eq!(trigger.fires_between(MON_0900, MON_0900, 10), 0);
+}
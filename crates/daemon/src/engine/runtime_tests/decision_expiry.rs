@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Decision expiry tests
+
+use super::*;
+use oj_core::{DecisionId, DecisionOption, DecisionSource, TimerId};
+
+const HOUR_MS: u64 = 3_600_000;
+
+/// A failed-gate decision on `job_id`, recorded in state and handled by the
+/// runtime the way the event loop would.
+async fn create_gate_decision(
+    ctx: &TestContext,
+    id: &str,
+    job_id: &str,
+    expiry: Option<(u64, usize)>,
+) -> DecisionId {
+    let id = DecisionId::from_string(id);
+    let event = Event::DecisionCreated {
+        id,
+        owner: JobId::from_string(job_id).into(),
+        project: String::new(),
+        created_at_ms: ctx.clock.epoch_ms(),
+        agent_id: AgentId::from_string("agent-1"),
+        source: DecisionSource::Gate,
+        context: "Gate command failed".to_string(),
+        options: ["Retry", "Skip", "Cancel"].map(DecisionOption::new).to_vec(),
+        questions: None,
+        expires_at_ms: expiry.map(|(at, _)| at),
+        default_choice: expiry.map(|(_, choice)| choice),
+        assignee: None,
+        reviewers: vec![],
+    };
+    ctx.runtime.lock_state_mut(|s| s.apply_event(&event));
+    ctx.runtime.handle_event(event).await.unwrap();
+    id
+}
+
+fn fire_decision_timeout(id: DecisionId) -> Event {
+    Event::TimerStart { id: TimerId::decision_timeout(id) }
+}
+
+async fn setup_job() -> (TestContext, String) {
+    let ctx = setup_with_runbook(&test_runbook_shell("build", "")).await;
+    let job_id = create_job_for_runbook(&ctx, "build", &[]).await;
+    (ctx, job_id)
+}
+
+#[tokio::test]
+async fn expiring_decision_arms_timer() {
+    let (ctx, job_id) = setup_job().await;
+    let expires_at = ctx.clock.epoch_ms() + HOUR_MS;
+    let id = create_gate_decision(&ctx, "dcn-1", &job_id, Some((expires_at, 2))).await;
+
+    let timer_ids = ctx.pending_timer_ids();
+    assert!(timer_ids.contains(&TimerId::decision_timeout(id).to_string()), "{:?}", timer_ids);
+}
+
+#[tokio::test]
+async fn decision_without_expiry_arms_no_timer() {
+    let (ctx, job_id) = setup_job().await;
+    create_gate_decision(&ctx, "dcn-1", &job_id, None).await;
+
+    assert_no_timer_with_prefix(&ctx.pending_timer_ids(), "decision-timeout:");
+}
+
+#[tokio::test]
+async fn expiry_resolves_with_default_choice() {
+    let (ctx, job_id) = setup_job().await;
+    let step = ctx.runtime.get_job(&job_id).unwrap().step;
+    let id = create_gate_decision(&ctx, "dcn-1", &job_id, Some((ctx.clock.epoch_ms(), 2))).await;
+
+    let events = ctx.runtime.handle_event(fire_decision_timeout(id)).await.unwrap();
+
+    assert!(
+        matches!(
+            &events[0],
+            Event::DecisionResolved { choices, message: None, timed_out: true, .. } if choices == &[2]
+        ),
+        "{:?}",
+        events
+    );
+    assert!(events.iter().any(|e| matches!(
+        e,
+        Event::StepCompleted { job_id: j, step: s } if j.as_str() == job_id && *s == step
+    )));
+    let decision = ctx.runtime.lock_state(|s| s.decisions[id.as_str()].clone());
+    assert!(decision.is_resolved());
+    assert!(decision.timed_out);
+}
+
+#[tokio::test]
+async fn expiry_after_human_answer_is_ignored() {
+    let (ctx, job_id) = setup_job().await;
+    let id = create_gate_decision(&ctx, "dcn-1", &job_id, Some((ctx.clock.epoch_ms(), 2))).await;
+    ctx.runtime.lock_state_mut(|s| {
+        s.apply_event(&Event::DecisionResolved {
+            id,
+            project: String::new(),
+            resolved_at_ms: ctx.clock.epoch_ms(),
+            choices: vec![3],
+            message: None,
+            timed_out: false,
+        })
+    });
+
+    let events = ctx.runtime.handle_event(fire_decision_timeout(id)).await.unwrap();
+
+    assert!(events.is_empty(), "{:?}", events);
+    let decision = ctx.runtime.lock_state(|s| s.decisions[id.as_str()].clone());
+    assert_eq!(decision.choices, [3]);
+    assert!(!decision.timed_out);
+}
+
+#[tokio::test]
+async fn expiry_of_superseded_decision_is_ignored() {
+    let (ctx, job_id) = setup_job().await;
+    let first = create_gate_decision(&ctx, "dcn-1", &job_id, Some((ctx.clock.epoch_ms(), 2))).await;
+    create_gate_decision(&ctx, "dcn-2", &job_id, None).await;
+
+    let events = ctx.runtime.handle_event(fire_decision_timeout(first)).await.unwrap();
+
+    assert!(events.is_empty(), "{:?}", events);
+}
@@ -7,6 +7,8 @@ mod crew;
 mod cron;
 mod cron_agent;
 mod cron_concurrency;
+mod cron_schedule;
+mod decision_expiry;
 mod directives;
 mod errors;
 mod idempotency;
@@ -21,11 +23,16 @@ mod steps;
 mod steps_cycles;
 mod steps_lifecycle;
 mod steps_locals;
+mod steps_parallel;
+mod steps_timeout;
 mod timer_cleanup;
 mod worker;
 mod worker_concurrency;
+mod worker_delay;
 mod worker_external;
+mod worker_order;
 mod worker_queue;
+mod worker_throttle;
 
 use super::*;
 use crate::engine::test_helpers::{
@@ -1,9 +1,12 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Tests for job notification lifecycle (on_start, on_done, on_fail)
+//! Tests for job notification lifecycle (on_start, on_done, on_fail) and
+//! routed queue and worker notifications
 
 use super::*;
+use crate::engine::test_helpers::load_runbook_hash;
+use oj_core::{NotifyKind, NotifySeverity};
 
 #[tokio::test]
 async fn job_on_start_emits_notification() {
@@ -25,6 +28,10 @@ async fn job_on_start_emits_notification() {
     assert_eq!(calls.len(), 1, "on_start should emit one notification");
     assert_eq!(calls[0].title, "my-feature");
     assert_eq!(calls[0].message, "Job my-feature started");
+    assert_eq!(calls[0].kind, NotifyKind::OnStart);
+    assert_eq!(calls[0].severity, NotifySeverity::Info);
+    assert_eq!(calls[0].owner, Some(JobId::from_string("job-1").into()));
+    assert_eq!(calls[0].step.as_deref(), Some("init"));
 }
 
 #[tokio::test]
@@ -53,6 +60,7 @@ async fn job_on_done_emits_notification() {
     assert_eq!(calls.len(), 1, "on_done should emit one notification");
     assert_eq!(calls[0].title, "my-feature");
     assert_eq!(calls[0].message, "Job my-feature completed");
+    assert_eq!(calls[0].kind, NotifyKind::OnDone);
 }
 
 #[tokio::test]
@@ -86,6 +94,9 @@ async fn job_on_fail_emits_notification() {
         "on_fail message should contain 'failed': {}",
         calls[0].message
     );
+    assert_eq!(calls[0].kind, NotifyKind::OnFail);
+    assert_eq!(calls[0].severity, NotifySeverity::Error);
+    assert_eq!(calls[0].step.as_deref(), Some("init"));
 }
 
 // =============================================================================
@@ -162,3 +173,74 @@ async fn gate_dead_failure_does_not_produce_automatic_notification() {
         calls
     );
 }
+
+// =============================================================================
+// Dead-letter and worker-stopped notifications are sent only when routed
+// =============================================================================
+
+/// Route `kinds` to the desktop notifier in the test project's config.
+fn route_to_desktop(ctx: &TestContext, kinds: &str) {
+    std::fs::write(
+        ctx.project_path.join(".oj/config.toml"),
+        format!("[[notify.route]]\nkinds = [{}]\nsinks = [\"desktop\"]\n", kinds),
+    )
+    .unwrap();
+    let path = ctx.project_path.clone();
+    ctx.runtime.lock_state_mut(|state| {
+        state.project_paths.insert(String::new(), path);
+    });
+}
+
+fn queue_dead(item_id: &str) -> Event {
+    Event::QueueDead {
+        queue: "bugs".to_string(),
+        project: String::new(),
+        item_id: item_id.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn queue_dead_notifies_when_routed() {
+    let ctx = setup_with_runbook(&test_runbook_worker("list = \"echo '[]'\"", 1)).await;
+    route_to_desktop(&ctx, "\"dead_letter\"");
+
+    ctx.runtime.handle_event(queue_dead("item-1")).await.unwrap();
+
+    let calls = ctx.notifier.calls();
+    assert_eq!(calls.len(), 1, "dead letter should emit one notification");
+    assert_eq!(calls[0].kind, NotifyKind::DeadLetter);
+    assert_eq!(calls[0].severity, NotifySeverity::Error);
+    assert_eq!(calls[0].title, "Dead letter: bugs");
+    assert!(calls[0].message.contains("item-1"), "{}", calls[0].message);
+}
+
+#[tokio::test]
+async fn queue_dead_is_silent_without_route() {
+    let ctx = setup_with_runbook(&test_runbook_worker("list = \"echo '[]'\"", 1)).await;
+
+    ctx.runtime.handle_event(queue_dead("item-1")).await.unwrap();
+
+    assert!(ctx.notifier.calls().is_empty());
+}
+
+#[tokio::test]
+async fn worker_stopped_notifies_once_when_routed() {
+    let runbook = test_runbook_worker("list = \"echo '[]'\"\ntake = \"echo taken\"", 1);
+    let ctx = setup_with_runbook(&runbook).await;
+    let hash = load_runbook_hash(&ctx, &runbook);
+    route_to_desktop(&ctx, "\"worker_stopped\", \"dead_letter\"");
+    ctx.runtime
+        .handle_event(worker_started("fixer", &ctx.project_path, &hash, "bugs", 1, ""))
+        .await
+        .unwrap();
+
+    let stopped = Event::WorkerStopped { worker: "fixer".to_string(), project: String::new() };
+    ctx.runtime.handle_event(stopped.clone()).await.unwrap();
+    ctx.runtime.handle_event(stopped).await.unwrap();
+
+    let calls = ctx.notifier.calls();
+    assert_eq!(calls.len(), 1, "only the running -> stopped transition notifies: {:?}", calls);
+    assert_eq!(calls[0].kind, NotifyKind::WorkerStopped);
+    assert_eq!(calls[0].severity, NotifySeverity::Warning);
+    assert_eq!(calls[0].title, "Worker stopped: fixer");
+}
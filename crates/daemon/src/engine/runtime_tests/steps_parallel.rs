@@ -0,0 +1,384 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel fan-out / fan-in step tests
+
+use super::*;
+use crate::adapters::AgentCall;
+use crate::engine::test_helpers::{background_sleep_command, wait_for_exit, wait_for_pid_file};
+use oj_core::{StepOutcome, TimerId};
+
+const FAN_OUT: &str = "on_done = [{ step = \"lint\" }, { step = \"test\" }]";
+
+fn parallel_runbook(lint_cfg: &str, join: bool) -> String {
+    let mut steps = vec![
+        ("build", "echo build", FAN_OUT),
+        ("lint", "echo lint", lint_cfg),
+        ("test", "echo test", ""),
+        ("cleanup", "echo cleanup", ""),
+    ];
+    if join {
+        steps.push(("publish", "echo publish", "after = [\"lint\", \"test\"]"));
+    }
+    test_runbook_steps("ci", "on_fail = { step = \"cleanup\" }", &steps)
+}
+
+/// `build` fans out to a shell `lint` branch and an agent `review` branch,
+/// joined by `publish`. `agent_cfg` goes inside `[agent.reviewer]`.
+fn agent_branch_runbook(lint_run: &str, agent_cfg: &str) -> String {
+    let steps = test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", "on_done = [{ step = \"lint\" }, { step = \"review\" }]"),
+            ("lint", lint_run, ""),
+            ("review", "{ agent = \"reviewer\" }", ""),
+            ("publish", "echo publish", "after = [\"lint\", \"review\"]"),
+        ],
+    );
+    format!("{steps}[agent.reviewer]\nrun = 'claude'\nprompt = \"Review\"\n{agent_cfg}\n")
+}
+
+fn branch_agent_id(ctx: &TestContext, job_id: &str, branch: &str) -> AgentId {
+    let job = ctx.runtime.get_job(job_id).unwrap();
+    AgentId::from_string(job.step_agent_id(branch).unwrap())
+}
+
+fn outcome_of(job: &Job, step: &str) -> StepOutcome {
+    job.step_history.iter().rfind(|r| r.name == step).unwrap().outcome.clone()
+}
+
+#[tokio::test]
+async fn fan_out_starts_all_branches() {
+    let ctx = setup_with_runbook(&parallel_runbook("", true)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert!(job.is_parallel());
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Running));
+    assert_eq!(job.branches.get("test"), Some(&StepStatus::Running));
+    assert_eq!(outcome_of(&job, "build"), StepOutcome::Completed);
+    assert_eq!(outcome_of(&job, "lint"), StepOutcome::Running);
+    assert_eq!(outcome_of(&job, "test"), StepOutcome::Running);
+}
+
+#[tokio::test]
+async fn join_waits_for_all_branches_then_collapses() {
+    let ctx = setup_with_runbook(&parallel_runbook("", true)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Completed));
+    assert!(!job.branches.contains_key("publish"), "join must wait for test");
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "test")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "publish");
+    assert_eq!(job.step_status, StepStatus::Running);
+    assert!(!job.is_parallel());
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "publish")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "done");
+}
+
+#[tokio::test]
+async fn branches_without_join_complete_job() {
+    let ctx = setup_with_runbook(&parallel_runbook("", false)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "test")).await.unwrap();
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "build");
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "done");
+    assert_eq!(job.step_status, StepStatus::Completed);
+    assert!(job.branches.is_empty());
+}
+
+#[tokio::test]
+async fn branch_on_done_chains_within_parallel_region() {
+    let ctx = setup_with_runbook(&test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", FAN_OUT),
+            ("lint", "echo lint", "on_done = { step = \"format\" }"),
+            ("format", "echo format", ""),
+            ("test", "echo test", ""),
+            ("publish", "echo publish", "after = [\"format\", \"test\"]"),
+        ],
+    ))
+    .await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+    ctx.runtime.handle_event(shell_ok(&job_id, "test")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.branches.get("format"), Some(&StepStatus::Running));
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "format")).await.unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "publish");
+}
+
+#[tokio::test]
+async fn branch_failure_cancels_siblings_and_follows_step_on_fail() {
+    let ctx = setup_with_runbook(&test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", FAN_OUT),
+            ("lint", "echo lint", "on_fail = { step = \"fix\" }"),
+            ("test", "echo test", ""),
+            ("fix", "echo fix", ""),
+        ],
+    ))
+    .await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_fail(&job_id, "lint")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "fix");
+    assert!(job.failing);
+    assert!(job.branches.is_empty());
+    assert_eq!(outcome_of(&job, "lint"), StepOutcome::Failed("shell exit code: 1".into()));
+    assert_eq!(outcome_of(&job, "test"), StepOutcome::Failed("cancelled".into()));
+}
+
+#[tokio::test]
+async fn branch_failure_falls_back_to_job_on_fail() {
+    let ctx = setup_with_runbook(&parallel_runbook("", true)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_fail(&job_id, "test")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "cleanup");
+    assert_eq!(job.error.as_deref(), Some("shell exit code: 1"));
+}
+
+#[tokio::test]
+async fn branch_failure_without_on_fail_fails_job() {
+    let ctx = setup_with_runbook(&test_runbook_steps(
+        "ci",
+        "",
+        &[("build", "echo build", FAN_OUT), ("lint", "echo lint", ""), ("test", "echo test", "")],
+    ))
+    .await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_fail(&job_id, "lint")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "failed");
+    assert!(job.branches.is_empty());
+}
+
+#[tokio::test]
+async fn exit_from_cancelled_branch_is_ignored() {
+    let ctx = setup_with_runbook(&parallel_runbook("", true)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.runtime.handle_event(shell_fail(&job_id, "lint")).await.unwrap();
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "cleanup");
+
+    // The cancelled "test" branch's shell still exits later
+    let events = ctx.runtime.handle_event(shell_ok(&job_id, "test")).await.unwrap();
+
+    assert!(events.is_empty());
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "cleanup");
+}
+
+#[tokio::test]
+async fn duplicate_branch_exit_is_ignored() {
+    let ctx = setup_with_runbook(&parallel_runbook("", true)).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+
+    let events = ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+
+    assert!(events.is_empty());
+    assert_eq!(
+        ctx.runtime.get_job(&job_id).unwrap().branches.get("test"),
+        Some(&StepStatus::Running)
+    );
+}
+
+#[tokio::test]
+async fn failing_branch_kills_sibling_shell_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid_file = dir.path().join("pid");
+    let ctx = setup_with_runbook(&test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", FAN_OUT),
+            ("lint", "echo lint", ""),
+            ("test", &background_sleep_command(&pid_file), ""),
+        ],
+    ))
+    .await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    let pid = wait_for_pid_file(&pid_file).await;
+
+    ctx.runtime.handle_event(shell_fail(&job_id, "lint")).await.unwrap();
+
+    assert!(wait_for_exit(pid).await, "cancelled branch's shell command should be killed");
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "failed");
+}
+
+#[tokio::test]
+async fn cancel_kills_branch_shell_commands() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid_file = dir.path().join("pid");
+    let ctx = setup_with_runbook(&test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", FAN_OUT),
+            ("lint", "echo lint", ""),
+            ("test", &background_sleep_command(&pid_file), ""),
+        ],
+    ))
+    .await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    let pid = wait_for_pid_file(&pid_file).await;
+
+    ctx.runtime.handle_event(Event::JobCancel { id: JobId::from_string(&job_id) }).await.unwrap();
+
+    assert!(wait_for_exit(pid).await, "cancelled job's branch command should be killed");
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "cancelled");
+}
+
+#[tokio::test]
+async fn agent_branch_runs_alongside_shell_branch() {
+    let mut ctx = setup_with_runbook(&agent_branch_runbook("sleep 30", "")).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.process_background_events().await;
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Running));
+    assert_eq!(job.branches.get("review"), Some(&StepStatus::Running));
+    assert_eq!(outcome_of(&job, "review"), StepOutcome::Running);
+    assert!(job.step_agent_id("review").is_some());
+    assert!(job.step_agent_id("lint").is_none());
+}
+
+#[tokio::test]
+async fn agent_branch_completion_joins() {
+    let mut ctx = setup_with_runbook(&agent_branch_runbook("sleep 30", "on_dead = \"done\"")).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.process_background_events().await;
+    let agent_id = branch_agent_id(&ctx, &job_id, "review");
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "lint")).await.unwrap();
+    ctx.runtime
+        .handle_event(agent_exited(agent_id, Some(0), JobId::from_string(&job_id).into()))
+        .await
+        .unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "publish");
+    assert!(!job.is_parallel());
+    assert_eq!(outcome_of(&job, "review"), StepOutcome::Completed);
+}
+
+#[tokio::test]
+async fn dead_branch_agent_found_by_liveness_check() {
+    let mut ctx = setup_with_runbook(&agent_branch_runbook("sleep 30", "on_dead = \"done\"")).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.process_background_events().await;
+    let agent_id = branch_agent_id(&ctx, &job_id, "review");
+    let owner = JobId::from_string(&job_id);
+
+    ctx.agents.set_agent_alive(&agent_id, false);
+    ctx.runtime.handle_event(Event::TimerStart { id: TimerId::liveness(owner) }).await.unwrap();
+    assert!(ctx.pending_timer_ids().contains(&TimerId::exit_deferred(owner).to_string()));
+    ctx.agents.set_agent_state(&agent_id, oj_core::AgentState::Exited { exit_code: Some(0) });
+    ctx.runtime
+        .handle_event(Event::TimerStart { id: TimerId::exit_deferred(owner) })
+        .await
+        .unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.branches.get("review"), Some(&StepStatus::Completed));
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Running));
+}
+
+#[tokio::test]
+async fn failing_branch_kills_sibling_agent() {
+    let mut ctx = setup_with_runbook(&agent_branch_runbook("sleep 30", "")).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.process_background_events().await;
+    let agent_id = branch_agent_id(&ctx, &job_id, "review");
+
+    ctx.runtime.handle_event(shell_fail(&job_id, "lint")).await.unwrap();
+    tokio::task::yield_now().await;
+
+    let killed = ctx
+        .agents
+        .calls()
+        .iter()
+        .any(|c| matches!(c, AgentCall::Kill { agent_id: aid } if *aid == agent_id));
+    assert!(killed, "sibling agent branch should be killed");
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(outcome_of(&job, "review"), StepOutcome::Failed("cancelled".into()));
+}
+
+#[tokio::test]
+async fn escalated_agent_branch_resumes_on_job_resume() {
+    let mut ctx = setup_with_runbook(&agent_branch_runbook("sleep 30", "")).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+    ctx.process_background_events().await;
+    let agent_id = branch_agent_id(&ctx, &job_id, "review");
+
+    // Default on_dead escalates: the branch waits on a decision
+    ctx.runtime
+        .handle_event(agent_exited(agent_id, Some(0), JobId::from_string(&job_id).into()))
+        .await
+        .unwrap();
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert!(job.branches["review"].is_waiting());
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Running));
+
+    ctx.agents.set_agent_state(&agent_id, oj_core::AgentState::WaitingForInput);
+    ctx.runtime
+        .handle_event(Event::JobResume {
+            id: JobId::from_string(&job_id),
+            message: Some("carry on".to_string()),
+            vars: HashMap::new(),
+            kill: false,
+        })
+        .await
+        .unwrap();
+    tokio::task::yield_now().await;
+
+    let nudged = ctx.agents.calls().iter().any(
+        |c| matches!(c, AgentCall::Send { agent_id: aid, input } if *aid == agent_id && input == "carry on"),
+    );
+    assert!(nudged, "waiting branch's agent should be nudged");
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.branches.get("review"), Some(&StepStatus::Running));
+    assert_eq!(job.branches.get("lint"), Some(&StepStatus::Running));
+}
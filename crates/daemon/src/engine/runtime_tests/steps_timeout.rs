@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Step timeout tests
+
+use super::*;
+use crate::adapters::AgentCall;
+use crate::engine::test_helpers::{
+    background_sleep_command, process_running, wait_for_exit, wait_for_pid_file,
+};
+use oj_core::{StepOutcome, TimerId};
+
+/// Job "ci" with a "build" step followed by `targets` (shell steps that
+/// build's config routes to).
+fn timeout_runbook(job_config: &str, build_cfg: &str, targets: &[&str]) -> String {
+    let mut steps = vec![("build", "echo build", build_cfg)];
+    steps.extend(targets.iter().map(|&t| (t, "echo target", "")));
+    test_runbook_steps("ci", job_config, &steps)
+}
+
+fn fire_step_timeout(job_id: &str, step: &str) -> Event {
+    Event::TimerStart { id: TimerId::step_timeout(JobId::from_string(job_id), step) }
+}
+
+fn outcome_of(job: &Job, step: &str) -> StepOutcome {
+    job.step_history.iter().rfind(|r| r.name == step).unwrap().outcome.clone()
+}
+
+#[tokio::test]
+async fn step_start_arms_timeout_timer() {
+    let ctx = setup_with_runbook(&timeout_runbook("", "timeout = \"20m\"", &[])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    let timer_ids = ctx.pending_timer_ids();
+    let expected = TimerId::step_timeout(JobId::from_string(&job_id), "build");
+    assert!(timer_ids.contains(&expected.to_string()), "timers: {:?}", timer_ids);
+}
+
+#[tokio::test]
+async fn step_without_timeout_arms_no_timer() {
+    let ctx = setup_with_runbook(&timeout_runbook("", "", &[])).await;
+    let _job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    assert_no_timer_with_prefix(&ctx.pending_timer_ids(), "step-timeout:");
+}
+
+#[tokio::test]
+async fn job_timeout_is_default_for_steps() {
+    let ctx = setup_with_runbook(&timeout_runbook("timeout = \"1h\"", "", &[])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.error.as_deref(), Some("timed out after 1h"));
+}
+
+#[tokio::test]
+async fn timeout_routes_to_on_timeout() {
+    let cfg =
+        "timeout = \"20m\"\non_timeout = { step = \"alert\" }\non_fail = { step = \"cleanup\" }";
+    let ctx = setup_with_runbook(&timeout_runbook("", cfg, &["alert", "cleanup"])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "alert");
+    assert_eq!(job.step_status, StepStatus::Running);
+    assert_eq!(outcome_of(&job, "build"), StepOutcome::TimedOut("timed out after 20m".into()));
+}
+
+#[tokio::test]
+async fn timeout_without_on_timeout_uses_on_fail() {
+    let cfg = "timeout = \"20m\"\non_fail = { step = \"cleanup\" }";
+    let ctx = setup_with_runbook(&timeout_runbook("", cfg, &["cleanup"])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "cleanup");
+    assert!(job.failing);
+}
+
+#[tokio::test]
+async fn timeout_without_handlers_fails_job() {
+    let ctx = setup_with_runbook(&timeout_runbook("", "timeout = \"20m\"", &[])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(job.step_status, StepStatus::Failed);
+    assert_eq!(outcome_of(&job, "build"), StepOutcome::TimedOut("timed out after 20m".into()));
+}
+
+#[tokio::test]
+async fn late_shell_exit_after_timeout_is_ignored() {
+    let cfg = "timeout = \"20m\"\non_timeout = { step = \"alert\" }";
+    let ctx = setup_with_runbook(&timeout_runbook("", cfg, &["alert"])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "alert");
+    assert_eq!(job.step_status, StepStatus::Running);
+}
+
+#[tokio::test]
+async fn shell_step_timeout_kills_command() {
+    let dir = tempfile::tempdir().unwrap();
+    let pid_file = dir.path().join("pid");
+    let runbook = test_runbook_steps(
+        "ci",
+        "",
+        &[
+            (
+                "build",
+                &background_sleep_command(&pid_file),
+                "timeout = \"20m\"\non_timeout = { step = \"alert\" }",
+            ),
+            ("alert", "echo alert", ""),
+        ],
+    );
+    let ctx = setup_with_runbook(&runbook).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    let pid = wait_for_pid_file(&pid_file).await;
+    assert!(process_running(pid));
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    assert!(wait_for_exit(pid).await, "timed-out shell command should be killed");
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "alert");
+}
+
+#[tokio::test]
+async fn stale_timeout_for_finished_step_is_ignored() {
+    let cfg = "timeout = \"20m\"\non_done = { step = \"ship\" }";
+    let ctx = setup_with_runbook(&timeout_runbook("", cfg, &["ship"])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    let result = ctx.runtime.handle_event(fire_step_timeout(&job_id, "build")).await.unwrap();
+
+    assert!(result.is_empty());
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "ship");
+    assert_eq!(job.step_status, StepStatus::Running);
+}
+
+#[tokio::test]
+async fn terminal_job_cancels_timeout_timer() {
+    let ctx = setup_with_runbook(&timeout_runbook("", "timeout = \"20m\"", &[])).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    assert_eq!(ctx.runtime.get_job(&job_id).unwrap().step, "done");
+    assert_no_timer_with_prefix(&ctx.pending_timer_ids(), "step-timeout:");
+}
+
+#[tokio::test]
+async fn agent_step_timeout_kills_agent() {
+    let runbook = format!(
+        "{}\n[agent.worker]\nrun = \"claude --print\"\n",
+        test_runbook_steps("ci", "", &[("work", "{ agent = \"worker\" }", "timeout = \"2h\"")])
+    );
+    let mut ctx = setup_with_runbook(&runbook).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.process_background_events().await;
+    let agent_id = get_agent_id(&ctx, &job_id).unwrap();
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "work")).await.unwrap();
+    tokio::task::yield_now().await;
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "failed");
+    assert_eq!(outcome_of(&job, "work"), StepOutcome::TimedOut("timed out after 2h".into()));
+    let killed = ctx
+        .agents
+        .calls()
+        .iter()
+        .any(|c| matches!(c, AgentCall::Kill { agent_id: aid } if *aid == agent_id));
+    assert!(killed, "timed out agent should be killed");
+}
+
+#[tokio::test]
+async fn branch_timeout_routes_to_on_timeout() {
+    let runbook = test_runbook_steps(
+        "ci",
+        "",
+        &[
+            ("build", "echo build", "on_done = [{ step = \"lint\" }, { step = \"test\" }]"),
+            ("lint", "echo lint", "timeout = \"5m\"\non_timeout = { step = \"alert\" }"),
+            ("test", "echo test", ""),
+            ("alert", "echo alert", ""),
+        ],
+    );
+    let ctx = setup_with_runbook(&runbook).await;
+    let job_id = create_job_for_runbook(&ctx, "ci", &[]).await;
+    ctx.runtime.handle_event(shell_ok(&job_id, "build")).await.unwrap();
+
+    ctx.runtime.handle_event(fire_step_timeout(&job_id, "lint")).await.unwrap();
+
+    let job = ctx.runtime.get_job(&job_id).unwrap();
+    assert_eq!(job.step, "alert");
+    assert!(!job.is_parallel());
+    assert_eq!(outcome_of(&job, "lint"), StepOutcome::TimedOut("timed out after 5m".into()));
+    assert_eq!(outcome_of(&job, "test"), StepOutcome::Failed("cancelled".into()));
+}
@@ -16,6 +16,8 @@ pub(super) fn push_persisted_items(ctx: &TestContext, queue: &str, count: usize)
                 item_id: format!("item-{}", i),
                 data: vars!("title" => format!("bug {}", i)),
                 pushed_at_ms: 1000 + i as u64,
+                priority: 0,
+                not_before_ms: None,
                 project: String::new(),
             });
         }
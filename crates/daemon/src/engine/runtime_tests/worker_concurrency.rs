@@ -172,6 +172,8 @@ async fn worker_at_capacity_does_not_dispatch() {
             item_id: "item-extra".to_string(),
             data: vars!("title" => "extra bug"),
             pushed_at_ms: 2000,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
     });
@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Delayed queue item tests (`not_before_ms`)
+
+use super::*;
+use oj_core::TimerId;
+use std::time::Duration;
+
+use super::worker::{count_dispatched, queue_item_status, start_worker_and_poll};
+use crate::storage::QueueItemStatus;
+
+/// Push a persisted item held back for `delay` from now (`None` = eligible).
+fn push_delayed_item(ctx: &TestContext, item_id: &str, delay: Option<Duration>) {
+    let now_ms = ctx.clock.epoch_ms();
+    ctx.runtime.lock_state_mut(|state| {
+        state.apply_event(&Event::QueuePushed {
+            queue: "bugs".to_string(),
+            item_id: item_id.to_string(),
+            data: vars!("title" => item_id),
+            pushed_at_ms: now_ms,
+            priority: 0,
+            not_before_ms: delay.map(|d| now_ms + d.as_millis() as u64),
+            project: String::new(),
+        });
+    });
+}
+
+fn persisted_runbook() -> String {
+    test_runbook_worker("type = \"persisted\"\nvars = [\"title\"]", 2)
+}
+
+#[tokio::test]
+async fn delayed_item_is_not_dispatched_before_its_time() {
+    let runbook = persisted_runbook();
+    let ctx = setup_with_runbook(&runbook).await;
+    push_delayed_item(&ctx, "later", Some(Duration::from_secs(2 * 3600)));
+    push_delayed_item(&ctx, "now", None);
+
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 2).await;
+
+    assert_eq!(count_dispatched(&events), 1);
+    assert_eq!(queue_item_status(&ctx, "bugs", "now"), Some(QueueItemStatus::Active));
+    assert_eq!(queue_item_status(&ctx, "bugs", "later"), Some(QueueItemStatus::Pending));
+}
+
+#[tokio::test]
+async fn poll_arms_delay_timer_for_earliest_delayed_item() {
+    let runbook = persisted_runbook();
+    let ctx = setup_with_runbook(&runbook).await;
+    push_delayed_item(&ctx, "late", Some(Duration::from_secs(3 * 3600)));
+    push_delayed_item(&ctx, "soon", Some(Duration::from_secs(600)));
+
+    start_worker_and_poll(&ctx, &runbook, "fixer", 2).await;
+
+    let scheduler = ctx.runtime.executor.scheduler();
+    let deadline = scheduler.lock().next_deadline().expect("delay timer should be set");
+    assert_eq!(deadline.saturating_duration_since(ctx.clock.now()), Duration::from_secs(600));
+    assert!(ctx.pending_timer_ids().contains(&TimerId::queue_delay("bugs", "").to_string()));
+}
+
+#[tokio::test]
+async fn no_delay_timer_without_delayed_items() {
+    let runbook = persisted_runbook();
+    let ctx = setup_with_runbook(&runbook).await;
+    push_delayed_item(&ctx, "now", None);
+
+    start_worker_and_poll(&ctx, &runbook, "fixer", 2).await;
+
+    assert_no_timer_with_prefix(&ctx.pending_timer_ids(), "queue-delay:");
+}
+
+#[tokio::test]
+async fn delay_timer_dispatches_item_once_eligible() {
+    let runbook = persisted_runbook();
+    let ctx = setup_with_runbook(&runbook).await;
+    push_delayed_item(&ctx, "soon", Some(Duration::from_secs(600)));
+    push_delayed_item(&ctx, "late", Some(Duration::from_secs(3 * 3600)));
+    start_worker_and_poll(&ctx, &runbook, "fixer", 2).await;
+
+    ctx.clock.advance(Duration::from_secs(600));
+    handle_event_chain(&ctx, Event::TimerStart { id: TimerId::queue_delay("bugs", "") }).await;
+
+    assert_eq!(queue_item_status(&ctx, "bugs", "soon"), Some(QueueItemStatus::Active));
+    assert_eq!(queue_item_status(&ctx, "bugs", "late"), Some(QueueItemStatus::Pending));
+
+    // The wake's poll re-arms the timer for the remaining item
+    let scheduler = ctx.runtime.executor.scheduler();
+    let deadline = scheduler.lock().next_deadline().expect("delay timer should be re-armed");
+    assert_eq!(
+        deadline.saturating_duration_since(ctx.clock.now()),
+        Duration::from_secs(3 * 3600 - 600)
+    );
+}
+
+/// A stale poll payload listing a delayed item must not dispatch it.
+#[tokio::test]
+async fn stale_poll_skips_delayed_items() {
+    let runbook = persisted_runbook();
+    let ctx = setup_with_runbook(&runbook).await;
+    push_delayed_item(&ctx, "later", Some(Duration::from_secs(3600)));
+    start_worker_and_poll(&ctx, &runbook, "fixer", 2).await;
+
+    let events = ctx
+        .runtime
+        .handle_event(Event::WorkerPolled {
+            worker: "fixer".to_string(),
+            project: String::new(),
+            items: vec![serde_json::json!({"id": "later", "title": "later"})],
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(count_dispatched(&events), 0);
+    assert_eq!(queue_item_status(&ctx, "bugs", "later"), Some(QueueItemStatus::Pending));
+}
@@ -205,6 +205,8 @@ async fn worker_dispatch_uses_namespaced_vars_only() {
             item_id: "item-1".to_string(),
             data: vars!("title" => "Fix login bug", "labels" => "bug,p1"),
             pushed_at_ms: 1000,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
     });
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Queue `order` policy and item priority tests
+
+use super::*;
+
+use super::worker::{load_runbook_hash, start_worker_and_poll};
+
+/// Push persisted items with the given priorities, oldest first.
+fn push_prioritized_items(ctx: &TestContext, priorities: &[i32]) {
+    ctx.runtime.lock_state_mut(|state| {
+        for (i, priority) in priorities.iter().enumerate() {
+            state.apply_event(&Event::QueuePushed {
+                queue: "bugs".to_string(),
+                item_id: format!("item-{}", i + 1),
+                data: vars!("title" => format!("bug {}", i + 1)),
+                pushed_at_ms: 1000 + i as u64,
+                priority: *priority,
+                not_before_ms: None,
+                project: String::new(),
+            });
+        }
+    });
+}
+
+fn dispatched_item_ids(events: &[Event]) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|e| match e {
+            Event::WorkerDispatched { item_id, .. } => Some(item_id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Dispatch one item from a persisted queue with the given order policy.
+async fn dispatch_one(order: &str, priorities: &[i32]) -> Vec<String> {
+    let queue = format!("type = \"persisted\"\nvars = [\"title\"]\norder = \"{order}\"");
+    let runbook = test_runbook_worker(&queue, 1);
+    let ctx = setup_with_runbook(&runbook).await;
+    push_prioritized_items(&ctx, priorities);
+
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 1).await;
+    dispatched_item_ids(&events)
+}
+
+#[tokio::test]
+async fn fifo_queue_dispatches_oldest_first() {
+    assert_eq!(dispatch_one("fifo", &[0, 5, 0]).await, vec!["item-1"]);
+}
+
+#[tokio::test]
+async fn lifo_queue_dispatches_newest_first() {
+    assert_eq!(dispatch_one("lifo", &[0, 5, 0]).await, vec!["item-3"]);
+}
+
+#[tokio::test]
+async fn priority_queue_dispatches_highest_priority_first() {
+    assert_eq!(dispatch_one("priority", &[0, 5, 0]).await, vec!["item-2"]);
+    assert_eq!(dispatch_one("priority", &[-1, -2, 0]).await, vec!["item-3"]);
+}
+
+#[tokio::test]
+async fn priority_queue_breaks_ties_oldest_first() {
+    assert_eq!(dispatch_one("priority", &[1, 1, -3]).await, vec!["item-1"]);
+}
+
+/// Priority dispatch continues down the queue as slots free up.
+#[tokio::test]
+async fn persisted_priority_queue_dispatches_all_by_priority() {
+    let runbook =
+        test_runbook_worker("type = \"persisted\"\nvars = [\"title\"]\norder = \"priority\"", 3);
+    let ctx = setup_with_runbook(&runbook).await;
+    push_prioritized_items(&ctx, &[1, 10, 0]);
+
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 3).await;
+
+    assert_eq!(dispatched_item_ids(&events), vec!["item-2", "item-1", "item-3"]);
+}
+
+/// External queues take priority from each item's `priority` field.
+#[tokio::test]
+async fn external_priority_queue_takes_highest_priority_first() {
+    let runbook =
+        test_runbook_worker("list = \"echo '[]'\"\ntake = \"echo taken\"\norder = \"priority\"", 1);
+    let ctx = setup_with_runbook(&runbook).await;
+    let hash = load_runbook_hash(&ctx, &runbook);
+    ctx.runtime
+        .handle_event(worker_started("fixer", &ctx.project_path, &hash, "bugs", 1, ""))
+        .await
+        .unwrap();
+
+    ctx.runtime
+        .handle_event(Event::WorkerPolled {
+            worker: "fixer".to_string(),
+            project: String::new(),
+            items: vec![
+                serde_json::json!({"id": "chore", "title": "tidy up"}),
+                serde_json::json!({"id": "hotfix", "title": "prod down", "priority": "9"}),
+                serde_json::json!({"id": "bug", "title": "bug", "priority": 2}),
+            ],
+        })
+        .await
+        .unwrap();
+
+    let workers = ctx.runtime.worker_states.lock();
+    let inflight = &workers.get("fixer").unwrap().inflight_items;
+    assert_eq!(inflight.len(), 1);
+    assert!(inflight.contains("hotfix"), "hotfix should be taken first: {inflight:?}");
+}
@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Worker rate limit and active window tests
+
+use super::*;
+use oj_core::{ThrottleReason, TimerId, WorkerThrottle};
+use std::time::Duration;
+
+use super::worker::{
+    count_dispatched, push_persisted_items, queue_item_status, start_worker_and_poll,
+};
+use crate::storage::QueueItemStatus;
+
+/// Persisted-queue worker with extra `[worker.fixer]` fields.
+fn throttled_runbook(limits: &str) -> String {
+    let base = test_runbook_worker("type = \"persisted\"\nvars = [\"title\"]", 5);
+    format!("{base}{limits}\n")
+}
+
+fn throttle(ctx: &TestContext) -> Option<WorkerThrottle> {
+    ctx.runtime.lock_state(|state| state.worker_throttles.get("fixer").copied())
+}
+
+/// Time until the next timer, asserting that it is the worker's throttle timer.
+fn throttle_timer_in(ctx: &TestContext) -> Duration {
+    let scheduler = ctx.runtime.executor.scheduler();
+    let mut sched = scheduler.lock();
+    let deadline = sched.next_deadline().expect("throttle timer should be set");
+    let fired = sched.fired_timers(deadline);
+    let id = TimerId::worker_throttle("fixer", "");
+    assert!(fired.contains(&Event::TimerStart { id }), "unexpected timers: {fired:?}");
+    deadline.saturating_duration_since(ctx.clock.now())
+}
+
+#[tokio::test]
+async fn rate_caps_dispatches_and_arms_throttle_timer() {
+    let runbook = throttled_runbook("rate = \"2/h\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 4);
+
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    assert_eq!(count_dispatched(&events), 2);
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-3"), Some(QueueItemStatus::Pending));
+    let until_ms = ctx.clock.epoch_ms() + 3_600_000;
+    assert_eq!(throttle(&ctx), Some(WorkerThrottle { reason: ThrottleReason::Rate, until_ms }));
+    assert_eq!(throttle_timer_in(&ctx), Duration::from_secs(3600));
+}
+
+#[tokio::test]
+async fn throttle_timer_resumes_dispatch_once_rate_frees() {
+    let runbook = throttled_runbook("rate = \"2/h\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 3);
+    start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    ctx.clock.advance(Duration::from_secs(3600));
+    handle_event_chain(&ctx, Event::TimerStart { id: TimerId::worker_throttle("fixer", "") }).await;
+
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-3"), Some(QueueItemStatus::Active));
+    assert_eq!(throttle(&ctx), None);
+}
+
+#[tokio::test]
+async fn closed_window_holds_dispatch_until_it_opens() {
+    // The fake clock starts at 00:16:40 UTC
+    let runbook = throttled_runbook("window = \"08:00-20:00\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 2);
+
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    assert_eq!(count_dispatched(&events), 0);
+    let opens_in = Duration::from_millis(8 * 3_600_000 - ctx.clock.epoch_ms());
+    assert_eq!(throttle(&ctx).map(|t| t.reason), Some(ThrottleReason::Window));
+    assert_eq!(throttle_timer_in(&ctx), opens_in);
+
+    ctx.clock.advance(opens_in);
+    handle_event_chain(&ctx, Event::TimerStart { id: TimerId::worker_throttle("fixer", "") }).await;
+
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-1"), Some(QueueItemStatus::Active));
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-2"), Some(QueueItemStatus::Active));
+    assert_eq!(throttle(&ctx), None);
+}
+
+#[tokio::test]
+async fn stopping_worker_clears_throttle() {
+    let runbook = throttled_runbook("window = \"08:00-20:00\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 1);
+    start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+    assert!(throttle(&ctx).is_some());
+
+    ctx.runtime
+        .handle_event(Event::WorkerStopped { worker: "fixer".to_string(), project: String::new() })
+        .await
+        .unwrap();
+
+    assert_eq!(throttle(&ctx), None);
+    assert_no_timer_with_prefix(&ctx.pending_timer_ids(), "worker-throttle:");
+}
+
+#[tokio::test]
+async fn rate_history_survives_worker_restart() {
+    let runbook = throttled_runbook("rate = \"2/h\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 3);
+    start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    ctx.runtime
+        .handle_event(Event::WorkerStopped { worker: "fixer".to_string(), project: String::new() })
+        .await
+        .unwrap();
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    assert_eq!(count_dispatched(&events), 0);
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-3"), Some(QueueItemStatus::Pending));
+    assert_eq!(throttle(&ctx).map(|t| t.reason), Some(ThrottleReason::Rate));
+}
+
+#[tokio::test]
+async fn rate_history_survives_daemon_restart() {
+    let runbook = throttled_runbook("rate = \"2/h\"");
+    let ctx = setup_with_runbook(&runbook).await;
+    push_persisted_items(&ctx, "bugs", 3);
+    // As in the daemon, the materialized state sees WorkerStarted first
+    let (_, hash) = hash_runbook(&runbook);
+    let started = worker_started("fixer", &ctx.project_path, &hash, "bugs", 5, "");
+    ctx.runtime.lock_state_mut(|state| state.apply_event(&started));
+    start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+    let dispatched_at_ms = ctx.clock.epoch_ms();
+
+    // A daemon restart loses the in-memory worker state; only the
+    // materialized state rebuilt from the WAL is left
+    ctx.runtime.worker_states.lock().clear();
+    ctx.clock.advance(Duration::from_secs(600));
+    let events = start_worker_and_poll(&ctx, &runbook, "fixer", 5).await;
+
+    assert_eq!(count_dispatched(&events), 0);
+    assert_eq!(queue_item_status(&ctx, "bugs", "item-3"), Some(QueueItemStatus::Pending));
+    let until_ms = dispatched_at_ms + 3_600_000;
+    assert_eq!(throttle(&ctx), Some(WorkerThrottle { reason: ThrottleReason::Rate, until_ms }));
+}
@@ -33,7 +33,12 @@ pub fn failure_transition_effects(job: &Job, on_fail: &str, error: &str) -> Vec<
     let job_id = JobId::from_string(&job.id);
     vec![
         Effect::Emit {
-            event: Event::StepFailed { job_id, step: job.step.clone(), error: error.to_string() },
+            event: Event::StepFailed {
+                job_id,
+                step: job.step.clone(),
+                error: error.to_string(),
+                timed_out: false,
+            },
         },
         Effect::Emit { event: Event::JobAdvanced { id: job_id, step: on_fail.to_string() } },
     ]
@@ -46,6 +51,7 @@ pub fn failure_after_cleanup_effects(job: &Job, error: &str) -> Vec<Effect> {
     let mut effects = vec![
         Effect::CancelTimer { id: TimerId::liveness(job_id) },
         Effect::CancelTimer { id: TimerId::exit_deferred(job_id) },
+        Effect::CancelTimer { id: TimerId::step_timeout(job_id, &job.step) },
         Effect::Emit { event: Event::StepCompleted { job_id, step: job.step.clone() } },
         Effect::Emit { event: Event::JobAdvanced { id: job_id, step: "failed".to_string() } },
         Effect::Emit {
@@ -53,6 +59,7 @@ pub fn failure_after_cleanup_effects(job: &Job, error: &str) -> Vec<Effect> {
                 job_id,
                 step: "failed".to_string(),
                 error: error.to_string(),
+                timed_out: false,
             },
         },
     ];
@@ -78,7 +85,12 @@ pub fn failure_effects(job: &Job, error: &str) -> Vec<Effect> {
         "failed",
         true,
         Effect::Emit {
-            event: Event::StepFailed { job_id, step: job.step.clone(), error: error.to_string() },
+            event: Event::StepFailed {
+                job_id,
+                step: job.step.clone(),
+                error: error.to_string(),
+                timed_out: false,
+            },
         },
     )
 }
@@ -96,6 +108,7 @@ pub fn cancellation_transition_effects(job: &Job, on_cancel_step: &str) -> Vec<E
                 job_id,
                 step: job.step.clone(),
                 error: "cancelled".to_string(),
+                timed_out: false,
             },
         },
         Effect::Emit { event: Event::JobAdvanced { id: job_id, step: on_cancel_step.to_string() } },
@@ -114,6 +127,7 @@ pub fn cancellation_effects(job: &Job) -> Vec<Effect> {
                 job_id,
                 step: job.step.clone(),
                 error: "cancelled".to_string(),
+                timed_out: false,
             },
         },
     )
@@ -133,6 +147,7 @@ pub fn suspension_effects(job: &Job) -> Vec<Effect> {
                 job_id,
                 step: job.step.clone(),
                 error: "suspended".to_string(),
+                timed_out: false,
             },
         },
     )
@@ -161,6 +176,7 @@ fn terminal_job_effects(
     let mut effects = vec![
         Effect::CancelTimer { id: TimerId::liveness(job_id) },
         Effect::CancelTimer { id: TimerId::exit_deferred(job_id) },
+        Effect::CancelTimer { id: TimerId::step_timeout(job_id, &job.step) },
     ];
 
     if force_advance || !job.is_terminal() {
@@ -171,6 +171,46 @@ pub(crate) fn worker_started(
     }
 }
 
+// ---- Process helpers ----
+
+/// Shell command that starts a long-running child and records its pid in `pid_file`.
+pub(crate) fn background_sleep_command(pid_file: &Path) -> String {
+    format!("sleep 30 & jobs -p > {}; wait", pid_file.display())
+}
+
+/// Wait (up to 5s) for `background_sleep_command` to record its child's pid.
+pub(crate) async fn wait_for_pid_file(pid_file: &Path) -> u32 {
+    for _ in 0..500 {
+        if let Ok(pid) = std::fs::read_to_string(pid_file).unwrap_or_default().trim().parse() {
+            return pid;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("no pid written to {}", pid_file.display());
+}
+
+/// Whether `pid` is still running (a zombie awaiting its reaper counts as gone).
+pub(crate) fn process_running(pid: u32) -> bool {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "stat=", "-p", &pid.to_string()])
+        .output()
+        .unwrap();
+    let stat = String::from_utf8_lossy(&output.stdout);
+    let stat = stat.trim();
+    !stat.is_empty() && !stat.starts_with('Z')
+}
+
+/// Wait (up to 5s) for `pid` to exit; returns whether it did.
+pub(crate) async fn wait_for_exit(pid: u32) -> bool {
+    for _ in 0..500 {
+        if !process_running(pid) {
+            return true;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    false
+}
+
 /// Build a `HashMap<String, String>` from key-value pairs.
 macro_rules! vars {
     ($($key:expr => $val:expr),* $(,)?) => {{
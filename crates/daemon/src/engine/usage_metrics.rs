@@ -10,6 +10,7 @@
 //! The collector runs as a background tokio task and writes frequently
 //! enough that cost data survives daemon crashes.
 
+use std::collections::BTreeMap;
 use std::fs::{self, OpenOptions};
 use std::io::Write;
 use std::path::PathBuf;
@@ -22,6 +23,7 @@ use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
 
 use crate::adapters::agent::{AgentAdapter, UsageData};
+use crate::metrics::{DaemonMetrics, TokenCounts};
 use crate::storage::MaterializedState;
 use oj_core::{MetricsHealth, OwnerId};
 
@@ -69,7 +71,12 @@ pub struct UsageMetricsCollector {
     agent_meta: std::collections::HashMap<String, AgentMeta>,
     /// Cached usage per agent (from adapter API responses)
     cached_usage: std::collections::HashMap<String, UsageData>,
+    /// Project of every agent seen, kept after the agent is gone so its
+    /// tokens stay in the project's totals
+    agent_projects: std::collections::HashMap<String, String>,
     health: Arc<Mutex<MetricsHealth>>,
+    /// Per-project token totals for the metrics exporter
+    metrics: Arc<DaemonMetrics>,
 }
 
 struct AgentMeta {
@@ -89,6 +96,7 @@ impl UsageMetricsCollector {
         state: Arc<Mutex<MaterializedState>>,
         agents: Arc<dyn AgentAdapter>,
         metrics_dir: PathBuf,
+        metrics: Arc<DaemonMetrics>,
     ) -> Arc<Mutex<MetricsHealth>> {
         let health = Arc::new(Mutex::new(MetricsHealth::default()));
 
@@ -98,7 +106,9 @@ impl UsageMetricsCollector {
             metrics_dir,
             agent_meta: std::collections::HashMap::new(),
             cached_usage: std::collections::HashMap::new(),
+            agent_projects: std::collections::HashMap::new(),
             health: Arc::clone(&health),
+            metrics,
         };
 
         let interval_secs = std::env::var("OJ_METRICS_INTERVAL_SECS")
@@ -152,6 +162,7 @@ impl UsageMetricsCollector {
                     status: format!("{}", record.status),
                 },
             );
+            self.agent_projects.insert(record.agent_id.clone(), record.project.clone());
 
             // Query agent's usage via adapter
             let agent_id = oj_core::AgentId::from_string(&record.agent_id);
@@ -160,6 +171,8 @@ impl UsageMetricsCollector {
             }
         }
 
+        self.metrics.set_tokens(self.token_totals());
+
         // Build records and write
         let records = self.build_records();
 
@@ -222,6 +235,21 @@ impl UsageMetricsCollector {
             .collect()
     }
 
+    /// Sum cached usage by project. Usage is cumulative per agent and cached
+    /// agents are never dropped, so the totals only grow.
+    fn token_totals(&self) -> BTreeMap<String, TokenCounts> {
+        let mut totals = BTreeMap::<String, TokenCounts>::new();
+        for (agent_id, usage) in &self.cached_usage {
+            let project = self.agent_projects.get(agent_id).cloned().unwrap_or_default();
+            let counts = totals.entry(project).or_default();
+            counts.input += usage.input_tokens;
+            counts.output += usage.output_tokens;
+            counts.cache_write += usage.cache_write_tokens;
+            counts.cache_read += usage.cache_read_tokens;
+        }
+        totals
+    }
+
     /// Append records to the JSONL file.
     fn write_records(&self, records: &[UsageRecord]) -> Result<(), std::io::Error> {
         let path = self.metrics_dir.join("usage.jsonl");
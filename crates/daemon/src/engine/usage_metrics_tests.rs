@@ -86,7 +86,9 @@ fn write_records_produces_valid_jsonl() {
         metrics_dir: metrics_dir.clone(),
         agent_meta: HashMap::new(),
         cached_usage: HashMap::new(),
+        agent_projects: HashMap::new(),
         health: Arc::new(Mutex::new(MetricsHealth::default())),
+        metrics: Arc::new(DaemonMetrics::default()),
     };
 
     let records = vec![
@@ -188,7 +190,9 @@ fn rotation_shifts_files_and_writes_baseline() {
         metrics_dir: metrics_dir.clone(),
         agent_meta: HashMap::new(),
         cached_usage,
+        agent_projects: HashMap::new(),
         health: Arc::new(Mutex::new(MetricsHealth::default())),
+        metrics: Arc::new(DaemonMetrics::default()),
     };
 
     collector.rotate_if_needed();
@@ -223,3 +227,40 @@ fn format_utc_now_produces_valid_timestamp() {
     let year: u32 = date_parts[0].parse().unwrap();
     assert!(year >= 2025);
 }
+
+#[test]
+fn token_totals_sum_by_project_including_gone_agents() {
+    let usage = |input, output| UsageData {
+        input_tokens: input,
+        output_tokens: output,
+        cache_read_tokens: 10,
+        ..Default::default()
+    };
+    let collector = UsageMetricsCollector {
+        state: Arc::new(Mutex::new(MaterializedState::default())),
+        agents: Arc::new(StubAdapter),
+        metrics_dir: tempfile::tempdir().unwrap().path().join("metrics"),
+        agent_meta: HashMap::new(),
+        cached_usage: HashMap::from([
+            ("a1".to_string(), usage(100, 50)),
+            ("a2".to_string(), usage(20, 5)),
+            ("a3".to_string(), usage(7, 3)),
+        ]),
+        // a2 has left state but its project is remembered
+        agent_projects: HashMap::from([
+            ("a1".to_string(), "api".to_string()),
+            ("a2".to_string(), "api".to_string()),
+            ("a3".to_string(), "web".to_string()),
+        ]),
+        health: Arc::new(Mutex::new(MetricsHealth::default())),
+        metrics: Arc::new(DaemonMetrics::default()),
+    };
+
+    let totals = collector.token_totals();
+
+    assert_eq!(
+        totals["api"],
+        TokenCounts { input: 120, output: 55, cache_write: 0, cache_read: 20 }
+    );
+    assert_eq!(totals["web"], TokenCounts { input: 7, output: 3, cache_write: 0, cache_read: 10 });
+}
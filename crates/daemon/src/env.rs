@@ -44,6 +44,28 @@ pub fn auth_token() -> Option<String> {
     std::env::var("OJ_AUTH_TOKEN").ok().filter(|s| !s.is_empty())
 }
 
+/// Token a replica presents to its primary (`ojd replica`), checked against
+/// the primary's `OJ_AUTH_TOKEN`.
+pub fn primary_token() -> Option<String> {
+    std::env::var("OJ_PRIMARY_TOKEN").ok().filter(|s| !s.is_empty())
+}
+
+/// HMAC key for signed decision callbacks over TCP. When unset, the
+/// `/decisions/<id>/resolve` endpoint refuses every request.
+pub fn decision_secret() -> Option<String> {
+    std::env::var("OJ_DECISION_SECRET").ok().filter(|s| !s.is_empty())
+}
+
+/// Address for the Prometheus `/metrics` endpoint. Unset disables it; a bare
+/// port binds to localhost, `HOST:PORT` binds where given.
+pub fn metrics_addr() -> Option<String> {
+    let addr = std::env::var("OJ_METRICS_ADDR").ok().filter(|s| !s.is_empty())?;
+    if addr.parse::<u16>().is_ok() {
+        return Some(format!("127.0.0.1:{}", addr));
+    }
+    Some(addr)
+}
+
 /// Shutdown drain timeout (default 5s, configurable via `OJ_DRAIN_TIMEOUT_MS`).
 pub fn drain_timeout() -> Duration {
     std::env::var("OJ_DRAIN_TIMEOUT_MS")
@@ -53,6 +75,27 @@ pub fn drain_timeout() -> Duration {
         .unwrap_or(Duration::from_secs(5))
 }
 
+/// How long terminal jobs stay in live state before moving to the job
+/// archive (default 24h, configurable via `OJ_ARCHIVE_AFTER_MS`).
+pub fn archive_after() -> Duration {
+    std::env::var("OJ_ARCHIVE_AFTER_MS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_secs(24 * 60 * 60))
+}
+
+/// How long sealed WAL segments and older snapshots are kept for
+/// `ojd inspect` (default 24h, configurable via `OJ_WAL_RETAIN_MS`; `0`
+/// truncates the WAL at every checkpoint).
+pub fn wal_retain() -> Duration {
+    std::env::var("OJ_WAL_RETAIN_MS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_secs(24 * 60 * 60))
+}
+
 /// Timer check interval override
 pub fn timer_check_ms() -> Option<Duration> {
     std::env::var("OJ_TIMER_CHECK_MS")
@@ -60,3 +103,14 @@ pub fn timer_check_ms() -> Option<Duration> {
         .and_then(|s| s.parse::<u64>().ok())
         .map(Duration::from_millis)
 }
+
+/// Daemon-wide notification webhook URL, used for projects that do not set
+/// `[notify.webhook]` in `.oj/config.toml`.
+pub fn notify_webhook() -> Option<String> {
+    std::env::var("OJ_NOTIFY_WEBHOOK").ok().filter(|s| !s.is_empty())
+}
+
+/// Payload format for `OJ_NOTIFY_WEBHOOK`: "json" (default) or "slack".
+pub fn notify_webhook_format() -> Option<String> {
+    std::env::var("OJ_NOTIFY_WEBHOOK_FORMAT").ok().filter(|s| !s.is_empty())
+}
@@ -6,13 +6,19 @@
 //! The EventBus writes events to WAL before notifying the engine,
 //! enabling crash recovery via snapshot + replay. Events are buffered in
 //! memory and periodically flushed to disk (~10ms durability window).
+//!
+//! Once the engine has applied an event it is also published to live
+//! subscribers (`Request::Subscribe`), who never see it before state does.
 
 use crate::storage::{Wal, WalEntry, WalError};
 use oj_core::Event;
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
+
+/// Events buffered per subscriber before a slow one starts missing events
+const SUBSCRIBER_BUFFER: usize = 1024;
 
 /// Event bus backed by WAL.
 ///
@@ -22,6 +28,7 @@ use tokio::sync::mpsc;
 pub(crate) struct EventBus {
     pub(crate) wal: Arc<Mutex<Wal>>,
     wake_tx: mpsc::Sender<()>,
+    live_tx: broadcast::Sender<Event>,
 }
 
 /// Reader for the event bus.
@@ -39,8 +46,9 @@ impl EventBus {
     pub fn new(wal: Wal) -> (Self, EventReader) {
         let wal = Arc::new(Mutex::new(wal));
         let (wake_tx, wake_rx) = mpsc::channel(1);
+        let (live_tx, _) = broadcast::channel(SUBSCRIBER_BUFFER);
 
-        (Self { wal: Arc::clone(&wal), wake_tx }, EventReader { wal, wake_rx })
+        (Self { wal: Arc::clone(&wal), wake_tx, live_tx }, EventReader { wal, wake_rx })
     }
 
     /// Append event to WAL (buffered, not yet durable).
@@ -55,6 +63,18 @@ impl EventBus {
         let _ = self.wake_tx.try_send(());
         Ok(seq)
     }
+
+    /// Receive every event published from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<Event> {
+        self.live_tx.subscribe()
+    }
+
+    /// Hand a processed event to live subscribers, if there are any.
+    pub fn publish(&self, event: &Event) {
+        if self.live_tx.receiver_count() > 0 {
+            let _ = self.live_tx.send(event.clone());
+        }
+    }
 }
 
 impl EventReader {
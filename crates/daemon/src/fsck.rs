@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `ojd fsck` — check the WAL and snapshot, e.g. after a full disk.
+//!
+//! Reads every WAL entry, checking that frames are intact, sequence numbers
+//! climb one at a time, and each event still parses, and reports the first
+//! bad entry by file and byte offset. The snapshot's sequence number is
+//! checked against the WAL: replay starts right after it, so a gap there
+//! loses events just like one inside the WAL.
+//!
+//! Checking only reads files. `--repair` cuts the WAL back to the last good
+//! entry, keeping the damaged file as `.bak` and setting later segments
+//! aside the same way. That needs the daemon stopped; its lock is held
+//! while repairing.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::lifecycle::{Config, LifecycleError, StateLock};
+use crate::storage::{load_snapshot, SnapshotError, Wal, WalCheck, WalError};
+
+const USAGE: &str = "Usage: ojd fsck [--state-dir <DIR>] [--repair]";
+
+#[derive(Debug, Error)]
+pub(crate) enum FsckError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+    #[error("failed to check WAL: {0}")]
+    Wal(#[from] WalError),
+    #[error("{0}")]
+    Lock(#[from] LifecycleError),
+}
+
+/// What a check found.
+#[derive(Debug)]
+pub(crate) struct Report {
+    /// Sequence number of the snapshot, `None` without one
+    pub snapshot: Result<Option<u64>, SnapshotError>,
+    pub wal: WalCheck,
+}
+
+impl Report {
+    /// Problems a repair can fix or not, as opposed to notes.
+    fn problems(&self) -> usize {
+        usize::from(self.snapshot.is_err()) + usize::from(self.wal.damage.is_some())
+    }
+}
+
+/// Check the state under `config`, without changing it.
+pub(crate) fn check(config: &Config) -> Result<Report, FsckError> {
+    let snapshot = load_snapshot(&config.snapshot_path).map(|s| s.map(|s| s.seq));
+    let after_seq = snapshot.as_ref().ok().copied().flatten().unwrap_or(0);
+    let wal = if config.wal_path.exists() {
+        Wal::check(&config.wal_path, after_seq)?
+    } else {
+        WalCheck::default()
+    };
+    Ok(Report { snapshot, wal })
+}
+
+/// Describe a report, with paths relative to the state directory.
+pub(crate) fn render(report: &Report, state_dir: &Path) -> String {
+    let name = |path: &Path| path.strip_prefix(state_dir).unwrap_or(path).display().to_string();
+    let mut out = String::new();
+    let _ = match &report.snapshot {
+        Ok(Some(seq)) => writeln!(out, "snapshot: seq {seq}"),
+        Ok(None) => writeln!(out, "snapshot: none"),
+        Err(e) => writeln!(out, "snapshot: unreadable: {e}"),
+    };
+
+    let wal = &report.wal;
+    let _ = write!(out, "wal: {} files, {} good entries", wal.files.len(), wal.entries);
+    if let (Some(first), Some(last)) = (wal.first_seq, wal.last_seq) {
+        let _ = write!(out, ", seq {first}-{last}");
+    }
+    let _ = writeln!(out);
+    if wal.unknown > 0 {
+        let _ = writeln!(out, "  {} entries of unknown types, ignored on replay", wal.unknown);
+    }
+    if let Some(damage) = &wal.damage {
+        let _ =
+            writeln!(out, "{}: offset {}: {}", name(&damage.path), damage.offset, damage.reason);
+        let kept = match wal.last_seq {
+            Some(seq) => format!("keeps entries up to seq {seq}"),
+            None => "keeps no entries".to_string(),
+        };
+        let _ = write!(out, "  repair {kept}, drops {} bytes", damage.dropped_bytes);
+        if !damage.later.is_empty() {
+            let _ = write!(out, " and sets aside {} later file(s)", damage.later.len());
+        }
+        let _ = writeln!(out);
+    }
+    if let (Ok(Some(snapshot_seq)), Some(last)) = (&report.snapshot, wal.last_seq) {
+        if last < *snapshot_seq && wal.damage.is_none() {
+            let _ = writeln!(
+                out,
+                "note: WAL ends at seq {last}, before the snapshot at seq {snapshot_seq}; \
+                 the snapshot holds the later state"
+            );
+        }
+    }
+
+    let _ = match report.problems() {
+        0 => writeln!(out, "ok"),
+        n => writeln!(out, "{n} problem(s) found"),
+    };
+    out
+}
+
+/// Entry point for `ojd fsck`. Returns the process exit code: 0 when the
+/// state is (or was repaired to be) consistent.
+pub(crate) fn main(args: &[String]) -> i32 {
+    match run(args) {
+        Ok(true) => 0,
+        Ok(false) => 1,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<bool, Box<dyn std::error::Error>> {
+    let parsed = parse_args(args)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    let _lock = if parsed.repair { Some(StateLock::acquire(&config)?) } else { None };
+
+    let report = check(&config)?;
+    print!("{}", render(&report, &config.state_dir));
+    let Some(damage) = report.wal.damage.as_ref().filter(|_| parsed.repair) else {
+        if report.wal.damage.is_some() {
+            println!("run with --repair to truncate the WAL to the last good entry");
+        }
+        return Ok(report.problems() == 0);
+    };
+
+    Wal::truncate(&config.wal_path, damage)?;
+    println!("repaired WAL; damaged files were kept as .bak\n");
+    let report = check(&config)?;
+    print!("{}", render(&report, &config.state_dir));
+    Ok(report.problems() == 0)
+}
+
+/// Parsed `ojd fsck` arguments.
+#[derive(Debug, PartialEq)]
+struct FsckArgs {
+    state_dir: Option<PathBuf>,
+    repair: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<FsckArgs, FsckError> {
+    let mut parsed = FsckArgs { state_dir: None, repair: false };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--repair" => parsed.repair = true,
+            "--state-dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| FsckError::Usage("--state-dir needs a value".to_string()))?;
+                parsed.state_dir = Some(PathBuf::from(value));
+            }
+            other => {
+                return Err(FsckError::Usage(format!("unexpected argument '{}'", other)));
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+#[path = "fsck_tests.rs"]
+mod tests;
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_core::{Event, TimerId};
+use tempfile::tempdir;
+
+use crate::storage::{Checkpointer, MaterializedState};
+
+use super::*;
+
+fn args(input: &[&str]) -> Vec<String> {
+    input.iter().map(|s| s.to_string()).collect()
+}
+
+fn timer(n: u64) -> Event {
+    Event::TimerStart { id: TimerId::from_string(format!("test:{n}")) }
+}
+
+/// A state directory with a snapshot at `snapshot_seq` and WAL entries
+/// 1..=`last_seq`, one segment per entry followed by an empty one.
+fn state_dir(dir: &Path, snapshot_seq: u64, last_seq: u64) -> Config {
+    let config = Config::at(dir.to_path_buf());
+    let mut wal = Wal::open(&config.wal_path, 0).unwrap();
+    wal.set_segment_bytes(1);
+    for n in 1..=last_seq {
+        wal.append(&timer(n)).unwrap();
+        wal.flush().unwrap();
+    }
+    if snapshot_seq > 0 {
+        Checkpointer::new(config.snapshot_path.clone())
+            .checkpoint_sync(snapshot_seq, &MaterializedState::default())
+            .unwrap();
+    }
+    config
+}
+
+fn segments(config: &Config) -> Vec<PathBuf> {
+    Wal::check(&config.wal_path, 0).unwrap().files
+}
+
+fn state_dir_arg(config: &Config) -> &str {
+    config.state_dir.to_str().unwrap()
+}
+
+#[test]
+fn consistent_state_is_ok() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 2, 3);
+
+    let report = check(&config).unwrap();
+
+    assert_eq!(
+        render(&report, &config.state_dir),
+        "snapshot: seq 2\nwal: 4 files, 3 good entries, seq 1-3\nok\n"
+    );
+    assert_eq!(main(&args(&["--state-dir", state_dir_arg(&config)])), 0);
+}
+
+#[test]
+fn reports_damage_without_changing_files() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 0, 3);
+    let segment = segments(&config).remove(2);
+    std::fs::write(&segment, b"OJWAL\0\0\x02torn").unwrap();
+
+    let out = render(&check(&config).unwrap(), &config.state_dir);
+
+    assert!(out.contains("wal: 4 files, 2 good entries, seq 1-2\n"), "{out}");
+    assert!(out.contains("wal/00000000000000000003.seg: offset 8: torn frame header\n"), "{out}");
+    assert!(
+        out.contains(
+            "  repair keeps entries up to seq 2, drops 4 bytes and sets aside 1 later file(s)\n"
+        ),
+        "{out}"
+    );
+    assert!(out.ends_with("1 problem(s) found\n"), "{out}");
+    assert_eq!(main(&args(&["--state-dir", state_dir_arg(&config)])), 1);
+    assert_eq!(std::fs::read(&segment).unwrap(), b"OJWAL\0\0\x02torn");
+}
+
+#[test]
+fn repair_truncates_to_last_good_entry() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 0, 3);
+    let segments = segments(&config);
+    std::fs::write(&segments[1], b"OJWAL\0\0\x02torn").unwrap();
+
+    assert_eq!(main(&args(&["--state-dir", state_dir_arg(&config), "--repair"])), 0);
+
+    let report = check(&config).unwrap();
+    assert!(report.wal.damage.is_none());
+    assert_eq!(report.wal.last_seq, Some(1));
+    assert!(segments[1].with_extension("bak").exists());
+    assert!(segments[2].with_extension("bak").exists());
+    assert!(!config.lock_path.exists());
+}
+
+#[test]
+fn gap_after_snapshot_is_a_problem() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 2, 5);
+    for segment in &segments(&config)[..3] {
+        std::fs::remove_file(segment).unwrap();
+    }
+
+    let out = render(&check(&config).unwrap(), &config.state_dir);
+
+    assert!(out.contains("offset 8: seq 3 is missing\n"), "{out}");
+    assert!(out.contains("  repair keeps no entries"), "{out}");
+}
+
+#[test]
+fn wal_ending_before_snapshot_is_a_note() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 5, 3);
+
+    let out = render(&check(&config).unwrap(), &config.state_dir);
+
+    assert!(out.contains("note: WAL ends at seq 3, before the snapshot at seq 5"), "{out}");
+    assert!(out.ends_with("ok\n"), "{out}");
+}
+
+#[test]
+fn unreadable_snapshot_is_a_problem_repair_cannot_fix() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 0, 2);
+    std::fs::write(&config.snapshot_path, b"not a snapshot").unwrap();
+
+    let out = render(&check(&config).unwrap(), &config.state_dir);
+
+    assert!(out.starts_with("snapshot: unreadable: "), "{out}");
+    assert!(out.ends_with("1 problem(s) found\n"), "{out}");
+    assert_eq!(main(&args(&["--state-dir", state_dir_arg(&config), "--repair"])), 1);
+}
+
+#[test]
+fn empty_state_dir_is_ok() {
+    let dir = tempdir().unwrap();
+    let config = Config::at(dir.path().to_path_buf());
+    assert_eq!(
+        render(&check(&config).unwrap(), dir.path()),
+        "snapshot: none\nwal: 0 files, 0 good entries\nok\n"
+    );
+}
+
+#[test]
+fn repair_refuses_while_daemon_holds_lock() {
+    let dir = tempdir().unwrap();
+    let config = state_dir(dir.path(), 0, 2);
+    let segment = segments(&config).remove(1);
+    std::fs::write(&segment, b"OJWAL\0\0\x02torn").unwrap();
+    let daemon_lock = std::fs::File::create(&config.lock_path).unwrap();
+    fs2::FileExt::lock_exclusive(&daemon_lock).unwrap();
+
+    assert_eq!(main(&args(&["--state-dir", state_dir_arg(&config), "--repair"])), 1);
+
+    assert_eq!(std::fs::read(&segment).unwrap(), b"OJWAL\0\0\x02torn");
+}
+
+#[yare::parameterized(
+    none = { &[], None, false },
+    repair = { &["--repair"], None, true },
+    state_dir = { &["--state-dir", "/backup/oj", "--repair"], Some("/backup/oj"), true },
+)]
+fn parses_flags(input: &[&str], state_dir: Option<&str>, repair: bool) {
+    assert_eq!(
+        parse_args(&args(input)).unwrap(),
+        FsckArgs { state_dir: state_dir.map(PathBuf::from), repair }
+    );
+}
+
+#[yare::parameterized(
+    state_dir_missing = { &["--state-dir"] },
+    unknown_flag = { &["--force"] },
+    positional = { &["now"] },
+)]
+fn rejects_bad_flags(input: &[&str]) {
+    assert!(matches!(parse_args(&args(input)), Err(FsckError::Usage(_))));
+}
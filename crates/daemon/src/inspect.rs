@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `ojd inspect` — point-in-time state inspection for post-mortems.
+//!
+//! Loads the snapshot, replays WAL entries up to a sequence number or wall
+//! clock time into a fresh `MaterializedState`, and answers a normal `Query`
+//! against it. Files are only read, so this is safe while the daemon runs.
+//!
+//! Replay starts from the newest snapshot at or before the cutoff: the
+//! current one, one kept in the snapshot history, or an empty state when
+//! the WAL still starts at seq 1. The daemon keeps history for
+//! `OJ_WAL_RETAIN_MS`; `--state-dir` points at a copy of a state directory
+//! (e.g. from a backup) to look further back.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use parking_lot::Mutex;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::lifecycle::Config;
+use crate::listener::{answer_query, QueryCtx};
+use crate::protocol::Query;
+use crate::storage::{
+    history_snapshots, load_snapshot, JobArchive, MaterializedState, Snapshot, SnapshotError, Wal,
+    WalError,
+};
+
+const USAGE: &str =
+    "Usage: ojd inspect [--state-dir <DIR>] [--seq <N> | --at <TIME>] [QUERY [KEY=VALUE ...]]";
+
+#[derive(Debug, Error)]
+pub(crate) enum InspectError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+    #[error("failed to load snapshot: {0}")]
+    Snapshot(#[from] SnapshotError),
+    #[error("failed to read WAL: {0}")]
+    Wal(#[from] WalError),
+    #[error("{requested} is before the oldest retained history at seq {seq}; raise OJ_WAL_RETAIN_MS to keep more")]
+    BeforeHistory { requested: String, seq: u64 },
+    #[error("WAL resumes at seq {found}, expected {expected}; the daemon checkpointed while reading, try again")]
+    Gap { expected: u64, found: u64 },
+}
+
+/// How far to replay.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Cutoff {
+    /// Everything in the WAL
+    Latest,
+    /// Up to and including this sequence number
+    Seq(u64),
+    /// Entries appended at or before this time (epoch ms)
+    Time(u64),
+}
+
+/// State as it was at the cutoff.
+pub(crate) struct Replayed {
+    pub state: MaterializedState,
+    /// Last sequence number applied
+    pub seq: u64,
+    /// When that entry was appended (epoch ms), if known
+    pub at_ms: Option<u64>,
+}
+
+/// Rebuild state from the newest usable snapshot plus WAL entries up to
+/// `cutoff`.
+pub(crate) fn replay(
+    snapshot_path: &Path,
+    wal_path: &Path,
+    cutoff: Cutoff,
+) -> Result<Replayed, InspectError> {
+    let mut replayed = match base_snapshot(snapshot_path, cutoff)? {
+        Some(snapshot) => Replayed {
+            at_ms: Some(snapshot.created_at.timestamp_millis() as u64),
+            state: snapshot.state,
+            seq: snapshot.seq,
+        },
+        None => Replayed { state: MaterializedState::default(), seq: 0, at_ms: None },
+    };
+
+    let entries = if wal_path.exists() { Wal::read_after(wal_path, replayed.seq)? } else { vec![] };
+    if let Some(first) = entries.first().filter(|e| e.seq > replayed.seq + 1) {
+        let gap = InspectError::Gap { expected: replayed.seq + 1, found: first.seq };
+        // Without a snapshot at or before the cutoff, the WAL must reach
+        // back to seq 1
+        let requested = match cutoff {
+            Cutoff::Seq(seq) if replayed.seq == 0 => format!("seq {}", seq),
+            Cutoff::Time(ms) if replayed.seq == 0 => format_ms(ms),
+            _ => return Err(gap),
+        };
+        return Err(match oldest_snapshot_seq(snapshot_path)? {
+            Some(seq) => InspectError::BeforeHistory { requested, seq },
+            None => gap,
+        });
+    }
+    for entry in entries {
+        let past_cutoff = match cutoff {
+            Cutoff::Latest => false,
+            Cutoff::Seq(seq) => entry.seq > seq,
+            // Entries from before timestamps were recorded have ts 0 and
+            // always precede timestamped ones
+            Cutoff::Time(ms) => entry.ts_ms > ms,
+        };
+        if past_cutoff {
+            break;
+        }
+        replayed.state.apply_event(&entry.event);
+        replayed.seq = entry.seq;
+        if entry.ts_ms > 0 {
+            replayed.at_ms = Some(entry.ts_ms);
+        }
+    }
+    Ok(replayed)
+}
+
+/// The newest snapshot at or before `cutoff`, checking the current
+/// snapshot before the ones kept in history.
+fn base_snapshot(snapshot_path: &Path, cutoff: Cutoff) -> Result<Option<Snapshot>, SnapshotError> {
+    let history = history_snapshots(snapshot_path)?;
+    let candidates =
+        std::iter::once(snapshot_path).chain(history.iter().rev().map(|(_, path)| path.as_path()));
+    for path in candidates {
+        let Some(snapshot) = load_snapshot(path)? else {
+            continue;
+        };
+        let covered = match cutoff {
+            Cutoff::Latest => true,
+            Cutoff::Seq(seq) => snapshot.seq <= seq,
+            Cutoff::Time(ms) => snapshot.created_at.timestamp_millis() as u64 <= ms,
+        };
+        if covered {
+            return Ok(Some(snapshot));
+        }
+    }
+    Ok(None)
+}
+
+/// Seq of the oldest snapshot replay can start from.
+fn oldest_snapshot_seq(snapshot_path: &Path) -> Result<Option<u64>, SnapshotError> {
+    match history_snapshots(snapshot_path)?.first() {
+        Some((seq, _)) => Ok(Some(*seq)),
+        None => Ok(load_snapshot(snapshot_path)?.map(|snapshot| snapshot.seq)),
+    }
+}
+
+/// Entry point for `ojd inspect`. Returns the process exit code.
+pub(crate) fn main(args: &[String]) -> i32 {
+    match run(args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_args(args, Local::now().date_naive())?;
+    let query = parse_query(&parsed.query)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    let cutoff = parsed.cutoff;
+
+    let replayed = replay(&config.snapshot_path, &config.wal_path, cutoff)?;
+    let at = replayed.at_ms.map(format_ms).unwrap_or_else(|| "unknown time".to_string());
+    eprintln!("state at seq {} ({})", replayed.seq, at);
+
+    let state = Arc::new(Mutex::new(replayed.state));
+    let ctx = QueryCtx {
+        state: &state,
+        orphans: &Arc::new(Mutex::new(Vec::new())),
+        metrics_health: &Arc::new(Mutex::new(Default::default())),
+        logs_path: &config.logs_path,
+        archive: &JobArchive::new(&config.archive_path),
+        start_time: Instant::now(),
+    };
+    let response = answer_query(&ctx, query);
+    println!("{}", serde_json::to_string_pretty(&response)?);
+    Ok(())
+}
+
+/// Parsed `ojd inspect` arguments.
+#[derive(Debug, PartialEq)]
+struct InspectArgs {
+    state_dir: Option<PathBuf>,
+    cutoff: Cutoff,
+    query: Vec<String>,
+}
+
+/// Split the flags from the query words.
+fn parse_args(args: &[String], today: NaiveDate) -> Result<InspectArgs, InspectError> {
+    let mut parsed = InspectArgs { state_dir: None, cutoff: Cutoff::Latest, query: Vec::new() };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seq" | "--at" if parsed.cutoff != Cutoff::Latest => {
+                return Err(InspectError::Usage("use only one of --seq and --at".to_string()));
+            }
+            "--seq" => {
+                let value = args.next().ok_or_else(|| missing_value("--seq"))?;
+                let seq = value.parse().map_err(|_| {
+                    InspectError::Usage(format!("invalid sequence number '{}'", value))
+                })?;
+                parsed.cutoff = Cutoff::Seq(seq);
+            }
+            "--at" => {
+                let value = args.next().ok_or_else(|| missing_value("--at"))?;
+                parsed.cutoff = Cutoff::Time(parse_time(value, today)?);
+            }
+            "--state-dir" => {
+                let value = args.next().ok_or_else(|| missing_value("--state-dir"))?;
+                parsed.state_dir = Some(PathBuf::from(value));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(InspectError::Usage(format!("unexpected argument '{}'", flag)));
+            }
+            _ => parsed.query.push(arg.clone()),
+        }
+    }
+    Ok(parsed)
+}
+
+fn missing_value(flag: &str) -> InspectError {
+    InspectError::Usage(format!("{} needs a value", flag))
+}
+
+/// Parse `--at`: RFC 3339, or local `YYYY-MM-DD HH:MM[:SS]`, or a local
+/// `HH:MM[:SS]` on `today`.
+fn parse_time(input: &str, today: NaiveDate) -> Result<u64, InspectError> {
+    let invalid = || {
+        InspectError::Usage(format!(
+            "invalid time '{}' (expected RFC 3339, 'YYYY-MM-DD HH:MM[:SS]' or 'HH:MM[:SS]')",
+            input
+        ))
+    };
+    if let Ok(t) = DateTime::parse_from_rfc3339(input) {
+        return Ok(t.timestamp_millis() as u64);
+    }
+    let naive = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(input, f).ok())
+        .or_else(|| {
+            ["%H:%M:%S", "%H:%M"]
+                .iter()
+                .find_map(|f| NaiveTime::parse_from_str(input, f).ok())
+                .map(|time| today.and_time(time))
+        })
+        .ok_or_else(invalid)?;
+    let local = Local.from_local_datetime(&naive).earliest().ok_or_else(invalid)?;
+    Ok(local.timestamp_millis() as u64)
+}
+
+/// Build a `Query` from either its JSON form or `Variant key=value ...`.
+/// Without arguments, asks for the status overview.
+fn parse_query(args: &[String]) -> Result<Query, InspectError> {
+    let Some((variant, fields)) = args.split_first() else {
+        return Ok(Query::StatusOverview);
+    };
+    if variant.starts_with('{') {
+        return serde_json::from_str(&args.join(" "))
+            .map_err(|e| InspectError::Usage(format!("invalid query: {}", e)));
+    }
+
+    let mut pairs = Vec::new();
+    for field in fields {
+        let (key, value) = field
+            .split_once('=')
+            .ok_or_else(|| InspectError::Usage(format!("expected KEY=VALUE, got '{}'", field)))?;
+        pairs.push((key, value));
+    }
+    // Values are strings unless the field needs another type, so try plain
+    // strings first and fall back to reading values as JSON (`lines=50`).
+    let build = |as_json: bool| {
+        let mut object = Map::new();
+        object.insert("type".to_string(), Value::String(variant.clone()));
+        for (key, value) in &pairs {
+            let value = if as_json {
+                serde_json::from_str(value).unwrap_or_else(|_| Value::from(*value))
+            } else {
+                Value::from(*value)
+            };
+            object.insert(key.to_string(), value);
+        }
+        serde_json::from_value::<Query>(Value::Object(object))
+    };
+    build(false)
+        .or_else(|e| build(true).map_err(|_| e))
+        .map_err(|e| InspectError::Usage(format!("invalid query: {}", e)))
+}
+
+fn format_ms(ms: u64) -> String {
+    Local
+        .timestamp_millis_opt(ms as i64)
+        .single()
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S%.3f %Z").to_string())
+        .unwrap_or_else(|| ms.to_string())
+}
+
+#[cfg(test)]
+#[path = "inspect_tests.rs"]
+mod tests;
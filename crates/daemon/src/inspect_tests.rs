@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use oj_core::{Event, JobId};
+use tempfile::tempdir;
+
+use crate::storage::{keep_in_history, Checkpointer};
+
+use super::*;
+
+/// 2026-01-15 12:00:00 UTC
+const NOON_UTC: u64 = 1_768_478_400_000;
+const MINUTE_MS: u64 = 60_000;
+
+fn created(id: &str) -> Event {
+    Event::JobCreated {
+        id: JobId::from_string(id),
+        kind: "build".to_string(),
+        name: id.to_string(),
+        runbook_hash: "abc".to_string(),
+        cwd: PathBuf::from("/tmp"),
+        vars: HashMap::new(),
+        initial_step: "plan".to_string(),
+        created_at_ms: 1_000,
+        project: "api".to_string(),
+        cron: None,
+    }
+}
+
+fn advanced(id: &str, step: &str) -> Event {
+    Event::JobAdvanced { id: JobId::from_string(id), step: step.to_string() }
+}
+
+/// WAL entries for job-1 moving plan → build → review, one minute apart
+/// starting at noon.
+fn write_history(path: &Path) {
+    let mut wal = Wal::open(path, 0).unwrap();
+    let events = [created("job-1"), advanced("job-1", "build"), advanced("job-1", "review")];
+    for (i, event) in events.iter().enumerate() {
+        wal.append_at(event, NOON_UTC + i as u64 * MINUTE_MS).unwrap();
+    }
+    wal.flush().unwrap();
+}
+
+fn step_at(dir: &Path, cutoff: Cutoff) -> (String, u64) {
+    let replayed = replay(&dir.join("snapshot.json"), &dir.join("wal"), cutoff).unwrap();
+    (replayed.state.jobs["job-1"].step.clone(), replayed.seq)
+}
+
+#[yare::parameterized(
+    latest = { Cutoff::Latest, "review", 3 },
+    first_seq = { Cutoff::Seq(1), "plan", 1 },
+    middle_seq = { Cutoff::Seq(2), "build", 2 },
+    past_end = { Cutoff::Seq(99), "review", 3 },
+    at_second_entry = { Cutoff::Time(NOON_UTC + MINUTE_MS), "build", 2 },
+    between_entries = { Cutoff::Time(NOON_UTC + MINUTE_MS + 30_000), "build", 2 },
+)]
+fn replays_up_to_cutoff(cutoff: Cutoff, step: &str, seq: u64) {
+    let dir = tempdir().unwrap();
+    write_history(&dir.path().join("wal"));
+    assert_eq!(step_at(dir.path(), cutoff), (step.to_string(), seq));
+}
+
+#[test]
+fn time_before_first_entry_is_empty() {
+    let dir = tempdir().unwrap();
+    write_history(&dir.path().join("wal"));
+
+    let replayed = replay(
+        &dir.path().join("snapshot.json"),
+        &dir.path().join("wal"),
+        Cutoff::Time(NOON_UTC - 1),
+    )
+    .unwrap();
+
+    assert!(replayed.state.jobs.is_empty());
+    assert_eq!((replayed.seq, replayed.at_ms), (0, None));
+}
+
+#[test]
+fn replays_from_snapshot() {
+    let dir = tempdir().unwrap();
+    write_history(&dir.path().join("wal"));
+    let mut state = MaterializedState::default();
+    state.apply_event(&created("job-1"));
+    state.apply_event(&advanced("job-1", "build"));
+    Checkpointer::new(dir.path().join("snapshot.json")).checkpoint_sync(2, &state).unwrap();
+
+    assert_eq!(step_at(dir.path(), Cutoff::Seq(2)), ("build".to_string(), 2));
+    assert_eq!(step_at(dir.path(), Cutoff::Latest), ("review".to_string(), 3));
+    // The WAL still starts at seq 1, so earlier cutoffs replay from scratch
+    assert_eq!(step_at(dir.path(), Cutoff::Seq(1)), ("plan".to_string(), 1));
+}
+
+#[test]
+fn replays_from_history_snapshot() {
+    let dir = tempdir().unwrap();
+    let snapshot_path = dir.path().join("snapshot.json");
+    let checkpointer = Checkpointer::new(snapshot_path.clone());
+    let mut state = MaterializedState::default();
+    state.apply_event(&created("job-1"));
+    checkpointer.checkpoint_sync(1, &state).unwrap();
+    keep_in_history(&snapshot_path, 1).unwrap();
+    state.apply_event(&advanced("job-1", "build"));
+    checkpointer.checkpoint_sync(2, &state).unwrap();
+    // Entries up to the history snapshot have been truncated
+    let mut wal = Wal::open(&dir.path().join("wal"), 1).unwrap();
+    wal.append(&advanced("job-1", "build")).unwrap();
+    wal.append(&advanced("job-1", "review")).unwrap();
+    wal.flush().unwrap();
+
+    assert_eq!(step_at(dir.path(), Cutoff::Seq(1)), ("plan".to_string(), 1));
+    assert_eq!(step_at(dir.path(), Cutoff::Seq(2)), ("build".to_string(), 2));
+    assert_eq!(step_at(dir.path(), Cutoff::Latest), ("review".to_string(), 3));
+
+    let err = replay(&snapshot_path, &dir.path().join("wal"), Cutoff::Seq(0)).err().unwrap();
+    assert!(matches!(err, InspectError::BeforeHistory { seq: 1, .. }), "{}", err);
+}
+
+#[test]
+fn missing_entries_after_snapshot_are_reported() {
+    let dir = tempdir().unwrap();
+    let mut state = MaterializedState::default();
+    state.apply_event(&created("job-1"));
+    Checkpointer::new(dir.path().join("snapshot.json")).checkpoint_sync(1, &state).unwrap();
+    let mut wal = Wal::open(&dir.path().join("wal"), 2).unwrap();
+    wal.append(&advanced("job-1", "review")).unwrap();
+    wal.flush().unwrap();
+
+    let err =
+        replay(&dir.path().join("snapshot.json"), &dir.path().join("wal"), Cutoff::Latest).err();
+
+    assert!(matches!(err, Some(InspectError::Gap { expected: 2, found: 3 })), "{:?}", err);
+}
+
+#[test]
+fn missing_files_give_empty_state() {
+    let dir = tempdir().unwrap();
+    let replayed =
+        replay(&dir.path().join("snapshot.json"), &dir.path().join("wal"), Cutoff::Latest).unwrap();
+    assert!(replayed.state.jobs.is_empty());
+    assert_eq!(replayed.seq, 0);
+}
+
+fn args(input: &[&str]) -> Vec<String> {
+    input.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn query_defaults_to_status_overview() {
+    assert_eq!(parse_query(&[]).unwrap(), Query::StatusOverview);
+}
+
+#[yare::parameterized(
+    shorthand = { &["GetJob", "id=job-1"], "job-1" },
+    json = { &[r#"{"type":"GetJob","id":"job-1"}"#], "job-1" },
+    numeric_looking_id = { &["GetJob", "id=1"], "1" },
+)]
+fn parses_get_job(input: &[&str], id: &str) {
+    assert_eq!(parse_query(&args(input)).unwrap(), Query::GetJob { id: id.to_string() });
+}
+
+#[test]
+fn shorthand_reads_numbers_for_numeric_fields() {
+    let query = parse_query(&args(&["GetQueueLogs", "queue=bugs", "lines=50", "offset=0"]));
+    assert_eq!(
+        query.unwrap(),
+        Query::GetQueueLogs {
+            queue: "bugs".to_string(),
+            project: String::new(),
+            lines: 50,
+            offset: 0
+        }
+    );
+}
+
+#[yare::parameterized(
+    unknown_variant = { &["GetEverything"] },
+    missing_field = { &["GetJob"] },
+    not_key_value = { &["GetJob", "job-1"] },
+    bad_json = { &["{not json"] },
+)]
+fn rejects_bad_queries(input: &[&str]) {
+    assert!(matches!(parse_query(&args(input)), Err(InspectError::Usage(_))));
+}
+
+#[test]
+fn parses_flags() {
+    let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+
+    let parsed = parse_args(&args(&["--seq", "42", "ListJobs"]), today).unwrap();
+    assert_eq!((parsed.cutoff, parsed.query), (Cutoff::Seq(42), args(&["ListJobs"])));
+
+    let parsed = parse_args(&args(&["--at", "2026-01-15T12:00:00Z"]), today).unwrap();
+    assert_eq!(parsed.cutoff, Cutoff::Time(NOON_UTC));
+
+    let parsed = parse_args(&args(&["--state-dir", "/backup/oj", "GetJob", "id=x"]), today);
+    assert_eq!(
+        parsed.unwrap(),
+        InspectArgs {
+            state_dir: Some(PathBuf::from("/backup/oj")),
+            cutoff: Cutoff::Latest,
+            query: args(&["GetJob", "id=x"]),
+        }
+    );
+
+    let parsed = parse_args(&args(&[]), today).unwrap();
+    assert_eq!(parsed, InspectArgs { state_dir: None, cutoff: Cutoff::Latest, query: vec![] });
+}
+
+#[yare::parameterized(
+    both = { &["--seq", "1", "--at", "12:00"] },
+    seq_not_number = { &["--seq", "soon"] },
+    seq_missing = { &["--seq"] },
+    state_dir_missing = { &["--state-dir"] },
+    bad_time = { &["--at", "noonish"] },
+    unknown_flag = { &["--before", "1"] },
+)]
+fn rejects_bad_flags(input: &[&str]) {
+    let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+    assert!(matches!(parse_args(&args(input), today), Err(InspectError::Usage(_))));
+}
+
+#[test]
+fn local_times_use_today() {
+    let today = NaiveDate::from_ymd_opt(2026, 1, 15).unwrap();
+    let expected = Local
+        .from_local_datetime(&today.and_hms_opt(14, 3, 0).unwrap())
+        .earliest()
+        .unwrap()
+        .timestamp_millis() as u64;
+
+    assert_eq!(parse_time("14:03", today).unwrap(), expected);
+    assert_eq!(parse_time("14:03:00", today).unwrap(), expected);
+    assert_eq!(parse_time("2026-01-15 14:03", today).unwrap(), expected);
+}
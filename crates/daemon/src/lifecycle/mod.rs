@@ -12,10 +12,12 @@ use std::fs::File;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use fs2::FileExt;
 use parking_lot::Mutex;
 use std::time::Instant;
 
 use crate::engine::Runtime;
+use crate::metrics::DaemonMetrics;
 use crate::storage::{Checkpointer, MaterializedState};
 use oj_core::{Breadcrumb, Event, MetricsHealth, SystemClock};
 use thiserror::Error;
@@ -41,10 +43,12 @@ pub struct Config {
     pub version_path: PathBuf,
     /// Path to daemon log file
     pub log_path: PathBuf,
-    /// Path to WAL file
+    /// Path to WAL segment directory
     pub wal_path: PathBuf,
     /// Path to snapshot file
     pub snapshot_path: PathBuf,
+    /// Path to archived job files
+    pub archive_path: PathBuf,
     /// Path to workspaces directory
     pub workspaces_path: PathBuf,
     /// Path to per-job log files
@@ -57,19 +61,23 @@ impl Config {
     /// Uses fixed paths under `~/.local/state/oj/` (or `$XDG_STATE_HOME/oj/`).
     /// One daemon serves all projects for a user.
     pub fn load() -> Result<Self, LifecycleError> {
-        let state_dir = state_dir()?;
+        Ok(Self::at(state_dir()?))
+    }
 
-        Ok(Self {
+    /// Configuration rooted at an explicit state directory.
+    pub fn at(state_dir: PathBuf) -> Self {
+        Self {
             socket_path: state_dir.join("daemon.sock"),
             lock_path: state_dir.join("daemon.pid"),
             version_path: state_dir.join("daemon.version"),
             log_path: state_dir.join("daemon.log"),
-            wal_path: state_dir.join("wal").join("events.wal"),
+            wal_path: state_dir.join("wal"),
             snapshot_path: state_dir.join("snapshot.json"),
+            archive_path: state_dir.join("archive"),
             workspaces_path: state_dir.join("workspaces"),
             logs_path: state_dir.join("logs"),
             state_dir,
-        })
+        }
     }
 }
 
@@ -96,6 +104,8 @@ pub struct DaemonState {
     pub orphans: Arc<Mutex<Vec<Breadcrumb>>>,
     /// Metrics collector health handle
     pub metrics_health: Arc<Mutex<MetricsHealth>>,
+    /// Checkpoint and token figures for the metrics exporter
+    pub metrics: Arc<DaemonMetrics>,
 }
 
 /// Result of daemon startup - includes both the daemon state and the listener.
@@ -160,6 +170,7 @@ impl DaemonState {
             let mut state = self.state.lock();
             state.apply_event(&event);
         }
+        self.event_bus.publish(&event);
 
         // Handle non-deletion events normally (deletion already handled above)
         let result_events = if matches!(&event, Event::JobDeleted { .. }) {
@@ -242,6 +253,37 @@ impl DaemonState {
     }
 }
 
+/// The daemon's lock, held by offline tools (`ojd migrate`, `ojd import`)
+/// so the daemon cannot start while they rewrite state. A lock file created
+/// only for this is removed again on drop.
+pub(crate) struct StateLock {
+    _file: File,
+    created: Option<PathBuf>,
+}
+
+impl StateLock {
+    /// Take the lock, failing with `LockFailed` if the daemon holds it.
+    pub(crate) fn acquire(config: &Config) -> Result<Self, LifecycleError> {
+        std::fs::create_dir_all(&config.state_dir)?;
+        let existed = config.lock_path.exists();
+        let file = std::fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&config.lock_path)?;
+        file.try_lock_exclusive().map_err(LifecycleError::LockFailed)?;
+        Ok(Self { _file: file, created: (!existed).then(|| config.lock_path.clone()) })
+    }
+}
+
+impl Drop for StateLock {
+    fn drop(&mut self) {
+        if let Some(path) = &self.created {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
 /// Lifecycle errors
 #[derive(Debug, Error)]
 pub enum LifecycleError {
@@ -84,6 +84,19 @@ async fn process_event_cancel_persists_to_wal() {
     assert!(has_step_failed, "StepFailed event should be in WAL");
 }
 
+#[tokio::test]
+async fn process_event_publishes_to_subscribers() {
+    let (mut daemon, _wal_path) = setup_daemon_with_job().await;
+    let mut events = daemon.event_bus.subscribe();
+    let cancel = Event::JobCancel { id: JobId::from_string("job-1") };
+
+    daemon.process_event(cancel.clone()).await.unwrap();
+
+    assert_eq!(events.try_recv().unwrap(), cancel);
+    // Result events are published when the engine loop processes them
+    assert!(events.try_recv().is_err());
+}
+
 #[tokio::test]
 async fn cancelled_job_survives_restart_as_terminal() {
     let (mut daemon, wal_path) = setup_daemon_with_job().await;
@@ -9,11 +9,16 @@
 //! based on persisted AgentRuntime — works for Local, Docker, and K8s agents.
 
 use std::collections::HashSet;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use oj_core::{AgentId, CrewId, CrewStatus, Event, JobId, OwnerId};
+use oj_core::{AgentId, CrewId, CrewStatus, CronCatchup, CronRecord, Event, Job, JobId, OwnerId};
 use tracing::{debug, info, warn};
 
 use super::ReconcileCtx;
+use crate::engine::CronTrigger;
+
+/// Most runs a `catchup = "all"` cron fires on recovery
+const MAX_CATCHUP_RUNS: usize = 50;
 
 #[cfg(test)]
 #[path = "reconcile_tests.rs"]
@@ -80,8 +85,18 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
         info!("Resuming {} running crons", running_crons.len());
     }
 
+    let now_ms =
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
     for cron in &running_crons {
         info!(cron = %cron.name, project = %cron.project, "resuming cron after daemon restart");
+
+        // Queue runs missed while the daemon was down, per the cron's policy.
+        // Handling CronStarted starts them, as many as its concurrency allows.
+        let runs = catchup_runs(cron, now_ms);
+        if runs > 0 {
+            info!(cron = %cron.name, project = %cron.project, runs, "catching up missed cron runs");
+            ctx.runtime.queue_cron_catchup(&cron.name, &cron.project, runs);
+        }
         let _ = ctx
             .event_tx
             .send(Event::CronStarted {
@@ -89,12 +104,25 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
                 project_path: cron.project_path.clone(),
                 runbook_hash: cron.runbook_hash.clone(),
                 interval: cron.interval.clone(),
+                schedule: cron.schedule.clone(),
+                timezone: cron.timezone.clone(),
+                catchup: cron.catchup,
                 target: cron.target.clone(),
                 project: cron.project.clone(),
             })
             .await;
     }
 
+    // Re-arm expiry for decisions still waiting on a human; ones that expired
+    // while the daemon was down resolve right away.
+    for decision in state.decisions.values().filter(|d| !d.is_resolved()) {
+        if let Some(expires_at_ms) = decision.expires_at_ms {
+            if let Err(e) = ctx.runtime.arm_decision_timeout(decision.id, expires_at_ms).await {
+                warn!(decision = %decision.id, error = %e, "failed to re-arm decision expiry");
+            }
+        }
+    }
+
     // Reconcile crew
     let non_terminal_runs: Vec<_> =
         state.crew.values().filter(|run| !run.status.is_terminal()).collect();
@@ -166,70 +194,141 @@ pub(crate) async fn reconcile_state(ctx: &ReconcileCtx) {
 
     info!("Reconciling {} non-terminal jobs", non_terminal.len());
 
+    let now_ms =
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
     for job in &non_terminal {
-        // Extract agent_id from step_history (stored when agent was spawned).
-        // This must match the UUID used during spawn — using any other format
-        // causes the handler's stale-event check to drop the event.
-        let agent_id_str =
-            job.step_history.iter().rfind(|r| r.name == job.step).and_then(|r| r.agent_id.clone());
-
-        // Waiting jobs (escalated to human) still need their monitoring reconnected
-        // so that agent state changes are detected after decision resolution.
-        if job.step_status.is_waiting() {
-            if agent_id_str.is_some() {
-                info!(job_id = %job.id, "reconnecting monitoring for Waiting job");
-                if let Err(e) = ctx.runtime.recover_agent(job).await {
-                    warn!(
-                        job_id = %job.id,
-                        error = %e,
-                        "failed to reconnect monitoring for Waiting job"
-                    );
+        // A fanned-out job recovers each of its active branches
+        if job.is_parallel() {
+            for branch in job.active_branches() {
+                let branch = job.branch_view(branch);
+                rearm_step_timeout(ctx, &branch, now_ms).await;
+                if reconcile_job_step(ctx, &branch).await {
+                    break;
                 }
             }
-            continue;
+        } else {
+            rearm_step_timeout(ctx, job, now_ms).await;
+            reconcile_job_step(ctx, job).await;
+        }
+    }
+
+    // Clean up orphaned resources (e.g., K8s pods) not owned by any known agent.
+    let known_agents: HashSet<AgentId> = state.agents.keys().map(AgentId::from_string).collect();
+    debug!(count = known_agents.len(), "cleaning up stale agent resources");
+    ctx.runtime.executor.agents.cleanup_stale_resources(&known_agents).await;
+}
+
+/// Reconnect (or fail) the agent of a job's current step. `job` may be a
+/// `Job::branch_view` of one of its parallel branches.
+///
+/// Returns true if the step had no agent to recover and the job was failed.
+async fn reconcile_job_step(ctx: &ReconcileCtx, job: &Job) -> bool {
+    // Extract agent_id from step_history (stored when agent was spawned).
+    // This must match the UUID used during spawn — using any other format
+    // causes the handler's stale-event check to drop the event.
+    let agent_id_str =
+        job.step_history.iter().rfind(|r| r.name == job.step).and_then(|r| r.agent_id.clone());
+
+    // Waiting jobs (escalated to human) still need their monitoring reconnected
+    // so that agent state changes are detected after decision resolution.
+    if job.step_status.is_waiting() {
+        if agent_id_str.is_some() {
+            info!(job_id = %job.id, "reconnecting monitoring for Waiting job");
+            if let Err(e) = ctx.runtime.recover_agent(job).await {
+                warn!(
+                    job_id = %job.id,
+                    error = %e,
+                    "failed to reconnect monitoring for Waiting job"
+                );
+            }
         }
+        return false;
+    }
 
-        let Some(ref aid) = agent_id_str else {
-            warn!(job_id = %job.id, "no agent_id in step_history, marking failed");
+    let Some(ref aid) = agent_id_str else {
+        warn!(job_id = %job.id, "no agent_id in step_history, marking failed");
+        let _ = ctx
+            .event_tx
+            .send(Event::JobAdvanced {
+                id: JobId::from_string(job.id.clone()),
+                step: "failed".to_string(),
+            })
+            .await;
+        return true;
+    };
+
+    // Try to reconnect monitoring via the RuntimeRouter.
+    // recover_agent registers the agent→job mapping, then calls
+    // RuntimeRouter::reconnect which probes the correct adapter
+    // based on the persisted AgentRuntime hint.
+    match ctx.runtime.recover_agent(job).await {
+        Ok(()) => {
+            info!(job_id = %job.id, agent_id = aid, "recovering: agent still running, reconnected");
+        }
+        Err(e) => {
+            // Reconnect failed — agent is unreachable, presumed gone.
+            // recover_agent already registered the agent→job mapping,
+            // so AgentGone handlers can find the owner.
+            info!(job_id = %job.id, agent_id = aid, error = %e, "recovering: agent gone while daemon was down");
+            let agent_id = AgentId::from_string(aid);
+            let job_id = JobId::from_string(job.id.clone());
             let _ = ctx
                 .event_tx
-                .send(Event::JobAdvanced {
-                    id: JobId::from_string(job.id.clone()),
-                    step: "failed".to_string(),
+                .send(Event::AgentGone {
+                    id: agent_id,
+                    owner: OwnerId::job(job_id),
+                    exit_code: None,
                 })
                 .await;
-            continue;
-        };
+        }
+    }
+    false
+}
 
-        // Try to reconnect monitoring via the RuntimeRouter.
-        // recover_agent registers the agent→job mapping, then calls
-        // RuntimeRouter::reconnect which probes the correct adapter
-        // based on the persisted AgentRuntime hint.
-        match ctx.runtime.recover_agent(job).await {
-            Ok(()) => {
-                info!(job_id = %job.id, agent_id = aid, "recovering: agent still running, reconnected");
-            }
-            Err(e) => {
-                // Reconnect failed — agent is unreachable, presumed gone.
-                // recover_agent already registered the agent→job mapping,
-                // so AgentGone handlers can find the owner.
-                info!(job_id = %job.id, agent_id = aid, error = %e, "recovering: agent gone while daemon was down");
-                let agent_id = AgentId::from_string(aid);
-                let job_id = JobId::from_string(job.id.clone());
-                let _ = ctx
-                    .event_tx
-                    .send(Event::AgentGone {
-                        id: agent_id,
-                        owner: OwnerId::job(job_id),
-                        exit_code: None,
-                    })
-                    .await;
-            }
+/// Re-arm the timeout of a job's current step (or branch) for the time it
+/// has left; one that ran out while the daemon was down fires right away.
+async fn rearm_step_timeout(ctx: &ReconcileCtx, job: &Job, now_ms: u64) {
+    let Some(started_at_ms) =
+        job.step_history.iter().rfind(|r| r.name == job.step).map(|r| r.started_at_ms)
+    else {
+        return;
+    };
+    let runbook = match ctx.runtime.cached_runbook(&job.runbook_hash) {
+        Ok(runbook) => runbook,
+        Err(e) => {
+            warn!(job_id = %job.id, error = %e, "cannot re-arm step timeout");
+            return;
         }
+    };
+    let Some(job_def) = runbook.get_job(&job.kind) else {
+        return;
+    };
+    let job_id = JobId::from_string(&job.id);
+    let elapsed = Duration::from_millis(now_ms.saturating_sub(started_at_ms));
+    if let Err(e) = ctx.runtime.arm_step_timeout(&job_id, job_def, &job.step, elapsed).await {
+        warn!(job_id = %job.id, error = %e, "failed to re-arm step timeout");
     }
+}
 
-    // Clean up orphaned resources (e.g., K8s pods) not owned by any known agent.
-    let known_agents: HashSet<AgentId> = state.agents.keys().map(AgentId::from_string).collect();
-    debug!(count = known_agents.len(), "cleaning up stale agent resources");
-    ctx.runtime.executor.agents.cleanup_stale_resources(&known_agents).await;
+/// How many missed runs to fire for a cron resuming after a restart.
+///
+/// Runs are missed if the cron's timer would have fired between its last
+/// fire (or start, if it never fired) and now.
+fn catchup_runs(cron: &CronRecord, now_ms: u64) -> usize {
+    let limit = match cron.catchup {
+        CronCatchup::None => return 0,
+        CronCatchup::Once => 1,
+        CronCatchup::All => MAX_CATCHUP_RUNS,
+    };
+    let since_ms = cron.last_fired_at_ms.unwrap_or(cron.started_at_ms);
+    if since_ms == 0 {
+        return 0;
+    }
+    match CronTrigger::parse(&cron.interval, cron.schedule.as_deref(), cron.timezone.as_deref()) {
+        Ok(trigger) => trigger.fires_between(since_ms, now_ms, limit),
+        Err(e) => {
+            warn!(cron = %cron.name, error = %e, "cannot compute missed cron runs");
+            0
+        }
+    }
 }
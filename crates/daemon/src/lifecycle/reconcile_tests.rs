@@ -32,6 +32,7 @@ async fn reconcile_state_resumes_running_workers() {
             queue: "tasks".to_string(),
             concurrency: 2,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
         },
     );
     test_state.workers.insert(
@@ -46,6 +47,7 @@ async fn reconcile_state_resumes_running_workers() {
             queue: "other".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
         },
     );
 
@@ -144,6 +146,7 @@ async fn reconcile_deduplicates_runbook_loaded_for_shared_hash() {
                 queue: "q".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
             },
         );
     }
@@ -446,3 +449,171 @@ async fn reconcile_crew_dead_agent_emits_gone() {
         _ => unreachable!(),
     }
 }
+
+fn running_cron(name: &str, catchup: oj_core::CronCatchup, last_fired_ago_ms: u64) -> CronRecord {
+    let now_ms =
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis()
+            as u64;
+    CronRecord {
+        name: name.to_string(),
+        project: String::new(),
+        project_path: PathBuf::from("/proj"),
+        runbook_hash: "abc123".to_string(),
+        status: "running".to_string(),
+        interval: "1h".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup,
+        target: oj_core::RunTarget::job("cleanup"),
+        started_at_ms: now_ms - 10 * 3_600_000,
+        last_fired_at_ms: Some(now_ms - last_fired_ago_ms),
+    }
+}
+
+/// Reconcile the given crons, returning the emitted events and the number of
+/// catch-up runs queued per cron.
+async fn reconcile_crons(crons: Vec<CronRecord>) -> (Vec<Event>, HashMap<String, usize>) {
+    let dir = tempdir().unwrap();
+    let dir_path = dir.path().to_owned();
+    let runtime = setup_reconcile_runtime(&dir_path);
+    let mut test_state = MaterializedState::default();
+    for cron in crons {
+        test_state.crons.insert(cron.name.clone(), cron);
+    }
+    let events = run_reconcile(&runtime, test_state, dir_path).await;
+    let queued = runtime.cron_catchup.lock().clone();
+    (events, queued)
+}
+
+fn catchup_count(queued: &HashMap<String, usize>, name: &str) -> usize {
+    queued.get(name).copied().unwrap_or(0)
+}
+
+#[tokio::test]
+async fn reconcile_cron_catchup_policies() {
+    use oj_core::CronCatchup;
+
+    // Down for 3.5 intervals of 1h: three runs were missed
+    let missed_ms = 3 * 3_600_000 + 1_800_000;
+    let (events, queued) = reconcile_crons(vec![
+        running_cron("none", CronCatchup::None, missed_ms),
+        running_cron("once", CronCatchup::Once, missed_ms),
+        running_cron("all", CronCatchup::All, missed_ms),
+    ])
+    .await;
+
+    assert_eq!(catchup_count(&queued, "none"), 0);
+    assert_eq!(catchup_count(&queued, "once"), 1);
+    assert_eq!(catchup_count(&queued, "all"), 3);
+
+    // Catch-up runs are queued for the cron's restart to start, never fired directly
+    assert!(events.iter().any(|e| matches!(e, Event::CronStarted { cron, .. } if cron == "all")));
+    assert!(!events.iter().any(|e| matches!(e, Event::CronOnce { .. })));
+}
+
+#[tokio::test]
+async fn reconcile_cron_catchup_nothing_missed() {
+    // Last fired 30m ago on a 1h interval: the next run is still due
+    let (events, queued) =
+        reconcile_crons(vec![running_cron("all", oj_core::CronCatchup::All, 1_800_000)]).await;
+
+    assert_eq!(catchup_count(&queued, "all"), 0);
+    assert!(events.iter().any(|e| matches!(e, Event::CronStarted { cron, .. } if cron == "all")));
+}
+
+#[tokio::test]
+async fn reconcile_cron_catchup_counts_schedule_fires() {
+    // Hourly schedule, last fired just over 2h ago: at least two fires missed
+    let mut cron = running_cron("hourly", oj_core::CronCatchup::All, 2 * 3_600_000 + 60_000);
+    cron.interval = String::new();
+    cron.schedule = Some("@hourly".to_string());
+
+    let (_, queued) = reconcile_crons(vec![cron]).await;
+
+    let runs = catchup_count(&queued, "hourly");
+    assert!((2..=3).contains(&runs), "expected 2-3 catch-up runs, got {}", runs);
+}
+
+fn expiring_decision(id: &str, job_id: &str, expires_at_ms: u64) -> Event {
+    Event::DecisionCreated {
+        id: oj_core::DecisionId::from_string(id),
+        owner: JobId::from_string(job_id).into(),
+        project: String::new(),
+        created_at_ms: 1_000,
+        agent_id: oj_core::AgentId::from_string("agent-1"),
+        source: oj_core::DecisionSource::Gate,
+        context: "gate failed".to_string(),
+        options: vec![],
+        questions: None,
+        expires_at_ms: Some(expires_at_ms),
+        default_choice: Some(1),
+        assignee: None,
+        reviewers: vec![],
+    }
+}
+
+#[tokio::test]
+async fn reconcile_rearms_expiry_for_pending_decisions() {
+    let dir = tempdir().unwrap();
+    let dir_path = dir.path().to_owned();
+    let runtime = setup_reconcile_runtime(&dir_path);
+    let mut test_state = MaterializedState::default();
+    test_state.apply_event(&expiring_decision("dcn-pending", "job-1", 1_000));
+    test_state.apply_event(&expiring_decision("dcn-answered", "job-2", 1_000));
+    test_state.apply_event(&Event::DecisionResolved {
+        id: oj_core::DecisionId::from_string("dcn-answered"),
+        project: String::new(),
+        resolved_at_ms: 500,
+        choices: vec![2],
+        message: None,
+        timed_out: false,
+    });
+
+    run_reconcile(&runtime, test_state, dir_path).await;
+
+    // Both expired long ago, so only the pending one is due immediately
+    let fired = runtime.executor.scheduler().lock().fired_timers(std::time::Instant::now());
+    let ids: Vec<_> = fired
+        .iter()
+        .filter_map(|e| match e {
+            Event::TimerStart { id } => Some(id.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(ids, ["decision-timeout:dcn-pending"]);
+}
+
+#[tokio::test]
+async fn reconcile_rearms_timeout_for_running_step() {
+    let dir = tempdir().unwrap();
+    let dir_path = dir.path().to_owned();
+    let runtime = setup_reconcile_runtime(&dir_path);
+
+    let mut runbook = test_runbook();
+    runbook.jobs.get_mut("test").unwrap().steps[0].timeout = Some("1h".to_string());
+    let hash = runbook_hash(&runbook);
+    let mut job = make_job_with_agent("job-1", "only-step", "agt-gone");
+    job.runbook_hash = hash.clone();
+    job.step_status = StepStatus::Running;
+    let mut test_state = MaterializedState::default();
+    test_state.apply_event(&Event::RunbookLoaded {
+        hash,
+        version: 1,
+        runbook: serde_json::to_value(&runbook).unwrap(),
+    });
+    test_state.jobs.insert("job-1".to_string(), job);
+    runtime.lock_state_mut(|state| *state = test_state.clone());
+
+    run_reconcile(&runtime, test_state, dir_path).await;
+
+    // The step started long before the restart, so its timeout is overdue
+    let fired = runtime.executor.scheduler().lock().fired_timers(std::time::Instant::now());
+    assert_eq!(fired.len(), 1, "unexpected timers: {fired:?}");
+    let events = runtime.handle_event(fired[0].clone()).await.unwrap();
+
+    assert!(events.iter().any(|e| matches!(
+        e,
+        Event::StepFailed { job_id, step, timed_out: true, .. }
+            if job_id.as_str() == "job-1" && step == "only-step"
+    )));
+}
@@ -20,6 +20,7 @@ use tokio::sync::mpsc;
 use tracing::{info, warn};
 
 use crate::event_bus::EventBus;
+use crate::metrics::DaemonMetrics;
 
 use super::{Config, DaemonState, LifecycleError, ReconcileCtx, StartupResult};
 
@@ -65,9 +66,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
     if let Some(parent) = config.socket_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    if let Some(parent) = config.wal_path.parent() {
-        std::fs::create_dir_all(parent)?;
-    }
+    std::fs::create_dir_all(&config.wal_path)?;
     std::fs::create_dir_all(&config.workspaces_path)?;
 
     // Write version file
@@ -206,6 +205,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
                                     item_id: item_id.to_string(),
                                     error: "job orphaned after daemon crash".to_string(),
                                     project: bc.project.clone(),
+                                    retry_at_ms: None,
                                 });
                                 events.push(Event::QueueDead {
                                     queue: item.queue.clone(),
@@ -259,10 +259,12 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
     ));
 
     // 10. Spawn usage metrics collector
+    let metrics = Arc::new(DaemonMetrics::default());
     let metrics_health = UsageMetricsCollector::spawn_collector(
         Arc::clone(&state),
         Arc::new(agent_adapter.clone()),
         config.state_dir.join("metrics"),
+        Arc::clone(&metrics),
     );
 
     // 11. Prepare reconciliation context (will run as background task after READY)
@@ -292,6 +294,7 @@ async fn startup_inner(config: &Config) -> Result<StartupResult, LifecycleError>
             start_time: Instant::now(),
             orphans,
             metrics_health,
+            metrics,
         },
         listener,
         event_reader,
@@ -148,6 +148,7 @@ fn reconcile_context_counts_running_workers() {
             queue: "q".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
         },
     );
     state.workers.insert(
@@ -162,6 +163,7 @@ fn reconcile_context_counts_running_workers() {
             queue: "q".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
         },
     );
 
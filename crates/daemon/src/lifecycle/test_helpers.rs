@@ -9,7 +9,7 @@ pub use std::sync::Arc;
 pub use parking_lot::Mutex;
 pub use tempfile::tempdir;
 
-pub use crate::storage::{load_snapshot, MaterializedState, Wal, WorkerRecord};
+pub use crate::storage::{load_snapshot, CronRecord, MaterializedState, Wal, WorkerRecord};
 pub use oj_core::{
     Crew, CrewId, CrewStatus, Event, Job, JobConfig, JobId, StepStatus, SystemClock,
 };
@@ -43,13 +43,18 @@ pub fn test_runbook() -> Runbook {
             on_done: None,
             on_fail: None,
             on_cancel: None,
+            timeout: None,
             notify: Default::default(),
+            decision: None,
             steps: vec![StepDef {
                 name: "only-step".to_string(),
                 run: RunDirective::Shell("echo done".to_string()),
+                after: vec![],
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             }],
         },
     );
@@ -141,6 +146,7 @@ pub async fn setup_daemon_with_job_and_reader() -> (DaemonState, EventReader, Pa
             log_path: dir_path.join("test.log"),
             wal_path: wal_path.clone(),
             snapshot_path: dir_path.join("test.snapshot"),
+            archive_path: dir_path.join("archive"),
             workspaces_path: dir_path.join("workspaces"),
             logs_path: dir_path.join("logs"),
         },
@@ -152,6 +158,7 @@ pub async fn setup_daemon_with_job_and_reader() -> (DaemonState, EventReader, Pa
         start_time: std::time::Instant::now(),
         orphans: Arc::new(Mutex::new(Vec::new())),
         metrics_health: Arc::new(Mutex::new(oj_core::MetricsHealth::default())),
+        metrics: Arc::new(crate::metrics::DaemonMetrics::default()),
     };
 
     (daemon, event_reader, wal_path)
@@ -167,6 +174,7 @@ pub fn test_config(dir: &Path) -> Config {
         log_path: dir.join("test.log"),
         wal_path: dir.join("test.wal"),
         snapshot_path: dir.join("test.snapshot"),
+        archive_path: dir.join("archive"),
         workspaces_path: dir.join("workspaces"),
         logs_path: dir.join("logs"),
     }
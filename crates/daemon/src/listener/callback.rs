@@ -0,0 +1,287 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Inbound HTTP decision callbacks.
+//!
+//! Lets a chat integration resolve a decision without shell access by POSTing
+//! a signed payload to the daemon's TCP port:
+//!
+//! ```text
+//! POST /decisions/<id>/resolve
+//! X-Oj-Timestamp: 1767225600
+//! X-Oj-Signature: sha256=<hex HMAC-SHA256 of the signed message>
+//!
+//! {"choice": 2, "message": "ship it"}
+//! ```
+//!
+//! The signed message is the method, path, decision id, timestamp and body,
+//! each followed by a newline except the body, so a signature only resolves
+//! the decision it was made for. The decision id must match exactly.
+//!
+//! The HMAC key is `OJ_DECISION_SECRET`; without it callbacks are refused.
+//! Accepted payloads take the same path as `oj decision resolve`.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ring::hmac;
+use serde::Deserialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{info, warn};
+
+use crate::env::ipc_timeout;
+use crate::protocol::Response;
+
+use super::decisions::handle_decision_resolve;
+use super::{ConnectionError, ListenCtx};
+
+/// Request line and headers larger than this are rejected
+const MAX_HEAD_BYTES: usize = 16 * 1024;
+
+/// Bodies larger than this are rejected
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// Requests signed further than this from the daemon's clock are rejected,
+/// so a captured request cannot be replayed later
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(300);
+
+/// Whether a TCP connection is speaking HTTP rather than the daemon protocol.
+///
+/// Protocol frames start with a big-endian length capped at 200 MiB, so their
+/// first byte is at most 0x0C; HTTP requests start with an uppercase method.
+pub(super) async fn is_http(stream: &TcpStream) -> bool {
+    let mut first = [0u8; 1];
+    let peek = tokio::time::timeout(ipc_timeout(), stream.peek(&mut first)).await;
+    matches!(peek, Ok(Ok(1)) if first[0].is_ascii_uppercase())
+}
+
+/// Serve one HTTP request and close the connection.
+pub(super) async fn handle_http<S>(mut stream: S, ctx: &ListenCtx)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let reply = match tokio::time::timeout(ipc_timeout(), read_request(&mut stream)).await {
+        Ok(Ok(request)) => respond(&request, ctx, unix_now()),
+        Ok(Err(reply)) => reply,
+        Err(_) => HttpReply::error(408, "request timed out"),
+    };
+    let _ = stream.write_all(reply.to_bytes().as_slice()).await;
+    let _ = stream.shutdown().await;
+}
+
+/// A parsed HTTP/1.1 request.
+#[derive(Debug)]
+pub(super) struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    /// Header names lowercased
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+    }
+}
+
+/// Status and JSON body to send back.
+#[derive(Debug, PartialEq)]
+pub(super) struct HttpReply {
+    pub status: u16,
+    pub body: serde_json::Value,
+}
+
+impl HttpReply {
+    pub fn error(status: u16, message: impl Into<String>) -> Self {
+        Self { status, body: serde_json::json!({ "error": message.into() }) }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            _ => "Internal Server Error",
+        };
+        let body = self.body.to_string();
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            body.len(),
+            body
+        )
+        .into_bytes()
+    }
+}
+
+pub(super) async fn read_request<R: AsyncRead + Unpin>(
+    reader: &mut R,
+) -> Result<HttpRequest, HttpReply> {
+    let mut buf = Vec::new();
+    let head_end = loop {
+        if let Some(i) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break i;
+        }
+        if buf.len() > MAX_HEAD_BYTES {
+            return Err(HttpReply::error(413, "request headers too large"));
+        }
+        let mut chunk = [0u8; 4096];
+        let n = reader
+            .read(&mut chunk)
+            .await
+            .map_err(|_| HttpReply::error(400, "failed to read request"))?;
+        if n == 0 {
+            return Err(HttpReply::error(400, "incomplete request"));
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buf[..head_end]).into_owned();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(HttpReply::error(400, "malformed request line"));
+    };
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    let content_length = match headers.iter().find(|(n, _)| n == "content-length") {
+        Some((_, v)) => {
+            v.parse::<usize>().map_err(|_| HttpReply::error(400, "invalid content-length"))?
+        }
+        None => 0,
+    };
+    if content_length > MAX_BODY_BYTES {
+        return Err(HttpReply::error(413, "request body too large"));
+    }
+    let mut body = buf.split_off(head_end + 4);
+    if body.len() < content_length {
+        let start = body.len();
+        body.resize(content_length, 0);
+        reader
+            .read_exact(&mut body[start..])
+            .await
+            .map_err(|_| HttpReply::error(400, "incomplete request body"))?;
+    }
+    body.truncate(content_length);
+
+    Ok(HttpRequest { method: method.to_string(), path: path.to_string(), headers, body })
+}
+
+/// Resolution payload, as sent by the caller.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ResolvePayload {
+    /// 1-indexed option
+    #[serde(default)]
+    choice: Option<usize>,
+    /// One 1-indexed option per question, for multi-question decisions
+    #[serde(default)]
+    choices: Vec<usize>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+fn respond(request: &HttpRequest, ctx: &ListenCtx, now_secs: u64) -> HttpReply {
+    let Some(id) = request
+        .path
+        .strip_prefix("/decisions/")
+        .and_then(|rest| rest.strip_suffix("/resolve"))
+        .filter(|id| !id.is_empty() && !id.contains('/'))
+    else {
+        return HttpReply::error(404, "not found");
+    };
+    if request.method != "POST" {
+        return HttpReply::error(405, "use POST");
+    }
+    let Some(secret) = ctx.decision_secret.as_deref() else {
+        return HttpReply::error(404, "decision callbacks are disabled (OJ_DECISION_SECRET)");
+    };
+
+    if let Err(reason) = verify_signature(secret, request, id, now_secs) {
+        warn!(decision = id, reason, "rejected decision callback");
+        return HttpReply::error(401, reason);
+    }
+    // `oj decision resolve` accepts id prefixes; a signed callback does not
+    if !ctx.state.lock().decisions.contains_key(id) {
+        return HttpReply::error(404, format!("decision not found: {}", id));
+    }
+
+    let payload: ResolvePayload = match serde_json::from_slice(&request.body) {
+        Ok(p) => p,
+        Err(e) => return HttpReply::error(400, format!("invalid payload: {}", e)),
+    };
+    if payload.choice.is_some() && !payload.choices.is_empty() {
+        return HttpReply::error(400, "use either choice or choices, not both");
+    }
+    let choices = payload.choice.map(|c| vec![c]).unwrap_or(payload.choices);
+
+    match handle_decision_resolve(ctx, id, choices, payload.message) {
+        Ok(Response::DecisionResolved { id }) => {
+            info!(decision = %id, "decision resolved via callback");
+            HttpReply { status: 200, body: serde_json::json!({ "resolved": id }) }
+        }
+        Ok(Response::Error { message }) => HttpReply::error(422, message),
+        Ok(other) => HttpReply::error(500, format!("unexpected response: {:?}", other)),
+        Err(ConnectionError::Internal(message)) => HttpReply::error(404, message),
+        Err(e) => HttpReply::error(500, e.to_string()),
+    }
+}
+
+/// Check `X-Oj-Signature` (`sha256=<hex>`) against the HMAC-SHA256 of the
+/// request's [`signed_message`], and that `X-Oj-Timestamp` is recent.
+fn verify_signature(
+    secret: &str,
+    request: &HttpRequest,
+    id: &str,
+    now_secs: u64,
+) -> Result<(), &'static str> {
+    let timestamp = request.header("x-oj-timestamp").unwrap_or_default();
+    let signature = request.header("x-oj-signature").unwrap_or_default();
+    let sent_at: u64 = timestamp.parse().map_err(|_| "missing or invalid X-Oj-Timestamp")?;
+    if now_secs.abs_diff(sent_at) > MAX_CLOCK_SKEW.as_secs() {
+        return Err("X-Oj-Timestamp is too far from the daemon's clock");
+    }
+    let tag = signature
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+        .ok_or("missing or malformed X-Oj-Signature")?;
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let message = signed_message(&request.method, &request.path, id, timestamp, &request.body);
+    hmac::verify(&key, &message, &tag).map_err(|_| "invalid signature")
+}
+
+/// `"<method>\n<path>\n<decision id>\n<timestamp>\n<body>"`
+fn signed_message(method: &str, path: &str, id: &str, timestamp: &str, body: &[u8]) -> Vec<u8> {
+    let mut message = Vec::new();
+    for part in [method, path, id, timestamp] {
+        message.extend_from_slice(part.as_bytes());
+        message.push(b'\n');
+    }
+    message.extend_from_slice(body);
+    message
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(s.get(i..i + 2)?, 16).ok()).collect()
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+#[cfg(test)]
+#[path = "callback_tests.rs"]
+mod tests;
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::sync::Arc;
+
+use oj_core::{DecisionOption, Event};
+use parking_lot::Mutex;
+use tempfile::tempdir;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use crate::storage::Wal;
+
+use super::super::test_ctx_with_wal;
+use super::super::test_fixtures::make_decision;
+use super::*;
+
+const SECRET: &str = "s3cret";
+const NOW: u64 = 1_767_225_600;
+
+fn sign(secret: &str, path: &str, timestamp: &str, body: &str) -> String {
+    let id = path.trim_start_matches("/decisions/").trim_end_matches("/resolve");
+    let message = signed_message("POST", path, id, timestamp, body.as_bytes());
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, &message);
+    let hex: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    format!("sha256={}", hex)
+}
+
+fn signed_request(path: &str, body: &str) -> HttpRequest {
+    let timestamp = NOW.to_string();
+    HttpRequest {
+        method: "POST".to_string(),
+        path: path.to_string(),
+        headers: vec![
+            ("x-oj-signature".to_string(), sign(SECRET, path, &timestamp, body)),
+            ("x-oj-timestamp".to_string(), timestamp),
+        ],
+        body: body.as_bytes().to_vec(),
+    }
+}
+
+/// Listener context with the callback secret set and unresolved idle
+/// decisions `dec-abc123` for job `job-1` and `dec-def456` for job `job-2`.
+fn setup(dir: &std::path::Path) -> (ListenCtx, Arc<Mutex<Wal>>) {
+    let (mut ctx, wal) = test_ctx_with_wal(dir);
+    ctx.decision_secret = Some(SECRET.to_string());
+    let decision = oj_core::Decision {
+        options: ["Nudge", "Done", "Cancel", "Dismiss"].map(DecisionOption::new).to_vec(),
+        ..make_decision("dec-abc123", "job-1", 1000)
+    };
+    let other = oj_core::Decision {
+        options: decision.options.clone(),
+        ..make_decision("dec-def456", "job-2", 1000)
+    };
+    ctx.state.lock().decisions.insert("dec-abc123".to_string(), decision);
+    ctx.state.lock().decisions.insert("dec-def456".to_string(), other);
+    (ctx, wal)
+}
+
+fn drain_events(wal: &Arc<Mutex<Wal>>) -> Vec<Event> {
+    let mut events = Vec::new();
+    let mut wal = wal.lock();
+    while let Some(entry) = wal.next_unprocessed().unwrap() {
+        events.push(entry.event);
+        wal.mark_processed(entry.seq);
+    }
+    events
+}
+
+#[test]
+fn signed_choice_resolves_decision() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = setup(dir.path());
+
+    let reply = respond(
+        &signed_request("/decisions/dec-abc123/resolve", r#"{"choice": 2, "message": "lgtm"}"#),
+        &ctx,
+        NOW,
+    );
+
+    assert_eq!(
+        reply,
+        HttpReply { status: 200, body: serde_json::json!({ "resolved": "dec-abc123" }) }
+    );
+    let events = drain_events(&wal);
+    assert!(
+        events.iter().any(|e| matches!(
+            e,
+            Event::DecisionResolved { id, choices, message, .. }
+                if id.as_str() == "dec-abc123" && choices == &[2] && message.as_deref() == Some("lgtm")
+        )),
+        "{:?}",
+        events
+    );
+}
+
+#[test]
+fn freeform_message_resolves_decision() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = setup(dir.path());
+
+    let reply = respond(
+        &signed_request("/decisions/dec-abc123/resolve", r#"{"message": "try the other API"}"#),
+        &ctx,
+        NOW,
+    );
+
+    assert_eq!(reply.status, 200);
+    assert!(drain_events(&wal).iter().any(|e| matches!(
+        e,
+        Event::DecisionResolved { choices, message, .. }
+            if choices.is_empty() && message.as_deref() == Some("try the other API")
+    )));
+}
+
+#[yare::parameterized(
+    wrong_secret = { "other", NOW, 401 },
+    stale = { SECRET, NOW - 301, 401 },
+    future = { SECRET, NOW + 301, 401 },
+    within_skew = { SECRET, NOW - 299, 200 },
+)]
+fn checks_signature_and_timestamp(secret: &str, signed_at: u64, status: u16) {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    let body = r#"{"choice": 1}"#;
+    let mut request = signed_request("/decisions/dec-abc123/resolve", body);
+    request.headers = vec![
+        ("x-oj-signature".to_string(), sign(secret, &request.path, &signed_at.to_string(), body)),
+        ("x-oj-timestamp".to_string(), signed_at.to_string()),
+    ];
+
+    assert_eq!(respond(&request, &ctx, NOW).status, status);
+}
+
+#[test]
+fn tampered_body_is_rejected() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = setup(dir.path());
+    let mut request = signed_request("/decisions/dec-abc123/resolve", r#"{"choice": 1}"#);
+    request.body = br#"{"choice": 3}"#.to_vec();
+
+    let reply = respond(&request, &ctx, NOW);
+
+    assert_eq!(reply, HttpReply::error(401, "invalid signature"));
+    assert!(drain_events(&wal).is_empty());
+}
+
+#[test]
+fn signature_for_another_decision_is_rejected() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = setup(dir.path());
+    let signed_for_a = signed_request("/decisions/dec-abc123/resolve", r#"{"choice": 1}"#);
+    let request = HttpRequest { path: "/decisions/dec-def456/resolve".to_string(), ..signed_for_a };
+
+    let reply = respond(&request, &ctx, NOW);
+
+    assert_eq!(reply, HttpReply::error(401, "invalid signature"));
+    assert!(drain_events(&wal).is_empty());
+}
+
+#[yare::parameterized(
+    no_signature = { None, Some("1767225600") },
+    no_timestamp = { Some("sha256=00"), None },
+    not_hex = { Some("sha256=zz"), Some("1767225600") },
+    no_prefix = { Some("abcd"), Some("1767225600") },
+)]
+fn missing_or_malformed_headers_are_unauthorized(signature: Option<&str>, timestamp: Option<&str>) {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    let mut request = signed_request("/decisions/dec-abc123/resolve", r#"{"choice": 1}"#);
+    request.headers = [("x-oj-signature", signature), ("x-oj-timestamp", timestamp)]
+        .into_iter()
+        .filter_map(|(n, v)| Some((n.to_string(), v?.to_string())))
+        .collect();
+
+    assert_eq!(respond(&request, &ctx, NOW).status, 401);
+}
+
+#[test]
+fn disabled_without_secret() {
+    let dir = tempdir().unwrap();
+    let (mut ctx, _wal) = setup(dir.path());
+    ctx.decision_secret = None;
+
+    let reply = respond(&signed_request("/decisions/dec-abc123/resolve", "{}"), &ctx, NOW);
+
+    assert_eq!(reply.status, 404);
+}
+
+#[yare::parameterized(
+    out_of_range = { "/decisions/dec-abc123/resolve", r#"{"choice": 9}"#, 422 },
+    empty = { "/decisions/dec-abc123/resolve", "{}", 422 },
+    unknown_decision = { "/decisions/dec-zzz/resolve", r#"{"choice": 1}"#, 404 },
+    id_prefix = { "/decisions/dec-abc/resolve", r#"{"choice": 1}"#, 404 },
+    unknown_field = { "/decisions/dec-abc123/resolve", r#"{"option": 1}"#, 400 },
+    both_forms = { "/decisions/dec-abc123/resolve", r#"{"choice": 1, "choices": [1]}"#, 400 },
+    not_json = { "/decisions/dec-abc123/resolve", "choice=1", 400 },
+    other_path = { "/decisions/dec-abc123", r#"{"choice": 1}"#, 404 },
+    nested_id = { "/decisions/a/b/resolve", r#"{"choice": 1}"#, 404 },
+)]
+fn rejects_bad_requests(path: &str, body: &str, status: u16) {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    assert_eq!(respond(&signed_request(path, body), &ctx, NOW).status, status);
+}
+
+#[test]
+fn already_resolved_decision_is_rejected() {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    ctx.state.lock().decisions.get_mut("dec-abc123").unwrap().resolved_at_ms = Some(2000);
+
+    let reply =
+        respond(&signed_request("/decisions/dec-abc123/resolve", r#"{"choice": 1}"#), &ctx, NOW);
+
+    assert_eq!(reply, HttpReply::error(422, "decision dec-abc123 is already resolved"));
+}
+
+#[test]
+fn get_is_not_allowed() {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        ..signed_request("/decisions/dec-abc123/resolve", "")
+    };
+    assert_eq!(respond(&request, &ctx, NOW).status, 405);
+}
+
+#[tokio::test]
+async fn serves_http_over_stream() {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    let body = r#"{"choice":3}"#;
+    let timestamp = unix_now().to_string();
+    let raw = format!(
+        "POST /decisions/dec-abc123/resolve HTTP/1.1\r\nHost: ojd\r\nX-Oj-Timestamp: {}\r\nX-OJ-Signature: {}\r\nContent-Length: {}\r\n\r\n{}",
+        timestamp,
+        sign(SECRET, "/decisions/dec-abc123/resolve", &timestamp, body),
+        body.len(),
+        body
+    );
+    let (mut client, server) = tokio::io::duplex(64 * 1024);
+
+    client.write_all(raw.as_bytes()).await.unwrap();
+    handle_http(server, &ctx).await;
+
+    let mut response = String::new();
+    client.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"), "{}", response);
+    assert!(response.ends_with(r#"{"resolved":"dec-abc123"}"#), "{}", response);
+}
+
+#[tokio::test]
+async fn oversized_body_is_rejected() {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = setup(dir.path());
+    let raw = format!(
+        "POST /decisions/dec-abc123/resolve HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+        MAX_BODY_BYTES + 1
+    );
+    let (mut client, server) = tokio::io::duplex(64 * 1024);
+
+    client.write_all(raw.as_bytes()).await.unwrap();
+    handle_http(server, &ctx).await;
+
+    let mut response = String::new();
+    client.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 413 "), "{}", response);
+}
+
+#[tokio::test]
+async fn detects_http_on_tcp() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let mut http = tokio::net::TcpStream::connect(addr).await.unwrap();
+    http.write_all(b"POST / HTTP/1.1\r\n").await.unwrap();
+    let (stream, _) = listener.accept().await.unwrap();
+    assert!(is_http(&stream).await);
+
+    let mut framed = tokio::net::TcpStream::connect(addr).await.unwrap();
+    framed.write_all(&42u32.to_be_bytes()).await.unwrap();
+    let (stream, _) = listener.accept().await.unwrap();
+    assert!(!is_http(&stream).await);
+}
@@ -140,7 +140,10 @@ pub(super) fn handle_cron_start(
         cron: cron.to_string(),
         project_path: v.project_path.clone(),
         runbook_hash: v.runbook_hash,
-        interval: v.cron_def.interval.clone(),
+        interval: v.cron_def.interval.clone().unwrap_or_default(),
+        schedule: v.cron_def.schedule.clone(),
+        timezone: v.cron_def.timezone.clone(),
+        catchup: v.cron_def.catchup,
         target: v.target,
         project: project.to_string(),
     };
@@ -28,6 +28,9 @@ fn make_cron_record(
         runbook_hash: "fake-hash".to_string(),
         status: status.to_string(),
         interval: interval.to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job(job_kind),
         started_at_ms: clock.epoch_ms(),
         last_fired_at_ms: None,
@@ -303,6 +306,9 @@ async fn once_with_wrong_project_path_falls_back_to_namespace() {
                 runbook_hash: "fake-hash".to_string(),
                 status: "running".to_string(),
                 interval: "24h".to_string(),
+                schedule: None,
+                timezone: None,
+                catchup: oj_core::CronCatchup::None,
                 target: oj_core::RunTarget::job("deploy"),
                 started_at_ms: 1_000,
                 last_fired_at_ms: None,
@@ -0,0 +1,230 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Prometheus metrics exporter.
+//!
+//! With `OJ_METRICS_ADDR` set, the daemon serves `GET /metrics` on that
+//! address in the Prometheus text exposition format. Gauges are computed
+//! from state on each scrape; checkpoint and token counters come from
+//! [`DaemonMetrics`](crate::metrics::DaemonMetrics). The endpoint has no
+//! authentication, so a bare port binds to localhost only.
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use oj_core::{AgentRecordStatus, AgentRuntime, StepStatusKind};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::error;
+
+use crate::env::ipc_timeout;
+use crate::storage::QueueItemStatus;
+
+use super::callback::{read_request, HttpReply, HttpRequest};
+use super::ListenCtx;
+
+/// Content type of the text exposition format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Serve `/metrics` until the process exits.
+pub(crate) async fn serve(listener: TcpListener, ctx: Arc<ListenCtx>) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let ctx = Arc::clone(&ctx);
+                tokio::spawn(async move { handle(stream, &ctx).await });
+            }
+            Err(e) => error!("Metrics accept error: {}", e),
+        }
+    }
+}
+
+/// Serve one HTTP request and close the connection.
+async fn handle<S>(mut stream: S, ctx: &ListenCtx)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let reply = match tokio::time::timeout(ipc_timeout(), read_request(&mut stream)).await {
+        Ok(Ok(request)) => respond(&request, ctx, unix_now_ms()),
+        Ok(Err(reply)) => reply.to_bytes(),
+        Err(_) => HttpReply::error(408, "request timed out").to_bytes(),
+    };
+    let _ = stream.write_all(&reply).await;
+    let _ = stream.shutdown().await;
+}
+
+fn respond(request: &HttpRequest, ctx: &ListenCtx, now_ms: u64) -> Vec<u8> {
+    // Scrapers may add a query string
+    let path = request.path.split('?').next().unwrap_or_default();
+    if path != "/metrics" {
+        return HttpReply::error(404, "not found").to_bytes();
+    }
+    if request.method != "GET" {
+        return HttpReply::error(405, "use GET").to_bytes();
+    }
+    let body = render(ctx, now_ms);
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        CONTENT_TYPE,
+        body.len(),
+        body
+    )
+    .into_bytes()
+}
+
+/// Render every metric in the text exposition format.
+pub(super) fn render(ctx: &ListenCtx, now_ms: u64) -> String {
+    let mut jobs = BTreeMap::<(String, String), u64>::new();
+    // (project, queue) -> (pending, dead)
+    let mut queues = BTreeMap::<(String, String), (u64, u64)>::new();
+    let mut agents: BTreeMap<String, u64> =
+        [AgentRuntime::Local, AgentRuntime::Docker, AgentRuntime::Kubernetes]
+            .iter()
+            .map(|runtime| (runtime.to_string(), 0))
+            .collect();
+    // project -> (pending, oldest created_at_ms)
+    let mut decisions = BTreeMap::<String, (u64, u64)>::new();
+    {
+        let state = ctx.state.lock();
+        for job in state.jobs.values() {
+            let status = StepStatusKind::from(&job.step_status).to_string();
+            *jobs.entry((job.project.clone(), status)).or_default() += 1;
+        }
+        for (key, items) in state.queue_items.iter() {
+            let (project, queue) = oj_core::split_scoped_name(key);
+            let counts = queues.entry((project.to_string(), queue.to_string())).or_default();
+            for item in items {
+                match item.status {
+                    QueueItemStatus::Pending => counts.0 += 1,
+                    QueueItemStatus::Dead => counts.1 += 1,
+                    _ => {}
+                }
+            }
+        }
+        for agent in state.agents.values() {
+            if matches!(
+                agent.status,
+                AgentRecordStatus::Starting | AgentRecordStatus::Running | AgentRecordStatus::Idle
+            ) {
+                *agents.entry(agent.runtime.to_string()).or_default() += 1;
+            }
+        }
+        for decision in state.decisions.values().filter(|d| !d.is_resolved()) {
+            let entry =
+                decisions.entry(decision.project.clone()).or_insert((0, decision.created_at_ms));
+            entry.0 += 1;
+            entry.1 = entry.1.min(decision.created_at_ms);
+        }
+    }
+    let wal_bytes = ctx.event_bus.wal.lock().disk_bytes();
+    let checkpoint = ctx.metrics.checkpoint();
+    let tokens = ctx.metrics.tokens();
+
+    let mut out = Exposition::default();
+    out.family("oj_uptime_seconds", "gauge", "Seconds since the daemon started");
+    out.sample("oj_uptime_seconds", &[], ctx.start_time.elapsed().as_secs());
+
+    out.family("oj_jobs", "gauge", "Jobs in state, by project and step status");
+    for ((project, status), count) in &jobs {
+        out.sample("oj_jobs", &[("project", project), ("status", status)], count);
+    }
+
+    out.family("oj_queue_depth", "gauge", "Pending items waiting in each queue");
+    for ((project, queue), (pending, _)) in &queues {
+        out.sample("oj_queue_depth", &[("project", project), ("queue", queue)], pending);
+    }
+    out.family("oj_queue_dead_items", "gauge", "Items in each queue that exhausted their retries");
+    for ((project, queue), (_, dead)) in &queues {
+        out.sample("oj_queue_dead_items", &[("project", project), ("queue", queue)], dead);
+    }
+
+    out.family("oj_agents_active", "gauge", "Agents starting, running or idle, by runtime");
+    for (runtime, count) in &agents {
+        out.sample("oj_agents_active", &[("runtime", runtime)], count);
+    }
+
+    out.family("oj_decisions_pending", "gauge", "Unresolved decisions, by project");
+    for (project, (count, _)) in &decisions {
+        out.sample("oj_decisions_pending", &[("project", project)], count);
+    }
+    out.family(
+        "oj_decision_backlog_age_seconds",
+        "gauge",
+        "Age of the oldest unresolved decision, by project",
+    );
+    for (project, (_, oldest_ms)) in &decisions {
+        let age = now_ms.saturating_sub(*oldest_ms) as f64 / 1000.0;
+        out.sample("oj_decision_backlog_age_seconds", &[("project", project)], age);
+    }
+
+    out.family("oj_wal_bytes", "gauge", "Size of the write-ahead log on disk");
+    out.sample("oj_wal_bytes", &[], wal_bytes);
+
+    out.family("oj_checkpoint_duration_seconds", "summary", "Time taken by completed checkpoints");
+    out.sample("oj_checkpoint_duration_seconds_sum", &[], checkpoint.duration_sum.as_secs_f64());
+    out.sample("oj_checkpoint_duration_seconds_count", &[], checkpoint.completed);
+    out.family(
+        "oj_checkpoint_last_duration_seconds",
+        "gauge",
+        "Time taken by the most recent completed checkpoint",
+    );
+    if let Some(last) = checkpoint.last_duration {
+        out.sample("oj_checkpoint_last_duration_seconds", &[], last.as_secs_f64());
+    }
+    out.family("oj_checkpoint_failures_total", "counter", "Checkpoints that failed to write");
+    out.sample("oj_checkpoint_failures_total", &[], checkpoint.failed);
+
+    out.family("oj_tokens_total", "counter", "Tokens used by agents, by project and token type");
+    for (project, counts) in &tokens {
+        for (kind, value) in [
+            ("input", counts.input),
+            ("output", counts.output),
+            ("cache_write", counts.cache_write),
+            ("cache_read", counts.cache_read),
+        ] {
+            out.sample("oj_tokens_total", &[("project", project), ("type", kind)], value);
+        }
+    }
+
+    out.text
+}
+
+/// Builder for the text exposition format.
+#[derive(Default)]
+struct Exposition {
+    text: String,
+}
+
+impl Exposition {
+    /// Start a metric family; its samples must follow.
+    fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.text, "# HELP {} {}", name, help);
+        let _ = writeln!(self.text, "# TYPE {} {}", name, kind);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.text.push_str(name);
+        if !labels.is_empty() {
+            let labels: Vec<String> = labels
+                .iter()
+                .map(|(label, value)| format!("{}=\"{}\"", label, escape_label(value)))
+                .collect();
+            let _ = write!(self.text, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(self.text, " {}", value);
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn unix_now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+#[cfg(test)]
+#[path = "exporter_tests.rs"]
+mod tests;
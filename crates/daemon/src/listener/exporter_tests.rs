@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use oj_core::{AgentRecord, OwnerId, StepStatus};
+use tempfile::tempdir;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use super::super::test_ctx;
+use super::super::test_fixtures::{make_decision, make_job_ns, make_queue_item};
+use super::*;
+use crate::metrics::TokenCounts;
+
+const NOW_MS: u64 = 1_767_225_600_000;
+
+fn make_agent(id: &str, runtime: AgentRuntime, status: AgentRecordStatus) -> AgentRecord {
+    AgentRecord {
+        agent_id: id.to_string(),
+        agent_name: "worker".to_string(),
+        owner: OwnerId::Job(oj_core::JobId::from_string("job-1")),
+        project: "oddjobs".to_string(),
+        workspace_path: "/tmp".into(),
+        status,
+        runtime,
+        auth_token: None,
+        created_at_ms: 0,
+        updated_at_ms: 0,
+    }
+}
+
+fn get(path: &str) -> HttpRequest {
+    HttpRequest { method: "GET".to_string(), path: path.to_string(), headers: vec![], body: vec![] }
+}
+
+/// Sample lines, without comments.
+fn samples(text: &str) -> Vec<&str> {
+    text.lines().filter(|l| !l.starts_with('#')).collect()
+}
+
+#[test]
+fn renders_gauges_from_state() {
+    let dir = tempdir().unwrap();
+    let ctx = test_ctx(dir.path());
+    {
+        let mut state = ctx.state.lock();
+        state.jobs.insert("job-1".to_string(), make_job_ns("job-1", "build", "api"));
+        state.jobs.insert("job-2".to_string(), make_job_ns("job-2", "build", "api"));
+        let mut waiting = make_job_ns("job-3", "review", "api");
+        waiting.step_status = StepStatus::Waiting(None);
+        state.jobs.insert("job-3".to_string(), waiting);
+
+        state.queue_items.insert(
+            "api/merge".to_string(),
+            vec![
+                make_queue_item("q1", QueueItemStatus::Pending),
+                make_queue_item("q2", QueueItemStatus::Pending),
+                make_queue_item("q3", QueueItemStatus::Active),
+                make_queue_item("q4", QueueItemStatus::Dead),
+            ],
+        );
+
+        state.agents.insert(
+            "a1".to_string(),
+            make_agent("a1", AgentRuntime::Docker, AgentRecordStatus::Running),
+        );
+        state.agents.insert(
+            "a2".to_string(),
+            make_agent("a2", AgentRuntime::Docker, AgentRecordStatus::Idle),
+        );
+        state.agents.insert(
+            "a3".to_string(),
+            make_agent("a3", AgentRuntime::Local, AgentRecordStatus::Exited),
+        );
+
+        state
+            .decisions
+            .insert("dec-1".to_string(), make_decision("dec-1", "job-1", NOW_MS - 90_000));
+        state
+            .decisions
+            .insert("dec-2".to_string(), make_decision("dec-2", "job-2", NOW_MS - 30_000));
+        let mut resolved = make_decision("dec-3", "job-3", NOW_MS - 600_000);
+        resolved.resolved_at_ms = Some(NOW_MS);
+        state.decisions.insert("dec-3".to_string(), resolved);
+    }
+
+    let text = render(&ctx, NOW_MS);
+    let samples = samples(&text);
+
+    for expected in [
+        r#"oj_jobs{project="api",status="running"} 2"#,
+        r#"oj_jobs{project="api",status="waiting"} 1"#,
+        r#"oj_queue_depth{project="api",queue="merge"} 2"#,
+        r#"oj_queue_dead_items{project="api",queue="merge"} 1"#,
+        r#"oj_agents_active{runtime="docker"} 2"#,
+        r#"oj_agents_active{runtime="local"} 0"#,
+        r#"oj_agents_active{runtime="kubernetes"} 0"#,
+        r#"oj_decisions_pending{project="oddjobs"} 2"#,
+        r#"oj_decision_backlog_age_seconds{project="oddjobs"} 90"#,
+    ] {
+        assert!(samples.contains(&expected), "missing {expected} in:\n{text}");
+    }
+}
+
+#[test]
+fn renders_recorded_checkpoints_and_tokens() {
+    let dir = tempdir().unwrap();
+    let ctx = test_ctx(dir.path());
+    ctx.metrics.checkpoint_completed(Duration::from_millis(250));
+    ctx.metrics.checkpoint_completed(Duration::from_millis(500));
+    ctx.metrics.checkpoint_failed();
+    ctx.metrics.set_tokens(BTreeMap::from([(
+        "api".to_string(),
+        TokenCounts { input: 1200, output: 300, cache_write: 0, cache_read: 40 },
+    )]));
+
+    let text = render(&ctx, NOW_MS);
+    let samples = samples(&text);
+
+    for expected in [
+        "oj_checkpoint_duration_seconds_sum 0.75",
+        "oj_checkpoint_duration_seconds_count 2",
+        "oj_checkpoint_last_duration_seconds 0.5",
+        "oj_checkpoint_failures_total 1",
+        r#"oj_tokens_total{project="api",type="input"} 1200"#,
+        r#"oj_tokens_total{project="api",type="output"} 300"#,
+        r#"oj_tokens_total{project="api",type="cache_read"} 40"#,
+    ] {
+        assert!(samples.contains(&expected), "missing {expected} in:\n{text}");
+    }
+    assert!(samples.iter().any(|s| s.starts_with("oj_wal_bytes ")), "{text}");
+}
+
+#[test]
+fn every_family_is_declared_once_before_its_samples() {
+    let dir = tempdir().unwrap();
+    let ctx = test_ctx(dir.path());
+    ctx.state.lock().jobs.insert("job-1".to_string(), make_job_ns("job-1", "build", "api"));
+
+    let text = render(&ctx, NOW_MS);
+
+    let mut declared = Vec::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("# TYPE ") {
+            let name = rest.split(' ').next().unwrap();
+            assert!(!declared.contains(&name), "{name} declared twice");
+            declared.push(name);
+        } else if !line.starts_with('#') {
+            let name = line.split(['{', ' ']).next().unwrap();
+            let family = declared.last().unwrap();
+            assert!(name.starts_with(family), "{name} outside its family {family}");
+        }
+    }
+}
+
+#[test]
+fn escapes_label_values() {
+    assert_eq!(escape_label(r#"a"b\c"#), r#"a\"b\\c"#);
+    assert_eq!(escape_label("line\nbreak"), r"line\nbreak");
+}
+
+#[yare::parameterized(
+    wrong_path = { "GET", "/status", "404 Not Found" },
+    wrong_method = { "POST", "/metrics", "405 Method Not Allowed" },
+    query_string = { "GET", "/metrics?format=text", "200 OK" },
+)]
+fn routes_requests(method: &str, path: &str, status_line: &str) {
+    let dir = tempdir().unwrap();
+    let ctx = test_ctx(dir.path());
+    let request = HttpRequest { method: method.to_string(), ..get(path) };
+
+    let reply = String::from_utf8(respond(&request, &ctx, NOW_MS)).unwrap();
+
+    assert!(reply.starts_with(&format!("HTTP/1.1 {status_line}\r\n")), "{reply}");
+}
+
+#[tokio::test]
+async fn serves_metrics_over_http() {
+    let dir = tempdir().unwrap();
+    let ctx = test_ctx(dir.path());
+    let (mut client, server) = tokio::io::duplex(64 * 1024);
+
+    let server = tokio::spawn(async move { handle(server, &ctx).await });
+    client.write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").await.unwrap();
+    let mut reply = String::new();
+    client.read_to_string(&mut reply).await.unwrap();
+    server.await.unwrap();
+
+    assert!(reply.starts_with("HTTP/1.1 200 OK\r\n"), "{reply}");
+    assert!(reply.contains(&format!("Content-Type: {CONTENT_TYPE}\r\n")), "{reply}");
+    assert!(reply.contains("# TYPE oj_jobs gauge"), "{reply}");
+}
@@ -8,14 +8,18 @@
 //! onto the EventBus for processing by the engine.
 
 mod attach;
+mod callback;
 mod commands;
 mod coop;
 mod crons;
 mod decisions;
+mod exporter;
 mod lifecycle;
 mod mutations;
 mod query;
 mod queues;
+mod replicate;
+mod subscribe;
 mod suggest;
 mod workers;
 
@@ -25,7 +29,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use std::time::Instant;
 
-use crate::storage::MaterializedState;
+use crate::storage::{JobArchive, MaterializedState};
 use oj_core::Event;
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
@@ -36,17 +40,24 @@ use tracing::{debug, error, info, warn};
 
 use crate::adapters::AgentAdapter;
 use crate::event_bus::EventBus;
+use crate::metrics::DaemonMetrics;
 use oj_core::{Breadcrumb, MetricsHealth};
 
 use crate::env::{ipc_timeout, PROTOCOL_VERSION};
 use crate::protocol::{self, Request, Response};
 
+pub(crate) use exporter::serve as serve_metrics;
+pub(crate) use query::{answer_query, QueryCtx};
+pub(crate) use replicate::HEARTBEAT_INTERVAL;
+
 /// Shared daemon context for all request handlers.
 pub(crate) struct ListenCtx {
     pub event_bus: EventBus,
     pub state: Arc<Mutex<MaterializedState>>,
     pub orphans: Arc<Mutex<Vec<Breadcrumb>>>,
     pub metrics_health: Arc<Mutex<MetricsHealth>>,
+    /// Checkpoint and token figures for the metrics exporter
+    pub metrics: Arc<DaemonMetrics>,
     pub state_dir: PathBuf,
     pub logs_path: PathBuf,
     pub start_time: Instant,
@@ -54,8 +65,13 @@ pub(crate) struct ListenCtx {
     /// Auth token for TCP connections (from `OJ_AUTH_TOKEN`).
     /// When set, TCP clients must provide this token in the Hello handshake.
     pub auth_token: Option<String>,
+    /// HMAC key for signed HTTP decision callbacks (from `OJ_DECISION_SECRET`).
+    /// When unset, callbacks are refused.
+    pub decision_secret: Option<String>,
     /// Agent adapter for infrastructure (attach proxying via get_coop_host)
     pub agent: Arc<dyn AgentAdapter>,
+    /// Archived terminal jobs, for queries that fall back to them
+    pub archive: JobArchive,
 }
 
 /// Listener task for accepting socket connections.
@@ -141,6 +157,10 @@ impl Listener {
                             debug!("TCP connection from {}", addr);
                             let ctx = Arc::clone(&self.ctx);
                             tokio::spawn(async move {
+                                if callback::is_http(&stream).await {
+                                    callback::handle_http(stream, &ctx).await;
+                                    return;
+                                }
                                 let (reader, writer) = stream.into_split();
                                 if let Err(e) = handle_connection(reader, writer, ConnectionSource::Tcp, &ctx).await {
                                     log_connection_error(e);
@@ -195,31 +215,14 @@ where
     W: AsyncWrite + AsyncWriteExt + Unpin + Send + 'static,
 {
     // Read request with timeout
-    let request = protocol::read_request(&mut reader, ipc_timeout()).await?;
+    let mut request = protocol::read_request(&mut reader, ipc_timeout()).await?;
 
     // TCP connections must authenticate via Hello handshake as the first request
     if source == ConnectionSource::Tcp {
-        if let Request::Hello { ref token, .. } = request {
-            if let Some(ref expected) = ctx.auth_token {
-                match token {
-                    Some(provided) if provided == expected => {
-                        // Token matches — proceed normally
-                    }
-                    _ => {
-                        let response = Response::Error { message: "unauthorized".to_string() };
-                        let _ =
-                            protocol::write_response(&mut writer, &response, ipc_timeout()).await;
-                        return Ok(());
-                    }
-                }
-            }
-            // No auth_token configured on daemon — allow all TCP connections
-        } else {
-            // TCP connections must start with Hello
-            let response =
-                Response::Error { message: "TCP connections must start with Hello".to_string() };
-            let _ = protocol::write_response(&mut writer, &response, ipc_timeout()).await;
-            return Ok(());
+        match authenticate_tcp(request, &mut reader, &mut writer, ctx.auth_token.as_deref()).await?
+        {
+            Some(next) => request = next,
+            None => return Ok(()),
         }
     }
 
@@ -237,6 +240,16 @@ where
         return attach::handle_agent_attach(id, token.as_deref(), reader, writer, ctx).await;
     }
 
+    // Subscribe likewise turns the connection into an event stream.
+    if let Request::Subscribe { filter } = request {
+        return subscribe::handle_subscribe(filter, reader, writer, ctx).await;
+    }
+
+    // As does Replicate, into a stream of WAL entries for a replica.
+    if let Request::Replicate { after_seq } = request {
+        return replicate::handle_replicate(after_seq, reader, writer, ctx).await;
+    }
+
     // Race handler against client disconnect
     let token = CancellationToken::new();
     let response = tokio::select! {
@@ -256,6 +269,44 @@ where
     Ok(())
 }
 
+/// Check the `Hello` that must open a TCP connection.
+///
+/// When `auth_token` is set the `Hello` must carry it. An accepted `Hello` is
+/// answered, and the connection then carries one more request, which is
+/// returned. `None` means the connection is done: the client was refused or
+/// hung up after the handshake.
+pub(crate) async fn authenticate_tcp<R, W>(
+    request: Request,
+    reader: &mut R,
+    writer: &mut W,
+    auth_token: Option<&str>,
+) -> Result<Option<Request>, ConnectionError>
+where
+    R: AsyncReadExt + Unpin,
+    W: AsyncWriteExt + Unpin,
+{
+    let Request::Hello { token, .. } = request else {
+        let response =
+            Response::Error { message: "TCP connections must start with Hello".to_string() };
+        let _ = protocol::write_response(writer, &response, ipc_timeout()).await;
+        return Ok(None);
+    };
+    // No auth_token configured on daemon — allow all TCP connections
+    if auth_token.is_some_and(|expected| token.as_deref() != Some(expected)) {
+        let response = Response::Error { message: "unauthorized".to_string() };
+        let _ = protocol::write_response(writer, &response, ipc_timeout()).await;
+        return Ok(None);
+    }
+
+    let response = Response::Hello { version: PROTOCOL_VERSION.to_string() };
+    protocol::write_response(writer, &response, ipc_timeout()).await?;
+    match protocol::read_request(reader, ipc_timeout()).await {
+        Ok(request) => Ok(Some(request)),
+        Err(protocol::ProtocolError::ConnectionClosed) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
 /// Detect client disconnect by reading from the socket after the request.
 ///
 /// In the request-response protocol, the client sends one request then waits.
@@ -404,8 +455,16 @@ async fn handle_request(
             crons::handle_cron_once(ctx, &project_path, &project, &cron).await
         }
 
-        Request::QueuePush { project_path, project, queue, data } => {
-            queues::handle_queue_push(ctx, &project_path, &project, &queue, data)
+        Request::QueuePush { project_path, project, queue, data, priority, not_before_ms } => {
+            queues::handle_queue_push(
+                ctx,
+                &project_path,
+                &project,
+                &queue,
+                data,
+                priority,
+                not_before_ms,
+            )
         }
 
         Request::QueueDrop { project_path, project, queue, item_id } => {
@@ -451,7 +510,9 @@ async fn handle_request(
         }
 
         // Intercepted in handle_connection before reaching handle_request
-        Request::AgentAttach { .. } => unreachable!(),
+        Request::AgentAttach { .. } | Request::Subscribe { .. } | Request::Replicate { .. } => {
+            unreachable!()
+        }
     }
 }
 
@@ -525,12 +586,15 @@ fn make_listen_ctx(event_bus: crate::event_bus::EventBus, dir: &std::path::Path)
         state: Arc::new(Mutex::new(MaterializedState::default())),
         orphans: Arc::new(Mutex::new(Vec::new())),
         metrics_health: Arc::new(Mutex::new(Default::default())),
+        metrics: Arc::new(DaemonMetrics::default()),
         state_dir: dir.to_path_buf(),
         logs_path: dir.to_path_buf(),
         start_time: Instant::now(),
         shutdown: Arc::new(Notify::new()),
         auth_token: None,
+        decision_secret: None,
         agent: std::sync::Arc::new(crate::adapters::FakeAgentAdapter::new()),
+        archive: crate::storage::JobArchive::new(dir.join("archive")),
     }
 }
 
@@ -336,6 +336,7 @@ fn auto_dismiss_decisions_for_job(ctx: &ListenCtx, job_id: &str) -> Result<(), C
                 message: Some("auto-dismissed by job resume".to_string()),
                 resolved_at_ms,
                 project,
+                timed_out: false,
             },
         )?;
     }
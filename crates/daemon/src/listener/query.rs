@@ -18,10 +18,16 @@ mod query_queues;
 #[path = "query_status.rs"]
 mod query_status;
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
-use crate::storage::MaterializedState;
+use parking_lot::Mutex;
+
+use crate::storage::{JobArchive, MaterializedState};
+use oj_core::Job;
 use oj_core::{namespace_to_option, scoped_name, split_scoped_name, StepStatusKind};
+use oj_core::{Breadcrumb, MetricsHealth};
 
 mod helpers {
     use crate::storage::MaterializedState;
@@ -47,14 +53,51 @@ use crate::protocol::{
 
 use super::ListenCtx;
 
+/// The parts of the daemon context that queries read.
+///
+/// Borrowed from the live [`ListenCtx`], or assembled around a replayed
+/// historical state for offline inspection.
+pub(crate) struct QueryCtx<'a> {
+    pub state: &'a Arc<Mutex<MaterializedState>>,
+    pub orphans: &'a Arc<Mutex<Vec<Breadcrumb>>>,
+    pub metrics_health: &'a Arc<Mutex<MetricsHealth>>,
+    pub logs_path: &'a Path,
+    pub archive: &'a JobArchive,
+    pub start_time: Instant,
+}
+
+impl ListenCtx {
+    fn query_ctx(&self) -> QueryCtx<'_> {
+        QueryCtx {
+            state: &self.state,
+            orphans: &self.orphans,
+            metrics_health: &self.metrics_health,
+            logs_path: &self.logs_path,
+            archive: &self.archive,
+            start_time: self.start_time,
+        }
+    }
+}
+
 /// Handle query requests (read-only state access).
 pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
+    answer_query(&ctx.query_ctx(), query)
+}
+
+/// Answer a query against whatever state `ctx` holds.
+pub(crate) fn answer_query(ctx: &QueryCtx<'_>, query: Query) -> Response {
     match &query {
-        Query::ListOrphans => return query_orphans::handle_list_orphans(&ctx.orphans),
+        Query::ListOrphans => return query_orphans::handle_list_orphans(ctx.orphans),
         Query::DismissOrphan { id } => {
-            return query_orphans::handle_dismiss_orphan(&ctx.orphans, id, &ctx.logs_path)
+            return query_orphans::handle_dismiss_orphan(ctx.orphans, id, ctx.logs_path)
+        }
+        Query::ListProjects => return query_projects::handle_list_projects(ctx.state),
+        Query::ListArchivedJobs => {
+            return match ctx.archive.list() {
+                Ok(jobs) => Response::Jobs { jobs: jobs.iter().map(JobSummary::from).collect() },
+                Err(e) => Response::Error { message: format!("failed to read job archive: {}", e) },
+            }
         }
-        Query::ListProjects => return query_projects::handle_list_projects(&ctx.state),
         _ => {}
     }
 
@@ -64,48 +107,34 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         Query::ListJobs => {
             let mut jobs: Vec<JobSummary> = state.jobs.values().map(JobSummary::from).collect();
 
-            query_orphans::append_orphan_summaries(&mut jobs, &ctx.orphans);
+            query_orphans::append_orphan_summaries(&mut jobs, ctx.orphans);
 
             Response::Jobs { jobs }
         }
 
         Query::GetJob { id } => {
-            let job = state.get_job(&id).map(|p| {
-                let steps: Vec<StepRecordDetail> =
-                    p.step_history.iter().map(StepRecordDetail::from).collect();
-
-                // Compute agent summaries from log files
-                let project = namespace_to_option(&p.project);
-                let agents =
-                    query_agents::compute_agent_summaries(&p.id, &steps, &ctx.logs_path, project);
-
-                // Filter variables to only show declared scope prefixes
-                // System variables (agent_id, job_id, prompt, etc.) are excluded
-                let vars = filter_vars_by_scope(&p.vars);
-
-                Box::new(JobDetail {
-                    id: oj_core::JobId::from_string(&p.id),
-                    name: p.name.clone(),
-                    kind: p.kind.clone(),
-                    step: p.step.clone(),
-                    step_status: StepStatusKind::from(&p.step_status),
-                    vars,
-                    workspace_path: p.workspace_path.clone(),
-                    error: p.error.clone(),
-                    steps,
-                    agents,
-                    project: p.project.clone(),
-                })
-            });
+            let job = state.get_job(&id).map(|p| job_detail(p, ctx.logs_path));
 
             // If not found in state, check orphans
-            let job = job.or_else(|| query_orphans::find_orphan_detail(&ctx.orphans, &id));
+            let job = job.or_else(|| query_orphans::find_orphan_detail(ctx.orphans, &id));
+
+            // Then the archive, without holding the state lock for the read
+            drop(state);
+            let job = job.or_else(|| match ctx.archive.find(&id) {
+                Ok(found) => found.map(|p| {
+                    Box::new(JobDetail { archived: true, ..*job_detail(&p, ctx.logs_path) })
+                }),
+                Err(e) => {
+                    tracing::warn!(error = %e, "failed to read job archive");
+                    None
+                }
+            });
 
             Response::Job { job }
         }
 
         Query::GetAgent { agent_id } => {
-            query_agents::handle_get_agent(agent_id, &state, &ctx.logs_path)
+            query_agents::handle_get_agent(agent_id, &state, ctx.logs_path)
         }
 
         Query::ListWorkspaces => {
@@ -144,11 +173,11 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         }
 
         Query::GetAgentLogs { id, step, lines, offset } => {
-            query_logs::handle_get_agent_logs(id, step, lines, offset, &state, &ctx.logs_path)
+            query_logs::handle_get_agent_logs(id, step, lines, offset, &state, ctx.logs_path)
         }
 
         Query::GetJobLogs { id, lines, offset } => {
-            query_logs::handle_get_job_logs(id, lines, offset, &state, &ctx.orphans, &ctx.logs_path)
+            query_logs::handle_get_job_logs(id, lines, offset, &state, ctx.orphans, ctx.logs_path)
         }
 
         Query::ListQueues { project_path, project } => {
@@ -220,7 +249,7 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         }
 
         Query::ListAgents { job_id, status } => {
-            query_agents::handle_list_agents(job_id, status, &state, &ctx.logs_path)
+            query_agents::handle_list_agents(job_id, status, &state, ctx.logs_path)
         }
 
         Query::GetWorkerLogs { name, project, lines, project_path, offset } => {
@@ -231,19 +260,12 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                 offset,
                 project_path,
                 &state,
-                &ctx.logs_path,
+                ctx.logs_path,
             )
         }
 
         Query::ListWorkers => {
-            let workers = state
-                .workers
-                .values()
-                .map(|w| {
-                    let updated_at_ms = worker_updated_at_ms(w, &state);
-                    WorkerSummary::from_worker(w, updated_at_ms)
-                })
-                .collect();
+            let workers = state.workers.values().map(|w| worker_summary(w, &state)).collect();
             Response::Workers { workers }
         }
 
@@ -255,7 +277,7 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                 offset,
                 project_path,
                 &state,
-                &ctx.logs_path,
+                ctx.logs_path,
             )
         }
 
@@ -267,7 +289,8 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
                 .values()
                 .map(|c| {
                     let time = query_crons::cron_time_display(c, now_ms);
-                    CronSummary::from_cron(c, time)
+                    let next_fire = query_crons::cron_next_fire_display(c, now_ms);
+                    CronSummary::from_cron(c, time, next_fire)
                 })
                 .collect();
             Response::Crons { crons }
@@ -275,20 +298,21 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
 
         Query::StatusOverview => query_status::handle_status_overview(
             &state,
-            &ctx.orphans,
-            &ctx.metrics_health,
+            ctx.orphans,
+            ctx.metrics_health,
             ctx.start_time,
         ),
 
         Query::GetQueueLogs { queue, project, lines, offset } => {
-            query_logs::handle_get_queue_logs(queue, project, lines, offset, &ctx.logs_path)
+            query_logs::handle_get_queue_logs(queue, project, lines, offset, ctx.logs_path)
         }
 
-        Query::ListDecisions { project: _ } => {
+        Query::ListDecisions { project: _, assignee } => {
             let mut decisions: Vec<DecisionSummary> = state
                 .decisions
                 .values()
                 .filter(|d| !d.is_resolved())
+                .filter(|d| assignee.as_deref().is_none_or(|user| d.is_for(user)))
                 .map(|d| {
                     let owner_name = helpers::owner_display_name(&d.owner, &state);
                     DecisionSummary::from_decision(d, owner_name)
@@ -307,15 +331,25 @@ pub(super) fn handle_query(ctx: &ListenCtx, query: Query) -> Response {
         }
 
         // Handled by early return above; included for exhaustiveness
-        Query::ListOrphans | Query::DismissOrphan { .. } | Query::ListProjects => unreachable!(),
+        Query::ListOrphans
+        | Query::DismissOrphan { .. }
+        | Query::ListProjects
+        | Query::ListArchivedJobs => unreachable!(),
     }
 }
 
-/// Derive `updated_at_ms` for a worker from its most recently active job.
-pub(super) fn worker_updated_at_ms(
+/// Summarize a worker along with its activity time and throttled state.
+pub(super) fn worker_summary(
     w: &crate::storage::WorkerRecord,
     state: &MaterializedState,
-) -> u64 {
+) -> WorkerSummary {
+    let updated_at_ms = worker_updated_at_ms(w, state);
+    let throttle = state.worker_throttles.get(&scoped_name(&w.project, &w.name));
+    WorkerSummary::from_worker(w, updated_at_ms, throttle)
+}
+
+/// Derive `updated_at_ms` for a worker from its most recently active job.
+fn worker_updated_at_ms(w: &crate::storage::WorkerRecord, state: &MaterializedState) -> u64 {
     w.active
         .iter()
         .filter_map(|pid| state.jobs.get(pid))
@@ -334,6 +368,33 @@ const ALLOWED_VAR_PREFIXES: &[&str] = &[
     "args.",   // Command arguments
 ];
 
+fn job_detail(p: &Job, logs_path: &Path) -> Box<JobDetail> {
+    let steps: Vec<StepRecordDetail> = p.step_history.iter().map(StepRecordDetail::from).collect();
+
+    // Compute agent summaries from log files
+    let project = namespace_to_option(&p.project);
+    let agents = query_agents::compute_agent_summaries(&p.id, &steps, logs_path, project);
+
+    // Filter variables to only show declared scope prefixes
+    // System variables (agent_id, job_id, prompt, etc.) are excluded
+    let vars = filter_vars_by_scope(&p.vars);
+
+    Box::new(JobDetail {
+        id: oj_core::JobId::from_string(&p.id),
+        name: p.name.clone(),
+        kind: p.kind.clone(),
+        step: p.step.clone(),
+        step_status: StepStatusKind::from(&p.step_status),
+        vars,
+        workspace_path: p.workspace_path.clone(),
+        error: p.error.clone(),
+        steps,
+        agents,
+        project: p.project.clone(),
+        archived: false,
+    })
+}
+
 /// Filter variables to only include user-facing scopes.
 /// Variables without a declared scope prefix are excluded.
 fn filter_vars_by_scope(
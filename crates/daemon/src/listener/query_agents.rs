@@ -34,7 +34,7 @@ pub(super) fn handle_get_agent(
         let step = steps.iter().find(|s| s.agent_id.as_deref() == Some(&summary.agent_id));
 
         let error = step.and_then(|s| {
-            if s.outcome == StepOutcomeKind::Failed {
+            if matches!(s.outcome, StepOutcomeKind::Failed | StepOutcomeKind::TimedOut) {
                 s.detail.clone()
             } else {
                 None
@@ -124,6 +124,7 @@ pub(super) fn handle_list_agents(
                         StepOutcome::Completed => Some("completed".to_string()),
                         StepOutcome::Waiting(reason) => Some(format!("idle: {}", reason)),
                         StepOutcome::Failed(msg) => Some(format!("failed: {}", msg)),
+                        StepOutcome::TimedOut(msg) => Some(format!("timed out: {}", msg)),
                         _ => None,
                     })
             }),
@@ -264,6 +265,7 @@ pub(super) fn compute_agent_summaries(
                     .as_ref()
                     .map(|d| format!("failed: {}", d))
                     .or(Some("failed".to_string())),
+                StepOutcomeKind::TimedOut => Some("timed out".to_string()),
                 StepOutcomeKind::Running => None,
             };
 
@@ -3,7 +3,10 @@
 
 //! Cron time display helpers for query responses.
 
+use crate::engine::next_fire_at_ms;
 use crate::storage::CronRecord;
+use chrono::{DateTime, FixedOffset, Local, TimeZone, Utc};
+use oj_runbook::{CronExpr, CronTimezone};
 
 /// Compute the human-readable time display for a cron.
 ///
@@ -11,17 +14,13 @@ use crate::storage::CronRecord;
 /// Stopped crons show "Xm ago" since last fire, or "-" if never fired.
 pub(super) fn cron_time_display(cron: &CronRecord, now_ms: u64) -> String {
     if cron.status == "running" {
-        let base_ms = cron.last_fired_at_ms.unwrap_or(cron.started_at_ms);
-        if base_ms == 0 {
-            return "-".to_string();
-        }
-        let interval_ms = parse_interval_ms(&cron.interval).unwrap_or(0);
-        let next_fire_ms = base_ms.saturating_add(interval_ms);
-        if next_fire_ms <= now_ms {
-            "now".to_string()
-        } else {
-            let remaining_secs = (next_fire_ms - now_ms) / 1000;
-            format!("in {}", oj_core::format_elapsed(remaining_secs))
+        match cron_next_fire_ms(cron, now_ms) {
+            None => "-".to_string(),
+            Some(next_fire_ms) if next_fire_ms <= now_ms => "now".to_string(),
+            Some(next_fire_ms) => {
+                let remaining_secs = (next_fire_ms - now_ms) / 1000;
+                format!("in {}", oj_core::format_elapsed(remaining_secs))
+            }
         }
     } else {
         // stopped
@@ -35,6 +34,53 @@ pub(super) fn cron_time_display(cron: &CronRecord, now_ms: u64) -> String {
     }
 }
 
+/// The next fire of a running cron as a timestamp on the schedule's wall
+/// clock (UTC for interval crons), e.g. "2026-03-02 09:00 +05:30".
+pub(super) fn cron_next_fire_display(cron: &CronRecord, now_ms: u64) -> Option<String> {
+    if cron.status != "running" {
+        return None;
+    }
+    let next_fire_ms = cron_next_fire_ms(cron, now_ms)?.max(now_ms);
+    let at = Utc.timestamp_millis_opt(i64::try_from(next_fire_ms).ok()?).single()?;
+    Some(match cron_timezone(cron)? {
+        CronTimezone::Utc => format_fire_time(at.with_timezone(&Utc)),
+        CronTimezone::Local => format_fire_time(at.with_timezone(&Local)),
+        CronTimezone::Fixed(secs) => {
+            format_fire_time(at.with_timezone(&FixedOffset::east_opt(secs)?))
+        }
+        CronTimezone::Named(tz) => format_fire_time(at.with_timezone(&tz)),
+    })
+}
+
+/// When a running cron fires next: one interval after it last fired (or
+/// started), or the next time its schedule matches.
+fn cron_next_fire_ms(cron: &CronRecord, now_ms: u64) -> Option<u64> {
+    if let Some(ref schedule) = cron.schedule {
+        let expr = CronExpr::parse(schedule).ok()?;
+        return next_fire_at_ms(&expr, cron_timezone(cron)?, now_ms);
+    }
+    let base_ms = cron.last_fired_at_ms.unwrap_or(cron.started_at_ms);
+    if base_ms == 0 {
+        return None;
+    }
+    let interval_ms = parse_interval_ms(&cron.interval).unwrap_or(0);
+    Some(base_ms.saturating_add(interval_ms))
+}
+
+fn cron_timezone(cron: &CronRecord) -> Option<CronTimezone> {
+    match cron.timezone {
+        Some(ref tz) => CronTimezone::parse(tz).ok(),
+        None => Some(CronTimezone::Utc),
+    }
+}
+
+fn format_fire_time<Tz: TimeZone>(at: DateTime<Tz>) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    at.format("%Y-%m-%d %H:%M %Z").to_string()
+}
+
 /// Parse an interval string like "30m", "1h", "6h" into milliseconds.
 pub(super) fn parse_interval_ms(s: &str) -> Option<u64> {
     let s = s.trim();
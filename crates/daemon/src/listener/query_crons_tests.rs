@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-use crate::listener::query::query_crons::{cron_time_display, parse_interval_ms};
+use crate::listener::query::query_crons::{
+    cron_next_fire_display, cron_time_display, parse_interval_ms,
+};
 use crate::storage::CronRecord;
 
 fn make_cron_record(
@@ -17,6 +19,9 @@ fn make_cron_record(
         runbook_hash: String::new(),
         status: status.to_string(),
         interval: interval.to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         started_at_ms,
         last_fired_at_ms,
@@ -38,6 +43,41 @@ fn time_display(status: &str, interval: &str, started: u64, fired: Option<u64>,
     assert_eq!(cron_time_display(&cron, NOW), expected);
 }
 
+/// Monday 2026-03-02 08:30:00 UTC
+const MON_0830: u64 = 1_772_440_200_000;
+
+fn make_schedule_record(status: &str, schedule: &str, timezone: Option<&str>) -> CronRecord {
+    CronRecord {
+        interval: String::new(),
+        schedule: Some(schedule.to_string()),
+        timezone: timezone.map(String::from),
+        ..make_cron_record(status, "", MON_0830 - 600_000, None)
+    }
+}
+
+#[yare::parameterized(
+    same_day = { "0 9 * * *", None, "in 30m", "2026-03-02 09:00 UTC" },
+    next_weekday = { "0 8 * * MON-FRI", None, "in 23h30m", "2026-03-03 08:00 UTC" },
+    fixed_offset = { "0 9 * * *", Some("+05:30"), "in 19h", "2026-03-03 09:00 +05:30" },
+)]
+fn schedule_display(schedule: &str, tz: Option<&str>, time: &str, next_fire: &str) {
+    let cron = make_schedule_record("running", schedule, tz);
+    assert_eq!(cron_time_display(&cron, MON_0830), time);
+    assert_eq!(cron_next_fire_display(&cron, MON_0830).as_deref(), Some(next_fire));
+}
+
+#[test]
+fn interval_next_fire_display_is_utc() {
+    let cron = make_cron_record("running", "30m", MON_0830 - 600_000, None);
+    assert_eq!(cron_next_fire_display(&cron, MON_0830).as_deref(), Some("2026-03-02 08:50 UTC"));
+}
+
+#[test]
+fn stopped_cron_has_no_next_fire() {
+    let cron = make_schedule_record("stopped", "0 9 * * *", None);
+    assert_eq!(cron_next_fire_display(&cron, MON_0830), None);
+}
+
 #[yare::parameterized(
     seconds = { "30s", Some(30_000) },
     minutes = { "5m", Some(300_000) },
@@ -114,6 +114,7 @@ pub(super) fn find_orphan_detail(
                     })
                     .collect(),
                 project: bc.project.clone(),
+                archived: false,
             })
         },
     )
@@ -117,18 +117,18 @@ pub(super) fn handle_status_overview(
     // Collect workers grouped by project
     let mut ns_workers: BTreeMap<String, Vec<WorkerSummary>> = BTreeMap::new();
     for w in state.workers.values() {
-        let updated_at_ms = super::worker_updated_at_ms(w, state);
-        ns_workers
-            .entry(w.project.clone())
-            .or_default()
-            .push(WorkerSummary::from_worker(w, updated_at_ms));
+        ns_workers.entry(w.project.clone()).or_default().push(super::worker_summary(w, state));
     }
 
     // Collect crons grouped by project
     let mut ns_crons: BTreeMap<String, Vec<CronSummary>> = BTreeMap::new();
     for c in state.crons.values() {
         let time = super::query_crons::cron_time_display(c, now_ms);
-        ns_crons.entry(c.project.clone()).or_default().push(CronSummary::from_cron(c, time));
+        let next_fire = super::query_crons::cron_next_fire_display(c, now_ms);
+        ns_crons
+            .entry(c.project.clone())
+            .or_default()
+            .push(CronSummary::from_cron(c, time, next_fire));
     }
 
     // Collect queue stats grouped by project
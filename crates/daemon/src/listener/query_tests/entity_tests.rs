@@ -6,7 +6,7 @@ use std::time::Instant;
 use tempfile::tempdir;
 
 use crate::storage::QueueItemStatus;
-use oj_core::{StepOutcome, StepStatus};
+use oj_core::{StepOutcome, StepStatus, ThrottleReason, WorkerThrottle};
 
 use super::{
     empty_orphans, empty_state, handle_query, make_decision, make_job, make_queue_item,
@@ -244,7 +244,7 @@ fn list_decisions_returns_most_recent_first() {
     }
 
     let response = handle_query(
-        Query::ListDecisions { project: "oddjobs".to_string() },
+        Query::ListDecisions { project: "oddjobs".to_string(), assignee: None },
         &state,
         &empty_orphans(),
         temp.path(),
@@ -264,3 +264,75 @@ fn list_decisions_returns_most_recent_first() {
         other => panic!("unexpected response: {:?}", other),
     }
 }
+
+#[yare::parameterized(
+    assignee = { "alice", &["d-alice"] },
+    reviewer = { "bob", &["d-alice", "d-bob"] },
+    nobody = { "carol", &[] },
+)]
+fn list_decisions_filters_by_assignee(user: &str, expected: &[&str]) {
+    let state = empty_state();
+    let temp = tempdir().unwrap();
+    {
+        let mut s = state.lock();
+        let assigned = |id: &str, at: u64, assignee: &str, reviewers: &[&str]| oj_core::Decision {
+            assignee: Some(assignee.to_string()),
+            reviewers: reviewers.iter().map(|r| r.to_string()).collect(),
+            ..make_decision(id, "p1", at)
+        };
+        s.decisions.insert("d-alice".to_string(), assigned("d-alice", 2000, "alice", &["bob"]));
+        s.decisions.insert("d-bob".to_string(), assigned("d-bob", 1000, "bob", &[]));
+        s.decisions.insert("d-none".to_string(), make_decision("d-none", "p1", 3000));
+    }
+
+    let response = handle_query(
+        Query::ListDecisions { project: String::new(), assignee: Some(user.to_string()) },
+        &state,
+        &empty_orphans(),
+        temp.path(),
+        Instant::now(),
+    );
+    match response {
+        Response::Decisions { decisions } => {
+            let ids: Vec<&str> = decisions.iter().map(|d| d.id.as_str()).collect();
+            assert_eq!(ids, expected);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn list_workers_reports_throttle_for_running_workers() {
+    let state = empty_state();
+    let temp = tempdir().unwrap();
+    let start = Instant::now();
+
+    {
+        let mut s = state.lock();
+        s.workers.insert("proj/fixer".to_string(), make_worker("fixer", "proj", "bugs", 0));
+        let mut stopped = make_worker("idle", "proj", "bugs", 0);
+        stopped.status = "stopped".to_string();
+        s.workers.insert("proj/idle".to_string(), stopped);
+        for key in ["proj/fixer", "proj/idle"] {
+            s.worker_throttles.insert(
+                key.to_string(),
+                WorkerThrottle { reason: ThrottleReason::Window, until_ms: 5_000 },
+            );
+        }
+    }
+
+    let response = handle_query(Query::ListWorkers, &state, &empty_orphans(), temp.path(), start);
+
+    match response {
+        Response::Workers { workers } => {
+            let fixer = workers.iter().find(|w| w.name == "fixer").unwrap();
+            assert_eq!(fixer.throttle.as_deref(), Some("window"));
+            assert_eq!(fixer.throttled_until_ms, Some(5_000));
+
+            let idle = workers.iter().find(|w| w.name == "idle").unwrap();
+            assert_eq!(idle.throttle, None);
+            assert_eq!(idle.throttled_until_ms, None);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
@@ -9,7 +9,9 @@ use tempfile::tempdir;
 
 use oj_core::{StepOutcome, StepStatus, StepStatusKind};
 
-use super::{empty_state, handle_query, make_breadcrumb, make_job, Query, Response};
+use crate::storage::JobArchive;
+
+use super::{empty_orphans, empty_state, handle_query, make_breadcrumb, make_job, Query, Response};
 
 #[test]
 fn list_jobs_includes_orphans() {
@@ -131,3 +133,71 @@ fn get_job_logs_resolves_orphan_prefix() {
         other => panic!("unexpected response: {:?}", other),
     }
 }
+
+#[test]
+fn get_job_falls_back_to_archive() {
+    let state = empty_state();
+    let temp = tempdir().unwrap();
+    let job = make_job(
+        "archived-1234",
+        "fix/old",
+        "oddjobs",
+        "done",
+        StepStatus::Completed,
+        StepOutcome::Completed,
+        None,
+        1000,
+    );
+    JobArchive::new(temp.path().join("archive")).append(&[job]).unwrap();
+
+    let response = handle_query(
+        Query::GetJob { id: "archived".to_string() },
+        &state,
+        &empty_orphans(),
+        temp.path(),
+        Instant::now(),
+    );
+
+    let Response::Job { job: Some(job) } = response else {
+        unreachable!("unexpected response: {:?}", response);
+    };
+    assert_eq!(
+        (job.id.as_str(), job.name.as_str(), job.archived),
+        ("archived-1234", "fix/old", true)
+    );
+}
+
+#[test]
+fn list_archived_jobs_reads_archive_only() {
+    let state = empty_state();
+    let temp = tempdir().unwrap();
+    let make = |id: &str| {
+        make_job(
+            id,
+            id,
+            "oddjobs",
+            "done",
+            StepStatus::Completed,
+            StepOutcome::Completed,
+            None,
+            1000,
+        )
+    };
+    state.lock().jobs.insert("live".to_string(), make("live"));
+    JobArchive::new(temp.path().join("archive")).append(&[make("old-1"), make("old-2")]).unwrap();
+
+    let response = handle_query(
+        Query::ListArchivedJobs,
+        &state,
+        &empty_orphans(),
+        temp.path(),
+        Instant::now(),
+    );
+
+    let Response::Jobs { jobs } = response else {
+        unreachable!("unexpected response: {:?}", response);
+    };
+    let mut ids: Vec<_> = jobs.iter().map(|j| j.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, ["old-1", "old-2"]);
+}
@@ -41,12 +41,15 @@ fn handle_query(
         state: Arc::clone(state),
         orphans: Arc::clone(orphans),
         metrics_health: Arc::new(Mutex::new(Default::default())),
+        metrics: Default::default(),
         state_dir: logs_path.to_path_buf(),
         logs_path: logs_path.to_path_buf(),
         start_time,
         shutdown: Arc::new(tokio::sync::Notify::new()),
         auth_token: None,
+        decision_secret: None,
         agent: std::sync::Arc::new(crate::adapters::FakeAgentAdapter::new()),
+        archive: crate::storage::JobArchive::new(logs_path.join("archive")),
     };
     real_handle_query(&ctx, query)
 }
@@ -67,15 +67,24 @@ pub(super) fn apply_defaults(
     final_data
 }
 
-/// Find a pending or active item with the same data (deduplication).
+/// Find a pending or active item that duplicates a push (deduplication).
 ///
-/// Returns the item ID if a duplicate is found, or `None` if no duplicate exists.
+/// Items match on the queue's `dedupe` key when it has one, otherwise on
+/// identical data. Returns the item ID if a duplicate is found, or `None` if
+/// no duplicate exists.
 pub(super) fn find_duplicate_item(
     state: &Arc<Mutex<MaterializedState>>,
     project: &str,
     queue_name: &str,
+    queue_def: &oj_runbook::QueueDef,
     data: &HashMap<String, String>,
 ) -> Option<String> {
+    let dedupe_key = queue_def.dedupe_key(data);
+    let is_duplicate = |other: &HashMap<String, String>| match dedupe_key {
+        Some(ref key) => queue_def.dedupe_key(other).as_ref() == Some(key),
+        None => other == data,
+    };
+
     let st = state.lock();
     let key = scoped_name(project, queue_name);
     st.queue_items.get(&key).and_then(|items| {
@@ -84,7 +93,7 @@ pub(super) fn find_duplicate_item(
             .find(|i| {
                 (i.status == crate::storage::QueueItemStatus::Pending
                     || i.status == crate::storage::QueueItemStatus::Active)
-                    && i.data == *data
+                    && is_duplicate(&i.data)
             })
             .map(|i| i.id.clone())
     })
@@ -26,6 +26,8 @@ pub(super) fn handle_queue_push(
     project: &str,
     queue: &str,
     data: serde_json::Value,
+    priority: Option<i32>,
+    not_before_ms: Option<u64>,
 ) -> Result<Response, ConnectionError> {
     let (runbook, effective_root) = match validation::load_and_validate_queue_def(
         ctx,
@@ -58,9 +60,10 @@ pub(super) fn handle_queue_push(
     }
     let final_data = data_handling::apply_defaults(queue_def, obj);
 
-    // Deduplicate: if a pending or active item with the same data exists, return it
+    // Deduplicate: if a pending or active item with the same data (or the
+    // same `dedupe` key) exists, return it
     if let Some(existing_id) =
-        data_handling::find_duplicate_item(&ctx.state, project, queue, &final_data)
+        data_handling::find_duplicate_item(&ctx.state, project, queue, queue_def, &final_data)
     {
         workers::wake_attached_workers(ctx, project_path, project, queue, &runbook)?;
         return Ok(Response::QueuePushed { queue: queue.to_string(), item_id: existing_id });
@@ -83,6 +86,8 @@ pub(super) fn handle_queue_push(
             item_id: item_id.clone(),
             data: final_data,
             pushed_at_ms,
+            priority: priority.unwrap_or(queue_def.priority),
+            not_before_ms,
             project: project.to_string(),
         },
     )?;
@@ -240,6 +245,7 @@ pub(super) fn handle_queue_retry(
                         queue: queue.to_string(),
                         item_id: resolved_id.clone(),
                         project: project.to_string(),
+                        scheduled: false,
                     },
                 )?;
                 retried.push(resolved_id);
@@ -312,6 +318,9 @@ pub(super) fn handle_queue_drain(
                         worker_name: i.worker.clone(),
                         pushed_at_ms: i.pushed_at_ms,
                         failures: i.failures,
+                        priority: i.priority,
+                        not_before_ms: i.not_before_ms,
+                        retry_at_ms: i.retry_at_ms,
                     })
                     .collect()
             })
@@ -379,6 +388,7 @@ pub(super) fn handle_queue_fail(
             item_id: resolved_id.clone(),
             error: "force-failed via oj queue fail".to_string(),
             project: project.to_string(),
+            retry_at_ms: None,
         },
     )?;
 
@@ -31,6 +31,8 @@ fn drop_removes_item_from_queue() {
         item_id: "item-abc123".to_string(),
         data: [("task".to_string(), "test".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
@@ -105,6 +107,8 @@ fn drop_with_prefix_resolves_unique_match() {
         item_id: "abc12345-0000-0000-0000-000000000000".to_string(),
         data: [("task".to_string(), "test".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
@@ -143,6 +147,8 @@ fn drop_ambiguous_prefix_returns_error() {
             item_id: format!("abc-{}", suffix),
             data: [("task".to_string(), "test".to_string())].into_iter().collect(),
             pushed_at_ms: 1_000_000,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
     }
@@ -173,6 +179,8 @@ fn drain_removes_all_pending_items() {
             item_id: format!("item-{}", i),
             data: [("task".to_string(), format!("task-{}", i))].into_iter().collect(),
             pushed_at_ms: 1_000_000 + i,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
     }
@@ -216,6 +224,8 @@ fn drain_skips_non_pending_items() {
         item_id: "pending-1".to_string(),
         data: [("task".to_string(), "pending".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     // One active item
@@ -224,6 +234,8 @@ fn drain_skips_non_pending_items() {
         item_id: "active-1".to_string(),
         data: [("task".to_string(), "active".to_string())].into_iter().collect(),
         pushed_at_ms: 2_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     initial_state.apply_event(&Event::QueueTaken {
@@ -238,6 +250,8 @@ fn drain_skips_non_pending_items() {
         item_id: "dead-1".to_string(),
         data: [("task".to_string(), "dead".to_string())].into_iter().collect(),
         pushed_at_ms: 3_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     initial_state.apply_event(&Event::QueueDead {
@@ -325,6 +339,9 @@ fn drop_with_wrong_project_path_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             interval: "24h".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("handle"),
             started_at_ms: 1_000,
             last_fired_at_ms: None,
@@ -336,6 +353,8 @@ fn drop_with_wrong_project_path_falls_back_to_namespace() {
         item_id: "item-abc123".to_string(),
         data: [("task".to_string(), "test".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: "my-project".to_string(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial)));
@@ -377,6 +396,9 @@ fn drain_with_wrong_project_path_falls_back_to_namespace() {
             runbook_hash: "fake-hash".to_string(),
             status: "running".to_string(),
             interval: "24h".to_string(),
+            schedule: None,
+            timezone: None,
+            catchup: oj_core::CronCatchup::None,
             target: oj_core::RunTarget::job("handle"),
             started_at_ms: 1_000,
             last_fired_at_ms: None,
@@ -388,6 +410,8 @@ fn drain_with_wrong_project_path_falls_back_to_namespace() {
         item_id: "pending-1".to_string(),
         data: [("task".to_string(), "test".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: "my-project".to_string(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial)));
@@ -422,6 +446,8 @@ fn drop_works_without_runbook_definition() {
         item_id: "item-orphan-1".to_string(),
         data: [("task".to_string(), "test".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
@@ -478,6 +504,8 @@ fn drain_works_without_runbook_definition() {
             item_id: format!("item-{}", i),
             data: [("task".to_string(), format!("task-{}", i))].into_iter().collect(),
             pushed_at_ms: 1_000_000 + i,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
     }
@@ -24,12 +24,15 @@ fn make_ctx(event_bus: EventBus, state: Arc<Mutex<MaterializedState>>) -> super:
         state,
         orphans: Arc::new(Mutex::new(Vec::new())),
         metrics_health: Arc::new(Mutex::new(Default::default())),
+        metrics: Default::default(),
         state_dir: std::path::PathBuf::new(),
         logs_path: std::path::PathBuf::new(),
         start_time: std::time::Instant::now(),
         shutdown: Arc::new(tokio::sync::Notify::new()),
         auth_token: None,
+        decision_secret: None,
         agent: std::sync::Arc::new(crate::adapters::FakeAgentAdapter::new()),
+        archive: crate::storage::JobArchive::new(std::path::PathBuf::new()),
     }
 }
 
@@ -142,6 +145,8 @@ fn push_and_mark_dead(
         item_id: item_id.to_string(),
         data: data_map,
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: project.to_string(),
     });
     state.lock().apply_event(&Event::QueueDead {
@@ -166,6 +171,8 @@ fn push_and_mark_failed(
         item_id: item_id.to_string(),
         data: data_map,
         pushed_at_ms,
+        priority: 0,
+        not_before_ms: None,
         project: project.to_string(),
     });
     state.lock().apply_event(&Event::QueueFailed {
@@ -173,5 +180,6 @@ fn push_and_mark_failed(
         item_id: item_id.to_string(),
         error: "test error".to_string(),
         project: project.to_string(),
+        retry_at_ms: None,
     });
 }
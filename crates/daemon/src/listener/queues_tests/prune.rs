@@ -32,6 +32,8 @@ fn push_and_mark_completed(
         item_id: item_id.to_string(),
         data: data_map,
         pushed_at_ms,
+        priority: 0,
+        not_before_ms: None,
         project: project.to_string(),
     });
     state.lock().apply_event(&Event::QueueCompleted {
@@ -56,6 +58,8 @@ fn push_and_mark_dead_at(
         item_id: item_id.to_string(),
         data: data_map,
         pushed_at_ms,
+        priority: 0,
+        not_before_ms: None,
         project: project.to_string(),
     });
     state.lock().apply_event(&Event::QueueDead {
@@ -221,6 +225,8 @@ fn prune_skips_active_pending_failed_items() {
         item_id: "pending-1".to_string(),
         data: [("task".to_string(), "p".to_string())].into_iter().collect(),
         pushed_at_ms: old_epoch_ms(),
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
 
@@ -230,6 +236,8 @@ fn prune_skips_active_pending_failed_items() {
         item_id: "active-1".to_string(),
         data: [("task".to_string(), "a".to_string())].into_iter().collect(),
         pushed_at_ms: old_epoch_ms(),
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     ctx.state.lock().apply_event(&Event::QueueTaken {
@@ -327,6 +335,8 @@ fn prune_works_without_runbook_definition() {
         item_id: "old-item-1".to_string(),
         data: data_map,
         pushed_at_ms: old_epoch_ms(),
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     ctx.state.lock().apply_event(&Event::QueueCompleted {
@@ -47,6 +47,59 @@ job "triage" {
     dir
 }
 
+/// Helper: create a project dir with a persisted queue keyed on `branch`.
+fn project_with_dedupe_queue() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let runbook_dir = dir.path().join(".oj/runbooks");
+    std::fs::create_dir_all(&runbook_dir).unwrap();
+    std::fs::write(
+        runbook_dir.join("test.hcl"),
+        r#"
+queue "merges" {
+  type   = "persisted"
+  vars   = ["branch", "title"]
+  dedupe = "${item.branch}"
+}
+"#,
+    )
+    .unwrap();
+    dir
+}
+
+/// Push `branch`/`title` to the dedupe queue, which already holds
+/// `existing-1` for branch "fix-1" in the given status.
+fn push_to_dedupe_queue(
+    existing_status: Option<Event>,
+    branch: &str,
+    title: &str,
+) -> (Response, Vec<Event>) {
+    let project = project_with_dedupe_queue();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+
+    let mut initial_state = MaterializedState::default();
+    initial_state.apply_event(&Event::QueuePushed {
+        queue: "merges".to_string(),
+        item_id: "existing-1".to_string(),
+        data: [("branch", "fix-1"), ("title", "first try")]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
+        project: String::new(),
+    });
+    if let Some(event) = existing_status {
+        initial_state.apply_event(&event);
+    }
+    let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
+
+    let data = serde_json::json!({ "branch": branch, "title": title });
+    let result = handle_queue_push(&ctx, project.path(), "", "merges", data, None, None).unwrap();
+    (result, drain_events(&wal))
+}
+
 // ── Push worker management tests ──────────────────────────────────────
 
 #[test]
@@ -58,7 +111,7 @@ fn push_auto_starts_stopped_worker() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     assert!(
         matches!(result, Response::QueuePushed { ref queue, .. } if queue == "tasks"),
@@ -110,13 +163,14 @@ fn push_wakes_running_worker() {
             queue: "tasks".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
             project: String::new(),
         },
     );
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     assert!(matches!(result, Response::QueuePushed { .. }));
 
@@ -144,7 +198,7 @@ fn push_with_no_workers_succeeds() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({ "task": "test-value" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     assert!(matches!(result, Response::QueuePushed { .. }));
 
@@ -154,6 +208,25 @@ fn push_with_no_workers_succeeds() {
     assert!(matches!(&events[0], Event::QueuePushed { .. }));
 }
 
+#[test]
+fn push_records_priority_and_not_before() {
+    let project = project_with_queue_only();
+    let wal_dir = tempdir().unwrap();
+    let (event_bus, wal, _) = test_event_bus(wal_dir.path());
+    let state = Arc::new(Mutex::new(MaterializedState::default()));
+    let ctx = make_ctx(event_bus, state);
+
+    let data = serde_json::json!({ "task": "nightly" });
+    handle_queue_push(&ctx, project.path(), "", "tasks", data, Some(7), Some(5_000)).unwrap();
+
+    let events = drain_events(&wal);
+    assert!(
+        matches!(&events[0], Event::QueuePushed { priority: 7, not_before_ms: Some(5_000), .. }),
+        "unexpected event: {:?}",
+        events[0]
+    );
+}
+
 // ── External queue push tests ─────────────────────────────────────────
 
 #[test]
@@ -175,6 +248,7 @@ fn push_external_queue_wakes_workers() {
             queue: "issues".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
             project: String::new(),
         },
     );
@@ -182,7 +256,7 @@ fn push_external_queue_wakes_workers() {
 
     // Push with empty data — should refresh, not error
     let data = serde_json::json!({});
-    let result = handle_queue_push(&ctx, project.path(), "", "issues", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "issues", data, None, None).unwrap();
 
     assert!(matches!(result, Response::Ok), "expected Ok, got {:?}", result);
 
@@ -208,7 +282,7 @@ fn push_external_queue_auto_starts_stopped_worker() {
     let ctx = make_ctx(event_bus, state);
 
     let data = serde_json::json!({});
-    let result = handle_queue_push(&ctx, project.path(), "", "issues", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "issues", data, None, None).unwrap();
 
     assert!(matches!(result, Response::Ok), "expected Ok, got {:?}", result);
 
@@ -246,13 +320,15 @@ fn push_deduplicates_pending_item_with_same_data() {
         item_id: "existing-item-1".to_string(),
         data: [("task".to_string(), "build-feature-x".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     // Push the same data again
     let data = serde_json::json!({ "task": "build-feature-x" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     // Should return the existing item ID, not create a new one
     assert!(
@@ -287,6 +363,8 @@ fn push_deduplicates_active_item_with_same_data() {
         item_id: "active-item-1".to_string(),
         data: [("task".to_string(), "build-feature-y".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     initial_state.apply_event(&Event::QueueTaken {
@@ -299,7 +377,7 @@ fn push_deduplicates_active_item_with_same_data() {
 
     // Push the same data again
     let data = serde_json::json!({ "task": "build-feature-y" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     // Should return the existing active item ID
     assert!(
@@ -332,6 +410,8 @@ fn push_allows_duplicate_data_when_previous_is_completed() {
         item_id: "completed-item-1".to_string(),
         data: [("task".to_string(), "build-feature-z".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     initial_state.apply_event(&Event::QueueCompleted {
@@ -343,7 +423,7 @@ fn push_allows_duplicate_data_when_previous_is_completed() {
 
     // Push the same data again — should succeed since the previous item is completed
     let data = serde_json::json!({ "task": "build-feature-z" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     // Should create a new item (different ID from completed one)
     match result {
@@ -374,6 +454,8 @@ fn push_allows_duplicate_data_when_previous_is_dead() {
         item_id: "dead-item-1".to_string(),
         data: [("task".to_string(), "build-feature-w".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     initial_state.apply_event(&Event::QueueDead {
@@ -385,7 +467,7 @@ fn push_allows_duplicate_data_when_previous_is_dead() {
 
     // Push the same data again — should succeed since the previous item is dead
     let data = serde_json::json!({ "task": "build-feature-w" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     match result {
         Response::QueuePushed { ref queue, ref item_id } => {
@@ -415,13 +497,15 @@ fn push_different_data_is_not_deduplicated() {
         item_id: "existing-item-1".to_string(),
         data: [("task".to_string(), "build-feature-x".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     let ctx = make_ctx(event_bus, Arc::new(Mutex::new(initial_state)));
 
     // Push different data — should create a new item
     let data = serde_json::json!({ "task": "build-feature-y" });
-    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data).unwrap();
+    let result = handle_queue_push(&ctx, project.path(), "", "tasks", data, None, None).unwrap();
 
     match result {
         Response::QueuePushed { ref queue, ref item_id } => {
@@ -460,6 +544,7 @@ fn push_with_wrong_project_path_falls_back_to_namespace() {
             queue: "tasks".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
             project: "my-project".to_string(),
         },
     );
@@ -467,9 +552,16 @@ fn push_with_wrong_project_path_falls_back_to_namespace() {
 
     // Call with a wrong project_path (simulating --project from a different directory).
     let data = serde_json::json!({ "task": "test-value" });
-    let result =
-        handle_queue_push(&ctx, std::path::Path::new("/wrong/path"), "my-project", "tasks", data)
-            .unwrap();
+    let result = handle_queue_push(
+        &ctx,
+        std::path::Path::new("/wrong/path"),
+        "my-project",
+        "tasks",
+        data,
+        None,
+        None,
+    )
+    .unwrap();
 
     assert!(
         matches!(result, Response::QueuePushed { ref queue, .. } if queue == "tasks"),
@@ -477,3 +569,63 @@ fn push_with_wrong_project_path_falls_back_to_namespace() {
         result
     );
 }
+
+// ── Dedupe key tests ──────────────────────────────────────────────────
+
+#[test]
+fn push_with_matching_dedupe_key_returns_existing_item() {
+    let (result, events) = push_to_dedupe_queue(None, "fix-1", "second try");
+
+    assert!(
+        matches!(result, Response::QueuePushed { ref item_id, .. } if item_id == "existing-1"),
+        "expected existing item, got {:?}",
+        result
+    );
+    assert!(!events.iter().any(|e| matches!(e, Event::QueuePushed { .. })));
+}
+
+#[test]
+fn push_with_matching_dedupe_key_returns_active_item() {
+    let taken = Event::QueueTaken {
+        queue: "merges".to_string(),
+        item_id: "existing-1".to_string(),
+        worker: "merger".to_string(),
+        project: String::new(),
+    };
+    let (result, _) = push_to_dedupe_queue(Some(taken), "fix-1", "second try");
+
+    assert!(
+        matches!(result, Response::QueuePushed { ref item_id, .. } if item_id == "existing-1"),
+        "expected existing item, got {:?}",
+        result
+    );
+}
+
+#[test]
+fn push_with_different_dedupe_key_creates_item() {
+    let (result, events) = push_to_dedupe_queue(None, "fix-2", "first try");
+
+    assert!(
+        matches!(result, Response::QueuePushed { ref item_id, .. } if item_id != "existing-1"),
+        "expected new item, got {:?}",
+        result
+    );
+    assert!(events.iter().any(|e| matches!(e, Event::QueuePushed { .. })));
+}
+
+#[test]
+fn push_with_dedupe_key_of_completed_item_creates_item() {
+    let completed = Event::QueueCompleted {
+        queue: "merges".to_string(),
+        item_id: "existing-1".to_string(),
+        project: String::new(),
+    };
+    let (result, events) = push_to_dedupe_queue(Some(completed), "fix-1", "second try");
+
+    assert!(
+        matches!(result, Response::QueuePushed { ref item_id, .. } if item_id != "existing-1"),
+        "expected new item, got {:?}",
+        result
+    );
+    assert!(events.iter().any(|e| matches!(e, Event::QueuePushed { .. })));
+}
@@ -221,6 +221,8 @@ fn retry_all_dead_items() {
         item_id: "pending-1".to_string(),
         data: [("task".to_string(), "p1".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
 
@@ -304,6 +306,8 @@ fn retry_bulk_mixed_results() {
         item_id: "pending-1".to_string(),
         data: [("task".to_string(), "p1".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     // "nonexistent" doesn't exist
@@ -384,6 +388,7 @@ fn retry_with_wrong_project_path_falls_back_to_namespace() {
             queue: "tasks".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
             project: "my-project".to_string(),
         },
     );
@@ -393,6 +398,8 @@ fn retry_with_wrong_project_path_falls_back_to_namespace() {
         item_id: "item-dead-1".to_string(),
         data: [("task".to_string(), "retry-me".to_string())].into_iter().collect(),
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: "my-project".to_string(),
     });
     initial.apply_event(&Event::QueueDead {
@@ -441,6 +448,8 @@ fn retry_works_without_runbook_definition() {
         item_id: "dead-orphan-1".to_string(),
         data: data_map,
         pushed_at_ms: 1_000_000,
+        priority: 0,
+        not_before_ms: None,
         project: String::new(),
     });
     ctx.state.lock().apply_event(&Event::QueueDead {
@@ -491,6 +500,8 @@ fn retry_all_dead_works_without_runbook_definition() {
             item_id: format!("dead-{}", i),
             data: data_map,
             pushed_at_ms: 1_000_000,
+            priority: 0,
+            not_before_ms: None,
             project: String::new(),
         });
         ctx.state.lock().apply_event(&Event::QueueDead {
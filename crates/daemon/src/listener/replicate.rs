@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Daemon-side handler for `Replicate`.
+//!
+//! A connection-upgrading request: after `Replicating`, the WAL is streamed
+//! to the replica as length-prefixed `ReplicaFrame`s. A replica resuming
+//! from a seq the WAL no longer holds (or one this daemon never wrote) gets
+//! a snapshot of state first. Entries are read from disk with a `WalTail`,
+//! so only flushed events are sent.
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, info};
+
+use crate::protocol::{self, Response};
+use crate::replica::{ReplicaEntry, ReplicaFrame};
+use crate::storage::{WalError, WalTail};
+
+use super::{ConnectionError, ListenCtx};
+
+/// How often the WAL is read when no events wake the stream. Events
+/// appended while handling another are flushed without a publish.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Idle time after which a heartbeat is sent.
+pub(crate) const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Time allowed for writing a snapshot, which can be much larger than the
+/// messages `ipc_timeout` is meant for.
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Handle a `Replicate` request, streaming the WAL until the replica goes away.
+pub(super) async fn handle_replicate<R, W>(
+    after_seq: u64,
+    mut reader: R,
+    mut writer: W,
+    ctx: &ListenCtx,
+) -> Result<(), ConnectionError>
+where
+    R: AsyncRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+{
+    // Published events only wake the stream; entries come from the WAL
+    let mut wake = ctx.event_bus.subscribe();
+    let mut probe = [0u8; 1];
+    let (dir, write_seq) = {
+        let wal = ctx.event_bus.wal.lock();
+        (wal.dir().to_path_buf(), wal.write_seq())
+    };
+    protocol::write_response(&mut writer, &Response::Replicating, super::ipc_timeout()).await?;
+    info!(after_seq, "replica connected");
+
+    // A replica ahead of the WAL followed some other daemon's history.
+    // The seq of the last snapshot sent tells a WAL gap from a slow replica.
+    let (mut tail, mut snapshot_seq) = if after_seq > write_seq {
+        let tail = send_snapshot(&mut writer, ctx, &dir).await?;
+        let seq = tail.seq();
+        (tail, Some(seq))
+    } else {
+        (WalTail::new(&dir, after_seq), None)
+    };
+    let mut last_sent = Instant::now();
+
+    loop {
+        let entries = match tail.read() {
+            Ok(entries) => entries,
+            Err(WalError::Truncated(seq)) if snapshot_seq != Some(seq) => {
+                debug!(seq, "replica is behind the WAL, sending a snapshot");
+                tail = send_snapshot(&mut writer, ctx, &dir).await?;
+                snapshot_seq = Some(tail.seq());
+                last_sent = Instant::now();
+                continue;
+            }
+            Err(e) => return Err(ConnectionError::Internal(format!("failed to read WAL: {e}"))),
+        };
+
+        let frame = if !entries.is_empty() {
+            // Custom events are never serialized
+            let entries = entries
+                .into_iter()
+                .filter(|e| !matches!(e.event, oj_core::Event::Custom))
+                .map(|e| ReplicaEntry { seq: e.seq, event: e.event })
+                .collect();
+            Some(ReplicaFrame::Entries { entries })
+        } else if last_sent.elapsed() >= HEARTBEAT_INTERVAL {
+            Some(ReplicaFrame::Heartbeat { seq: tail.seq() })
+        } else {
+            None
+        };
+        if let Some(frame) = frame {
+            if send(&mut writer, &frame, super::ipc_timeout()).await.is_err() {
+                debug!("replica disconnected");
+                return Ok(());
+            }
+            last_sent = Instant::now();
+        }
+
+        tokio::select! {
+            received = wake.recv() => {
+                if let Err(RecvError::Closed) = received {
+                    return Ok(());
+                }
+            }
+            _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            // Replicas never send after the request; a read returning
+            // means the replica hung up.
+            _ = reader.read(&mut probe) => {
+                info!(seq = tail.seq(), "replica disconnected");
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Send state as of the last processed entry and return a tail following
+/// the WAL from there.
+async fn send_snapshot<W>(
+    writer: &mut W,
+    ctx: &ListenCtx,
+    dir: &Path,
+) -> Result<WalTail, ConnectionError>
+where
+    W: AsyncWrite + Unpin + Send,
+{
+    // Same lock order as the checkpoint task: state, then WAL
+    let (seq, state) = {
+        let state = ctx.state.lock();
+        let seq = ctx.event_bus.wal.lock().processed_seq();
+        (seq, state.clone())
+    };
+    let frame = ReplicaFrame::Snapshot { seq, state: Box::new(state) };
+    send(writer, &frame, SNAPSHOT_TIMEOUT).await?;
+    Ok(WalTail::new(dir, seq))
+}
+
+async fn send<W>(
+    writer: &mut W,
+    frame: &ReplicaFrame,
+    timeout: Duration,
+) -> Result<(), ConnectionError>
+where
+    W: AsyncWriteExt + Unpin,
+{
+    let data = protocol::encode(frame)?;
+    tokio::time::timeout(timeout, protocol::write_message(writer, &data))
+        .await
+        .map_err(|_| protocol::ProtocolError::Timeout)??;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "replicate_tests.rs"]
+mod tests;
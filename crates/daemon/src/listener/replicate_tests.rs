@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::sync::Arc;
+
+use oj_core::{Event, JobId};
+use tempfile::tempdir;
+use tokio::io::DuplexStream;
+
+use super::super::test_ctx_with_wal;
+use super::super::test_fixtures::make_job;
+use super::*;
+use crate::replica::Replica;
+
+fn advanced(job: &str) -> Event {
+    Event::JobAdvanced { id: JobId::from_string(job), step: "review".to_string() }
+}
+
+/// Start streaming to a replica that has applied `after_seq`, returning the
+/// replica's end once `Replicating` has been read.
+async fn connect(ctx: &Arc<ListenCtx>, after_seq: u64) -> DuplexStream {
+    let (mut client, server) = tokio::io::duplex(1024 * 1024);
+    let (server_read, server_write) = tokio::io::split(server);
+    tokio::spawn({
+        let ctx = Arc::clone(ctx);
+        async move { handle_replicate(after_seq, server_read, server_write, &ctx).await }
+    });
+    let ack = protocol::read_message(&mut client).await.unwrap();
+    assert_eq!(protocol::decode::<Response>(&ack).unwrap(), Response::Replicating);
+    client
+}
+
+async fn next_frame(client: &mut DuplexStream) -> ReplicaFrame {
+    protocol::decode(&protocol::read_message(client).await.unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn streams_wal_entries_after_the_replicas_seq() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(dir.path());
+    let ctx = Arc::new(ctx);
+    {
+        let mut wal = wal.lock();
+        wal.append(&advanced("job-1")).unwrap();
+        wal.append(&advanced("job-2")).unwrap();
+        wal.flush().unwrap();
+    }
+
+    let mut client = connect(&ctx, 1).await;
+    let ReplicaFrame::Entries { entries } = next_frame(&mut client).await else {
+        unreachable!("expected entries")
+    };
+    assert_eq!(entries.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(entries[0].event, advanced("job-2"));
+
+    // Later flushes follow once an event wakes the stream
+    wal.lock().append(&advanced("job-3")).unwrap();
+    wal.lock().flush().unwrap();
+    ctx.event_bus.publish(&advanced("job-3"));
+    let ReplicaFrame::Entries { entries } = next_frame(&mut client).await else {
+        unreachable!("expected entries")
+    };
+    assert_eq!(entries.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![3]);
+}
+
+#[tokio::test]
+async fn replica_ahead_of_the_wal_gets_a_snapshot() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(dir.path());
+    ctx.state.lock().jobs.insert("job-1".to_string(), make_job("job-1", "plan"));
+    {
+        let mut wal = wal.lock();
+        wal.append(&advanced("job-1")).unwrap();
+        wal.flush().unwrap();
+        wal.mark_processed(1);
+    }
+    let ctx = Arc::new(ctx);
+
+    let mut client = connect(&ctx, 40).await;
+    let mut replica = Replica::default();
+    let frame = next_frame(&mut client).await;
+    assert!(matches!(frame, ReplicaFrame::Snapshot { seq: 1, .. }), "{frame:?}");
+    replica.apply(frame);
+
+    assert_eq!(replica.seq(), 1);
+    assert_eq!(replica.state.lock().jobs["job-1"].step, "plan");
+}
+
+#[tokio::test]
+async fn replica_behind_a_checkpoint_gets_a_snapshot() {
+    let dir = tempdir().unwrap();
+    let (ctx, wal) = test_ctx_with_wal(dir.path());
+    {
+        let mut wal = wal.lock();
+        wal.set_segment_bytes(1);
+        for job in ["job-1", "job-2", "job-3"] {
+            wal.append(&advanced(job)).unwrap();
+            wal.flush().unwrap();
+        }
+        wal.mark_processed(2);
+        wal.truncate_before(3).unwrap();
+    }
+    let ctx = Arc::new(ctx);
+
+    let mut client = connect(&ctx, 0).await;
+
+    let frame = next_frame(&mut client).await;
+    assert!(matches!(frame, ReplicaFrame::Snapshot { seq: 2, .. }), "{frame:?}");
+    let ReplicaFrame::Entries { entries } = next_frame(&mut client).await else {
+        unreachable!("expected entries")
+    };
+    assert_eq!(entries.iter().map(|e| e.seq).collect::<Vec<_>>(), vec![3]);
+}
+
+#[tokio::test]
+async fn stops_when_the_replica_disconnects() {
+    let dir = tempdir().unwrap();
+    let (ctx, _wal) = test_ctx_with_wal(dir.path());
+    let (mut client, server) = tokio::io::duplex(1024);
+    let (server_read, server_write) = tokio::io::split(server);
+
+    let handler =
+        tokio::spawn(async move { handle_replicate(0, server_read, server_write, &ctx).await });
+    protocol::read_message(&mut client).await.unwrap();
+    drop(client);
+
+    handler.await.unwrap().unwrap();
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Daemon-side handler for `Subscribe`.
+//!
+//! A connection-upgrading request: after `Subscribed`, every processed event
+//! that passes the client's filter is written as one JSON line until the
+//! client disconnects.
+
+use oj_core::{Event, OwnerId};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{debug, warn};
+
+use crate::protocol::{self, EventFilter, Response};
+use crate::storage::MaterializedState;
+
+use super::{ConnectionError, ListenCtx};
+
+/// Handle a `Subscribe` request, streaming events until the client goes away.
+pub(super) async fn handle_subscribe<R, W>(
+    filter: EventFilter,
+    mut reader: R,
+    mut writer: W,
+    ctx: &ListenCtx,
+) -> Result<(), ConnectionError>
+where
+    R: AsyncRead + Unpin + Send,
+    W: AsyncWrite + Unpin + Send,
+{
+    // Subscribe before acknowledging so nothing published after the client
+    // sees `Subscribed` is missed.
+    let mut events = ctx.event_bus.subscribe();
+    let mut probe = [0u8; 1];
+    protocol::write_response(&mut writer, &Response::Subscribed, super::ipc_timeout()).await?;
+
+    loop {
+        let event = tokio::select! {
+            received = events.recv() => match received {
+                Ok(event) => event,
+                Err(RecvError::Lagged(missed)) => {
+                    warn!(missed, "subscriber fell behind, dropped events");
+                    continue;
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            },
+            // Subscribers never send after the request; a read returning
+            // means the client hung up.
+            _ = reader.read(&mut probe) => {
+                debug!("subscriber disconnected");
+                return Ok(());
+            }
+        };
+
+        let Some(line) = frame(&filter, &event, &ctx.state.lock()) else {
+            continue;
+        };
+        if writer.write_all(&line).await.is_err() {
+            debug!("subscriber disconnected");
+            return Ok(());
+        }
+    }
+}
+
+/// Encode an event as a JSON line, or `None` when the filter rejects it.
+fn frame(filter: &EventFilter, event: &Event, state: &MaterializedState) -> Option<Vec<u8>> {
+    // Custom events are never serialized
+    let value = serde_json::to_value(event).ok()?;
+    let owner = event_owner(event, state);
+    let project = value
+        .get("project")
+        .and_then(|p| p.as_str())
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .or_else(|| owner.and_then(|o| owner_project(&o, state)));
+
+    let owner = owner.map(|o| o.to_string());
+    if !filter.matches(event.name(), owner.as_deref(), project.as_deref()) {
+        return None;
+    }
+    let mut line = serde_json::to_vec(&value).ok()?;
+    line.push(b'\n');
+    Some(line)
+}
+
+/// Owner named by the event, or looked up from the agent or decision it
+/// refers to.
+fn event_owner(event: &Event, state: &MaterializedState) -> Option<OwnerId> {
+    event.owner().or_else(|| match event {
+        Event::AgentInput { id, .. }
+        | Event::AgentRespond { id, .. }
+        | Event::AgentIdle { id }
+        | Event::AgentStopBlocked { id }
+        | Event::AgentStopAllowed { id }
+        | Event::AgentPrompt { id, .. } => state.agents.get(id.as_str()).map(|a| a.owner),
+        Event::DecisionResolved { id, .. } => state.decisions.get(id.as_str()).map(|d| d.owner),
+        _ => None,
+    })
+}
+
+fn owner_project(owner: &OwnerId, state: &MaterializedState) -> Option<String> {
+    match owner {
+        OwnerId::Job(id) => state.jobs.get(id.as_str()).map(|job| job.project.clone()),
+        OwnerId::Crew(id) => state.crew.get(id.as_str()).map(|crew| crew.project.clone()),
+    }
+}
+
+#[cfg(test)]
+#[path = "subscribe_tests.rs"]
+mod tests;
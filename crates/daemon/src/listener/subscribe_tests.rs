@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::sync::Arc;
+
+use oj_core::{DecisionId, JobId};
+use tempfile::tempdir;
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use super::super::test_ctx;
+use super::super::test_fixtures::{make_decision, make_job_ns};
+use super::*;
+
+fn advanced(job: &str) -> Event {
+    Event::JobAdvanced { id: JobId::from_string(job), step: "review".to_string() }
+}
+
+fn filter(project: Option<&str>, owners: &[&str], kinds: &[&str]) -> EventFilter {
+    EventFilter {
+        project: project.map(str::to_string),
+        owners: owners.iter().map(|s| s.to_string()).collect(),
+        kinds: kinds.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+fn state_with_job() -> MaterializedState {
+    let mut state = MaterializedState::default();
+    state.jobs.insert("job-1".to_string(), make_job_ns("job-1", "build", "api"));
+    state
+}
+
+#[test]
+fn frames_event_as_json_line() {
+    let line = frame(&EventFilter::default(), &advanced("job-1"), &state_with_job()).unwrap();
+
+    assert_eq!(line.last(), Some(&b'\n'));
+    let decoded: Event = serde_json::from_slice(&line).unwrap();
+    assert_eq!(decoded, advanced("job-1"));
+}
+
+#[yare::parameterized(
+    job_project = { Some("api"), true },
+    other_project = { Some("web"), false },
+    any_project = { None, true },
+)]
+fn project_is_resolved_from_owner(project: Option<&str>, expected: bool) {
+    let line = frame(&filter(project, &[], &[]), &advanced("job-1"), &state_with_job());
+    assert_eq!(line.is_some(), expected);
+}
+
+#[test]
+fn unknown_job_only_matches_without_project() {
+    let state = MaterializedState::default();
+    assert!(frame(&filter(Some("api"), &[], &[]), &advanced("job-9"), &state).is_none());
+    assert!(frame(&filter(None, &["job-9"], &[]), &advanced("job-9"), &state).is_some());
+}
+
+#[test]
+fn decision_resolution_uses_decision_owner() {
+    let mut state = MaterializedState::default();
+    state.decisions.insert("dec-1".to_string(), make_decision("dec-1", "job-1", 1000));
+    let resolved = Event::DecisionResolved {
+        id: DecisionId::from_string("dec-1"),
+        project: "oddjobs".to_string(),
+        resolved_at_ms: 2000,
+        choices: vec![1],
+        message: None,
+        timed_out: false,
+    };
+
+    assert!(frame(&filter(None, &["job-1"], &["decision"]), &resolved, &state).is_some());
+    assert!(frame(&filter(None, &["job-2"], &[]), &resolved, &state).is_none());
+}
+
+#[test]
+fn custom_events_are_skipped() {
+    assert!(frame(&EventFilter::default(), &Event::Custom, &state_with_job()).is_none());
+}
+
+#[tokio::test]
+async fn streams_matching_events_until_client_disconnects() {
+    let dir = tempdir().unwrap();
+    let ctx = Arc::new(test_ctx(dir.path()));
+    ctx.state.lock().jobs.insert("job-1".to_string(), make_job_ns("job-1", "build", "api"));
+    let (client, server) = tokio::io::duplex(64 * 1024);
+    let (server_read, server_write) = tokio::io::split(server);
+
+    let handler = tokio::spawn({
+        let ctx = Arc::clone(&ctx);
+        async move {
+            handle_subscribe(filter(None, &["job-1"], &[]), server_read, server_write, &ctx).await
+        }
+    });
+
+    let mut client = BufReader::new(client);
+    let ack = protocol::read_message(&mut client).await.unwrap();
+    assert_eq!(protocol::decode::<Response>(&ack).unwrap(), Response::Subscribed);
+
+    ctx.event_bus.publish(&advanced("job-2"));
+    ctx.event_bus.publish(&advanced("job-1"));
+    let mut line = String::new();
+    client.read_line(&mut line).await.unwrap();
+    assert_eq!(serde_json::from_str::<Event>(&line).unwrap(), advanced("job-1"));
+
+    drop(client);
+    handler.await.unwrap().unwrap();
+}
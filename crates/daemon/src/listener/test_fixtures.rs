@@ -239,6 +239,7 @@ pub fn make_worker(name: &str, project: &str, queue: &str, active: usize) -> Wor
         queue: queue.to_string(),
         concurrency: 3,
         owners: HashMap::new(),
+        dispatched_at_ms: vec![],
     }
 }
 
@@ -251,6 +252,9 @@ pub fn make_queue_item(id: &str, status: QueueItemStatus) -> QueueItem {
         worker: None,
         pushed_at_ms: 0,
         failures: 0,
+        priority: 0,
+        not_before_ms: None,
+        retry_at_ms: None,
     }
 }
 
@@ -262,6 +266,9 @@ pub fn make_cron(name: &str, project: &str, project_path: &str) -> CronRecord {
         runbook_hash: String::new(),
         status: "running".to_string(),
         interval: "5m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("check"),
         started_at_ms: 0,
         last_fired_at_ms: None,
@@ -283,5 +290,10 @@ pub fn make_decision(id: &str, job_id: &str, created_at_ms: u64) -> Decision {
         resolved_at_ms: None,
         superseded_by: None,
         project: "oddjobs".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        timed_out: false,
+        assignee: None,
+        reviewers: vec![],
     }
 }
@@ -111,6 +111,7 @@ fn stop_error_suggestions(
                 queue: "tasks".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: ns.to_string(),
             },
         );
@@ -159,6 +160,7 @@ fn restart_stops_existing_then_starts() {
                 queue: "tasks".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: String::new(),
             },
         );
@@ -221,6 +223,7 @@ job "handle" {
                 queue: "tasks".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: String::new(),
             },
         );
@@ -285,6 +288,7 @@ fn resolve_effective_project_path_uses_known_path_when_namespace_differs() {
             queue: "merges".to_string(),
             concurrency: 1,
             owners: HashMap::new(),
+            dispatched_at_ms: vec![],
             project: "wok".to_string(),
         },
     );
@@ -377,6 +381,7 @@ job "handle-merge" {
                 queue: "other".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: "wok".to_string(),
             },
         );
@@ -440,6 +445,7 @@ job "build" {
                 queue: "bugs".to_string(),
                 concurrency: 3,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: String::new(),
             },
         );
@@ -487,6 +493,7 @@ fn stop_all_stops_running_workers_in_namespace() {
                 queue: "q1".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: "proj".to_string(),
             },
         );
@@ -501,6 +508,7 @@ fn stop_all_stops_running_workers_in_namespace() {
                 queue: "q2".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: "proj".to_string(),
             },
         );
@@ -515,6 +523,7 @@ fn stop_all_stops_running_workers_in_namespace() {
                 queue: "q3".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: "proj".to_string(),
             },
         );
@@ -530,6 +539,7 @@ fn stop_all_stops_running_workers_in_namespace() {
                 queue: "q4".to_string(),
                 concurrency: 1,
                 owners: HashMap::new(),
+                dispatched_at_ms: vec![],
                 project: "other".to_string(),
             },
         );
@@ -165,3 +165,53 @@ async fn early_disconnect_cancels_via_token() {
     assert_eq!(result, "disconnected");
     assert!(token.is_cancelled());
 }
+
+/// Send `requests` to `authenticate_tcp` as a TCP client would, returning
+/// what it handed on and the response to the Hello.
+async fn tcp_handshake(
+    requests: &[super::Request],
+    auth_token: Option<&str>,
+) -> (Option<super::Request>, super::Response) {
+    use super::protocol;
+
+    let (mut client, server) = tokio::io::duplex(64 * 1024);
+    let (mut reader, mut writer) = tokio::io::split(server);
+    for request in requests {
+        protocol::write_message(&mut client, &protocol::encode(request).unwrap()).await.unwrap();
+    }
+    let first = protocol::read_request(&mut reader, super::ipc_timeout()).await.unwrap();
+    let handed_on =
+        super::authenticate_tcp(first, &mut reader, &mut writer, auth_token).await.unwrap();
+    let response = protocol::read_message(&mut client).await.unwrap();
+    (handed_on, protocol::decode(&response).unwrap())
+}
+
+fn hello(token: Option<&str>) -> super::Request {
+    super::Request::Hello { version: "test".to_string(), token: token.map(str::to_string) }
+}
+
+#[tokio::test]
+async fn tcp_hello_with_token_carries_next_request() {
+    let (handed_on, response) =
+        tcp_handshake(&[hello(Some("secret")), super::Request::Status], Some("secret")).await;
+
+    assert!(matches!(response, super::Response::Hello { .. }), "{response:?}");
+    assert_eq!(handed_on, Some(super::Request::Status));
+}
+
+#[tokio::test]
+async fn tcp_hello_with_wrong_token_is_refused() {
+    let (handed_on, response) =
+        tcp_handshake(&[hello(Some("guess")), super::Request::Status], Some("secret")).await;
+
+    assert_eq!(response, super::Response::Error { message: "unauthorized".to_string() });
+    assert_eq!(handed_on, None);
+}
+
+#[tokio::test]
+async fn tcp_must_start_with_hello() {
+    let (handed_on, response) = tcp_handshake(&[super::Request::Status], None).await;
+
+    assert!(matches!(response, super::Response::Error { .. }), "{response:?}");
+    assert_eq!(handed_on, None);
+}
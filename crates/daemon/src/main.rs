@@ -21,18 +21,24 @@ mod adapters;
 mod engine;
 mod env;
 mod event_bus;
+mod fsck;
+mod inspect;
 mod lifecycle;
 mod listener;
+mod metrics;
+mod migrate;
 mod protocol;
+mod replica;
 mod storage;
+mod transfer;
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 
-use crate::storage::{Checkpointer, MaterializedState, Wal};
+use crate::storage::{Checkpointer, JobArchive, MaterializedState, Wal};
 use oj_core::{Clock, Event, JobId};
 use tokio::signal::unix::{signal, SignalKind};
 use tokio::sync::Notify;
@@ -41,6 +47,7 @@ use tracing::{error, info};
 use crate::event_bus::EventBus;
 use crate::lifecycle::{Config, LifecycleError, StartupResult};
 use crate::listener::Listener;
+use crate::metrics::DaemonMetrics;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -62,14 +69,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 println!("be invoked directly. It listens on a Unix socket for commands");
                 println!("from `oj`.");
                 println!();
+                println!("COMMANDS:");
+                println!("    export           Write state to a file for moving to another host");
+                println!("                     (ojd export <FILE>)");
+                println!("    fsck             Check the WAL and snapshot for damage");
+                println!("                     (ojd fsck [--repair])");
+                println!("    import           Load an export into an empty state directory");
+                println!("                     (ojd import [--map <OLD>=<NEW> ...] <FILE>)");
+                println!("    inspect          Show state as of a WAL sequence number or time");
+                println!("                     (ojd inspect [--seq <N> | --at <TIME>] [QUERY])");
+                println!("    migrate          Upgrade stored state to the current schema version");
+                println!("                     (ojd migrate [--dry-run])");
+                println!("    replica          Follow another daemon's WAL and serve it read-only");
+                println!("                     (ojd replica <HOST:PORT>)");
+                println!();
                 println!("OPTIONS:");
                 println!("    -h, --help       Print help information");
                 println!("    -v, --version    Print version information");
                 return Ok(());
             }
+            "export" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(transfer::export_main(&args));
+            }
+            "fsck" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(fsck::main(&args));
+            }
+            "import" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(transfer::import_main(&args));
+            }
+            "inspect" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(inspect::main(&args));
+            }
+            "migrate" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(migrate::main(&args));
+            }
+            "replica" => {
+                let args: Vec<String> = std::env::args().skip(2).collect();
+                std::process::exit(replica::main(&args).await);
+            }
             _ => {
                 eprintln!("error: unexpected argument '{arg}'");
-                eprintln!("Usage: ojd [--help | --version]");
+                eprintln!("Usage: ojd [--help | --version | export ... | fsck ... | import ... | inspect ... | migrate ... | replica ...]");
                 std::process::exit(1);
             }
         }
@@ -139,13 +184,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         state: Arc::clone(&daemon.state),
         orphans: Arc::clone(&daemon.orphans),
         metrics_health: Arc::clone(&daemon.metrics_health),
+        metrics: Arc::clone(&daemon.metrics),
         state_dir: daemon.config.state_dir.clone(),
         logs_path: daemon.config.logs_path.clone(),
         start_time: daemon.start_time,
         shutdown: Arc::clone(&shutdown_notify),
         auth_token: crate::env::auth_token(),
+        decision_secret: crate::env::decision_secret(),
         agent: Arc::clone(&daemon.agent),
+        archive: JobArchive::new(&daemon.config.archive_path),
     });
+    if let Some(addr) = crate::env::metrics_addr() {
+        let metrics_listener = tokio::net::TcpListener::bind(&addr).await.map_err(|e| {
+            let msg = format!("Failed to bind metrics address {}: {}", addr, e);
+            error!("{}", msg);
+            Box::<dyn std::error::Error>::from(msg)
+        })?;
+        info!("Metrics exporter listening on http://{}/metrics", addr);
+        tokio::spawn(listener::serve_metrics(metrics_listener, Arc::clone(&ctx)));
+    }
     let listener = if let Some(port) = crate::env::tcp_port() {
         let tcp_listener = tokio::net::TcpListener::bind(("0.0.0.0", port)).await.map_err(|e| {
             let msg = format!("Failed to bind TCP port {}: {}", port, e);
@@ -164,6 +221,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Arc::clone(&daemon.state),
         Arc::clone(&event_reader.wal),
         daemon.config.snapshot_path.clone(),
+        Arc::clone(&daemon.metrics),
+    );
+
+    // Spawn archive task to move old terminal jobs out of state
+    spawn_archiver(
+        Arc::clone(&daemon.state),
+        daemon.event_bus.clone(),
+        JobArchive::new(&daemon.config.archive_path),
     );
 
     // Spawn flush task for group commit (~10ms durability window)
@@ -376,69 +441,192 @@ fn spawn_flush_task(event_bus: EventBus) {
 /// Checkpoint interval (60 seconds)
 const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(60);
 
+/// Deltas to accumulate before folding them into a full snapshot
+const COMPACT_AFTER_DELTAS: usize = 30;
+
 /// Spawn a task that periodically saves snapshots and truncates WAL.
 ///
-/// This provides durability with bounded recovery time. Checkpoints run in a
-/// background thread to minimize main thread blocking — only the state clone
-/// happens on the async task, with serialization/compression/I/O on a dedicated
-/// thread.
+/// This provides durability with bounded recovery time. Each checkpoint
+/// writes a delta of the entities changed since the last one, so only the
+/// changed entries are copied under the lock, with serialization/compression/
+/// I/O on a dedicated thread. Once enough deltas pile up they are compacted
+/// into a full snapshot, also off the main thread.
 ///
 /// ## Durability Guarantee
 ///
-/// WAL truncation only happens after the snapshot is fully durable:
-/// 1. Snapshot written to temp file
+/// WAL truncation only happens after the delta is fully durable:
+/// 1. Delta written to temp file
 /// 2. Temp file fsync'd
 /// 3. Atomic rename to final path
 /// 4. Directory fsync'd (makes rename durable across power loss)
 /// 5. THEN truncate WAL
 ///
 /// This ordering ensures no data loss even on crash during checkpoint.
+///
+/// Full snapshots are also kept in history for `OJ_WAL_RETAIN_MS`, and the
+/// WAL is only truncated up to the oldest of them, so `ojd inspect` can
+/// replay that far back.
 fn spawn_checkpoint(
     state: Arc<Mutex<MaterializedState>>,
     event_wal: Arc<Mutex<Wal>>,
     snapshot_path: PathBuf,
+    metrics: Arc<DaemonMetrics>,
 ) {
-    let checkpointer = Checkpointer::new(snapshot_path);
+    let checkpointer = Checkpointer::new(snapshot_path.clone());
+    let retain = crate::env::wal_retain();
+    // Nothing has been processed since startup, so this is the seq of the
+    // loaded snapshot; changes replayed from the WAL are still dirty
+    let mut last_seq = event_wal.lock().processed_seq();
 
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(CHECKPOINT_INTERVAL);
+        // After a failed delta the dirty entries are lost, so the next
+        // checkpoint must write the whole state
+        let mut force_full = false;
 
         loop {
             interval.tick().await;
+            let started = Instant::now();
 
-            // Get current state and processed seq (brief lock)
-            let (state_ref, processed_seq) = {
-                let state_guard = state.lock();
+            // Collect changes and processed seq (brief lock)
+            let full = force_full;
+            let (handle, processed_seq) = {
+                let mut state_guard = state.lock();
                 let wal_guard = event_wal.lock();
-                (state_guard.clone(), wal_guard.processed_seq())
+                let processed_seq = wal_guard.processed_seq();
+                if processed_seq == last_seq && !force_full {
+                    continue;
+                }
+                let handle = if force_full {
+                    state_guard.clear_dirty();
+                    checkpointer.start(processed_seq, &state_guard)
+                } else {
+                    checkpointer.start_delta(last_seq, processed_seq, state_guard.take_delta())
+                };
+                (handle, processed_seq)
             };
 
-            // Only checkpoint if we've processed some events
-            if processed_seq == 0 {
-                continue;
-            }
-
-            // Start background checkpoint (state clone happens here, I/O on thread)
-            let handle = checkpointer.start(processed_seq, &state_ref);
-
             // Wait for checkpoint to be fully durable before truncating WAL
             // This runs on a thread pool, so we spawn_blocking to await it
             let result = tokio::task::spawn_blocking(move || handle.wait()).await;
 
-            match result {
-                Ok(Ok(_)) => {
+            let deltas = match result {
+                Ok(Ok(result)) => {
+                    metrics.checkpoint_completed(started.elapsed());
+                    force_full = false;
+                    last_seq = processed_seq;
+                    if full {
+                        keep_history(&snapshot_path, processed_seq, retain);
+                    }
                     // NOW safe to truncate WAL (snapshot is durable)
+                    let truncate_seq = wal_truncate_seq(&snapshot_path, retain, processed_seq);
                     let mut wal = event_wal.lock();
-                    if let Err(e) = wal.truncate_before(processed_seq) {
+                    if let Err(e) = wal.truncate_before(truncate_seq) {
                         tracing::warn!(error = %e, "failed to truncate WAL after checkpoint");
                     }
+                    result.deltas
                 }
                 Ok(Err(e)) => {
+                    metrics.checkpoint_failed();
+                    force_full = true;
                     tracing::warn!(error = %e, "checkpoint failed, WAL not truncated");
+                    continue;
                 }
                 Err(e) => {
+                    metrics.checkpoint_failed();
+                    force_full = true;
                     tracing::warn!(error = %e, "checkpoint task panicked");
+                    continue;
+                }
+            };
+
+            if deltas >= COMPACT_AFTER_DELTAS {
+                let handle = checkpointer.start_compaction();
+                match tokio::task::spawn_blocking(move || handle.wait()).await {
+                    Ok(Ok(result)) => {
+                        tracing::debug!(
+                            seq = result.seq,
+                            size_bytes = result.size_bytes,
+                            "compacted snapshot deltas"
+                        );
+                        keep_history(&snapshot_path, result.seq, retain);
+                    }
+                    Ok(Err(e)) => tracing::warn!(error = %e, "snapshot compaction failed"),
+                    Err(e) => tracing::warn!(error = %e, "snapshot compaction panicked"),
+                }
+            }
+        }
+    });
+}
+
+/// Keep the full snapshot just written for `ojd inspect`.
+fn keep_history(snapshot_path: &Path, seq: u64, retain: Duration) {
+    if retain.is_zero() {
+        return;
+    }
+    if let Err(e) = crate::storage::keep_in_history(snapshot_path, seq) {
+        tracing::warn!(error = %e, seq, "failed to keep snapshot in history");
+    }
+}
+
+/// Seq the WAL can be truncated before. With retention on, entries after
+/// the oldest snapshot in history are kept; until the first one is kept,
+/// nothing is truncated.
+fn wal_truncate_seq(snapshot_path: &Path, retain: Duration, processed_seq: u64) -> u64 {
+    if retain.is_zero() {
+        return processed_seq;
+    }
+    match crate::storage::prune_history(snapshot_path, retain, SystemTime::now()) {
+        Ok(oldest) => oldest.map_or(0, |seq| seq.min(processed_seq)),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to prune snapshot history, WAL not truncated");
+            0
+        }
+    }
+}
+
+/// Archive interval (10 minutes)
+const ARCHIVE_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Spawn a task that periodically moves old terminal jobs into the archive.
+///
+/// Jobs are written to the archive (durably) before `job:archived` removes
+/// them from state, so a crash in between archives them twice rather than
+/// losing them.
+fn spawn_archiver(state: Arc<Mutex<MaterializedState>>, event_bus: EventBus, archive: JobArchive) {
+    let retention_ms = crate::env::archive_after().as_millis() as u64;
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ARCHIVE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            let now_ms = oj_core::SystemClock.epoch_ms();
+            let jobs = crate::storage::due_for_archive(&state.lock(), now_ms, retention_ms);
+            if jobs.is_empty() {
+                continue;
+            }
+
+            let archive = archive.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                archive.append(&jobs).map(|()| jobs.into_iter().map(|j| JobId::from_string(&j.id)))
+            })
+            .await;
+
+            match result {
+                Ok(Ok(ids)) => {
+                    let mut count = 0;
+                    for id in ids {
+                        if let Err(e) = event_bus.send(Event::JobArchived { id }) {
+                            tracing::warn!(error = %e, "failed to record archived job");
+                        }
+                        count += 1;
+                    }
+                    info!(count, "archived terminal jobs");
                 }
+                Ok(Err(e)) => tracing::warn!(error = %e, "failed to archive jobs"),
+                Err(e) => tracing::warn!(error = %e, "archive task panicked"),
             }
         }
     });
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Values recorded by background tasks for the metrics exporter.
+//!
+//! Most exported metrics (jobs, queues, agents, decisions) are computed
+//! from state when scraped. This holds the rest: figures that only exist
+//! while a task runs, such as checkpoint timings and token usage fetched
+//! by the usage collector. Everything resets when the daemon restarts.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+/// Shared recorder, written by the checkpoint task and usage collector.
+#[derive(Debug, Default)]
+pub(crate) struct DaemonMetrics {
+    checkpoint: Mutex<CheckpointStats>,
+    /// Token usage by project
+    tokens: Mutex<BTreeMap<String, TokenCounts>>,
+}
+
+/// Checkpoints written since startup.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct CheckpointStats {
+    pub completed: u64,
+    pub failed: u64,
+    /// Total time spent on completed checkpoints
+    pub duration_sum: Duration,
+    /// Time the most recent completed checkpoint took
+    pub last_duration: Option<Duration>,
+}
+
+/// Cumulative token counts for one project.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TokenCounts {
+    pub input: u64,
+    pub output: u64,
+    pub cache_write: u64,
+    pub cache_read: u64,
+}
+
+impl DaemonMetrics {
+    /// Record a checkpoint that became durable after `duration`.
+    pub fn checkpoint_completed(&self, duration: Duration) {
+        let mut stats = self.checkpoint.lock();
+        stats.completed += 1;
+        stats.duration_sum += duration;
+        stats.last_duration = Some(duration);
+    }
+
+    /// Record a checkpoint that failed to write.
+    pub fn checkpoint_failed(&self) {
+        self.checkpoint.lock().failed += 1;
+    }
+
+    pub fn checkpoint(&self) -> CheckpointStats {
+        *self.checkpoint.lock()
+    }
+
+    /// Replace token usage with the collector's latest totals.
+    pub fn set_tokens(&self, by_project: BTreeMap<String, TokenCounts>) {
+        *self.tokens.lock() = by_project;
+    }
+
+    pub fn tokens(&self) -> BTreeMap<String, TokenCounts> {
+        self.tokens.lock().clone()
+    }
+}
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `ojd migrate` — upgrade on-disk state to the current schema version.
+//!
+//! The daemon migrates old snapshots and WAL entries as it loads them, so
+//! upgrading without this works too. `--dry-run` shows what that would
+//! change first: the migrations that apply to each snapshot, delta, and WAL
+//! file, the state keys they add, remove or change, and WAL events replay
+//! would ignore. It also loads the state the way startup does, so a
+//! migration that would keep the daemon from starting fails here instead.
+//!
+//! Without `--dry-run`, old files are rewritten at the current version.
+//! That needs the daemon stopped; its lock is held while rewriting.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::lifecycle::{Config, LifecycleError, StateLock};
+use crate::storage::{
+    load_snapshot, read_unmigrated, snapshot_version, CheckpointError, Checkpointer,
+    MigrationError, MigrationRegistry, SnapshotError, Wal, WalError, WalFileReport,
+    CURRENT_SNAPSHOT_VERSION,
+};
+
+const USAGE: &str = "Usage: ojd migrate [--state-dir <DIR>] [--dry-run]";
+
+/// Changes listed per file before the rest are counted
+const MAX_CHANGES_SHOWN: usize = 20;
+
+#[derive(Debug, Error)]
+pub(crate) enum MigrateError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+    #[error("failed to load snapshot: {0}")]
+    Snapshot(#[from] SnapshotError),
+    #[error("failed to read WAL: {0}")]
+    Wal(#[from] WalError),
+    #[error("failed to write snapshot: {0}")]
+    Checkpoint(#[from] CheckpointError),
+    #[error("{0}")]
+    Migration(#[from] MigrationError),
+    #[error("{0}")]
+    Lock(#[from] LifecycleError),
+}
+
+/// A key the migrations add, remove, or change, as a dotted path.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Change {
+    Added(String),
+    Removed(String),
+    Changed(String),
+}
+
+/// How one snapshot or delta file would change.
+#[derive(Debug)]
+pub(crate) struct FilePlan {
+    pub path: PathBuf,
+    pub version: u32,
+    /// Descriptions of the migrations that apply, in order
+    pub migrations: Vec<&'static str>,
+    pub changes: Vec<Change>,
+}
+
+/// Everything an upgrade would touch.
+#[derive(Debug)]
+pub(crate) struct Plan {
+    pub files: Vec<FilePlan>,
+    pub wal: Vec<WalFileReport>,
+    /// Sequence number the migrated snapshot loads at
+    pub seq: u64,
+}
+
+impl Plan {
+    fn snapshot_outdated(&self) -> bool {
+        self.files.iter().any(|f| f.version != CURRENT_SNAPSHOT_VERSION)
+    }
+
+    fn wal_outdated(&self) -> bool {
+        self.wal.iter().any(|f| f.version != CURRENT_SNAPSHOT_VERSION)
+    }
+}
+
+/// Work out what upgrading the state under `config` would change, without
+/// changing it.
+pub(crate) fn plan(config: &Config) -> Result<Plan, MigrateError> {
+    let registry = MigrationRegistry::new();
+    let mut files = Vec::new();
+    for (path, before) in read_unmigrated(&config.snapshot_path)? {
+        let version = snapshot_version(&before);
+        let migrations = registry
+            .path(version, CURRENT_SNAPSHOT_VERSION)?
+            .iter()
+            .map(|m| m.description())
+            .collect();
+        let after = registry.migrate_to(before.clone(), CURRENT_SNAPSHOT_VERSION)?;
+        let mut changes = Vec::new();
+        diff("", &before, &after, &mut changes);
+        changes.retain(|c| *c != Change::Changed("v".to_string()));
+        files.push(FilePlan { path, version, migrations, changes });
+    }
+    let wal = if config.wal_path.exists() { Wal::survey(&config.wal_path)? } else { vec![] };
+
+    // Load the way startup does, so a broken migration fails here
+    let seq = load_snapshot(&config.snapshot_path)?.map_or(0, |s| s.seq);
+    Ok(Plan { files, wal, seq })
+}
+
+/// Record the keys `after` adds, removes, or changes relative to `before`.
+fn diff(path: &str, before: &Value, after: &Value, changes: &mut Vec<Change>) {
+    let (Value::Object(old), Value::Object(new)) = (before, after) else {
+        if before != after {
+            changes.push(Change::Changed(path.to_string()));
+        }
+        return;
+    };
+    let key_path =
+        |key: &str| if path.is_empty() { key.to_string() } else { format!("{path}.{key}") };
+    for (key, old_value) in old {
+        match new.get(key) {
+            Some(new_value) => diff(&key_path(key), old_value, new_value, changes),
+            None => changes.push(Change::Removed(key_path(key))),
+        }
+    }
+    for key in new.keys().filter(|k| !old.contains_key(*k)) {
+        changes.push(Change::Added(key_path(key)));
+    }
+}
+
+/// Describe a plan, with paths relative to the state directory.
+pub(crate) fn render(plan: &Plan, state_dir: &Path) -> String {
+    let name = |path: &Path| path.strip_prefix(state_dir).unwrap_or(path).display().to_string();
+    let versions = |version: u32| {
+        if version == CURRENT_SNAPSHOT_VERSION {
+            format!("v{version} (current)")
+        } else {
+            format!("v{version} → v{CURRENT_SNAPSHOT_VERSION}")
+        }
+    };
+
+    let mut out = String::new();
+    for file in &plan.files {
+        let _ = writeln!(out, "{}: {}", name(&file.path), versions(file.version));
+        for description in &file.migrations {
+            let _ = writeln!(out, "  migration: {description}");
+        }
+        for change in file.changes.iter().take(MAX_CHANGES_SHOWN) {
+            let _ = match change {
+                Change::Added(key) => writeln!(out, "  + {key}"),
+                Change::Removed(key) => writeln!(out, "  - {key}"),
+                Change::Changed(key) => writeln!(out, "  ~ {key}"),
+            };
+        }
+        if file.changes.len() > MAX_CHANGES_SHOWN {
+            let _ = writeln!(out, "  ... and {} more", file.changes.len() - MAX_CHANGES_SHOWN);
+        }
+    }
+    for file in &plan.wal {
+        let _ = write!(
+            out,
+            "{}: {}, {} entries",
+            name(&file.path),
+            versions(file.version),
+            file.entries
+        );
+        if file.version != CURRENT_SNAPSHOT_VERSION {
+            let _ = write!(out, ", {} changed by migrations", file.migrated);
+        }
+        let _ = writeln!(out);
+        for (tag, count) in &file.unknown {
+            let _ = writeln!(out, "  {count} × unknown event type '{tag}', ignored on replay");
+        }
+        if file.undecodable > 0 {
+            let _ = writeln!(out, "  {} entries no longer parse", file.undecodable);
+        }
+    }
+    if plan.files.is_empty() && plan.wal.is_empty() {
+        let _ = writeln!(out, "no snapshot or WAL found");
+    }
+    out
+}
+
+/// Rewrite files from older versions at the current one.
+fn apply(config: &Config, plan: &Plan) -> Result<(), MigrateError> {
+    if plan.wal_outdated() {
+        // Opening imports a legacy WAL and repairs damage
+        drop(Wal::open(&config.wal_path, plan.seq)?);
+        let rewritten = Wal::upgrade(&config.wal_path)?;
+        println!("rewrote {} WAL segment(s) at v{}", rewritten, CURRENT_SNAPSHOT_VERSION);
+    }
+    if plan.snapshot_outdated() {
+        if let Some(snapshot) = load_snapshot(&config.snapshot_path)? {
+            Checkpointer::new(config.snapshot_path.clone())
+                .checkpoint_sync(snapshot.seq, &snapshot.state)?;
+            println!("rewrote snapshot at v{} (seq {})", CURRENT_SNAPSHOT_VERSION, snapshot.seq);
+        }
+    }
+    Ok(())
+}
+
+/// Entry point for `ojd migrate`. Returns the process exit code.
+pub(crate) fn main(args: &[String]) -> i32 {
+    match run(args) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_args(args)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    let _lock = if parsed.dry_run { None } else { Some(StateLock::acquire(&config)?) };
+
+    let plan = plan(&config)?;
+    print!("{}", render(&plan, &config.state_dir));
+    if !plan.snapshot_outdated() && !plan.wal_outdated() {
+        println!("state is at v{}; nothing to migrate", CURRENT_SNAPSHOT_VERSION);
+    } else if parsed.dry_run {
+        println!("dry run: nothing was changed");
+    } else {
+        apply(&config, &plan)?;
+    }
+    Ok(())
+}
+
+/// Parsed `ojd migrate` arguments.
+#[derive(Debug, PartialEq)]
+struct MigrateArgs {
+    state_dir: Option<PathBuf>,
+    dry_run: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<MigrateArgs, MigrateError> {
+    let mut parsed = MigrateArgs { state_dir: None, dry_run: false };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--dry-run" => parsed.dry_run = true,
+            "--state-dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| MigrateError::Usage("--state-dir needs a value".to_string()))?;
+                parsed.state_dir = Some(PathBuf::from(value));
+            }
+            other => {
+                return Err(MigrateError::Usage(format!("unexpected argument '{}'", other)));
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+#[path = "migrate_tests.rs"]
+mod tests;
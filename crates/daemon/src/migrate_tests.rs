@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use tempfile::tempdir;
+
+use super::*;
+
+const V1_SNAPSHOT: &str = include_str!("../tests/fixtures/storage/v1/snapshot.json");
+const V1_SEGMENT: &[u8] =
+    include_bytes!("../tests/fixtures/storage/v1/wal/00000000000000000001.seg");
+
+/// A state directory as a v1 daemon left it.
+fn v1_state_dir(dir: &Path) -> Config {
+    let config = Config::at(dir.to_path_buf());
+    std::fs::write(&config.snapshot_path, zstd::encode_all(V1_SNAPSHOT.as_bytes(), 3).unwrap())
+        .unwrap();
+    std::fs::create_dir_all(&config.wal_path).unwrap();
+    std::fs::write(config.wal_path.join("00000000000000000001.seg"), V1_SEGMENT).unwrap();
+    config
+}
+
+fn args(input: &[&str]) -> Vec<String> {
+    input.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn plans_v1_upgrade() {
+    let dir = tempdir().unwrap();
+    let config = v1_state_dir(dir.path());
+
+    let plan = plan(&config).unwrap();
+
+    assert_eq!(plan.seq, 2);
+    let [snapshot] = plan.files.as_slice() else {
+        unreachable!("expected one snapshot file, got {:?}", plan.files);
+    };
+    assert_eq!((snapshot.version, snapshot.migrations.len()), (1, 1));
+    assert!(snapshot.changes.contains(&Change::Removed("state.sessions".to_string())));
+    assert!(snapshot.changes.contains(&Change::Added("state.agents".to_string())));
+    assert!(snapshot.changes.contains(&Change::Added("state.project_paths".to_string())));
+    let [segment] = plan.wal.as_slice() else {
+        unreachable!("expected one WAL segment, got {:?}", plan.wal);
+    };
+    assert_eq!((segment.version, segment.entries, segment.undecodable), (1, 5, 0));
+    assert!(segment.unknown.is_empty());
+}
+
+#[test]
+fn render_names_files_and_changes() {
+    let dir = tempdir().unwrap();
+    let config = v1_state_dir(dir.path());
+
+    let out = render(&plan(&config).unwrap(), &config.state_dir);
+
+    assert!(out.contains("snapshot.json: v1 → v2\n"), "{out}");
+    assert!(out.contains("  - state.sessions\n"), "{out}");
+    assert!(out.contains("  + state.agents\n"), "{out}");
+    assert!(
+        out.contains("wal/00000000000000000001.seg: v1 → v2, 5 entries, 0 changed by migrations\n"),
+        "{out}"
+    );
+}
+
+#[test]
+fn reports_unknown_events_in_legacy_wal() {
+    let dir = tempdir().unwrap();
+    let config = Config::at(dir.path().to_path_buf());
+    std::fs::create_dir_all(&config.wal_path).unwrap();
+    std::fs::write(
+        config.wal_path.join("events.wal"),
+        concat!(
+            r#"{"seq":1,"event":{"type":"session:created","id":"s1"}}"#,
+            "\n",
+            r#"{"seq":2,"event":{"type":"session:created","id":"s2"}}"#,
+            "\n",
+            r#"{"seq":3,"event":{"type":"system:shutdown"}}"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+
+    let plan = plan(&config).unwrap();
+
+    let [legacy] = plan.wal.as_slice() else {
+        unreachable!("expected the legacy WAL, got {:?}", plan.wal);
+    };
+    assert_eq!((legacy.version, legacy.entries), (1, 3));
+    assert_eq!(legacy.unknown.get("session:created"), Some(&2));
+    let out = render(&plan, &config.state_dir);
+    assert!(out.contains("2 × unknown event type 'session:created'"), "{out}");
+}
+
+#[test]
+fn dry_run_changes_nothing() {
+    let dir = tempdir().unwrap();
+    let config = v1_state_dir(dir.path());
+    let before = std::fs::read(&config.snapshot_path).unwrap();
+
+    let state_dir = dir.path().to_str().unwrap();
+    assert_eq!(main(&args(&["--state-dir", state_dir, "--dry-run"])), 0);
+
+    assert_eq!(std::fs::read(&config.snapshot_path).unwrap(), before);
+    assert_eq!(
+        std::fs::read(config.wal_path.join("00000000000000000001.seg")).unwrap(),
+        V1_SEGMENT
+    );
+    assert!(!config.lock_path.exists());
+}
+
+#[test]
+fn migrate_rewrites_at_current_version() {
+    let dir = tempdir().unwrap();
+    let config = v1_state_dir(dir.path());
+
+    let state_dir = dir.path().to_str().unwrap();
+    assert_eq!(main(&args(&["--state-dir", state_dir])), 0);
+
+    let plan = plan(&config).unwrap();
+    assert!(!plan.snapshot_outdated() && !plan.wal_outdated(), "{plan:?}");
+    assert_eq!(plan.seq, 2);
+    let entries = Wal::read_after(&config.wal_path, 2).unwrap();
+    assert_eq!(entries.iter().map(|e| e.seq).collect::<Vec<_>>(), [3, 4, 5]);
+    assert!(!config.lock_path.exists());
+}
+
+#[test]
+fn migrate_refuses_while_daemon_holds_lock() {
+    let dir = tempdir().unwrap();
+    let config = v1_state_dir(dir.path());
+    let daemon_lock = std::fs::File::create(&config.lock_path).unwrap();
+    fs2::FileExt::lock_exclusive(&daemon_lock).unwrap();
+    let before = std::fs::read(&config.snapshot_path).unwrap();
+
+    let state_dir = dir.path().to_str().unwrap();
+    assert_eq!(main(&args(&["--state-dir", state_dir])), 1);
+
+    assert_eq!(std::fs::read(&config.snapshot_path).unwrap(), before);
+    assert!(config.lock_path.exists());
+}
+
+#[test]
+fn empty_state_dir_has_nothing_to_migrate() {
+    let dir = tempdir().unwrap();
+    let plan = plan(&Config::at(dir.path().to_path_buf())).unwrap();
+    assert!(plan.files.is_empty() && plan.wal.is_empty());
+    assert_eq!(render(&plan, dir.path()), "no snapshot or WAL found\n");
+}
+
+#[yare::parameterized(
+    none = { &[], None, false },
+    dry_run = { &["--dry-run"], None, true },
+    state_dir = { &["--state-dir", "/backup/oj", "--dry-run"], Some("/backup/oj"), true },
+)]
+fn parses_flags(input: &[&str], state_dir: Option<&str>, dry_run: bool) {
+    assert_eq!(
+        parse_args(&args(input)).unwrap(),
+        MigrateArgs { state_dir: state_dir.map(PathBuf::from), dry_run }
+    );
+}
+
+#[yare::parameterized(
+    state_dir_missing = { &["--state-dir"] },
+    unknown_flag = { &["--force"] },
+    positional = { &["now"] },
+)]
+fn rejects_bad_flags(input: &[&str]) {
+    assert!(matches!(parse_args(&args(input)), Err(MigrateError::Usage(_))));
+}
@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Connecting to the primary and applying its replication stream.
+
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tracing::{info, warn};
+
+use crate::env::{ipc_timeout, PROTOCOL_VERSION};
+use crate::listener::HEARTBEAT_INTERVAL;
+use crate::protocol::{self, ProtocolError, Request, Response};
+
+use super::{Replica, ReplicaError, ReplicaFrame};
+
+/// First delay before reconnecting; doubled after each failed attempt.
+const RECONNECT_MIN: Duration = Duration::from_secs(1);
+
+/// Longest delay between reconnect attempts.
+const RECONNECT_MAX: Duration = Duration::from_secs(30);
+
+/// Silence after which the primary is taken to be gone. It sends a
+/// heartbeat every `HEARTBEAT_INTERVAL` while idle.
+const STALL_TIMEOUT: Duration = Duration::from_secs(3 * HEARTBEAT_INTERVAL.as_secs());
+
+/// Follow `primary` until the process stops, reconnecting after failures
+/// and resuming from the last applied entry.
+pub(super) async fn follow(primary: &str, token: Option<String>, replica: &mut Replica) {
+    let mut delay = RECONNECT_MIN;
+    loop {
+        let before = replica.seq();
+        let result = match TcpStream::connect(primary).await {
+            Ok(stream) => {
+                let (reader, writer) = stream.into_split();
+                stream_from(reader, writer, token.clone(), replica).await
+            }
+            Err(e) => Err(e.into()),
+        };
+        match result {
+            Err(ReplicaError::Protocol(ProtocolError::ConnectionClosed)) | Ok(()) => {
+                info!(primary, seq = replica.seq(), "primary closed the replication stream");
+            }
+            Err(e) => warn!(primary, seq = replica.seq(), "replication failed: {}", e),
+        }
+
+        // Progress means the primary was reachable; retry promptly
+        if replica.seq() != before {
+            delay = RECONNECT_MIN;
+        }
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(RECONNECT_MAX);
+    }
+}
+
+/// Authenticate, request the stream from the last applied entry, and apply
+/// frames until the connection ends.
+pub(super) async fn stream_from<R, W>(
+    mut reader: R,
+    mut writer: W,
+    token: Option<String>,
+    replica: &mut Replica,
+) -> Result<(), ReplicaError>
+where
+    R: AsyncReadExt + Unpin,
+    W: AsyncWriteExt + Unpin,
+{
+    let hello = Request::Hello { version: PROTOCOL_VERSION.to_string(), token };
+    match exchange(&mut reader, &mut writer, &hello).await? {
+        Response::Hello { .. } => {}
+        Response::Error { message } => return Err(ReplicaError::Refused(message)),
+        other => return Err(ReplicaError::Unexpected(format!("{:?}", other))),
+    }
+
+    let request = Request::Replicate { after_seq: replica.seq() };
+    match exchange(&mut reader, &mut writer, &request).await? {
+        Response::Replicating => {}
+        Response::Error { message } => return Err(ReplicaError::Refused(message)),
+        other => return Err(ReplicaError::Unexpected(format!("{:?}", other))),
+    }
+    info!(after_seq = replica.seq(), "following primary");
+
+    loop {
+        let bytes = tokio::time::timeout(STALL_TIMEOUT, protocol::read_message(&mut reader))
+            .await
+            .map_err(|_| ReplicaError::Stalled(STALL_TIMEOUT.as_secs()))??;
+        let frame: ReplicaFrame = protocol::decode(&bytes)?;
+        replica.apply(frame);
+    }
+}
+
+async fn exchange<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    request: &Request,
+) -> Result<Response, ReplicaError>
+where
+    R: AsyncReadExt + Unpin,
+    W: AsyncWriteExt + Unpin,
+{
+    let data = protocol::encode(request)?;
+    tokio::time::timeout(ipc_timeout(), protocol::write_message(writer, &data))
+        .await
+        .map_err(|_| ProtocolError::Timeout)??;
+    let bytes = tokio::time::timeout(ipc_timeout(), protocol::read_message(reader))
+        .await
+        .map_err(|_| ProtocolError::Timeout)??;
+    Ok(protocol::decode(&bytes)?)
+}
@@ -0,0 +1,220 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `ojd replica` — a read-only daemon following another daemon's WAL.
+//!
+//! The replica connects to the primary's TCP listener, authenticates with
+//! `Hello`, and sends `Replicate` with the last sequence number it applied.
+//! The primary answers with a snapshot when that entry is no longer in its
+//! WAL, then streams WAL entries as they are flushed. Each entry goes
+//! through `MaterializedState::apply_event`, the same path as replay on
+//! startup, so the replica's state matches the primary's as of that entry.
+//!
+//! State is kept in memory only: a restarted replica starts over from a
+//! snapshot. The replica answers `Query` and `Status` on its own socket
+//! (and TCP port, when `OJ_TCP_PORT` is set) and refuses everything that
+//! would change state. It never runs the engine, so it never spawns agents.
+
+mod follow;
+mod serve;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use oj_core::Event;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::net::{TcpListener, UnixListener};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::Notify;
+use tracing::info;
+
+use crate::lifecycle::{Config, LifecycleError, StateLock};
+use crate::protocol::ProtocolError;
+use crate::storage::{JobArchive, MaterializedState};
+
+const USAGE: &str = "Usage: ojd replica [--state-dir <DIR>] <PRIMARY>";
+
+#[derive(Debug, Error)]
+pub(crate) enum ReplicaError {
+    #[error("{0}\n{USAGE}")]
+    Usage(String),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("protocol error: {0}")]
+    Protocol(#[from] ProtocolError),
+    #[error("primary refused replication: {0}")]
+    Refused(String),
+    #[error("unexpected response from primary: {0}")]
+    Unexpected(String),
+    #[error("nothing from primary for {0}s")]
+    Stalled(u64),
+}
+
+/// One message of the replication stream, after `Replicating`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum ReplicaFrame {
+    /// The primary's state as of `seq`, replacing the replica's
+    Snapshot { seq: u64, state: Box<MaterializedState> },
+    /// WAL entries in order
+    Entries { entries: Vec<ReplicaEntry> },
+    /// Sent while the WAL is idle, so a silent primary can be told apart
+    /// from a dead one
+    Heartbeat { seq: u64 },
+}
+
+/// A WAL entry as sent to replicas.
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ReplicaEntry {
+    pub seq: u64,
+    pub event: Event,
+}
+
+/// State materialized from the primary's stream.
+#[derive(Default)]
+pub(crate) struct Replica {
+    pub state: Arc<Mutex<MaterializedState>>,
+    /// Last sequence number applied
+    seq: u64,
+}
+
+impl Replica {
+    /// Last sequence number applied, where the stream resumes.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Apply a frame from the primary.
+    pub fn apply(&mut self, frame: ReplicaFrame) {
+        match frame {
+            ReplicaFrame::Snapshot { seq, state } => {
+                info!(seq, "loaded snapshot from primary");
+                *self.state.lock() = *state;
+                self.seq = seq;
+            }
+            ReplicaFrame::Entries { entries } => {
+                // Seqs can skip: custom events are not sent
+                let mut state = self.state.lock();
+                for entry in entries {
+                    if entry.seq > self.seq {
+                        state.apply_event(&entry.event);
+                        self.seq = entry.seq;
+                    }
+                }
+            }
+            // Everything up to `seq` has been sent, so a stream ending in
+            // unsent entries resumes after them
+            ReplicaFrame::Heartbeat { seq } => self.seq = self.seq.max(seq),
+        }
+    }
+}
+
+/// Entry point for `ojd replica`. Runs until stopped; returns the process
+/// exit code.
+pub(crate) async fn main(args: &[String]) -> i32 {
+    match run(args).await {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    }
+}
+
+async fn run(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_args(args)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    // Also keeps a primary from starting on the same state directory
+    let _lock = StateLock::acquire(&config)?;
+    let _log_guard = crate::setup_logging(&config)?;
+
+    if config.socket_path.exists() {
+        std::fs::remove_file(&config.socket_path)?;
+    }
+    let unix = UnixListener::bind(&config.socket_path)
+        .map_err(|e| LifecycleError::BindFailed(config.socket_path.clone(), e))?;
+    let tcp = match crate::env::tcp_port() {
+        Some(port) => Some(TcpListener::bind(("0.0.0.0", port)).await?),
+        None => None,
+    };
+
+    let mut replica = Replica::default();
+    let shutdown = Arc::new(Notify::new());
+    let ctx = Arc::new(serve::ServeCtx {
+        state: Arc::clone(&replica.state),
+        orphans: Arc::new(Mutex::new(Vec::new())),
+        metrics_health: Arc::new(Mutex::new(Default::default())),
+        logs_path: config.logs_path.clone(),
+        archive: JobArchive::new(&config.archive_path),
+        start_time: Instant::now(),
+        auth_token: crate::env::auth_token(),
+        shutdown: Arc::clone(&shutdown),
+    });
+    tokio::spawn(serve::serve(unix, tcp, ctx));
+    info!(primary = %parsed.primary, "Replica ready, listening on {}", config.socket_path.display());
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    tokio::select! {
+        _ = follow::follow(&parsed.primary, crate::env::primary_token(), &mut replica) => {}
+        _ = sigterm.recv() => info!("Received SIGTERM, shutting down replica"),
+        _ = sigint.recv() => info!("Received SIGINT, shutting down replica"),
+        _ = shutdown.notified() => info!("Shutdown requested, shutting down replica"),
+    }
+
+    let _ = std::fs::remove_file(&config.socket_path);
+    Ok(())
+}
+
+/// Parsed `ojd replica` arguments.
+#[derive(Debug, PartialEq)]
+struct ReplicaArgs {
+    state_dir: Option<PathBuf>,
+    /// Primary's `host:port`
+    primary: String,
+}
+
+fn parse_args(args: &[String]) -> Result<ReplicaArgs, ReplicaError> {
+    let mut state_dir = None;
+    let mut primary = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--state-dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| ReplicaError::Usage("--state-dir needs a value".to_string()))?;
+                state_dir = Some(PathBuf::from(value));
+            }
+            flag if flag.starts_with('-') => {
+                return Err(ReplicaError::Usage(format!("unexpected argument '{}'", flag)));
+            }
+            addr if primary.is_none() => {
+                let addr = addr.strip_prefix("tcp://").unwrap_or(addr);
+                if !addr.contains(':') {
+                    return Err(ReplicaError::Usage(format!(
+                        "primary '{}' must be host:port",
+                        arg
+                    )));
+                }
+                primary = Some(addr.trim_end_matches('/').to_string());
+            }
+            other => {
+                return Err(ReplicaError::Usage(format!("unexpected argument '{}'", other)));
+            }
+        }
+    }
+    let primary =
+        primary.ok_or_else(|| ReplicaError::Usage("missing primary address".to_string()))?;
+    Ok(ReplicaArgs { state_dir, primary })
+}
+
+#[cfg(test)]
+#[path = "mod_tests.rs"]
+mod tests;
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+
+use oj_core::JobId;
+
+use super::*;
+use crate::protocol;
+
+fn args(input: &[&str]) -> Vec<String> {
+    input.iter().map(|s| s.to_string()).collect()
+}
+
+fn job_created(id: &str) -> Event {
+    Event::JobCreated {
+        id: JobId::from_string(id),
+        kind: "build".to_string(),
+        name: id.to_string(),
+        runbook_hash: "abc".to_string(),
+        cwd: PathBuf::from("/src/api"),
+        vars: HashMap::new(),
+        initial_step: "plan".to_string(),
+        created_at_ms: 1_000,
+        project: "api".to_string(),
+        cron: None,
+    }
+}
+
+fn advanced(id: &str, step: &str) -> Event {
+    Event::JobAdvanced { id: JobId::from_string(id), step: step.to_string() }
+}
+
+fn entries(events: &[(u64, Event)]) -> ReplicaFrame {
+    ReplicaFrame::Entries {
+        entries: events
+            .iter()
+            .map(|(seq, e)| ReplicaEntry { seq: *seq, event: e.clone() })
+            .collect(),
+    }
+}
+
+/// Frames as the replica receives them: encoded by the primary and decoded
+/// from bytes.
+fn sent(frame: &ReplicaFrame) -> ReplicaFrame {
+    protocol::decode(&protocol::encode(frame).unwrap()).unwrap()
+}
+
+#[test]
+fn entries_are_applied_in_order() {
+    let mut replica = Replica::default();
+
+    replica.apply(sent(&entries(&[(1, job_created("job-1")), (2, advanced("job-1", "build"))])));
+
+    assert_eq!(replica.seq(), 2);
+    assert_eq!(replica.state.lock().jobs["job-1"].step, "build");
+}
+
+#[test]
+fn entries_already_applied_are_skipped() {
+    let mut replica = Replica::default();
+    replica.apply(entries(&[(1, job_created("job-1")), (2, advanced("job-1", "build"))]));
+
+    replica.apply(entries(&[(2, advanced("job-1", "plan")), (3, advanced("job-1", "ship"))]));
+
+    assert_eq!(replica.seq(), 3);
+    assert_eq!(replica.state.lock().jobs["job-1"].step, "ship");
+}
+
+#[test]
+fn snapshot_replaces_state() {
+    let mut replica = Replica::default();
+    replica.apply(entries(&[(1, job_created("job-1"))]));
+    let mut primary = MaterializedState::default();
+    primary.apply_event(&job_created("job-2"));
+
+    replica.apply(sent(&ReplicaFrame::Snapshot { seq: 9, state: Box::new(primary) }));
+
+    assert_eq!(replica.seq(), 9);
+    let state = replica.state.lock();
+    assert!(!state.jobs.contains_key("job-1"));
+    assert_eq!(state.jobs["job-2"].cwd, PathBuf::from("/src/api"));
+}
+
+#[test]
+fn heartbeat_moves_resume_point_past_unsent_entries() {
+    let mut replica = Replica::default();
+    replica.apply(entries(&[(1, job_created("job-1"))]));
+
+    replica.apply(sent(&ReplicaFrame::Heartbeat { seq: 3 }));
+    replica.apply(ReplicaFrame::Heartbeat { seq: 2 });
+
+    assert_eq!(replica.seq(), 3);
+}
+
+#[yare::parameterized(
+    host_port = { &["primary:7777"], None, "primary:7777" },
+    url = { &["tcp://10.0.0.5:7777/"], None, "10.0.0.5:7777" },
+    state_dir = { &["--state-dir", "/var/lib/oj-replica", "primary:7777"], Some("/var/lib/oj-replica"), "primary:7777" },
+)]
+fn parses_args(input: &[&str], state_dir: Option<&str>, primary: &str) {
+    assert_eq!(
+        parse_args(&args(input)).unwrap(),
+        ReplicaArgs { state_dir: state_dir.map(PathBuf::from), primary: primary.to_string() }
+    );
+}
+
+#[yare::parameterized(
+    missing_primary = { &[] },
+    no_port = { &["primary"] },
+    two_primaries = { &["a:1", "b:2"] },
+    state_dir_missing = { &["--state-dir"] },
+    unknown_flag = { &["--follow", "a:1"] },
+)]
+fn rejects_bad_args(input: &[&str]) {
+    assert!(matches!(parse_args(&args(input)), Err(ReplicaError::Usage(_))));
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! The replica's read-only listener.
+//!
+//! Speaks the daemon's protocol on the replica's own socket, answering
+//! from the replicated state. Requests that would change state are refused
+//! with an error naming the replica, since they belong on the primary.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+
+use oj_core::{Breadcrumb, MetricsHealth};
+use parking_lot::Mutex;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::{TcpListener, UnixListener};
+use tokio::sync::Notify;
+use tracing::{debug, error};
+
+use crate::env::{ipc_timeout, PROTOCOL_VERSION};
+use crate::listener::{answer_query, authenticate_tcp, ConnectionError, QueryCtx};
+use crate::protocol::{self, Request, Response};
+use crate::storage::{JobArchive, MaterializedState};
+
+/// What the replica's request handlers share.
+pub(super) struct ServeCtx {
+    pub state: Arc<Mutex<MaterializedState>>,
+    /// Always empty: orphans are found by the primary's reconciliation
+    pub orphans: Arc<Mutex<Vec<Breadcrumb>>>,
+    pub metrics_health: Arc<Mutex<MetricsHealth>>,
+    pub logs_path: PathBuf,
+    pub archive: JobArchive,
+    pub start_time: Instant,
+    /// Token TCP clients must present, as for the primary (`OJ_AUTH_TOKEN`)
+    pub auth_token: Option<String>,
+    pub shutdown: Arc<Notify>,
+}
+
+/// Accept connections until the process exits.
+pub(super) async fn serve(unix: UnixListener, tcp: Option<TcpListener>, ctx: Arc<ServeCtx>) {
+    loop {
+        let accepted = tokio::select! {
+            result = unix.accept() => result.map(|(stream, _)| {
+                let (reader, writer) = stream.into_split();
+                let ctx = Arc::clone(&ctx);
+                tokio::spawn(async move { handle_connection(reader, writer, false, &ctx).await })
+            }),
+            result = async {
+                match &tcp {
+                    Some(tcp) => tcp.accept().await,
+                    None => std::future::pending().await,
+                }
+            } => result.map(|(stream, _)| {
+                let (reader, writer) = stream.into_split();
+                let ctx = Arc::clone(&ctx);
+                tokio::spawn(async move { handle_connection(reader, writer, true, &ctx).await })
+            }),
+        };
+        if let Err(e) = accepted {
+            error!("Replica accept error: {}", e);
+        }
+    }
+}
+
+async fn handle_connection<R, W>(mut reader: R, mut writer: W, tcp: bool, ctx: &ServeCtx)
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    if let Err(e) = respond(&mut reader, &mut writer, tcp, ctx).await {
+        debug!("Replica connection error: {}", e);
+    }
+}
+
+async fn respond<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    tcp: bool,
+    ctx: &ServeCtx,
+) -> Result<(), ConnectionError>
+where
+    R: AsyncReadExt + Unpin,
+    W: AsyncWriteExt + Unpin,
+{
+    let mut request = protocol::read_request(reader, ipc_timeout()).await?;
+    if tcp {
+        match authenticate_tcp(request, reader, writer, ctx.auth_token.as_deref()).await? {
+            Some(next) => request = next,
+            None => return Ok(()),
+        }
+    }
+    let response = answer(request, ctx);
+    protocol::write_response(writer, &response, ipc_timeout()).await?;
+    Ok(())
+}
+
+/// Answer a request from the replicated state.
+pub(super) fn answer(request: Request, ctx: &ServeCtx) -> Response {
+    match request {
+        Request::Ping => Response::Pong,
+        Request::Hello { .. } => Response::Hello { version: PROTOCOL_VERSION.to_string() },
+        Request::Query { query } => {
+            let query_ctx = QueryCtx {
+                state: &ctx.state,
+                orphans: &ctx.orphans,
+                metrics_health: &ctx.metrics_health,
+                logs_path: &ctx.logs_path,
+                archive: &ctx.archive,
+                start_time: ctx.start_time,
+            };
+            answer_query(&query_ctx, query)
+        }
+        Request::Status => {
+            let jobs_active = ctx.state.lock().jobs.values().filter(|j| !j.is_terminal()).count();
+            Response::Status {
+                uptime_secs: ctx.start_time.elapsed().as_secs(),
+                jobs_active,
+                orphan_count: 0,
+            }
+        }
+        // Stops the replica only; agents run under the primary
+        Request::Shutdown { .. } => {
+            ctx.shutdown.notify_one();
+            Response::ShuttingDown
+        }
+        _ => Response::Error {
+            message: "this daemon is a read-only replica; send changes to the primary".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+#[path = "serve_tests.rs"]
+mod tests;
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_core::JobId;
+use tempfile::tempdir;
+
+use super::*;
+use crate::protocol::Query;
+
+fn serve_ctx(dir: &std::path::Path, auth_token: Option<&str>) -> ServeCtx {
+    ServeCtx {
+        state: Arc::new(Mutex::new(MaterializedState::default())),
+        orphans: Arc::new(Mutex::new(Vec::new())),
+        metrics_health: Arc::new(Mutex::new(Default::default())),
+        logs_path: dir.join("logs"),
+        archive: JobArchive::new(dir.join("archive")),
+        start_time: Instant::now(),
+        auth_token: auth_token.map(str::to_string),
+        shutdown: Arc::new(Notify::new()),
+    }
+}
+
+fn with_job(ctx: &ServeCtx) {
+    ctx.state.lock().apply_event(&oj_core::Event::JobCreated {
+        id: JobId::from_string("job-1"),
+        kind: "build".to_string(),
+        name: "job-1".to_string(),
+        runbook_hash: "abc".to_string(),
+        cwd: PathBuf::from("/src/api"),
+        vars: Default::default(),
+        initial_step: "plan".to_string(),
+        created_at_ms: 1_000,
+        project: "api".to_string(),
+        cron: None,
+    });
+}
+
+#[test]
+fn answers_queries_from_replicated_state() {
+    let dir = tempdir().unwrap();
+    let ctx = serve_ctx(dir.path(), None);
+    with_job(&ctx);
+
+    let Response::Jobs { jobs } = answer(Request::Query { query: Query::ListJobs }, &ctx) else {
+        unreachable!("expected jobs")
+    };
+
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].id, "job-1");
+}
+
+#[test]
+fn status_counts_active_jobs() {
+    let dir = tempdir().unwrap();
+    let ctx = serve_ctx(dir.path(), None);
+    with_job(&ctx);
+
+    let response = answer(Request::Status, &ctx);
+
+    assert!(
+        matches!(response, Response::Status { jobs_active: 1, orphan_count: 0, .. }),
+        "{response:?}"
+    );
+}
+
+#[yare::parameterized(
+    job_cancel = { Request::JobCancel { ids: vec!["job-1".to_string()] } },
+    agent_kill = { Request::AgentKill { id: "agent-1".to_string() } },
+    event = { Request::Event { event: oj_core::Event::Shutdown } },
+    subscribe = { Request::Subscribe { filter: Default::default() } },
+    replicate = { Request::Replicate { after_seq: 0 } },
+)]
+fn refuses_changes(request: Request) {
+    let dir = tempdir().unwrap();
+    let ctx = serve_ctx(dir.path(), None);
+    with_job(&ctx);
+
+    let response = answer(request, &ctx);
+
+    let Response::Error { message } = response else { unreachable!("expected error") };
+    assert!(message.contains("read-only replica"), "{message}");
+    assert!(!ctx.state.lock().jobs["job-1"].is_terminal());
+}
+
+#[tokio::test]
+async fn tcp_clients_authenticate_with_the_replicas_token() {
+    let dir = tempdir().unwrap();
+    let ctx = serve_ctx(dir.path(), Some("dashboard"));
+    let (mut client, server) = tokio::io::duplex(64 * 1024);
+    let (reader, writer) = tokio::io::split(server);
+    let server = tokio::spawn(async move { handle_connection(reader, writer, true, &ctx).await });
+
+    let hello = Request::Hello { version: "test".to_string(), token: Some("dashboard".into()) };
+    for request in [hello, Request::Ping] {
+        protocol::write_message(&mut client, &protocol::encode(&request).unwrap()).await.unwrap();
+    }
+    let mut responses = Vec::new();
+    for _ in 0..2 {
+        let bytes = protocol::read_message(&mut client).await.unwrap();
+        responses.push(protocol::decode::<Response>(&bytes).unwrap());
+    }
+
+    assert!(matches!(responses[0], Response::Hello { .. }), "{responses:?}");
+    assert_eq!(responses[1], Response::Pong);
+    server.await.unwrap();
+}
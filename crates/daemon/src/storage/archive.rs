@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Archive of terminal jobs moved out of the materialized state.
+//!
+//! Jobs that finished longer ago than the retention window are written to
+//! one zstd-compressed JSONL file per month (`jobs-2026-01.jsonl.zst`, by
+//! when the job ended), then dropped from state with `job:archived`. That
+//! keeps snapshots and query scans proportional to recent work while
+//! `oj job show` can still find old jobs.
+//!
+//! Each append adds one zstd frame to the end of the month's file rather
+//! than rewriting it. A frame cut short by a crash is ignored by readers and
+//! truncated away by the next append. A job archived twice (crash between
+//! the write and the event) is listed once.
+//!
+//! Lookups by ID go through an index of the IDs in each file, rebuilt only
+//! for files that changed since it was taken, so a miss does not decode the
+//! whole archive.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use chrono::{DateTime, Utc};
+use oj_core::Job;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use thiserror::Error;
+
+use super::MaterializedState;
+
+const PREFIX: &str = "jobs-";
+const SUFFIX: &str = ".jsonl.zst";
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Debug, Error)]
+pub enum ArchiveError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Per-month job archive under a directory.
+#[derive(Debug, Clone)]
+pub struct JobArchive {
+    dir: PathBuf,
+    /// Job IDs per archive file, shared between clones
+    index: Arc<Mutex<HashMap<PathBuf, FileIndex>>>,
+}
+
+/// The job IDs in one archive file, as of its size and modification time.
+#[derive(Debug)]
+struct FileIndex {
+    len: u64,
+    modified: Option<SystemTime>,
+    ids: Vec<String>,
+}
+
+/// Just the ID of an archived job, for indexing.
+#[derive(Deserialize)]
+struct JobIdOnly {
+    id: String,
+}
+
+impl JobArchive {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into(), index: Arc::default() }
+    }
+
+    /// Add jobs to the archive. Durable when this returns.
+    pub fn append(&self, jobs: &[Job]) -> Result<(), ArchiveError> {
+        let mut by_month: BTreeMap<String, Vec<&Job>> = BTreeMap::new();
+        for job in jobs {
+            by_month.entry(month_of(job)).or_default().push(job);
+        }
+        if by_month.is_empty() {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&self.dir)?;
+        for (month, jobs) in by_month {
+            let path = self.dir.join(format!("{PREFIX}{month}{SUFFIX}"));
+            let mut lines = Vec::new();
+            for job in jobs {
+                lines.extend(serde_json::to_vec(job)?);
+                lines.push(b'\n');
+            }
+            let frame = zstd::encode_all(lines.as_slice(), ZSTD_LEVEL)?;
+
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path)?;
+            // Drop a partial frame left by a crash, then add this one after it
+            let end = complete_frames_len(&fs::read(&path)?) as u64;
+            file.set_len(end)?;
+            file.seek(SeekFrom::Start(end))?;
+            file.write_all(&frame)?;
+            file.sync_all()?;
+        }
+        File::open(&self.dir)?.sync_all()?;
+        Ok(())
+    }
+
+    /// All archived jobs, oldest month first.
+    pub fn list(&self) -> Result<Vec<Job>, ArchiveError> {
+        let mut jobs = Vec::new();
+        let mut index = HashMap::new();
+        for path in self.files()? {
+            for job in read_jobs(&path)? {
+                // A later copy replaces an earlier one in place
+                match index.get(&job.id) {
+                    Some(&i) => jobs[i] = job,
+                    None => {
+                        index.insert(job.id.clone(), jobs.len());
+                        jobs.push(job);
+                    }
+                }
+            }
+        }
+        Ok(jobs)
+    }
+
+    /// Find an archived job by ID or unique prefix.
+    ///
+    /// Only the file holding the job is decoded.
+    pub fn find(&self, id: &str) -> Result<Option<Job>, ArchiveError> {
+        // The newest file holding each matching ID, since a later copy wins
+        let mut matches: HashMap<String, PathBuf> = HashMap::new();
+        {
+            let mut index = self.index.lock();
+            let files = self.files()?;
+            index.retain(|path, _| files.contains(path));
+            for path in files {
+                let ids = indexed_ids(&mut index, &path)?;
+                for job_id in ids.iter().filter(|j| oj_core::id::prefix_matches(j, id)) {
+                    matches.insert(job_id.clone(), path.clone());
+                }
+            }
+        }
+
+        let (job_id, path) = match matches.get_key_value(id) {
+            Some((job_id, path)) => (job_id.clone(), path.clone()),
+            None if matches.len() == 1 => matches.into_iter().next().unwrap_or_default(),
+            None => return Ok(None),
+        };
+        Ok(read_jobs(&path)?.into_iter().rfind(|j| j.id == job_id))
+    }
+
+    /// Archive files, oldest month first.
+    fn files(&self) -> Result<Vec<PathBuf>, ArchiveError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+        let mut files = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+            if name.starts_with(PREFIX) && name.ends_with(SUFFIX) {
+                files.push(path);
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+}
+
+/// Terminal jobs that ended at least `retention_ms` before `now_ms`.
+///
+/// Suspended jobs can still be resumed, so they are never archived.
+pub fn due_for_archive(state: &MaterializedState, now_ms: u64, retention_ms: u64) -> Vec<Job> {
+    state
+        .jobs
+        .values()
+        .filter(|job| job.is_terminal() && !job.is_suspended())
+        .filter(|job| ended_at_ms(job).is_some_and(|ended| ended + retention_ms <= now_ms))
+        .cloned()
+        .collect()
+}
+
+/// When a job ended: the end of its last recorded step.
+fn ended_at_ms(job: &Job) -> Option<u64> {
+    job.step_history.last().map(|r| r.finished_at_ms.unwrap_or(r.started_at_ms))
+}
+
+/// `YYYY-MM` of when the job ended.
+fn month_of(job: &Job) -> String {
+    let ms = ended_at_ms(job).unwrap_or(0);
+    DateTime::<Utc>::from_timestamp_millis(ms as i64)
+        .unwrap_or(DateTime::UNIX_EPOCH)
+        .format("%Y-%m")
+        .to_string()
+}
+
+/// Length of the complete zstd frames at the start of `data`; anything after
+/// is a frame cut short by a crash.
+fn complete_frames_len(data: &[u8]) -> usize {
+    let mut end = 0;
+    while end < data.len() {
+        match zstd::zstd_safe::find_frame_compressed_size(&data[end..]) {
+            Ok(size) if size > 0 => end += size,
+            _ => break,
+        }
+    }
+    end
+}
+
+/// The decompressed JSONL of an archive file's complete frames.
+fn read_lines(path: &Path) -> Result<Vec<u8>, ArchiveError> {
+    let data = fs::read(path)?;
+    Ok(zstd::decode_all(&data[..complete_frames_len(&data)])?)
+}
+
+fn read_jobs(path: &Path) -> Result<Vec<Job>, ArchiveError> {
+    let mut jobs = Vec::new();
+    for line in read_lines(path)?.lines() {
+        let line = line?;
+        if !line.is_empty() {
+            jobs.push(serde_json::from_str(&line)?);
+        }
+    }
+    Ok(jobs)
+}
+
+/// The job IDs in `path`, re-read only if the file changed since indexed.
+fn indexed_ids<'a>(
+    index: &'a mut HashMap<PathBuf, FileIndex>,
+    path: &Path,
+) -> Result<&'a [String], ArchiveError> {
+    let meta = fs::metadata(path)?;
+    let (len, modified) = (meta.len(), meta.modified().ok());
+    let stale = index.get(path).is_none_or(|f| f.len != len || f.modified != modified);
+    if stale {
+        let mut ids = Vec::new();
+        for line in read_lines(path)?.lines() {
+            let line = line?;
+            if !line.is_empty() {
+                ids.push(serde_json::from_str::<JobIdOnly>(&line)?.id);
+            }
+        }
+        index.insert(path.to_path_buf(), FileIndex { len, modified, ids });
+    }
+    Ok(index.get(path).map(|f| f.ids.as_slice()).unwrap_or_default())
+}
+
+#[cfg(test)]
+#[path = "archive_tests.rs"]
+mod tests;
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::{StepOutcome, StepRecord};
+use tempfile::tempdir;
+
+/// 2026-01-15 12:00:00 UTC
+const JAN_15: u64 = 1_768_478_400_000;
+/// 2026-02-15 12:00:00 UTC
+const FEB_15: u64 = 1_771_156_800_000;
+const HOUR_MS: u64 = 60 * 60 * 1000;
+
+fn job(id: &str, step: &str, ended_at_ms: u64) -> Job {
+    Job::builder()
+        .id(id)
+        .step(step)
+        .step_history(vec![StepRecord {
+            name: "build".to_string(),
+            started_at_ms: ended_at_ms - 1000,
+            finished_at_ms: Some(ended_at_ms),
+            outcome: StepOutcome::Completed,
+            agent_id: None,
+            agent_name: None,
+        }])
+        .build()
+}
+
+fn ids(jobs: &[Job]) -> Vec<&str> {
+    jobs.iter().map(|j| j.id.as_str()).collect()
+}
+
+#[test]
+fn append_writes_one_file_per_month() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+
+    archive.append(&[job("a", "done", JAN_15), job("b", "done", FEB_15)]).unwrap();
+    archive.append(&[job("c", "failed", JAN_15 + HOUR_MS)]).unwrap();
+
+    let mut files: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["jobs-2026-01.jsonl.zst", "jobs-2026-02.jsonl.zst"]);
+    assert_eq!(ids(&archive.list().unwrap()), ["a", "c", "b"]);
+}
+
+#[test]
+fn jobs_archived_twice_are_listed_once() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+
+    archive.append(&[job("a", "done", JAN_15), job("b", "done", JAN_15)]).unwrap();
+    archive.append(&[job("a", "failed", JAN_15)]).unwrap();
+
+    let jobs = archive.list().unwrap();
+    assert_eq!(ids(&jobs), ["a", "b"]);
+    assert_eq!(jobs[0].step, "failed");
+}
+
+#[yare::parameterized(
+    exact = { "job-abc", Some("job-abc") },
+    unique_prefix = { "job-x", Some("job-xyz") },
+    ambiguous_prefix = { "job-", None },
+    missing = { "nope", None },
+)]
+fn find_matches_id_or_unique_prefix(query: &str, expected: Option<&str>) {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+    archive.append(&[job("job-abc", "done", JAN_15), job("job-xyz", "done", FEB_15)]).unwrap();
+
+    let found = archive.find(query).unwrap();
+
+    assert_eq!(found.as_ref().map(|j| j.id.as_str()), expected);
+}
+
+#[test]
+fn missing_archive_is_empty() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path().join("archive"));
+
+    assert!(archive.list().unwrap().is_empty());
+    assert!(archive.find("a").unwrap().is_none());
+}
+
+#[yare::parameterized(
+    done_past_retention = { "done", JAN_15, true },
+    cancelled_past_retention = { "cancelled", JAN_15, true },
+    done_within_retention = { "done", JAN_15 + 23 * HOUR_MS, false },
+    running = { "build", JAN_15, false },
+    suspended = { "suspended", JAN_15, false },
+)]
+fn due_for_archive_selects_old_terminal_jobs(step: &str, ended_at_ms: u64, due: bool) {
+    let mut state = MaterializedState::default();
+    state.jobs.insert("a".to_string(), job("a", step, ended_at_ms));
+
+    let jobs = due_for_archive(&state, JAN_15 + 24 * HOUR_MS, 24 * HOUR_MS);
+
+    assert_eq!(!jobs.is_empty(), due);
+}
+
+#[test]
+fn append_adds_a_frame_without_rewriting() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+    let path = dir.path().join("jobs-2026-01.jsonl.zst");
+
+    archive.append(&[job("a", "done", JAN_15)]).unwrap();
+    let first = std::fs::read(&path).unwrap();
+    archive.append(&[job("b", "done", JAN_15)]).unwrap();
+    let both = std::fs::read(&path).unwrap();
+
+    assert!(both.starts_with(&first));
+    assert_eq!(ids(&archive.list().unwrap()), ["a", "b"]);
+}
+
+#[test]
+fn partial_frame_from_a_crash_is_ignored_and_replaced() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+    let path = dir.path().join("jobs-2026-01.jsonl.zst");
+    archive.append(&[job("a", "done", JAN_15)]).unwrap();
+    let intact = std::fs::read(&path).unwrap();
+
+    // A crash mid-append leaves the start of a frame behind
+    archive.append(&[job("b", "done", JAN_15)]).unwrap();
+    let torn = std::fs::read(&path).unwrap();
+    std::fs::write(&path, &torn[..intact.len() + 8]).unwrap();
+    assert_eq!(ids(&archive.list().unwrap()), ["a"]);
+
+    archive.append(&[job("b", "done", JAN_15)]).unwrap();
+
+    assert_eq!(ids(&archive.list().unwrap()), ["a", "b"]);
+}
+
+#[test]
+fn find_sees_jobs_appended_through_another_handle() {
+    let dir = tempdir().unwrap();
+    let reader = JobArchive::new(dir.path());
+    let writer = JobArchive::new(dir.path());
+    writer.append(&[job("job-abc", "done", JAN_15)]).unwrap();
+    assert!(reader.find("job-xyz").unwrap().is_none());
+
+    writer.append(&[job("job-xyz", "failed", JAN_15)]).unwrap();
+
+    let found = reader.find("job-xyz").unwrap().unwrap();
+    assert_eq!(found.step, "failed");
+}
+
+#[test]
+fn find_returns_latest_copy() {
+    let dir = tempdir().unwrap();
+    let archive = JobArchive::new(dir.path());
+
+    archive.append(&[job("a", "done", JAN_15)]).unwrap();
+    archive.append(&[job("a", "failed", FEB_15)]).unwrap();
+
+    assert_eq!(archive.find("a").unwrap().unwrap().step, "failed");
+}
@@ -9,21 +9,27 @@
 //!
 //! ## Design
 //!
+//! Regular checkpoints write a delta of the entities changed since the
+//! previous checkpoint, so the work done under the state lock scales with
+//! churn rather than total state:
+//!
 //! ```text
 //! Main Thread                    Background Thread
 //! ───────────────────────────    ─────────────────────────────
-//! clone state (~10ms)
+//! take dirty entities
 //!   │
-//!   └─────────────────────────→  serialize + compress (~130ms)
-//!                                write to .tmp (~20ms)
-//!                                fsync .tmp (~50ms)
-//!                                rename → snapshot (~1ms)
-//!                                fsync directory (~30ms)
+//!   └─────────────────────────→  serialize + compress
+//!                                write to .tmp, fsync
+//!                                rename → <seq>.delta
+//!                                fsync directory
 //!                                  │
 //!   ←────────────────────────────┘ (completion signal)
 //! truncate WAL (safe now)
 //! ```
 //!
+//! Compaction later folds the deltas into a new full snapshot entirely on
+//! the background thread, from the files on disk, then deletes them.
+//!
 //! ## Testability
 //!
 //! The `CheckpointWriter` trait abstracts all I/O operations, enabling:
@@ -32,8 +38,13 @@
 //! - Verification of fsync ordering guarantees
 
 use crate::storage::migration::MigrationRegistry;
-use crate::storage::{MaterializedState, Snapshot, SnapshotError, CURRENT_SNAPSHOT_VERSION};
-use chrono::Utc;
+use crate::storage::snapshot::{delta_path, delta_seq, deltas_dir, DeltaSnapshot};
+use crate::storage::{
+    MaterializedState, Snapshot, SnapshotError, StateDelta, CURRENT_SNAPSHOT_VERSION,
+};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::Value;
 use std::fs::File;
 use std::io::Write;
@@ -53,6 +64,8 @@ pub enum CheckpointError {
     Compress(String),
     #[error("checkpoint failed: {0}")]
     Failed(String),
+    #[error("failed to load snapshot: {0}")]
+    Load(#[from] SnapshotError),
 }
 
 /// Result of a completed checkpoint
@@ -60,8 +73,10 @@ pub enum CheckpointError {
 pub struct CheckpointResult {
     /// Sequence number that was checkpointed
     pub seq: u64,
-    /// Size of the compressed snapshot in bytes
+    /// Size of the compressed snapshot or delta in bytes
     pub size_bytes: u64,
+    /// Deltas on disk not yet compacted into the snapshot
+    pub deltas: usize,
 }
 
 /// Trait abstracting checkpoint I/O for testability.
@@ -83,6 +98,12 @@ pub trait CheckpointWriter: Send + Sync + 'static {
 
     /// Get file size (for metrics).
     fn file_size(&self, path: &Path) -> Result<u64, CheckpointError>;
+
+    /// List files in a directory (empty if it does not exist).
+    fn list_dir(&self, path: &Path) -> Result<Vec<PathBuf>, CheckpointError>;
+
+    /// Delete a file.
+    fn remove_file(&self, path: &Path) -> Result<(), CheckpointError>;
 }
 
 /// Production checkpoint writer using real filesystem operations.
@@ -119,6 +140,24 @@ impl CheckpointWriter for FsCheckpointWriter {
     fn file_size(&self, path: &Path) -> Result<u64, CheckpointError> {
         Ok(std::fs::metadata(path)?.len())
     }
+
+    fn list_dir(&self, path: &Path) -> Result<Vec<PathBuf>, CheckpointError> {
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e.into()),
+        };
+        let mut paths = Vec::new();
+        for entry in entries {
+            paths.push(entry?.path());
+        }
+        Ok(paths)
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<(), CheckpointError> {
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
 }
 
 /// Handle to a running checkpoint operation.
@@ -172,7 +211,7 @@ impl<W: CheckpointWriter + Clone> Checkpointer<W> {
         }
     }
 
-    /// Start a background checkpoint.
+    /// Start a background full checkpoint.
     ///
     /// This clones the state and spawns a thread to serialize, compress, and
     /// write the snapshot. The returned handle must be waited on before
@@ -183,14 +222,65 @@ impl<W: CheckpointWriter + Clone> Checkpointer<W> {
         let snapshot_path = self.snapshot_path.clone();
         let compression_level = self.compression_level;
 
-        let (tx, rx) = mpsc::channel();
+        self.spawn(move || {
+            checkpoint_blocking(&writer, seq, &state_clone, &snapshot_path, compression_level)
+        })
+    }
+
+    /// Start a background delta checkpoint.
+    ///
+    /// `base_seq` is the sequence number of the previous snapshot or delta,
+    /// which `delta` applies on top of. The returned handle must be waited
+    /// on before truncating the WAL.
+    pub fn start_delta(&self, base_seq: u64, seq: u64, delta: StateDelta) -> CheckpointHandle {
+        let writer = self.writer.clone();
+        let snapshot_path = self.snapshot_path.clone();
+        let compression_level = self.compression_level;
 
+        self.spawn(move || {
+            let delta = DeltaSnapshot {
+                version: CURRENT_SNAPSHOT_VERSION,
+                base_seq,
+                seq,
+                delta,
+                created_at: Utc::now(),
+            };
+            let path = delta_path(&snapshot_path, seq);
+            let compressed = compress(&delta, compression_level)?;
+            let size_bytes =
+                write_durably(&writer, &compressed, &path.with_extension("tmp"), &path)?;
+            let deltas = delta_files(&writer, &snapshot_path)?.len();
+            Ok(CheckpointResult { seq, size_bytes, deltas })
+        })
+    }
+
+    /// Start folding the deltas into a new full snapshot in the background.
+    ///
+    /// Works from the files on disk, so it needs no access to live state.
+    pub fn start_compaction(&self) -> CheckpointHandle {
+        let writer = self.writer.clone();
+        let snapshot_path = self.snapshot_path.clone();
+        let compression_level = self.compression_level;
+
+        self.spawn(move || {
+            let Some(snapshot) = load_snapshot(&snapshot_path)? else {
+                return Ok(CheckpointResult { seq: 0, size_bytes: 0, deltas: 0 });
+            };
+            // A separate temp file, so a shutdown checkpoint running at
+            // the same time cannot interleave writes with this one
+            let tmp_path = snapshot_path.with_extension("compact.tmp");
+            write_snapshot(&writer, &snapshot, &tmp_path, &snapshot_path, compression_level)
+        })
+    }
+
+    fn spawn(
+        &self,
+        work: impl FnOnce() -> Result<CheckpointResult, CheckpointError> + Send + 'static,
+    ) -> CheckpointHandle {
+        let (tx, rx) = mpsc::channel();
         let handle = thread::spawn(move || {
-            let result =
-                checkpoint_blocking(&writer, seq, &state_clone, &snapshot_path, compression_level);
-            let _ = tx.send(result);
+            let _ = tx.send(work());
         });
-
         CheckpointHandle { receiver: rx, handle }
     }
 
@@ -204,7 +294,7 @@ impl<W: CheckpointWriter + Clone> Checkpointer<W> {
     }
 }
 
-/// Perform checkpoint I/O (runs on background thread).
+/// Perform a full checkpoint (runs on background thread).
 fn checkpoint_blocking<W: CheckpointWriter>(
     writer: &W,
     seq: u64,
@@ -212,60 +302,159 @@ fn checkpoint_blocking<W: CheckpointWriter>(
     snapshot_path: &Path,
     compression_level: i32,
 ) -> Result<CheckpointResult, CheckpointError> {
-    let tmp_path = snapshot_path.with_extension("tmp");
-
-    // 1. Build snapshot struct
     let snapshot = Snapshot {
         version: CURRENT_SNAPSHOT_VERSION,
         seq,
         state: state.clone(),
         created_at: Utc::now(),
     };
+    let tmp_path = snapshot_path.with_extension("tmp");
+    write_snapshot(writer, &snapshot, &tmp_path, snapshot_path, compression_level)
+}
+
+/// Write a full snapshot, then delete the deltas it covers.
+fn write_snapshot<W: CheckpointWriter>(
+    writer: &W,
+    snapshot: &Snapshot,
+    tmp_path: &Path,
+    snapshot_path: &Path,
+    compression_level: i32,
+) -> Result<CheckpointResult, CheckpointError> {
+    let compressed = compress(snapshot, compression_level)?;
+    let size_bytes = write_durably(writer, &compressed, tmp_path, snapshot_path)?;
+
+    // The snapshot is durable, so covered deltas are redundant; ones that
+    // survive a failed delete are skipped on load
+    let mut deltas = 0;
+    for (seq, path) in delta_files(writer, snapshot_path)? {
+        if seq > snapshot.seq {
+            deltas += 1;
+        } else if let Err(e) = writer.remove_file(&path) {
+            tracing::warn!(path = %path.display(), error = %e, "failed to remove compacted delta");
+        }
+    }
 
-    // 2. Serialize to JSON
-    let json_bytes = serde_json::to_vec(&snapshot)?;
+    Ok(CheckpointResult { seq: snapshot.seq, size_bytes, deltas })
+}
 
-    // 3. Compress with zstd
-    let compressed = zstd::encode_all(json_bytes.as_slice(), compression_level)
-        .map_err(|e| CheckpointError::Compress(e.to_string()))?;
+/// Serialize to JSON and compress with zstd.
+fn compress<T: Serialize>(value: &T, compression_level: i32) -> Result<Vec<u8>, CheckpointError> {
+    let json_bytes = serde_json::to_vec(value)?;
+    zstd::encode_all(json_bytes.as_slice(), compression_level)
+        .map_err(|e| CheckpointError::Compress(e.to_string()))
+}
 
-    // 4. Write to temp file
-    writer.write_tmp(&tmp_path, &compressed)?;
+/// Write data to `path` via a temp file so it is durable when this returns.
+/// Returns the final size in bytes.
+fn write_durably<W: CheckpointWriter>(
+    writer: &W,
+    data: &[u8],
+    tmp_path: &Path,
+    path: &Path,
+) -> Result<u64, CheckpointError> {
+    // 1. Write to temp file
+    writer.write_tmp(tmp_path, data)?;
 
-    // 5. Fsync temp file (data durable)
-    writer.fsync_file(&tmp_path)?;
+    // 2. Fsync temp file (data durable)
+    writer.fsync_file(tmp_path)?;
 
-    // 6. Atomic rename
-    writer.rename(&tmp_path, snapshot_path)?;
+    // 3. Atomic rename
+    writer.rename(tmp_path, path)?;
 
-    // 7. Fsync directory (rename durable) - CRITICAL for WAL truncation safety
-    if let Some(parent) = snapshot_path.parent() {
+    // 4. Fsync directory (rename durable) - CRITICAL for WAL truncation safety
+    if let Some(parent) = path.parent() {
         writer.fsync_dir(parent)?;
     }
 
-    // 8. Get final size for metrics
-    let size_bytes = writer.file_size(snapshot_path).unwrap_or(compressed.len() as u64);
+    // 5. Get final size for metrics
+    Ok(writer.file_size(path).unwrap_or(data.len() as u64))
+}
 
-    Ok(CheckpointResult { seq, size_bytes })
+/// Delta files for a snapshot, oldest first.
+fn delta_files<W: CheckpointWriter>(
+    writer: &W,
+    snapshot_path: &Path,
+) -> Result<Vec<(u64, PathBuf)>, CheckpointError> {
+    let mut deltas: Vec<_> = writer
+        .list_dir(&deltas_dir(snapshot_path))?
+        .into_iter()
+        .filter_map(|path| Some((delta_seq(&path)?, path)))
+        .collect();
+    deltas.sort();
+    Ok(deltas)
 }
 
-/// Load a zstd-compressed snapshot.
+/// Load a zstd-compressed snapshot and apply its deltas.
 pub fn load_snapshot(path: &Path) -> Result<Option<Snapshot>, SnapshotError> {
-    if !path.exists() {
-        return Ok(None);
+    let base = if path.exists() { Some(read_compressed::<Snapshot>(path)?) } else { None };
+    let deltas = on_disk_deltas(path)?;
+    if deltas.is_empty() {
+        return Ok(base);
     }
 
-    // Decompress and parse
-    let file = File::open(path)?;
-    let decoder = zstd::stream::read::Decoder::new(file)
-        .map_err(|e| SnapshotError::Io(std::io::Error::other(e.to_string())))?;
-    let value: Value = serde_json::from_reader(decoder)?;
+    let mut snapshot = base.unwrap_or_else(|| Snapshot {
+        version: CURRENT_SNAPSHOT_VERSION,
+        seq: 0,
+        state: MaterializedState::default(),
+        created_at: DateTime::UNIX_EPOCH,
+    });
+    for (seq, delta_path) in deltas {
+        // Already folded into the snapshot by a compaction
+        if seq <= snapshot.seq {
+            continue;
+        }
+        let delta: DeltaSnapshot = read_compressed(&delta_path)?;
+        if delta.base_seq != snapshot.seq {
+            return Err(SnapshotError::BrokenChain {
+                seq: delta.seq,
+                base_seq: delta.base_seq,
+                expected: snapshot.seq,
+            });
+        }
+        snapshot.state.apply_delta(delta.delta);
+        snapshot.seq = delta.seq;
+        snapshot.created_at = delta.created_at;
+    }
+    Ok(Some(snapshot))
+}
+
+/// The snapshot and delta files at `path`, as written (not migrated).
+pub(crate) fn read_unmigrated(path: &Path) -> Result<Vec<(PathBuf, Value)>, SnapshotError> {
+    let mut files = Vec::new();
+    if path.exists() {
+        files.push((path.to_owned(), read_value(path)?));
+    }
+    let deltas = on_disk_deltas(path)?;
+    for (_, delta_path) in deltas {
+        let value = read_value(&delta_path)?;
+        files.push((delta_path, value));
+    }
+    Ok(files)
+}
+
+/// Delta files next to the snapshot at `path`, oldest first.
+fn on_disk_deltas(path: &Path) -> Result<Vec<(u64, PathBuf)>, SnapshotError> {
+    delta_files(&FsCheckpointWriter, path).map_err(|e| match e {
+        CheckpointError::Io(e) => SnapshotError::Io(e),
+        other => SnapshotError::Io(std::io::Error::other(other.to_string())),
+    })
+}
+
+/// Decompress, migrate, and parse a snapshot or delta file.
+fn read_compressed<T: DeserializeOwned>(path: &Path) -> Result<T, SnapshotError> {
+    let value = read_value(path)?;
 
     // Run through migration
     let registry = MigrationRegistry::new();
     let migrated = registry.migrate_to(value, CURRENT_SNAPSHOT_VERSION)?;
-    let snapshot: Snapshot = serde_json::from_value(migrated)?;
-    Ok(Some(snapshot))
+    Ok(serde_json::from_value(migrated)?)
+}
+
+fn read_value(path: &Path) -> Result<Value, SnapshotError> {
+    let file = File::open(path)?;
+    let decoder = zstd::stream::read::Decoder::new(file)
+        .map_err(|e| SnapshotError::Io(std::io::Error::other(e.to_string())))?;
+    Ok(serde_json::from_reader(decoder)?)
 }
 
 #[cfg(test)]
@@ -18,6 +18,7 @@ struct IoLog {
     pub fsyncs_file: Vec<PathBuf>,
     pub fsyncs_dir: Vec<PathBuf>,
     pub renames: Vec<(PathBuf, PathBuf)>,
+    pub removes: Vec<PathBuf>,
 }
 
 /// Fake writer that records operations and supports error injection.
@@ -131,6 +132,17 @@ impl CheckpointWriter for FakeCheckpointWriter {
         let data = self.written_data.lock().unwrap();
         Ok(data.get(path).map(|d| d.len() as u64).unwrap_or(0))
     }
+
+    fn list_dir(&self, path: &Path) -> Result<Vec<PathBuf>, CheckpointError> {
+        let data = self.written_data.lock().unwrap();
+        Ok(data.keys().filter(|p| p.parent() == Some(path)).cloned().collect())
+    }
+
+    fn remove_file(&self, path: &Path) -> Result<(), CheckpointError> {
+        self.written_data.lock().unwrap().remove(path);
+        self.log.lock().unwrap().removes.push(path.to_owned());
+        Ok(())
+    }
 }
 
 fn test_config(id: &str, name: &str) -> JobConfig {
@@ -372,14 +384,15 @@ fn test_load_zstd_snapshot_with_current_version_succeeds() {
         "seq": 42,
         "state": {{
             "jobs": {{}},
-            "sessions": {{}},
             "workspaces": {{}},
             "runbooks": {{}},
             "workers": {{}},
             "queue_items": {{}},
             "crons": {{}},
             "decisions": {{}},
-            "crew": {{}}
+            "crew": {{}},
+            "agents": {{}},
+            "project_paths": {{}}
         }},
         "created_at": "2025-01-01T00:00:00Z"
     }}"#,
@@ -395,3 +408,120 @@ fn test_load_zstd_snapshot_with_current_version_succeeds() {
     assert_eq!(result.seq, 42);
     assert_eq!(result.version, CURRENT_SNAPSHOT_VERSION);
 }
+
+// ── Delta snapshots ──────────────────────────────────────────────────────────
+
+fn job_ids(state: &MaterializedState) -> Vec<String> {
+    let mut ids: Vec<String> = state.jobs.keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_delta_is_written_durably_to_deltas_dir() {
+    let writer = FakeCheckpointWriter::new();
+    let checkpointer =
+        Checkpointer::with_writer(writer.clone(), PathBuf::from("/data/snapshot.json"));
+    let mut state = create_test_state(2);
+
+    let result = checkpointer.start_delta(0, 7, state.take_delta()).wait().unwrap();
+
+    assert_eq!((result.seq, result.deltas), (7, 1));
+    let log = writer.log();
+    let delta = PathBuf::from("/data/snapshot.deltas/00000000000000000007.delta");
+    assert_eq!(log.renames, [(delta.with_extension("tmp"), delta.clone())]);
+    assert_eq!(log.fsyncs_file, [delta.with_extension("tmp")]);
+    assert_eq!(log.fsyncs_dir, [PathBuf::from("/data/snapshot.deltas")]);
+
+    let data = zstd::decode_all(writer.get_written_data(&delta).unwrap().as_slice()).unwrap();
+    let delta: DeltaSnapshot = serde_json::from_slice(&data).unwrap();
+    assert_eq!((delta.base_seq, delta.seq), (0, 7));
+    assert_eq!(job_ids(&delta.delta.state), ["job-0", "job-1"]);
+}
+
+#[test]
+fn test_load_snapshot_applies_delta_chain() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    let checkpointer = Checkpointer::new(path.clone());
+    let mut state = create_test_state(2);
+    checkpointer.checkpoint_sync(10, &state).unwrap();
+    state.clear_dirty();
+
+    state.jobs.remove("job-0");
+    checkpointer.start_delta(10, 12, state.take_delta()).wait().unwrap();
+    let job = Job::new(test_config("job-9", "late"), &SystemClock);
+    state.jobs.insert("job-9".to_string(), job);
+    let result = checkpointer.start_delta(12, 15, state.take_delta()).wait().unwrap();
+    assert_eq!(result.deltas, 2);
+
+    let loaded = load_snapshot(&path).unwrap().unwrap();
+    assert_eq!(loaded.seq, 15);
+    assert_eq!(job_ids(&loaded.state), ["job-1", "job-9"]);
+}
+
+#[test]
+fn test_load_snapshot_from_deltas_alone() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    let mut state = create_test_state(1);
+
+    Checkpointer::new(path.clone()).start_delta(0, 3, state.take_delta()).wait().unwrap();
+
+    let loaded = load_snapshot(&path).unwrap().unwrap();
+    assert_eq!((loaded.seq, job_ids(&loaded.state)), (3, vec!["job-0".to_string()]));
+}
+
+#[test]
+fn test_load_snapshot_rejects_broken_delta_chain() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    let checkpointer = Checkpointer::new(path.clone());
+    let mut state = create_test_state(1);
+    checkpointer.checkpoint_sync(10, &state).unwrap();
+
+    checkpointer.start_delta(11, 14, state.take_delta()).wait().unwrap();
+
+    let err = load_snapshot(&path).unwrap_err();
+    assert!(
+        matches!(err, SnapshotError::BrokenChain { seq: 14, base_seq: 11, expected: 10 }),
+        "{err:?}"
+    );
+}
+
+#[test]
+fn test_compaction_folds_deltas_into_snapshot() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    let checkpointer = Checkpointer::new(path.clone());
+    let mut state = create_test_state(3);
+    checkpointer.checkpoint_sync(10, &state).unwrap();
+    state.clear_dirty();
+    state.jobs.remove("job-2");
+    checkpointer.start_delta(10, 20, state.take_delta()).wait().unwrap();
+
+    let result = checkpointer.start_compaction().wait().unwrap();
+
+    assert_eq!((result.seq, result.deltas), (20, 0));
+    assert_eq!(std::fs::read_dir(dir.path().join("snapshot.deltas")).unwrap().count(), 0);
+    let loaded = load_snapshot(&path).unwrap().unwrap();
+    assert_eq!((loaded.seq, job_ids(&loaded.state)), (20, vec!["job-0".into(), "job-1".into()]));
+}
+
+#[test]
+fn test_full_checkpoint_removes_covered_deltas() {
+    let writer = FakeCheckpointWriter::new();
+    let checkpointer =
+        Checkpointer::with_writer(writer.clone(), PathBuf::from("/data/snapshot.json"));
+    let mut state = create_test_state(1);
+    checkpointer.start_delta(0, 5, state.take_delta()).wait().unwrap();
+    checkpointer.start_delta(5, 9, state.take_delta()).wait().unwrap();
+
+    let result = checkpointer.checkpoint_sync(5, &state).unwrap();
+
+    assert_eq!(result.deltas, 1);
+    assert_eq!(
+        writer.log().removes,
+        [PathBuf::from("/data/snapshot.deltas/00000000000000000005.delta")]
+    );
+}
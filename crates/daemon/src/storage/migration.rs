@@ -5,10 +5,20 @@
 //!
 //! Migrations transform snapshot JSON from one version to the next.
 //! The registry chains migrations to reach the current version.
+//!
+//! WAL segments record the version their events were written at, and a
+//! migration can also rewrite those events (e.g. a renamed type tag or
+//! field), so old entries replay as the current `Event` schema. Without
+//! that, an event whose tag changed would quietly parse as `Custom`.
+//!
+//! Each migration has golden fixtures under `tests/fixtures/storage/v<N>/`
+//! written by the version it upgrades from.
 
-use serde_json::Value;
+use serde_json::{Map, Value};
 use thiserror::Error;
 
+use super::CURRENT_SNAPSHOT_VERSION;
+
 /// Errors that can occur during migration
 #[derive(Debug, Error)]
 pub enum MigrationError {
@@ -16,13 +26,22 @@ pub enum MigrationError {
     NoPath(u32, u32),
     #[error("snapshot version {0} is newer than supported ({1})")]
     TooNew(u32, u32),
+    #[error("v{0} migration failed: {1}")]
+    Invalid(u32, String),
 }
 
 /// A migration from one snapshot version to the next.
 pub(crate) trait Migration: Send + Sync {
     fn source_version(&self) -> u32;
     fn target_version(&self) -> u32;
+    /// One line on what changes, shown by `ojd migrate`.
+    fn description(&self) -> &'static str;
+    /// Upgrade a snapshot or delta file.
     fn migrate(&self, snapshot: &mut Value) -> Result<(), MigrationError>;
+    /// Upgrade one WAL event. Most migrations leave events alone.
+    fn migrate_event(&self, _event: &mut Value) -> Result<(), MigrationError> {
+        Ok(())
+    }
 }
 
 /// Registry of migrations for upgrading snapshots.
@@ -33,37 +52,47 @@ pub(crate) struct MigrationRegistry {
 impl MigrationRegistry {
     /// Create a new registry with all known migrations.
     pub fn new() -> Self {
-        Self { migrations: Vec::new() }
+        Self { migrations: vec![Box::new(V1ToV2)] }
     }
 
-    /// Migrate a snapshot to the target version.
-    pub fn migrate_to(&self, mut snapshot: Value, target: u32) -> Result<Value, MigrationError> {
-        let current = snapshot.get("v").and_then(|v| v.as_u64()).unwrap_or(1) as u32;
-
-        if current == target {
-            return Ok(snapshot);
+    /// Migrations taking `from` to `target`, in order.
+    pub fn path(&self, from: u32, target: u32) -> Result<Vec<&dyn Migration>, MigrationError> {
+        if from > target {
+            return Err(MigrationError::TooNew(from, target));
         }
-        if current > target {
-            return Err(MigrationError::TooNew(current, target));
-        }
-
-        let mut version = current;
+        let mut path = Vec::new();
+        let mut version = from;
         while version < target {
             let migration = self
                 .migrations
                 .iter()
                 .find(|m| m.source_version() == version)
                 .ok_or(MigrationError::NoPath(version, target))?;
-
-            migration.migrate(&mut snapshot)?;
             version = migration.target_version();
+            path.push(migration.as_ref());
+        }
+        Ok(path)
+    }
 
+    /// Migrate a snapshot to the target version.
+    pub fn migrate_to(&self, mut snapshot: Value, target: u32) -> Result<Value, MigrationError> {
+        let current = snapshot_version(&snapshot);
+        for migration in self.path(current, target)? {
+            migration.migrate(&mut snapshot)?;
             if let Some(obj) = snapshot.as_object_mut() {
-                obj.insert("v".into(), version.into());
+                obj.insert("v".into(), migration.target_version().into());
             }
         }
         Ok(snapshot)
     }
+
+    /// Migrate a WAL event written at `version` to the current version.
+    pub fn migrate_event(&self, mut event: Value, version: u32) -> Result<Value, MigrationError> {
+        for migration in self.path(version, CURRENT_SNAPSHOT_VERSION)? {
+            migration.migrate_event(&mut event)?;
+        }
+        Ok(event)
+    }
 }
 
 impl Default for MigrationRegistry {
@@ -72,6 +101,60 @@ impl Default for MigrationRegistry {
     }
 }
 
+/// Schema version of a snapshot or delta; files from before versioning are v1.
+pub(crate) fn snapshot_version(snapshot: &Value) -> u32 {
+    snapshot.get("v").and_then(|v| v.as_u64()).unwrap_or(1) as u32
+}
+
+/// v1 → v2: the state holds exactly the persisted collections.
+///
+/// v1 snapshots can still carry the retired `sessions` map, and omit
+/// collections added over v1's lifetime (`agents`, `project_paths`, ...),
+/// which were filled in by `#[serde(default)]`.
+struct V1ToV2;
+
+/// Collections every v2 state has.
+const V2_COLLECTIONS: [&str; 10] = [
+    "jobs",
+    "workspaces",
+    "runbooks",
+    "workers",
+    "queue_items",
+    "crons",
+    "decisions",
+    "crew",
+    "agents",
+    "project_paths",
+];
+
+impl Migration for V1ToV2 {
+    fn source_version(&self) -> u32 {
+        1
+    }
+
+    fn target_version(&self) -> u32 {
+        2
+    }
+
+    fn description(&self) -> &'static str {
+        "drop the retired sessions map and fill in missing state collections"
+    }
+
+    fn migrate(&self, snapshot: &mut Value) -> Result<(), MigrationError> {
+        let Some(state) = snapshot.get_mut("state") else {
+            return Err(MigrationError::Invalid(1, "missing state".to_string()));
+        };
+        let Some(state) = state.as_object_mut() else {
+            return Err(MigrationError::Invalid(1, "state is not an object".to_string()));
+        };
+        state.remove("sessions");
+        for name in V2_COLLECTIONS {
+            state.entry(name).or_insert_with(|| Value::Object(Map::new()));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 #[path = "migration_tests.rs"]
 mod tests;
@@ -23,7 +23,7 @@ fn test_too_new_error() {
 fn test_no_path_error() {
     // Try to migrate from v1 to v2 with no registered migrations
     let v1 = json!({"v": 1, "seq": 1, "state": {}});
-    let registry = MigrationRegistry::new();
+    let registry = MigrationRegistry { migrations: Vec::new() };
     assert!(matches!(registry.migrate_to(v1, 2), Err(MigrationError::NoPath(1, 2))));
 }
 
@@ -37,6 +37,9 @@ impl Migration for MockV1ToV2 {
     fn target_version(&self) -> u32 {
         2
     }
+    fn description(&self) -> &'static str {
+        "mark as migrated"
+    }
     fn migrate(&self, snapshot: &mut Value) -> Result<(), MigrationError> {
         // Add a new field as part of migration
         if let Some(obj) = snapshot.as_object_mut() {
@@ -48,8 +51,7 @@ impl Migration for MockV1ToV2 {
 
 #[test]
 fn test_migration_chain() {
-    let mut registry = MigrationRegistry::new();
-    registry.migrations.push(Box::new(MockV1ToV2));
+    let registry = MigrationRegistry { migrations: vec![Box::new(MockV1ToV2)] };
 
     let v1 = json!({"v": 1, "seq": 42, "state": {}});
     let result = registry.migrate_to(v1, 2).unwrap();
@@ -58,3 +60,226 @@ fn test_migration_chain() {
     assert_eq!(result["seq"], 42);
     assert_eq!(result["migrated"], true);
 }
+
+#[test]
+fn v1_to_v2_drops_sessions_and_fills_collections() {
+    let v1 = json!({
+        "v": 1,
+        "seq": 7,
+        "state": {"jobs": {"job-1": {"id": "job-1"}}, "sessions": {"s": {}}, "workspaces": {}},
+    });
+
+    let v2 = MigrationRegistry::new().migrate_to(v1, 2).unwrap();
+
+    let mut keys: Vec<&str> = v2["state"].as_object().unwrap().keys().map(|k| k.as_str()).collect();
+    keys.sort();
+    let mut expected = V2_COLLECTIONS.to_vec();
+    expected.sort();
+    assert_eq!(keys, expected);
+    assert_eq!(v2["state"]["jobs"]["job-1"], json!({"id": "job-1"}));
+    assert_eq!((v2["v"].clone(), v2["seq"].clone()), (json!(2), json!(7)));
+}
+
+#[test]
+fn v1_to_v2_needs_a_state() {
+    let err = MigrationRegistry::new().migrate_to(json!({"seq": 1}), 2).err();
+    assert!(matches!(err, Some(MigrationError::Invalid(1, _))), "{:?}", err);
+}
+
+#[test]
+fn path_lists_migrations_in_order() {
+    let registry = MigrationRegistry::new();
+    let descriptions: Vec<_> = registry
+        .path(1, CURRENT_SNAPSHOT_VERSION)
+        .unwrap()
+        .iter()
+        .map(|m| m.description())
+        .collect();
+    assert_eq!(descriptions.len(), (CURRENT_SNAPSHOT_VERSION - 1) as usize);
+    assert!(registry.path(CURRENT_SNAPSHOT_VERSION, CURRENT_SNAPSHOT_VERSION).unwrap().is_empty());
+}
+
+/// An event renamed between v1 and v2.
+struct RenameTimer;
+
+impl Migration for RenameTimer {
+    fn source_version(&self) -> u32 {
+        1
+    }
+    fn target_version(&self) -> u32 {
+        2
+    }
+    fn description(&self) -> &'static str {
+        "rename timer:begin to timer:start"
+    }
+    fn migrate(&self, _snapshot: &mut Value) -> Result<(), MigrationError> {
+        Ok(())
+    }
+    fn migrate_event(&self, event: &mut Value) -> Result<(), MigrationError> {
+        if event["type"] == "timer:begin" {
+            event["type"] = "timer:start".into();
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn event_migrations_apply_from_the_written_version() {
+    let registry = MigrationRegistry { migrations: vec![Box::new(RenameTimer)] };
+    let old = json!({"type": "timer:begin", "id": "t"});
+
+    let migrated = registry.migrate_event(old.clone(), 1).unwrap();
+    let event = crate::storage::segment::event_from_value(&migrated).unwrap();
+    assert_eq!(event, oj_core::Event::TimerStart { id: oj_core::TimerId::from_string("t") });
+
+    // Already at the current version: left alone
+    assert_eq!(
+        registry.migrate_event(old, CURRENT_SNAPSHOT_VERSION).unwrap()["type"],
+        "timer:begin"
+    );
+}
+
+#[test]
+fn event_migrations_default_to_leaving_events_alone() {
+    let registry = MigrationRegistry { migrations: vec![Box::new(MockV1ToV2)] };
+    let old = json!({"type": "timer:begin", "id": "t"});
+    assert_eq!(registry.migrate_event(old.clone(), 1).unwrap(), old);
+}
+
+#[test]
+fn event_from_newer_version_is_rejected() {
+    let err = MigrationRegistry::new().migrate_event(json!({}), CURRENT_SNAPSHOT_VERSION + 1).err();
+    assert!(matches!(err, Some(MigrationError::TooNew(..))), "{:?}", err);
+}
+
+// ── Golden fixtures ──────────────────────────────────────────────────────────
+//
+// Files written by earlier versions, in `tests/fixtures/storage/v<N>/`. The
+// v1 snapshot is at seq 2 and the WAL holds seqs 1-5: job-1 created and
+// advanced to build, worker fixer started, an item pushed to bugs, and
+// job-1 advanced to review.
+
+mod golden {
+    use std::path::Path;
+
+    use tempfile::tempdir;
+
+    use crate::storage::{load_snapshot, MaterializedState, Wal, CURRENT_SNAPSHOT_VERSION};
+
+    const V1_SNAPSHOT: &str = include_str!("../../tests/fixtures/storage/v1/snapshot.json");
+    const V1_SEGMENT: &[u8] =
+        include_bytes!("../../tests/fixtures/storage/v1/wal/00000000000000000001.seg");
+    const V1_LEGACY_WAL: &str = include_str!("../../tests/fixtures/storage/v1/events.wal");
+
+    fn write_v1_snapshot(dir: &Path) {
+        let compressed = zstd::encode_all(V1_SNAPSHOT.as_bytes(), 3).unwrap();
+        std::fs::write(dir.join("snapshot.json"), compressed).unwrap();
+    }
+
+    fn write_v1_segment(dir: &Path) {
+        std::fs::create_dir_all(dir.join("wal")).unwrap();
+        std::fs::write(dir.join("wal/00000000000000000001.seg"), V1_SEGMENT).unwrap();
+    }
+
+    fn segment_version(path: &Path) -> u32 {
+        let bytes = std::fs::read(path).unwrap();
+        crate::storage::segment::parse_header(&bytes).unwrap()
+    }
+
+    fn assert_replayed(state: &MaterializedState) {
+        assert_eq!(state.jobs["job-1"].step, "review");
+        assert_eq!(state.workers.len(), 1);
+        assert_eq!(state.queue_items.values().map(Vec::len).sum::<usize>(), 1);
+        assert!(state.project_paths.contains_key("api"));
+    }
+
+    #[test]
+    fn v1_snapshot_loads() {
+        let dir = tempdir().unwrap();
+        write_v1_snapshot(dir.path());
+
+        let snapshot = load_snapshot(&dir.path().join("snapshot.json")).unwrap().unwrap();
+
+        assert_eq!((snapshot.version, snapshot.seq), (CURRENT_SNAPSHOT_VERSION, 2));
+        assert_eq!(snapshot.state.jobs["job-1"].step, "build");
+        assert!(snapshot.state.agents.is_empty());
+    }
+
+    #[test]
+    fn v1_segment_replays_onto_v1_snapshot() {
+        let dir = tempdir().unwrap();
+        write_v1_snapshot(dir.path());
+        write_v1_segment(dir.path());
+
+        let mut state = load_snapshot(&dir.path().join("snapshot.json")).unwrap().unwrap().state;
+        let wal = Wal::open(&dir.path().join("wal"), 2).unwrap();
+        let entries = wal.entries_after(2).unwrap();
+
+        assert_eq!(entries.iter().map(|e| e.seq).collect::<Vec<_>>(), [3, 4, 5]);
+        for entry in &entries {
+            state.apply_event(&entry.event);
+        }
+        assert_replayed(&state);
+    }
+
+    #[test]
+    fn new_entries_after_v1_segment_go_to_current_segment() {
+        let dir = tempdir().unwrap();
+        write_v1_segment(dir.path());
+        let wal_dir = dir.path().join("wal");
+
+        let mut wal = Wal::open(&wal_dir, 0).unwrap();
+        let seq = wal.append(&oj_core::Event::Shutdown).unwrap();
+        wal.flush().unwrap();
+        drop(wal);
+
+        assert_eq!(seq, 6);
+        assert_eq!(segment_version(&wal_dir.join("00000000000000000001.seg")), 1);
+        assert_eq!(
+            segment_version(&wal_dir.join("00000000000000000006.seg")),
+            CURRENT_SNAPSHOT_VERSION
+        );
+        let wal = Wal::open(&wal_dir, 0).unwrap();
+        let seqs: Vec<u64> = wal.entries_after(0).unwrap().iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn v1_legacy_wal_imports_at_current_version() {
+        let dir = tempdir().unwrap();
+        let wal_dir = dir.path().join("wal");
+        std::fs::create_dir_all(&wal_dir).unwrap();
+        std::fs::write(wal_dir.join("events.wal"), V1_LEGACY_WAL).unwrap();
+
+        let wal = Wal::open(&wal_dir, 0).unwrap();
+        let mut state = MaterializedState::default();
+        for entry in wal.entries_after(0).unwrap() {
+            state.apply_event(&entry.event);
+        }
+
+        assert_replayed(&state);
+        assert_eq!(
+            segment_version(&wal_dir.join("00000000000000000001.seg")),
+            CURRENT_SNAPSHOT_VERSION
+        );
+    }
+
+    #[test]
+    fn v1_segment_upgrades_in_place() {
+        let dir = tempdir().unwrap();
+        write_v1_segment(dir.path());
+        let wal_dir = dir.path().join("wal");
+
+        assert_eq!(Wal::upgrade(&wal_dir).unwrap(), 1);
+        assert_eq!(Wal::upgrade(&wal_dir).unwrap(), 0);
+
+        let segment = wal_dir.join("00000000000000000001.seg");
+        assert_eq!(segment_version(&segment), CURRENT_SNAPSHOT_VERSION);
+        let entries = Wal::read_after(&wal_dir, 0).unwrap();
+        assert_eq!(
+            entries.iter().map(|e| (e.seq, e.ts_ms)).collect::<Vec<_>>()[1],
+            (2, 1_768_478_460_000)
+        );
+        assert_eq!(entries.len(), 5);
+    }
+}
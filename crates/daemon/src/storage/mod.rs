@@ -3,18 +3,28 @@
 
 //! Storage layer for Odd Jobs
 
+mod archive;
 mod checkpoint;
 mod migration;
+mod segment;
 mod snapshot;
 mod state;
+mod tail;
 mod wal;
 
+pub use archive::{due_for_archive, ArchiveError, JobArchive};
 pub use checkpoint::{load_snapshot, Checkpointer};
-pub use snapshot::{Snapshot, SnapshotError, CURRENT_SNAPSHOT_VERSION};
-pub use state::{CronRecord, MaterializedState, QueueItemStatus, QueuePollMeta, WorkerRecord};
-pub use wal::{Wal, WalEntry, WalError};
+pub(crate) use checkpoint::{read_unmigrated, CheckpointError};
+pub(crate) use migration::{snapshot_version, MigrationError, MigrationRegistry};
+pub use snapshot::{
+    history_snapshots, keep_in_history, prune_history, Snapshot, SnapshotError,
+    CURRENT_SNAPSHOT_VERSION,
+};
+pub use state::{
+    CronRecord, MaterializedState, QueueItemStatus, QueuePollMeta, StateDelta, WorkerRecord,
+};
+pub use tail::WalTail;
+pub use wal::{Wal, WalCheck, WalEntry, WalError, WalFileReport};
 
-#[cfg(test)]
-pub(crate) use migration::MigrationError;
 #[cfg(test)]
 pub use state::{QueueItem, Workspace, WorkspaceType};
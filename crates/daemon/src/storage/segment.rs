@@ -0,0 +1,395 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! On-disk format for WAL segments.
+//!
+//! A segment is named after the first sequence number it holds
+//! (`00000000000000000042.seg`) and starts with an 8-byte header: the magic
+//! `OJWAL\0` and the schema version (u16 BE) its events were written with.
+//! Each group commit appends one frame:
+//!
+//! ```text
+//! [len: u32 LE][crc32: u32 LE][payload: zstd(entry*)]
+//! entry = [seq: u64 LE][ts: u64 LE][len: u32 LE][event JSON]
+//! ```
+//!
+//! The checksum covers the compressed payload, so a frame cut short by a
+//! crash or damaged on disk is detected rather than half-applied.
+//!
+//! Events from segments written at an older schema version are passed
+//! through the event migrations before they are parsed. Segments written
+//! before the version was recorded have `\0\x01` there, i.e. version 1.
+
+use oj_core::Event;
+use serde_json::Value;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::migration::{MigrationError, MigrationRegistry};
+use super::wal::{WalEntry, WalError};
+use super::CURRENT_SNAPSHOT_VERSION;
+
+/// Segment format name, followed by the schema version
+const MAGIC: &[u8; 6] = b"OJWAL\0";
+
+/// Length of the segment header; the first frame starts here
+pub(super) const HEADER_LEN: u64 = MAGIC.len() as u64 + 2;
+
+const FRAME_HEADER_LEN: usize = 8;
+
+/// Larger lengths can only come from a damaged header
+const MAX_FRAME_BYTES: usize = 64 * 1024 * 1024;
+
+const ZSTD_LEVEL: i32 = 3;
+
+const EXTENSION: &str = "seg";
+
+/// An entry with its event still encoded: waiting to be written, or read
+/// back before migration.
+#[derive(Debug, Clone)]
+pub(super) struct PendingEntry {
+    pub seq: u64,
+    pub ts_ms: u64,
+    pub json: Vec<u8>,
+}
+
+/// A segment file and the first sequence number it holds.
+#[derive(Debug, Clone)]
+pub(super) struct Segment {
+    pub first_seq: u64,
+    pub path: PathBuf,
+}
+
+/// Result of reading one frame.
+pub(super) enum Frame<T = WalEntry> {
+    /// Decoded entries and the frame's size in bytes
+    Entries(Vec<T>, u64),
+    /// Clean end of data
+    End,
+    /// Torn or damaged frame
+    Corrupt(&'static str),
+}
+
+/// Segments in `dir`, oldest first.
+pub(super) fn list(dir: &Path) -> Result<Vec<Segment>, WalError> {
+    let mut segments = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+            continue;
+        }
+        let first_seq = path.file_stem().and_then(|s| s.to_str()).and_then(|s| s.parse().ok());
+        if let Some(first_seq) = first_seq {
+            segments.push(Segment { first_seq, path });
+        }
+    }
+    segments.sort_by_key(|s| s.first_seq);
+    Ok(segments)
+}
+
+/// Create an empty segment starting at `first_seq` and make it durable.
+pub(super) fn create(dir: &Path, first_seq: u64) -> Result<(Segment, File), WalError> {
+    let path = dir.join(format!("{:020}.{}", first_seq, EXTENSION));
+    let mut file =
+        OpenOptions::new().create(true).truncate(true).read(true).write(true).open(&path)?;
+    file.write_all(&header(CURRENT_SNAPSHOT_VERSION))?;
+    file.sync_all()?;
+    File::open(dir)?.sync_all()?;
+    Ok((Segment { first_seq, path }, file))
+}
+
+/// Segment header for events written at `version`.
+pub(super) fn header(version: u32) -> [u8; HEADER_LEN as usize] {
+    let mut header = [0u8; HEADER_LEN as usize];
+    header[..MAGIC.len()].copy_from_slice(MAGIC);
+    header[MAGIC.len()..].copy_from_slice(&(version as u16).to_be_bytes());
+    header
+}
+
+/// Schema version from a segment header, or `None` if it is not one.
+pub(super) fn parse_header(bytes: &[u8]) -> Option<u32> {
+    let version = bytes.strip_prefix(MAGIC)?.first_chunk::<2>()?;
+    Some(u16::from_be_bytes(*version) as u32)
+}
+
+/// Schema version of an open segment, read from its header.
+pub(super) fn read_version(reader: &mut impl Read) -> Result<u32, WalError> {
+    let mut bytes = [0u8; HEADER_LEN as usize];
+    let version = match read_full(reader, &mut bytes)? {
+        len if len == bytes.len() => parse_header(&bytes),
+        _ => None,
+    };
+    // Headers are checked when the WAL is opened; a bad one here leaves the
+    // frames to fail on their own
+    let version = version.unwrap_or(CURRENT_SNAPSHOT_VERSION);
+    check_version(version)?;
+    Ok(version)
+}
+
+fn check_version(version: u32) -> Result<(), WalError> {
+    if version > CURRENT_SNAPSHOT_VERSION {
+        return Err(MigrationError::TooNew(version, CURRENT_SNAPSHOT_VERSION).into());
+    }
+    Ok(())
+}
+
+/// Encode a batch of entries as a single frame.
+pub(super) fn encode_frame(entries: &[PendingEntry]) -> Result<Vec<u8>, WalError> {
+    let mut body = Vec::new();
+    for entry in entries {
+        body.extend_from_slice(&entry.seq.to_le_bytes());
+        body.extend_from_slice(&entry.ts_ms.to_le_bytes());
+        body.extend_from_slice(&(entry.json.len() as u32).to_le_bytes());
+        body.extend_from_slice(&entry.json);
+    }
+    let payload = zstd::stream::encode_all(body.as_slice(), ZSTD_LEVEL)?;
+
+    let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + payload.len());
+    frame.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&crc32(&payload).to_le_bytes());
+    frame.extend_from_slice(&payload);
+    Ok(frame)
+}
+
+/// Read the next frame from a segment written at `version`.
+pub(super) fn read_frame(reader: &mut impl Read, version: u32) -> Result<Frame, WalError> {
+    Ok(match read_raw_frame(reader)? {
+        Frame::Entries(raw, len) => match decode_events(raw, version) {
+            Some(entries) => Frame::Entries(entries, len),
+            None => Frame::Corrupt("undecodable entry"),
+        },
+        Frame::End => Frame::End,
+        Frame::Corrupt(reason) => Frame::Corrupt(reason),
+    })
+}
+
+/// Read the next frame, leaving the events as written.
+fn read_raw_frame(reader: &mut impl Read) -> Result<Frame<PendingEntry>, WalError> {
+    let mut header = [0u8; FRAME_HEADER_LEN];
+    match read_full(reader, &mut header)? {
+        0 => return Ok(Frame::End),
+        FRAME_HEADER_LEN => {}
+        _ => return Ok(Frame::Corrupt("torn frame header")),
+    }
+    let len = u32::from_le_bytes([header[0], header[1], header[2], header[3]]) as usize;
+    let checksum = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+    if len > MAX_FRAME_BYTES {
+        return Ok(Frame::Corrupt("frame length out of range"));
+    }
+
+    let mut payload = vec![0u8; len];
+    if read_full(reader, &mut payload)? < len {
+        return Ok(Frame::Corrupt("torn frame"));
+    }
+    if crc32(&payload) != checksum {
+        return Ok(Frame::Corrupt("checksum mismatch"));
+    }
+    let Ok(body) = zstd::stream::decode_all(payload.as_slice()) else {
+        return Ok(Frame::Corrupt("undecodable frame"));
+    };
+    match decode_entries(&body) {
+        Some(entries) => Ok(Frame::Entries(entries, (FRAME_HEADER_LEN + len) as u64)),
+        None => Ok(Frame::Corrupt("undecodable entry")),
+    }
+}
+
+/// A segment read into memory.
+pub(super) struct Scan<T = WalEntry> {
+    /// Schema version from the header
+    pub version: u32,
+    pub entries: Vec<T>,
+    /// Bytes up to the end of the last good frame
+    pub valid_len: usize,
+    /// Why reading stopped early, if it did
+    pub corrupt: Option<&'static str>,
+}
+
+/// Decode every frame in a segment's bytes, stopping at the first bad one.
+///
+/// Fails if the segment was written by a newer schema version.
+pub(super) fn scan(bytes: &[u8]) -> Result<Scan, WalError> {
+    scan_with(bytes, |reader, version| read_frame(reader, version))
+}
+
+/// Like `scan`, but leaves the events as written.
+pub(super) fn scan_raw(bytes: &[u8]) -> Result<Scan<PendingEntry>, WalError> {
+    scan_with(bytes, |reader, _| read_raw_frame(reader))
+}
+
+/// A segment split into frames, with events as written.
+pub(super) struct Frames {
+    /// Schema version from the header
+    pub version: u32,
+    /// Offset and entries of each good frame
+    pub frames: Vec<(u64, Vec<PendingEntry>)>,
+    /// Offset of the first bad frame, and why it is bad
+    pub corrupt: Option<(u64, &'static str)>,
+}
+
+/// Split a segment's bytes into frames, stopping at the first bad one.
+///
+/// Fails if the segment was written by a newer schema version.
+pub(super) fn frames(bytes: &[u8]) -> Result<Frames, WalError> {
+    let mut frames =
+        Frames { version: CURRENT_SNAPSHOT_VERSION, frames: Vec::new(), corrupt: None };
+    let Some(version) = parse_header(bytes) else {
+        frames.corrupt = Some((0, "bad segment header"));
+        return Ok(frames);
+    };
+    check_version(version)?;
+    frames.version = version;
+    let mut offset = HEADER_LEN;
+    let mut rest = &bytes[HEADER_LEN as usize..];
+    loop {
+        match read_raw_frame(&mut rest)? {
+            Frame::Entries(entries, len) => {
+                frames.frames.push((offset, entries));
+                offset += len;
+            }
+            Frame::End => return Ok(frames),
+            Frame::Corrupt(reason) => {
+                frames.corrupt = Some((offset, reason));
+                return Ok(frames);
+            }
+        }
+    }
+}
+
+fn scan_with<T>(
+    bytes: &[u8],
+    read: impl Fn(&mut &[u8], u32) -> Result<Frame<T>, WalError>,
+) -> Result<Scan<T>, WalError> {
+    let mut scan = Scan {
+        version: CURRENT_SNAPSHOT_VERSION,
+        entries: Vec::new(),
+        valid_len: 0,
+        corrupt: None,
+    };
+    let Some(version) = parse_header(bytes) else {
+        scan.corrupt = Some("bad segment header");
+        return Ok(scan);
+    };
+    check_version(version)?;
+    scan.version = version;
+    scan.valid_len = HEADER_LEN as usize;
+    let mut rest = &bytes[HEADER_LEN as usize..];
+    loop {
+        match read(&mut rest, version)? {
+            Frame::Entries(entries, len) => {
+                scan.entries.extend(entries);
+                scan.valid_len += len as usize;
+            }
+            Frame::End => return Ok(scan),
+            Frame::Corrupt(reason) => {
+                scan.corrupt = Some(reason);
+                return Ok(scan);
+            }
+        }
+    }
+}
+
+fn decode_entries(mut body: &[u8]) -> Option<Vec<PendingEntry>> {
+    let mut entries = Vec::new();
+    while !body.is_empty() {
+        let (seq, rest) = take_u64(body)?;
+        let (ts_ms, rest) = take_u64(rest)?;
+        let (len, rest) = rest.split_first_chunk::<4>()?;
+        let len = u32::from_le_bytes(*len) as usize;
+        if rest.len() < len {
+            return None;
+        }
+        let (json, rest) = rest.split_at(len);
+        entries.push(PendingEntry { seq, ts_ms, json: json.to_vec() });
+        body = rest;
+    }
+    Some(entries)
+}
+
+fn decode_events(raw: Vec<PendingEntry>, version: u32) -> Option<Vec<WalEntry>> {
+    let decoder = EventDecoder::new(version);
+    let mut entries = Vec::with_capacity(raw.len());
+    for entry in raw {
+        let event = decoder.decode(&entry.json)?;
+        entries.push(WalEntry { seq: entry.seq, ts_ms: entry.ts_ms, event });
+    }
+    Some(entries)
+}
+
+/// Parses events written at a schema version, migrating older ones first.
+pub(super) struct EventDecoder {
+    version: u32,
+    registry: Option<MigrationRegistry>,
+}
+
+impl EventDecoder {
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            registry: (version != CURRENT_SNAPSHOT_VERSION).then(MigrationRegistry::new),
+        }
+    }
+
+    pub fn decode(&self, json: &[u8]) -> Option<Event> {
+        let Some(registry) = &self.registry else {
+            return serde_json::from_slice(json).ok();
+        };
+        let value: Value = serde_json::from_slice(json).ok()?;
+        let value = registry.migrate_event(value, self.version).ok()?;
+        event_from_value(&value).ok()
+    }
+}
+
+/// Parse a migrated event. Goes back through bytes because IDs only
+/// deserialize from borrowed strings.
+pub(super) fn event_from_value(value: &Value) -> Result<Event, serde_json::Error> {
+    serde_json::from_slice(&serde_json::to_vec(value)?)
+}
+
+fn take_u64(bytes: &[u8]) -> Option<(u64, &[u8])> {
+    let (value, rest) = bytes.split_first_chunk::<8>()?;
+    Some((u64::from_le_bytes(*value), rest))
+}
+
+/// Fill `buf` as far as the reader allows, returning the bytes read.
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// CRC-32 (IEEE), as used by zlib and gzip.
+pub(super) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc = CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { 0xEDB8_8320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+#[cfg(test)]
+#[path = "segment_tests.rs"]
+mod tests;
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use oj_core::TimerId;
+use tempfile::tempdir;
+
+fn pending(seq: u64, name: &str) -> PendingEntry {
+    let event = Event::TimerStart { id: TimerId::from_string(name) };
+    PendingEntry { seq, ts_ms: seq * 1000, json: serde_json::to_vec(&event).unwrap() }
+}
+
+fn segment_bytes(frames: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes = header(CURRENT_SNAPSHOT_VERSION).to_vec();
+    for frame in frames {
+        bytes.extend_from_slice(frame);
+    }
+    bytes
+}
+
+#[test]
+fn crc32_matches_reference_value() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+}
+
+#[test]
+fn frame_round_trips_batch() {
+    let frame = encode_frame(&[pending(7, "a"), pending(8, "b")]).unwrap();
+
+    let Frame::Entries(entries, len) =
+        read_frame(&mut frame.as_slice(), CURRENT_SNAPSHOT_VERSION).unwrap()
+    else {
+        unreachable!("frame did not decode");
+    };
+
+    assert_eq!(len, frame.len() as u64);
+    let decoded: Vec<_> = entries.iter().map(|e| (e.seq, e.ts_ms, e.event.clone())).collect();
+    assert_eq!(
+        decoded,
+        [
+            (7, 7000, Event::TimerStart { id: TimerId::from_string("a") }),
+            (8, 8000, Event::TimerStart { id: TimerId::from_string("b") }),
+        ]
+    );
+}
+
+#[test]
+fn empty_input_is_end() {
+    assert!(matches!(
+        read_frame(&mut [].as_slice(), CURRENT_SNAPSHOT_VERSION).unwrap(),
+        Frame::End
+    ));
+}
+
+#[yare::parameterized(
+    torn_header = { |f: &mut Vec<u8>| f.truncate(5), "torn frame header" },
+    torn_payload = { |f: &mut Vec<u8>| { f.pop(); }, "torn frame" },
+    flipped_payload = { |f: &mut Vec<u8>| { if let Some(b) = f.last_mut() { *b ^= 1 } }, "checksum mismatch" },
+    huge_length = { |f: &mut Vec<u8>| f[..4].copy_from_slice(&u32::MAX.to_le_bytes()), "frame length out of range" },
+)]
+fn damaged_frames_are_detected(damage: fn(&mut Vec<u8>), reason: &str) {
+    let mut frame = encode_frame(&[pending(1, "a")]).unwrap();
+    damage(&mut frame);
+
+    assert!(matches!(
+        read_frame(&mut frame.as_slice(), CURRENT_SNAPSHOT_VERSION).unwrap(),
+        Frame::Corrupt(r) if r == reason
+    ));
+}
+
+#[test]
+fn scan_stops_at_first_bad_frame() {
+    let first = encode_frame(&[pending(1, "a")]).unwrap();
+    let mut second = encode_frame(&[pending(2, "b")]).unwrap();
+    second.truncate(second.len() - 1);
+    let bytes = segment_bytes(&[first.clone(), second]);
+
+    let scan = scan(&bytes).unwrap();
+
+    assert_eq!(scan.entries.iter().map(|e| e.seq).collect::<Vec<_>>(), [1]);
+    assert_eq!(scan.valid_len, HEADER_LEN as usize + first.len());
+    assert_eq!(scan.corrupt, Some("torn frame"));
+}
+
+#[test]
+fn scan_rejects_missing_header() {
+    let scan = scan(b"OJWAL").unwrap();
+    assert_eq!((scan.valid_len, scan.corrupt), (0, Some("bad segment header")));
+}
+
+#[yare::parameterized(
+    current = { &header(CURRENT_SNAPSHOT_VERSION), Some(CURRENT_SNAPSHOT_VERSION) },
+    // Segments from before the version was recorded
+    unversioned = { b"OJWAL\0\0\x01", Some(1) },
+    too_short = { b"OJWAL\0\0", None },
+    wrong_magic = { b"OJWAX\0\0\x01", None },
+)]
+fn header_records_schema_version(bytes: &[u8], version: Option<u32>) {
+    assert_eq!(parse_header(bytes), version);
+}
+
+#[test]
+fn scan_reports_segment_version() {
+    let mut bytes = header(1).to_vec();
+    bytes.extend(encode_frame(&[pending(1, "a")]).unwrap());
+
+    let scan = scan(&bytes).unwrap();
+
+    assert_eq!(scan.version, 1);
+    assert_eq!(scan.entries.len(), 1);
+}
+
+#[test]
+fn scan_rejects_newer_version() {
+    let bytes = header(CURRENT_SNAPSHOT_VERSION + 1);
+
+    let err = scan(&bytes).err();
+
+    assert!(matches!(err, Some(WalError::Migration(MigrationError::TooNew(..)))), "{:?}", err);
+}
+
+#[test]
+fn list_orders_segments_and_ignores_other_files() {
+    let dir = tempdir().unwrap();
+    create(dir.path(), 120).unwrap();
+    create(dir.path(), 3).unwrap();
+    std::fs::write(dir.path().join("00000000000000000003.bak"), b"").unwrap();
+    std::fs::write(dir.path().join("events.wal"), b"").unwrap();
+
+    let firsts: Vec<u64> = list(dir.path()).unwrap().iter().map(|s| s.first_seq).collect();
+
+    assert_eq!(firsts, [3, 120]);
+    assert_eq!(
+        std::fs::read(dir.path().join("00000000000000000003.seg")).unwrap(),
+        header(CURRENT_SNAPSHOT_VERSION)
+    );
+}
@@ -4,19 +4,22 @@
 //! Snapshot persistence for crash recovery.
 //!
 //! Snapshots store the complete materialized state at a point in time,
-//! identified by the WAL sequence number. Recovery loads the snapshot
-//! and replays WAL entries after that sequence.
+//! identified by the WAL sequence number. Between full snapshots,
+//! checkpoints write deltas holding only the entities that changed.
+//! Recovery loads the snapshot, applies its deltas in order, and replays
+//! WAL entries after the last one.
 
 use crate::storage::migration::MigrationError;
-use crate::storage::MaterializedState;
+use crate::storage::{MaterializedState, StateDelta};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs::{self};
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 use thiserror::Error;
 
-/// Current snapshot schema version
-pub const CURRENT_SNAPSHOT_VERSION: u32 = 1;
+/// Current schema version of snapshots, deltas, and WAL segments
+pub const CURRENT_SNAPSHOT_VERSION: u32 = 2;
 
 /// Errors that can occur in snapshot operations
 #[derive(Debug, Error)]
@@ -27,6 +30,8 @@ pub enum SnapshotError {
     Json(#[from] serde_json::Error),
     #[error("migration error: {0}")]
     Migration(#[from] MigrationError),
+    #[error("delta at seq {seq} applies to seq {base_seq}, but the state is at seq {expected}")]
+    BrokenChain { seq: u64, base_seq: u64, expected: u64 },
 }
 
 /// A snapshot of the materialized state at a point in time.
@@ -43,6 +48,102 @@ pub struct Snapshot {
     pub created_at: DateTime<Utc>,
 }
 
+/// Changes between two checkpoints, written between full snapshots.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeltaSnapshot {
+    /// Schema version for migrations
+    #[serde(rename = "v")]
+    pub version: u32,
+    /// Sequence number of the snapshot or delta this applies on top of
+    pub base_seq: u64,
+    /// WAL sequence number at the time of the delta
+    pub seq: u64,
+    /// Entities changed since `base_seq`
+    #[serde(flatten)]
+    pub delta: StateDelta,
+    /// When this delta was created
+    pub created_at: DateTime<Utc>,
+}
+
+/// Directory holding the deltas for a snapshot.
+pub fn deltas_dir(snapshot_path: &Path) -> PathBuf {
+    snapshot_path.with_extension("deltas")
+}
+
+/// Path of the delta ending at `seq`.
+pub(crate) fn delta_path(snapshot_path: &Path, seq: u64) -> PathBuf {
+    deltas_dir(snapshot_path).join(format!("{:020}.delta", seq))
+}
+
+/// Sequence number a delta file ends at, from its name.
+pub(crate) fn delta_seq(path: &Path) -> Option<u64> {
+    if path.extension()? != "delta" {
+        return None;
+    }
+    path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// Directory holding older snapshots kept for `ojd inspect`.
+pub fn history_dir(snapshot_path: &Path) -> PathBuf {
+    snapshot_path.with_extension("history")
+}
+
+/// Copy the snapshot at `snapshot_path`, which is at `seq`, into the
+/// history directory.
+pub fn keep_in_history(snapshot_path: &Path, seq: u64) -> std::io::Result<()> {
+    let dir = history_dir(snapshot_path);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{:020}.snapshot", seq));
+    let tmp_path = path.with_extension("tmp");
+    fs::copy(snapshot_path, &tmp_path)?;
+    fs::File::open(&tmp_path)?.sync_all()?;
+    fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Snapshots in the history directory, oldest first.
+pub fn history_snapshots(snapshot_path: &Path) -> std::io::Result<Vec<(u64, PathBuf)>> {
+    let entries = match fs::read_dir(history_dir(snapshot_path)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let mut snapshots = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "snapshot") {
+            if let Some(seq) = path.file_stem().and_then(|s| s.to_str()?.parse().ok()) {
+                snapshots.push((seq, path));
+            }
+        }
+    }
+    snapshots.sort();
+    Ok(snapshots)
+}
+
+/// Delete history snapshots kept more than `retain` before `now`, except
+/// the newest of them, which covers the start of the window. Returns the
+/// seq of the oldest snapshot left; the WAL must be kept from there on.
+pub fn prune_history(
+    snapshot_path: &Path,
+    retain: Duration,
+    now: SystemTime,
+) -> std::io::Result<Option<u64>> {
+    let snapshots = history_snapshots(snapshot_path)?;
+    let mut expired: usize = 0;
+    for (_, path) in &snapshots {
+        let kept_at = fs::metadata(path)?.modified()?;
+        if kept_at + retain > now {
+            break;
+        }
+        expired += 1;
+    }
+    for (_, path) in &snapshots[..expired.saturating_sub(1)] {
+        fs::remove_file(path)?;
+    }
+    Ok(snapshots.get(expired.saturating_sub(1)).map(|(seq, _)| *seq))
+}
+
 const MAX_BAK_FILES: u32 = 3;
 
 /// Pick the next `.bak` / `.bak.N` path, rotating older backups out.
@@ -74,3 +175,7 @@ pub(crate) fn rotate_bak_path(path: &Path) -> PathBuf {
 
     bak(1)
 }
+
+#[cfg(test)]
+#[path = "snapshot_tests.rs"]
+mod tests;
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::storage::{Checkpointer, MaterializedState};
+use tempfile::tempdir;
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+/// Keep a snapshot at each seq, each kept one hour after the previous
+/// and the last one at `now`.
+fn keep_hourly(snapshot_path: &Path, seqs: &[u64], now: SystemTime) {
+    let checkpointer = Checkpointer::new(snapshot_path.to_path_buf());
+    for (i, &seq) in seqs.iter().enumerate() {
+        checkpointer.checkpoint_sync(seq, &MaterializedState::default()).unwrap();
+        keep_in_history(snapshot_path, seq).unwrap();
+        let kept_at = now - HOUR * (seqs.len() - 1 - i) as u32;
+        let path = history_dir(snapshot_path).join(format!("{:020}.snapshot", seq));
+        fs::File::options().write(true).open(path).unwrap().set_modified(kept_at).unwrap();
+    }
+}
+
+fn seqs(snapshot_path: &Path) -> Vec<u64> {
+    history_snapshots(snapshot_path).unwrap().into_iter().map(|(seq, _)| seq).collect()
+}
+
+#[test]
+fn kept_snapshots_load_at_their_seq() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    keep_hourly(&path, &[10, 20], SystemTime::now());
+
+    assert_eq!(seqs(&path), vec![10, 20]);
+    let (_, oldest) = &history_snapshots(&path).unwrap()[0];
+    assert_eq!(crate::storage::load_snapshot(oldest).unwrap().unwrap().seq, 10);
+}
+
+#[test]
+fn missing_history_is_empty() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    assert!(history_snapshots(&path).unwrap().is_empty());
+    assert_eq!(prune_history(&path, HOUR, SystemTime::now()).unwrap(), None);
+}
+
+#[yare::parameterized(
+    all_recent = { 4, &[10, 20, 30, 40], Some(10) },
+    keeps_newest_expired = { 2, &[20, 30, 40], Some(20) },
+    all_expired = { 0, &[40], Some(40) },
+)]
+fn prune_keeps_start_of_window(retain_hours: u32, left: &[u64], oldest: Option<u64>) {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("snapshot.json");
+    let now = SystemTime::now();
+    keep_hourly(&path, &[10, 20, 30, 40], now);
+
+    let pruned = prune_history(&path, HOUR * retain_hours, now).unwrap();
+
+    assert_eq!(pruned, oldest);
+    assert_eq!(seqs(&path), left);
+}
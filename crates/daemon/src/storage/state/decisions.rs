@@ -20,6 +20,10 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             questions,
             created_at_ms,
             project,
+            expires_at_ms,
+            default_choice,
+            assignee,
+            reviewers,
         } => {
             // Idempotency: skip if already exists
             if !state.decisions.contains_key(id.as_str()) {
@@ -33,9 +37,16 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                     return;
                 }
 
-                // Auto-dismiss previous unresolved decisions for the same owner
-                for existing in state.decisions.values_mut() {
-                    if existing.owner == *owner && !existing.is_resolved() {
+                // Auto-dismiss previous unresolved decisions for the same owner,
+                // touching only those so the next checkpoint delta stays small
+                let superseded: Vec<String> = state
+                    .decisions
+                    .iter()
+                    .filter(|(_, d)| d.owner == *owner && !d.is_resolved())
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in superseded {
+                    if let Some(existing) = state.decisions.get_mut(&key) {
                         existing.resolved_at_ms = Some(*created_at_ms);
                         existing.superseded_by = Some(*id);
                     }
@@ -57,6 +68,11 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                         resolved_at_ms: None,
                         superseded_by: None,
                         project: project.clone(),
+                        expires_at_ms: *expires_at_ms,
+                        default_choice: *default_choice,
+                        timed_out: false,
+                        assignee: assignee.clone(),
+                        reviewers: reviewers.clone(),
                     },
                 );
             }
@@ -76,11 +92,12 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             }
         }
 
-        Event::DecisionResolved { id, choices, message, resolved_at_ms, .. } => {
+        Event::DecisionResolved { id, choices, message, resolved_at_ms, timed_out, .. } => {
             if let Some(decision) = state.decisions.get_mut(id.as_str()) {
                 decision.choices.clone_from(choices);
                 decision.message.clone_from(message);
                 decision.resolved_at_ms = Some(*resolved_at_ms);
+                decision.timed_out = *timed_out;
             }
         }
 
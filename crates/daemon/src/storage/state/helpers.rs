@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::tracked::TrackedMap;
 use super::types::QueueItem;
 
 /// Current epoch time in milliseconds.
@@ -42,7 +43,7 @@ pub(crate) fn find_queue_item_mut<'a>(
 
 /// Apply a mutation to a job only if it hasn't reached a terminal state.
 pub(crate) fn apply_if_not_terminal(
-    jobs: &mut HashMap<String, Job>,
+    jobs: &mut TrackedMap<Job>,
     job_id: &str,
     f: impl FnOnce(&mut Job),
 ) {
@@ -79,7 +80,7 @@ pub(crate) fn create_agent_record(
 
 /// Remove unresolved decisions owned by a specific owner.
 pub(crate) fn cleanup_unresolved_decisions_for_owner(
-    decisions: &mut HashMap<String, Decision>,
+    decisions: &mut TrackedMap<Decision>,
     owner: &OwnerId,
 ) {
     decisions.retain(|_, d| d.owner != *owner || d.is_resolved());
@@ -68,6 +68,15 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                 }
 
                 let now = helpers::epoch_ms_now();
+                // Leaving a parallel region: abandon any branches still running
+                for (name, status) in std::mem::take(&mut job.branches) {
+                    if matches!(
+                        status,
+                        StepStatus::Pending | StepStatus::Running | StepStatus::Waiting(_)
+                    ) {
+                        job.finalize_step(&name, StepOutcome::Failed("cancelled".into()), now);
+                    }
+                }
                 // Finalize the previous step
                 let outcome = match step.as_str() {
                     "failed" | "cancelled" | "suspended" => {
@@ -109,9 +118,19 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             // Remove from worker active_job_ids and item_owners on terminal states
             if step == "done" || step == "failed" || step == "cancelled" || step == "suspended" {
                 let job_id_str = id.to_string();
-                for record in state.workers.values_mut() {
-                    record.active.retain(|pid| pid != &job_id_str);
-                    record.owners.remove(&job_id_str);
+                let owning_workers: Vec<String> = state
+                    .workers
+                    .iter()
+                    .filter(|(_, w)| {
+                        w.active.contains(&job_id_str) || w.owners.contains_key(&job_id_str)
+                    })
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                for key in owning_workers {
+                    if let Some(record) = state.workers.get_mut(&key) {
+                        record.active.retain(|pid| pid != &job_id_str);
+                        record.owners.remove(&job_id_str);
+                    }
                 }
                 // Clean up unresolved decisions for the completed job
                 helpers::cleanup_unresolved_decisions_for_owner(
@@ -121,18 +140,48 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             }
         }
 
-        Event::StepStarted { job_id, agent_id, agent_name, .. } => {
-            if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
+        Event::JobForked { id, steps } => {
+            if let Some(job) = state.jobs.get_mut(id.as_str()) {
+                let now = helpers::epoch_ms_now();
+                // Idempotency: each step runs at most once per parallel region
+                for step in steps {
+                    if job.branches.contains_key(step) {
+                        continue;
+                    }
+                    job.branches.insert(step.clone(), StepStatus::Pending);
+                    job.record_step_visit(step);
+                    job.push_step(step, now);
+                }
                 job.step_status = StepStatus::Running;
-                if let Some(aid) = agent_id {
-                    job.set_current_step_agent_id(aid.as_str());
+            }
+        }
+
+        Event::StepStarted { job_id, step, agent_id, agent_name } => {
+            if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
+                let aid = agent_id.as_ref().map(|a| a.as_str());
+                // A parallel branch records onto its own step record
+                if job.branches.contains_key(step) {
+                    job.branches.insert(step.clone(), StepStatus::Running);
+                    job.set_step_agent(step, aid, agent_name.as_deref());
+                    job.update_step_outcome(step, StepOutcome::Running);
+                } else {
+                    job.step_status = StepStatus::Running;
+                    if let Some(aid) = aid {
+                        job.set_current_step_agent_id(aid);
+                    }
+                    if let Some(aname) = agent_name {
+                        job.set_current_step_agent_name(aname.as_str());
+                    }
+                    job.update_current_step_outcome(StepOutcome::Running);
+                }
 
+                if let Some(aid) = aid {
                     // Insert unified agent record for job-embedded agents
                     let workspace =
                         job.workspace_path.as_ref().cloned().unwrap_or_else(|| job.cwd.clone());
-                    state.agents.entry(aid.as_str().to_string()).or_insert_with(|| {
+                    state.agents.entry(aid.to_string()).or_insert_with(|| {
                         helpers::create_agent_record(
-                            aid.as_str(),
+                            aid,
                             agent_name.clone().unwrap_or_default(),
                             (*job_id).into(),
                             job.project.clone(),
@@ -141,33 +190,54 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                         )
                     });
                 }
-                if let Some(aname) = agent_name {
-                    job.set_current_step_agent_name(aname.as_str());
-                }
-                job.update_current_step_outcome(StepOutcome::Running);
             }
         }
 
-        Event::StepWaiting { job_id, reason, decision_id, .. } => {
+        Event::StepWaiting { job_id, step, reason, decision_id } => {
             if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
-                job.step_status = StepStatus::Waiting(decision_id.clone());
                 if reason.is_some() {
                     job.error.clone_from(reason);
                 }
-                let reason_str = reason.clone().unwrap_or_default();
-                job.update_current_step_outcome(StepOutcome::Waiting(reason_str));
+                let outcome = StepOutcome::Waiting(reason.clone().unwrap_or_default());
+                if let Some(status) = job.branches.get_mut(step) {
+                    *status = StepStatus::Waiting(decision_id.clone());
+                    job.update_step_outcome(step, outcome);
+                    return;
+                }
+                job.step_status = StepStatus::Waiting(decision_id.clone());
+                job.update_current_step_outcome(outcome);
             }
         }
 
-        Event::StepCompleted { job_id, .. } => {
+        Event::StepCompleted { job_id, step } => {
             if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
+                if let Some(status) = job.branches.get_mut(step) {
+                    *status = StepStatus::Completed;
+                    job.finalize_step(step, StepOutcome::Completed, helpers::epoch_ms_now());
+                    return;
+                }
                 job.step_status = StepStatus::Completed;
                 job.finalize_current_step(StepOutcome::Completed, helpers::epoch_ms_now());
             }
         }
 
-        Event::StepFailed { job_id, error, .. } => {
+        Event::StepFailed { job_id, step, error, timed_out } => {
             if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
+                let outcome = if *timed_out {
+                    StepOutcome::TimedOut(error.clone())
+                } else {
+                    StepOutcome::Failed(error.clone())
+                };
+                if let Some(status) = job.branches.get_mut(step) {
+                    *status = StepStatus::Failed;
+                    job.finalize_step(step, outcome, helpers::epoch_ms_now());
+                    // Sibling cancellations don't fail the job itself
+                    if error != "cancelled" {
+                        job.step_status = StepStatus::Failed;
+                        job.error = Some(error.clone());
+                    }
+                    return;
+                }
                 // Don't overwrite Suspended status — suspension_effects() emits
                 // JobAdvanced{step:"suspended"} then StepFailed, and the latter
                 // must not reset the status back to Failed.
@@ -175,10 +245,7 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                     job.step_status = StepStatus::Failed;
                 }
                 job.error = Some(error.clone());
-                job.finalize_current_step(
-                    StepOutcome::Failed(error.clone()),
-                    helpers::epoch_ms_now(),
-                );
+                job.finalize_current_step(outcome, helpers::epoch_ms_now());
             }
         }
 
@@ -200,7 +267,16 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             }
         }
 
-        Event::JobDeleted { id } => {
+        // Archiving moves a terminal job out of state the same way; its
+        // workspace and logs are left alone. The archiver picks jobs before
+        // this lands, so one resumed in between stays in state.
+        Event::JobDeleted { id } | Event::JobArchived { id } => {
+            let archivable = |job: &Job| job.is_terminal() && !job.is_suspended();
+            if matches!(event, Event::JobArchived { .. })
+                && !state.jobs.get(id.as_str()).is_some_and(archivable)
+            {
+                return;
+            }
             state.jobs.remove(id.as_str());
             // Clean up all decisions and agents associated with the deleted job
             let owner = OwnerId::Job(*id);
@@ -208,8 +284,13 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             state.agents.retain(|_, rec| rec.owner != owner);
         }
 
-        Event::ShellExited { job_id, exit_code, .. } => {
+        Event::ShellExited { job_id, step, exit_code, .. } => {
             if let Some(job) = state.jobs.get_mut(job_id.as_str()) {
+                // Branch outcomes are recorded by the runtime via StepCompleted/StepFailed,
+                // and a step that already timed out and moved on ignores its late exit
+                if job.branches.contains_key(step) || job.step != *step {
+                    return;
+                }
                 let now = helpers::epoch_ms_now();
                 if *exit_code == 0 {
                     job.step_status = StepStatus::Completed;
@@ -8,59 +8,124 @@ mod decisions;
 mod helpers;
 mod jobs;
 mod queues;
+mod tracked;
 mod types;
 mod workers;
 mod workspaces;
 
+pub use tracked::TrackedMap;
 #[cfg(test)]
 pub use types::WorkspaceType;
 pub use types::{
-    CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, StoredRunbook, WorkerRecord, Workspace,
+    CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, StoredRunbook, WorkerRecord,
+    WorkerThrottle, Workspace,
 };
 
 use oj_core::{AgentRecord, Crew, Decision, Event, Job};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 /// Materialized state built from WAL operations
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct MaterializedState {
-    pub jobs: HashMap<String, Job>,
-    pub workspaces: HashMap<String, Workspace>,
-    #[serde(default)]
-    pub runbooks: HashMap<String, StoredRunbook>,
-    #[serde(default)]
-    pub workers: HashMap<String, WorkerRecord>,
-    #[serde(default)]
-    pub queue_items: HashMap<String, Vec<QueueItem>>,
-    #[serde(default)]
-    pub crons: HashMap<String, CronRecord>,
-    #[serde(default)]
-    pub decisions: HashMap<String, Decision>,
-    #[serde(default)]
-    pub crew: HashMap<String, Crew>,
+    pub jobs: TrackedMap<Job>,
+    pub workspaces: TrackedMap<Workspace>,
+    pub runbooks: TrackedMap<StoredRunbook>,
+    pub workers: TrackedMap<WorkerRecord>,
+    pub queue_items: TrackedMap<Vec<QueueItem>>,
+    pub crons: TrackedMap<CronRecord>,
+    pub decisions: TrackedMap<Decision>,
+    pub crew: TrackedMap<Crew>,
     /// Unified agent index: agent_id → AgentRecord.
     ///
     /// Populated from existing events (StepStarted, CrewStarted, agent
     /// state events) during WAL replay. Provides a single source of truth
     /// for all agent queries regardless of whether the agent is job-embedded
     /// or standalone.
-    #[serde(default)]
-    pub agents: HashMap<String, AgentRecord>,
+    pub agents: TrackedMap<AgentRecord>,
     /// Runtime-only poll metadata: scoped_queue_key → last poll info.
     /// Not persisted — repopulates naturally as workers resume polling.
     #[serde(skip)]
     pub poll_meta: HashMap<String, QueuePollMeta>,
+    /// Runtime-only: scoped worker key → why it is holding back dispatches.
+    /// Not persisted — set again by the worker's next poll.
+    #[serde(skip)]
+    pub worker_throttles: HashMap<String, WorkerThrottle>,
     /// Durable project → project path mapping.
     ///
     /// Populated from WorkerStarted, CronStarted, and CommandRun events.
     /// Never cleared by deletion events, so the mapping survives worker/cron pruning.
+    pub project_paths: TrackedMap<PathBuf>,
+}
+
+/// Persisted collections, for code that handles each one alike.
+macro_rules! tracked_collections {
+    ($each:ident) => {
+        $each!(jobs);
+        $each!(workspaces);
+        $each!(runbooks);
+        $each!(workers);
+        $each!(queue_items);
+        $each!(crons);
+        $each!(decisions);
+        $each!(crew);
+        $each!(agents);
+        $each!(project_paths);
+    };
+}
+
+/// Entities changed since the previous checkpoint.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct StateDelta {
+    /// Changed and added entities, as a partial state
+    pub state: MaterializedState,
+    /// Keys removed from each collection, by field name
     #[serde(default)]
-    pub project_paths: HashMap<String, PathBuf>,
+    pub removed: BTreeMap<String, Vec<String>>,
 }
 
 impl MaterializedState {
+    /// Copy out entities changed since the last call and reset tracking.
+    ///
+    /// Cost is proportional to the number of changed entities, not the
+    /// size of the state.
+    pub fn take_delta(&mut self) -> StateDelta {
+        let mut delta = StateDelta::default();
+        macro_rules! take {
+            ($field:ident) => {
+                let removed = self.$field.take_changes(&mut delta.state.$field);
+                if !removed.is_empty() {
+                    delta.removed.insert(stringify!($field).to_string(), removed);
+                }
+            };
+        }
+        tracked_collections!(take);
+        delta
+    }
+
+    /// Apply a delta read from disk. The result is not marked dirty.
+    pub fn apply_delta(&mut self, delta: StateDelta) {
+        let StateDelta { state, removed } = delta;
+        macro_rules! apply {
+            ($field:ident) => {
+                let gone = removed.get(stringify!($field)).map(Vec::as_slice).unwrap_or(&[]);
+                self.$field.apply_changes(state.$field, gone);
+            };
+        }
+        tracked_collections!(apply);
+    }
+
+    /// Forget pending changes, after the whole state has been written.
+    pub fn clear_dirty(&mut self) {
+        macro_rules! clear {
+            ($field:ident) => {
+                self.$field.clear_dirty();
+            };
+        }
+        tracked_collections!(clear);
+    }
+
     /// Get a job by ID or unique prefix (like git commit hashes)
     pub fn get_job(&self, id: &str) -> Option<&Job> {
         helpers::find_by_prefix(&self.jobs, id)
@@ -124,6 +189,7 @@ impl MaterializedState {
             Event::JobCreated { .. }
             | Event::RunbookLoaded { .. }
             | Event::JobAdvanced { .. }
+            | Event::JobForked { .. }
             | Event::StepStarted { .. }
             | Event::StepWaiting { .. }
             | Event::StepCompleted { .. }
@@ -132,6 +198,7 @@ impl MaterializedState {
             | Event::JobCancelling { .. }
             | Event::JobSuspending { .. }
             | Event::JobDeleted { .. }
+            | Event::JobArchived { .. }
             | Event::ShellExited { .. }
             | Event::JobUpdated { .. } => jobs::apply(self, event),
 
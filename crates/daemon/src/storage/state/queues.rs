@@ -11,7 +11,15 @@ use super::MaterializedState;
 
 pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
     match event {
-        Event::QueuePushed { queue, item_id, data, pushed_at_ms, project } => {
+        Event::QueuePushed {
+            queue,
+            item_id,
+            data,
+            pushed_at_ms,
+            priority,
+            not_before_ms,
+            project,
+        } => {
             let key = scoped_name(project, queue);
             let items = state.queue_items.entry(key).or_default();
             // Idempotency: skip if item already exists
@@ -24,6 +32,9 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                     worker: None,
                     pushed_at_ms: *pushed_at_ms,
                     failures: 0,
+                    priority: *priority,
+                    not_before_ms: *not_before_ms,
+                    retry_at_ms: None,
                 });
             }
         }
@@ -47,7 +58,7 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             }
         }
 
-        Event::QueueFailed { queue, item_id, project, .. } => {
+        Event::QueueFailed { queue, item_id, project, retry_at_ms, .. } => {
             let key = scoped_name(project, queue);
             if let Some(items) = state.queue_items.get_mut(&key) {
                 if let Some(item) = helpers::find_queue_item_mut(items, item_id) {
@@ -57,6 +68,7 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                         item.failures += 1;
                     }
                     item.status = QueueItemStatus::Failed;
+                    item.retry_at_ms = *retry_at_ms;
                 }
             }
         }
@@ -68,13 +80,21 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             }
         }
 
-        Event::QueueRetry { queue, item_id, project } => {
+        Event::QueueRetry { queue, item_id, project, scheduled } => {
             let key = scoped_name(project, queue);
             if let Some(items) = state.queue_items.get_mut(&key) {
                 if let Some(item) = helpers::find_queue_item_mut(items, item_id) {
+                    // A scheduled retry only resurrects a still-failed item;
+                    // a manual retry may have already re-queued it.
+                    if *scheduled && item.status != QueueItemStatus::Failed {
+                        return;
+                    }
                     item.status = QueueItemStatus::Pending;
-                    item.failures = 0;
+                    if !*scheduled {
+                        item.failures = 0;
+                    }
                     item.worker = None;
+                    item.retry_at_ms = None;
                 }
             }
         }
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Collections that remember which entries changed.
+//!
+//! Reads go through `Deref` to the inner `HashMap`; every way of mutating
+//! is an inherent method that marks the touched keys dirty. Checkpoints
+//! then write only the dirty entries instead of the whole state.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::collections::hash_map::{Entry, IterMut, ValuesMut};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Deref;
+
+/// A `HashMap<String, V>` that tracks keys changed since the last
+/// `take_changes`.
+#[derive(Debug, Clone)]
+pub struct TrackedMap<V> {
+    map: HashMap<String, V>,
+    dirty: HashSet<String>,
+}
+
+impl<V> Default for TrackedMap<V> {
+    fn default() -> Self {
+        Self { map: HashMap::new(), dirty: HashSet::new() }
+    }
+}
+
+impl<V> Deref for TrackedMap<V> {
+    type Target = HashMap<String, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<V> From<HashMap<String, V>> for TrackedMap<V> {
+    fn from(map: HashMap<String, V>) -> Self {
+        let dirty = map.keys().cloned().collect();
+        Self { map, dirty }
+    }
+}
+
+impl<V> FromIterator<(String, V)> for TrackedMap<V> {
+    fn from_iter<I: IntoIterator<Item = (String, V)>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<HashMap<_, _>>())
+    }
+}
+
+impl<'a, V> IntoIterator for &'a TrackedMap<V> {
+    type Item = (&'a String, &'a V);
+    type IntoIter = std::collections::hash_map::Iter<'a, String, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+impl<V: Serialize> Serialize for TrackedMap<V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.map.serialize(serializer)
+    }
+}
+
+/// Deserialized maps start clean: they match what is on disk.
+impl<'de, V: Deserialize<'de>> Deserialize<'de> for TrackedMap<V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self { map: HashMap::deserialize(deserializer)?, dirty: HashSet::new() })
+    }
+}
+
+impl<V> TrackedMap<V> {
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        self.dirty.insert(key.clone());
+        self.map.insert(key, value)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        String: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = String> + ?Sized,
+    {
+        let removed = self.map.remove(key);
+        if removed.is_some() {
+            self.dirty.insert(key.to_owned());
+        }
+        removed
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        String: Borrow<Q>,
+        Q: Hash + Eq + ToOwned<Owned = String> + ?Sized,
+    {
+        let value = self.map.get_mut(key);
+        if value.is_some() {
+            self.dirty.insert(key.to_owned());
+        }
+        value
+    }
+
+    pub fn entry(&mut self, key: String) -> Entry<'_, String, V> {
+        self.dirty.insert(key.clone());
+        self.map.entry(key)
+    }
+
+    /// Mutable access to every value; marks them all dirty.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, String, V> {
+        self.dirty.extend(self.map.keys().cloned());
+        self.map.values_mut()
+    }
+
+    /// Mutable access to every entry; marks them all dirty.
+    pub fn iter_mut(&mut self) -> IterMut<'_, String, V> {
+        self.dirty.extend(self.map.keys().cloned());
+        self.map.iter_mut()
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(&String, &mut V) -> bool) {
+        let dirty = &mut self.dirty;
+        self.map.retain(|key, value| {
+            let kept = keep(key, value);
+            if !kept {
+                dirty.insert(key.clone());
+            }
+            kept
+        });
+    }
+
+    /// Whether any entry changed since the last `take_changes`.
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Forget pending changes, e.g. after a full snapshot.
+    pub fn clear_dirty(&mut self) {
+        self.dirty.clear();
+    }
+
+    /// Apply changes read from a delta without marking them dirty.
+    pub fn apply_changes(&mut self, upserts: TrackedMap<V>, removed: &[String]) {
+        for key in removed {
+            self.map.remove(key);
+        }
+        self.map.extend(upserts.map);
+    }
+}
+
+impl<V: Clone> TrackedMap<V> {
+    /// Copy out entries changed since the last call, returning the keys
+    /// that were removed. Clears the dirty set.
+    pub fn take_changes(&mut self, upserts: &mut TrackedMap<V>) -> Vec<String> {
+        let mut removed = Vec::new();
+        for key in self.dirty.drain() {
+            match self.map.get(&key) {
+                Some(value) => {
+                    upserts.map.insert(key, value.clone());
+                }
+                None => removed.push(key),
+            }
+        }
+        removed.sort();
+        removed
+    }
+}
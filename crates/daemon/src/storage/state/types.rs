@@ -4,7 +4,8 @@
 //! Record types used by daemon subsystems.
 
 pub use oj_core::{
-    CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, WorkerRecord, Workspace, WorkspaceType,
+    CronRecord, QueueItem, QueueItemStatus, QueuePollMeta, WorkerRecord, WorkerThrottle, Workspace,
+    WorkspaceType,
 };
 
 use serde::{Deserialize, Serialize};
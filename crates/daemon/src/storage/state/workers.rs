@@ -8,6 +8,11 @@ use oj_core::{scoped_name, Event};
 use super::types::{CronRecord, WorkerRecord};
 use super::MaterializedState;
 
+/// Most dispatch times kept per worker. The runtime prunes the history
+/// against the worker's `rate` when it restores it; this only bounds the
+/// record for workers that dispatch far more often than any sane rate.
+const MAX_DISPATCH_HISTORY: usize = 1000;
+
 pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
     match event {
         Event::WorkerStarted {
@@ -19,11 +24,12 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             project,
         } => {
             let key = scoped_name(project, worker);
-            // Preserve active_job_ids and item_owners from before restart
-            let (existing_job_ids, existing_item_owners) = state
+            // Preserve active_job_ids, item_owners and dispatch history from
+            // before restart
+            let (existing_job_ids, existing_item_owners, existing_dispatches) = state
                 .workers
                 .get(&key)
-                .map(|w| (w.active.clone(), w.owners.clone()))
+                .map(|w| (w.active.clone(), w.owners.clone(), w.dispatched_at_ms.clone()))
                 .unwrap_or_default();
 
             if !project.is_empty() {
@@ -41,18 +47,30 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                     queue: queue.clone(),
                     concurrency: *concurrency,
                     owners: existing_item_owners,
+                    dispatched_at_ms: existing_dispatches,
                 },
             );
         }
 
         Event::WorkerDispatched { worker, item_id, owner, project } => {
             let key = scoped_name(project, worker);
+            // The dispatched job was created just before this event
+            let created_at_ms = owner
+                .as_job()
+                .and_then(|id| state.jobs.get(id.as_str()))
+                .and_then(|job| job.step_history.first())
+                .map(|step| step.started_at_ms);
             if let Some(record) = state.workers.get_mut(&key) {
                 let pid = owner.to_string();
                 if !record.active.contains(&pid) {
                     record.active.push(pid.clone());
                 }
-                record.owners.insert(pid, item_id.clone());
+                let first_dispatch = record.owners.insert(pid, item_id.clone()).is_none();
+                if let (true, Some(at_ms)) = (first_dispatch, created_at_ms) {
+                    record.dispatched_at_ms.push(at_ms);
+                    let excess = record.dispatched_at_ms.len().saturating_sub(MAX_DISPATCH_HISTORY);
+                    record.dispatched_at_ms.drain(..excess);
+                }
             }
         }
 
@@ -75,7 +93,17 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
             state.workers.remove(&key);
         }
 
-        Event::CronStarted { cron, project, project_path, runbook_hash, interval, target } => {
+        Event::CronStarted {
+            cron,
+            project,
+            project_path,
+            runbook_hash,
+            interval,
+            schedule,
+            timezone,
+            catchup,
+            target,
+        } => {
             if !project.is_empty() {
                 state.project_paths.insert(project.clone(), project_path.clone());
             }
@@ -95,6 +123,9 @@ pub(crate) fn apply(state: &mut MaterializedState, event: &Event) {
                     runbook_hash: runbook_hash.clone(),
                     status: "running".to_string(),
                     interval: interval.clone(),
+                    schedule: schedule.clone(),
+                    timezone: timezone.clone(),
+                    catchup: *catchup,
                     target: target.clone(),
                     started_at_ms: now_ms,
                     last_fired_at_ms,
@@ -12,6 +12,9 @@ fn cron_started_creates_record() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: "myns".to_string(),
     });
@@ -34,6 +37,9 @@ fn cron_stopped_updates_status() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -55,6 +61,9 @@ fn cron_started_is_idempotent() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -65,6 +74,9 @@ fn cron_started_is_idempotent() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "def456".to_string(),
         interval: "1h".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -82,6 +94,9 @@ fn cron_deleted_removes_record() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: "myns".to_string(),
     });
@@ -109,6 +124,9 @@ fn cron_deleted_empty_namespace() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -149,6 +167,9 @@ fn cron_fired_updates_last_fired_at() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -172,6 +193,9 @@ fn cron_started_sets_started_at_ms() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -187,6 +211,9 @@ fn cron_restart_preserves_last_fired_at() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -206,6 +233,9 @@ fn cron_restart_preserves_last_fired_at() {
         project_path: PathBuf::from("/test/project"),
         runbook_hash: "abc123".to_string(),
         interval: "30m".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::None,
         target: oj_core::RunTarget::job("cleanup"),
         project: String::new(),
     });
@@ -213,3 +243,41 @@ fn cron_restart_preserves_last_fired_at() {
     // last_fired_at should be preserved
     assert_eq!(state.crons["janitor"].last_fired_at_ms, fired_ms);
 }
+
+#[test]
+fn cron_started_records_schedule_and_timezone() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::CronStarted {
+        cron: "standup".to_string(),
+        project_path: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        interval: String::new(),
+        schedule: Some("0 9 * * MON-FRI".to_string()),
+        timezone: Some("local".to_string()),
+        catchup: oj_core::CronCatchup::None,
+        target: oj_core::RunTarget::job("post"),
+        project: String::new(),
+    });
+
+    let record = &state.crons["standup"];
+    assert_eq!(record.schedule.as_deref(), Some("0 9 * * MON-FRI"));
+    assert_eq!(record.timezone.as_deref(), Some("local"));
+}
+
+#[test]
+fn cron_started_records_catchup_policy() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&Event::CronStarted {
+        cron: "nightly".to_string(),
+        project_path: PathBuf::from("/test/project"),
+        runbook_hash: "abc123".to_string(),
+        interval: "24h".to_string(),
+        schedule: None,
+        timezone: None,
+        catchup: oj_core::CronCatchup::Once,
+        target: oj_core::RunTarget::job("report"),
+        project: String::new(),
+    });
+
+    assert_eq!(state.crons["nightly"].catchup, oj_core::CronCatchup::Once);
+}
@@ -18,6 +18,10 @@ fn decision_created_event(id: &str, job_id: &str) -> Event {
         questions: None,
         created_at_ms: 2_000_000,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     }
 }
 
@@ -35,6 +39,10 @@ fn decision_for_crew(id: &str, run_id: &str, created_at_ms: u64) -> Event {
         questions: None,
         created_at_ms,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     }
 }
 
@@ -52,6 +60,10 @@ fn decision_for_job_at(id: &str, job_id: &str, created_at_ms: u64) -> Event {
         questions: None,
         created_at_ms,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     }
 }
 
@@ -98,6 +110,7 @@ fn decision_resolved() {
         message: Some("Looks good".to_string()),
         resolved_at_ms: 3_000_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
 
     let dec = &state.decisions["dec-abc123"];
@@ -143,6 +156,7 @@ fn job_terminal_preserves_resolved_decisions() {
         message: None,
         resolved_at_ms: 3_000_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
     assert!(state.decisions["dec-1"].is_resolved());
 
@@ -161,6 +175,7 @@ fn job_deleted_removes_all_decisions() {
         message: None,
         resolved_at_ms: 3_000_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
 
     assert_eq!(state.decisions.len(), 2);
@@ -275,6 +290,7 @@ fn new_decision_does_not_affect_already_resolved() {
         message: Some("approved".to_string()),
         resolved_at_ms: 2_500_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
     assert!(state.decisions["dec-1"].is_resolved());
 
@@ -307,6 +323,10 @@ fn approval_cannot_supersede_question_decision() {
         questions: None,
         created_at_ms: 2_000_000,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     });
     assert!(!state.decisions["dec-question"].is_resolved());
 
@@ -324,6 +344,10 @@ fn approval_cannot_supersede_question_decision() {
         questions: None,
         created_at_ms: 3_000_000,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     });
 
     // Approval decision should NOT be created (dominated by Question)
@@ -354,6 +378,10 @@ fn question_can_supersede_approval_decision() {
         questions: None,
         created_at_ms: 2_000_000,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     });
     assert!(!state.decisions["dec-approval"].is_resolved());
 
@@ -368,6 +396,10 @@ fn question_can_supersede_approval_decision() {
         questions: None,
         created_at_ms: 3_000_000,
         project: "testns".to_string(),
+        expires_at_ms: None,
+        default_choice: None,
+        assignee: None,
+        reviewers: vec![],
     });
 
     // Question decision should be created, superseding the Approval
@@ -398,6 +430,7 @@ fn superseded_decision_cannot_be_resolved() {
         message: None,
         resolved_at_ms: 4_000_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
 
     // The WAL handler always applies, but the superseded_by remains set
@@ -418,6 +451,7 @@ fn decision_resolved_with_no_chosen_auto_dismiss() {
         message: Some("auto-dismissed by job resume".to_string()),
         resolved_at_ms: 3_000_000,
         project: "testns".to_string(),
+        timed_out: false,
     });
 
     let dec = &state.decisions["dec-1"];
@@ -426,3 +460,18 @@ fn decision_resolved_with_no_chosen_auto_dismiss() {
     assert_eq!(dec.message.as_deref(), Some("auto-dismissed by job resume"));
     assert_eq!(dec.resolved_at_ms, Some(3_000_000));
 }
+
+#[test]
+fn superseding_decision_touches_only_same_owner_decisions() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&decision_for_job_at("dec-a", "job-1", 1_000));
+    state.apply_event(&decision_for_job_at("dec-b", "job-2", 1_000));
+    state.clear_dirty();
+
+    state.apply_event(&decision_for_job_at("dec-c", "job-1", 2_000));
+
+    let delta = state.take_delta();
+    let mut keys: Vec<&String> = delta.state.decisions.keys().collect();
+    keys.sort();
+    assert_eq!(keys, ["dec-a", "dec-c"]);
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn sorted_keys<V>(map: &TrackedMap<V>) -> Vec<String> {
+    let mut keys: Vec<String> = map.keys().cloned().collect();
+    keys.sort();
+    keys
+}
+
+#[test]
+fn take_delta_holds_only_changed_entities() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.apply_event(&job_create_event("job-2", "build", "b", "init"));
+    state.take_delta();
+
+    state.apply_event(&job_transition_event("job-2", "deploy"));
+    let delta = state.take_delta();
+
+    assert_eq!(sorted_keys(&delta.state.jobs), ["job-2"]);
+    assert_eq!(delta.state.jobs["job-2"].step, "deploy");
+    assert!(delta.removed.is_empty());
+    assert!(delta.state.workspaces.is_empty());
+}
+
+#[test]
+fn take_delta_resets_tracking() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.take_delta();
+
+    let delta = state.take_delta();
+
+    assert!(delta.state.jobs.is_empty());
+    assert!(!state.jobs.is_dirty());
+}
+
+#[test]
+fn take_delta_records_removals() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.take_delta();
+
+    state.apply_event(&job_delete_event("job-1"));
+    let delta = state.take_delta();
+
+    assert!(delta.state.jobs.is_empty());
+    assert_eq!(delta.removed.get("jobs"), Some(&vec!["job-1".to_string()]));
+}
+
+#[test]
+fn entity_added_and_removed_between_checkpoints_is_a_removal() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.apply_event(&job_delete_event("job-1"));
+
+    let delta = state.take_delta();
+
+    assert_eq!(delta.removed.get("jobs"), Some(&vec!["job-1".to_string()]));
+}
+
+#[test]
+fn applying_deltas_reproduces_state() {
+    let mut live = MaterializedState::default();
+    let mut restored = MaterializedState::default();
+    let batches = [
+        vec![
+            job_create_event("job-1", "build", "a", "init"),
+            job_create_event("job-2", "build", "b", "init"),
+        ],
+        vec![job_transition_event("job-1", "deploy"), job_delete_event("job-2")],
+        vec![job_create_event("job-3", "build", "c", "init")],
+    ];
+
+    for batch in &batches {
+        for event in batch {
+            live.apply_event(event);
+        }
+        let delta = live.take_delta();
+        let json = serde_json::to_vec(&delta).unwrap();
+        restored.apply_delta(serde_json::from_slice(&json).unwrap());
+    }
+
+    assert_eq!(sorted_keys(&restored.jobs), ["job-1", "job-3"]);
+    assert_eq!(restored.jobs["job-1"].step, "deploy");
+    assert!(!restored.jobs.is_dirty());
+}
+
+#[test]
+fn bulk_mutation_marks_every_entry() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.apply_event(&job_create_event("job-2", "build", "b", "init"));
+    state.clear_dirty();
+
+    state.jobs.values_mut().for_each(|job| job.step = "done".to_string());
+
+    assert_eq!(sorted_keys(&state.take_delta().state.jobs), ["job-1", "job-2"]);
+}
+
+#[test]
+fn terminal_job_touches_only_its_worker() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&worker_start_event("fixer", ""));
+    state.apply_event(&worker_start_event("other", ""));
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    state.apply_event(&Event::WorkerDispatched {
+        worker: "fixer".to_string(),
+        item_id: "item-1".to_string(),
+        owner: JobId::from_string("job-1").into(),
+        project: String::new(),
+    });
+    state.clear_dirty();
+
+    state.apply_event(&job_transition_event("job-1", "done"));
+
+    let delta = state.take_delta();
+    assert_eq!(sorted_keys(&delta.state.workers), ["fixer"]);
+    assert!(delta.state.workers["fixer"].active.is_empty());
+}
+
+#[test]
+fn loaded_state_starts_clean() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+
+    let json = serde_json::to_string(&state).unwrap();
+    let loaded: MaterializedState = serde_json::from_str(&json).unwrap();
+
+    assert!(loaded.jobs.contains_key("job-1"));
+    assert!(!loaded.jobs.is_dirty());
+}
@@ -5,6 +5,7 @@ mod agents;
 mod attempts;
 mod cron;
 mod decisions;
+mod delta;
 mod idempotency;
 mod queue;
 mod step_history;
@@ -37,6 +38,22 @@ fn apply_event_job_delete() {
     assert!(!state.jobs.contains_key("job-1"));
 }
 
+#[yare::parameterized(
+    done = { "done", false },
+    failed = { "failed", false },
+    cancelled = { "cancelled", false },
+    resumed = { "init", true },
+    suspended = { "suspended", true },
+)]
+fn apply_event_job_archived_removes_only_terminal_job(step: &str, kept: bool) {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&job_transition_event("job-1", step));
+    state.apply_event(&Event::JobArchived { id: JobId::from_string("job-1") });
+
+    assert_eq!(state.jobs.contains_key("job-1"), kept);
+}
+
 #[test]
 fn apply_event_job_transition() {
     let mut state = MaterializedState::default();
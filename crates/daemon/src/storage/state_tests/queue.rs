@@ -147,6 +147,7 @@ fn item_retry_resets_to_pending() {
         queue: "bugs".to_string(),
         item_id: "item-1".to_string(),
         project: String::new(),
+        scheduled: false,
     });
 
     assert_eq!(state.queue_items["bugs"][0].status, QueueItemStatus::Pending);
@@ -154,6 +155,50 @@ fn item_retry_resets_to_pending() {
     assert!(state.queue_items["bugs"][0].worker.is_none());
 }
 
+fn scheduled_retry_event(queue_name: &str, item_id: &str) -> Event {
+    Event::QueueRetry {
+        queue: queue_name.to_string(),
+        item_id: item_id.to_string(),
+        project: String::new(),
+        scheduled: true,
+    }
+}
+
+#[test]
+fn scheduled_retry_keeps_failure_count() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+    state.apply_event(&Event::QueueFailed {
+        queue: "bugs".to_string(),
+        item_id: "item-1".to_string(),
+        error: "job failed".to_string(),
+        project: String::new(),
+        retry_at_ms: Some(60_000),
+    });
+    assert_eq!(state.queue_items["bugs"][0].retry_at_ms, Some(60_000));
+
+    state.apply_event(&scheduled_retry_event("bugs", "item-1"));
+
+    let item = &state.queue_items["bugs"][0];
+    assert_eq!(item.status, QueueItemStatus::Pending);
+    assert_eq!(item.failures, 1);
+    assert!(item.retry_at_ms.is_none());
+    assert!(item.worker.is_none());
+}
+
+#[test]
+fn scheduled_retry_skips_item_no_longer_failed() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&queue_pushed_event("bugs", "item-1"));
+    state.apply_event(&queue_taken_event("bugs", "item-1", "fixer"));
+
+    state.apply_event(&scheduled_retry_event("bugs", "item-1"));
+
+    assert_eq!(state.queue_items["bugs"][0].status, QueueItemStatus::Active);
+    assert_eq!(state.queue_items["bugs"][0].worker.as_deref(), Some("fixer"));
+}
+
 #[test]
 fn dead_sets_dead_status() {
     let mut state = MaterializedState::default();
@@ -206,6 +251,7 @@ fn item_retry_on_dead_resets_to_pending() {
         queue: "bugs".to_string(),
         item_id: "item-1".to_string(),
         project: String::new(),
+        scheduled: false,
     });
 
     assert_eq!(state.queue_items["bugs"][0].status, QueueItemStatus::Pending);
@@ -2,6 +2,7 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 use super::*;
+use oj_core::StepStatus;
 
 #[test]
 fn initialized_on_create() {
@@ -111,6 +112,44 @@ fn shell_completed_failure() {
     assert_eq!(job.step_history[0].outcome, StepOutcome::Failed("shell exit code: 42".to_string()));
 }
 
+#[test]
+fn step_timed_out_sets_outcome() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&Event::StepFailed {
+        job_id: JobId::from_string("job-1"),
+        step: "init".to_string(),
+        error: "timed out after 20m".to_string(),
+        timed_out: true,
+    });
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.step_status, StepStatus::Failed);
+    assert_eq!(job.error.as_deref(), Some("timed out after 20m"));
+    assert_eq!(
+        job.step_history[0].outcome,
+        StepOutcome::TimedOut("timed out after 20m".to_string())
+    );
+}
+
+#[test]
+fn late_shell_exit_for_previous_step_is_ignored() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&job_transition_event("job-1", "alert"));
+    state.apply_event(&Event::ShellExited {
+        job_id: JobId::from_string("job-1"),
+        step: "init".to_string(),
+        exit_code: 0,
+        stdout: None,
+        stderr: None,
+    });
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.step_history[1].outcome, StepOutcome::Running);
+    assert_eq!(job.step_status, StepStatus::Pending);
+}
+
 #[test]
 fn serde_roundtrip() {
     let mut state = MaterializedState::default();
@@ -127,3 +166,102 @@ fn serde_roundtrip() {
     assert_eq!(job.step_history[1].name, "plan");
     assert_eq!(job.step_history[1].outcome, StepOutcome::Running);
 }
+
+fn fork_event(job_id: &str, steps: &[&str]) -> Event {
+    Event::JobForked {
+        id: JobId::from_string(job_id),
+        steps: steps.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+#[test]
+fn fork_appends_branch_records_once() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&Event::StepCompleted {
+        job_id: JobId::from_string("job-1"),
+        step: "init".to_string(),
+    });
+    let fork = fork_event("job-1", &["lint", "test"]);
+    state.apply_event(&fork);
+    state.apply_event(&fork);
+
+    let job = &state.jobs["job-1"];
+    let names: Vec<&str> = job.step_history.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(names, vec!["init", "lint", "test"]);
+    assert_eq!(job.branches.len(), 2);
+    assert_eq!(job.get_step_visits("lint"), 1);
+    assert_eq!(job.step_status, StepStatus::Running);
+}
+
+#[test]
+fn branch_completion_finalizes_named_record() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&fork_event("job-1", &["lint", "test"]));
+    state.apply_event(&Event::StepCompleted {
+        job_id: JobId::from_string("job-1"),
+        step: "lint".to_string(),
+    });
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.step_history[1].name, "lint");
+    assert_eq!(job.step_history[1].outcome, StepOutcome::Completed);
+    assert_eq!(job.step_history[2].outcome, StepOutcome::Running);
+    assert_eq!(job.branches["lint"], StepStatus::Completed);
+    assert_eq!(job.step_status, StepStatus::Running);
+}
+
+#[test]
+fn advance_out_of_fork_abandons_running_branches() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&fork_event("job-1", &["lint", "test"]));
+    state.apply_event(&job_transition_event("job-1", "cleanup"));
+
+    let job = &state.jobs["job-1"];
+    assert!(job.branches.is_empty());
+    assert_eq!(job.step_history[1].outcome, StepOutcome::Failed("cancelled".to_string()));
+    assert_eq!(job.step_history[2].outcome, StepOutcome::Failed("cancelled".to_string()));
+    assert_eq!(job.step, "cleanup");
+}
+
+#[test]
+fn branch_start_records_agent_on_branch() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&fork_event("job-1", &["lint", "review"]));
+    state.apply_event(&Event::StepStarted {
+        job_id: JobId::from_string("job-1"),
+        step: "review".to_string(),
+        agent_id: Some(AgentId::from_string("agent-review")),
+        agent_name: Some("reviewer".to_string()),
+    });
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.step, "init");
+    assert_eq!(job.branches["review"], StepStatus::Running);
+    assert_eq!(job.step_agent_id("review"), Some("agent-review"));
+    assert_eq!(job.step_agent_id("init"), None);
+    assert_eq!(job.agent_branch("agent-review"), Some("review"));
+}
+
+#[test]
+fn branch_waiting_leaves_siblings_untouched() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&job_create_event("job-1", "build", "test", "init"));
+    state.apply_event(&fork_event("job-1", &["lint", "review"]));
+    state.apply_event(&Event::StepWaiting {
+        job_id: JobId::from_string("job-1"),
+        step: "review".to_string(),
+        reason: Some("idle".to_string()),
+        decision_id: Some("dec-1".to_string()),
+    });
+
+    let job = &state.jobs["job-1"];
+    assert_eq!(job.branches["review"], StepStatus::Waiting(Some("dec-1".to_string())));
+    assert_eq!(job.branches["lint"], StepStatus::Pending);
+    assert_eq!(job.step_status, StepStatus::Running);
+    assert!(job.is_branch_active("review"));
+    assert_eq!(job.step_history[2].outcome, StepOutcome::Waiting("idle".to_string()));
+}
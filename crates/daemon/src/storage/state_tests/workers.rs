@@ -64,6 +64,28 @@ fn worker_started_preserves_active_job_ids(ns: &str, worker_key: &str) {
     assert!(worker.active.contains(&"job-2".to_string()));
 }
 
+#[test]
+fn worker_dispatched_records_job_creation_time() {
+    let mut state = MaterializedState::default();
+    state.apply_event(&worker_start_event("fixer", ""));
+    state.apply_event(&job_create_event("job-1", "build", "a", "init"));
+    let dispatched = Event::WorkerDispatched {
+        worker: "fixer".to_string(),
+        item_id: "item-1".to_string(),
+        owner: JobId::from_string("job-1").into(),
+        project: String::new(),
+    };
+    state.apply_event(&dispatched);
+    // Replayed twice, still one dispatch
+    state.apply_event(&dispatched);
+    assert_eq!(state.workers["fixer"].dispatched_at_ms, [1_000_000]);
+
+    // Kept once the job finishes and when WorkerStarted is replayed
+    state.apply_event(&job_transition_event("job-1", "done"));
+    state.apply_event(&worker_start_event("fixer", ""));
+    assert_eq!(state.workers["fixer"].dispatched_at_ms, [1_000_000]);
+}
+
 #[test]
 fn worker_deleted_lifecycle_and_ghost() {
     let mut state = MaterializedState::default();
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Following a WAL directory from outside the daemon's `Wal`.
+//!
+//! Used to stream the WAL to replicas: each read returns the entries
+//! flushed since the last one. Segments are read frame by frame like
+//! `Wal::next_unprocessed` does, moving to the next segment once the
+//! current one has been sealed and fully read.
+
+use std::fs::File;
+use std::io::{self, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use tracing::debug;
+
+use super::segment::{self, Frame, HEADER_LEN};
+use super::wal::{WalEntry, WalError};
+
+/// Reads entries after a sequence number as they are flushed.
+pub struct WalTail {
+    dir: PathBuf,
+    /// Highest seq returned
+    seq: u64,
+    /// Segment being read, opened on the first read
+    reading: Option<Reading>,
+}
+
+/// Position in the segment being read.
+struct Reading {
+    first_seq: u64,
+    file: File,
+    version: u32,
+    /// Offset of the next frame
+    offset: u64,
+    /// Whether a later segment was seen after the last read reached the
+    /// end, so the next end is final
+    sealed: bool,
+}
+
+impl WalTail {
+    /// Follow the WAL in `dir` from the entry after `after_seq`.
+    pub fn new(dir: &Path, after_seq: u64) -> Self {
+        Self { dir: dir.to_owned(), seq: after_seq, reading: None }
+    }
+
+    /// Highest sequence number read so far.
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Entries flushed since the last read, oldest first.
+    ///
+    /// Fails with `WalError::Truncated` once the entry after `seq()` is no
+    /// longer in the WAL, e.g. because a checkpoint deleted its segment.
+    pub fn read(&mut self) -> Result<Vec<WalEntry>, WalError> {
+        let mut entries = Vec::new();
+        loop {
+            let Some(reading) = self.reading.as_mut() else {
+                if !self.open(None)? {
+                    return Ok(entries);
+                }
+                continue;
+            };
+
+            reading.file.seek(SeekFrom::Start(reading.offset))?;
+            match segment::read_frame(&mut BufReader::new(&reading.file), reading.version)? {
+                Frame::Entries(frame, len) => {
+                    reading.offset += len;
+                    reading.sealed = false;
+                    for entry in frame {
+                        if entry.seq > self.seq {
+                            self.seq = entry.seq;
+                            entries.push(entry);
+                        }
+                    }
+                }
+                // The writer flushes a segment's last frame before creating
+                // the next one, so after seeing the next one this segment
+                // is read once more before moving on
+                Frame::End if reading.sealed => {
+                    let after = reading.first_seq;
+                    if !self.open(Some(after))? {
+                        return Ok(entries);
+                    }
+                }
+                Frame::End => {
+                    let after = reading.first_seq;
+                    let later = segment::list(&self.dir)?.iter().any(|s| s.first_seq > after);
+                    match self.reading.as_mut() {
+                        Some(reading) if later => reading.sealed = true,
+                        _ => return Ok(entries),
+                    }
+                }
+                // Most likely a frame still being written; it is read again
+                // once complete
+                Frame::Corrupt(reason) => {
+                    debug!(offset = reading.offset, reason, "WAL tail ends in a partial frame");
+                    return Ok(entries);
+                }
+            }
+        }
+    }
+
+    /// Open the segment holding the entry after `seq`, or the one after the
+    /// segment starting at `after`. Returns false when there is none yet.
+    fn open(&mut self, after: Option<u64>) -> Result<bool, WalError> {
+        let segments = segment::list(&self.dir)?;
+        let next = match after {
+            Some(after) => segments.iter().find(|s| s.first_seq > after),
+            None => segments.iter().rev().find(|s| s.first_seq <= self.seq + 1),
+        };
+        let Some(next) = next else {
+            // Only later segments: the entries before them were deleted
+            if segments.first().is_some_and(|s| s.first_seq > self.seq + 1) {
+                return Err(WalError::Truncated(self.seq));
+            }
+            return Ok(false);
+        };
+        // A segment starting later would skip entries
+        if next.first_seq > self.seq + 1 {
+            return Err(WalError::Truncated(self.seq));
+        }
+
+        let mut file = match File::open(&next.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                return Err(WalError::Truncated(self.seq))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let version = segment::read_version(&mut file)?;
+        self.reading = Some(Reading {
+            first_seq: next.first_seq,
+            file,
+            version,
+            offset: HEADER_LEN,
+            sealed: false,
+        });
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+#[path = "tail_tests.rs"]
+mod tests;
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+use crate::storage::Wal;
+use oj_core::{Event, TimerId};
+use std::io::Write;
+use tempfile::tempdir;
+
+fn test_event(n: u64) -> Event {
+    Event::TimerStart { id: TimerId::from_string(format!("test:{n}")) }
+}
+
+fn seqs(entries: &[WalEntry]) -> Vec<u64> {
+    entries.iter().map(|e| e.seq).collect()
+}
+
+fn append(wal: &mut Wal, from: u64, to: u64) {
+    for n in from..=to {
+        wal.append(&test_event(n)).unwrap();
+    }
+    wal.flush().unwrap();
+}
+
+#[test]
+fn reads_entries_as_they_are_flushed() {
+    let dir = tempdir().unwrap();
+    let mut wal = Wal::open(dir.path(), 0).unwrap();
+    let mut tail = WalTail::new(dir.path(), 0);
+
+    assert!(tail.read().unwrap().is_empty());
+    append(&mut wal, 1, 2);
+    assert_eq!(seqs(&tail.read().unwrap()), vec![1, 2]);
+
+    wal.append(&test_event(3)).unwrap();
+    assert!(tail.read().unwrap().is_empty(), "unflushed entries are not read");
+    wal.flush().unwrap();
+    assert_eq!(seqs(&tail.read().unwrap()), vec![3]);
+    assert_eq!(tail.seq(), 3);
+}
+
+#[test]
+fn starts_after_the_given_seq() {
+    let dir = tempdir().unwrap();
+    let mut wal = Wal::open(dir.path(), 0).unwrap();
+    append(&mut wal, 1, 5);
+
+    let mut tail = WalTail::new(dir.path(), 3);
+
+    assert_eq!(seqs(&tail.read().unwrap()), vec![4, 5]);
+}
+
+#[test]
+fn follows_into_new_segments() {
+    let dir = tempdir().unwrap();
+    let mut wal = Wal::open(dir.path(), 0).unwrap();
+    wal.set_segment_bytes(1);
+    let mut tail = WalTail::new(dir.path(), 0);
+
+    append(&mut wal, 1, 1);
+    append(&mut wal, 2, 2);
+    assert_eq!(seqs(&tail.read().unwrap()), vec![1, 2]);
+    append(&mut wal, 3, 3);
+    assert_eq!(seqs(&tail.read().unwrap()), vec![3]);
+}
+
+#[test]
+fn checkpointed_segments_are_reported_as_truncated() {
+    let dir = tempdir().unwrap();
+    let mut wal = Wal::open(dir.path(), 0).unwrap();
+    wal.set_segment_bytes(1);
+    append(&mut wal, 1, 1);
+    append(&mut wal, 2, 2);
+    append(&mut wal, 3, 3);
+    wal.truncate_before(3).unwrap();
+
+    let mut behind = WalTail::new(dir.path(), 1);
+    let mut current = WalTail::new(dir.path(), 2);
+
+    assert!(matches!(behind.read(), Err(WalError::Truncated(1))));
+    assert_eq!(seqs(&current.read().unwrap()), vec![3]);
+}
+
+#[test]
+fn partial_frame_is_read_once_complete() {
+    let dir = tempdir().unwrap();
+    let mut wal = Wal::open(dir.path(), 0).unwrap();
+    append(&mut wal, 1, 1);
+    let active = segment::list(dir.path()).unwrap().pop().unwrap().path;
+    let mut tail = WalTail::new(dir.path(), 0);
+    assert_eq!(seqs(&tail.read().unwrap()), vec![1]);
+
+    // A frame the daemon is halfway through writing
+    let len = std::fs::metadata(&active).unwrap().len();
+    let mut file = std::fs::OpenOptions::new().append(true).open(&active).unwrap();
+    file.write_all(b"\x10\0\0").unwrap();
+    assert!(tail.read().unwrap().is_empty());
+
+    file.set_len(len).unwrap();
+    append(&mut wal, 2, 2);
+    assert_eq!(seqs(&tail.read().unwrap()), vec![2]);
+}
@@ -1,21 +1,30 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! JSONL event write-ahead log with group commit support.
+//! Segmented event write-ahead log with group commit support.
 //!
 //! Events are durably stored before processing, enabling crash recovery
 //! via snapshot + replay. Group commit batches writes (~10ms) for performance.
 //!
-//! Each entry is a single line of JSON: `{"seq":N,"event":{...}}\n`
+//! The WAL is a directory of segment files (see `segment` for the format).
+//! Each flush appends one checksummed, compressed frame to the newest
+//! segment, which is sealed once it grows past `SEGMENT_BYTES`. Truncation
+//! deletes sealed segments instead of rewriting the log.
 
 use oj_core::Event;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, VecDeque};
 use std::fs::{File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
-use tracing::warn;
+use tracing::{info, warn};
+
+use super::migration::{MigrationError, MigrationRegistry};
+use super::segment::{self, EventDecoder, Frame, PendingEntry, Segment, HEADER_LEN};
+use super::CURRENT_SNAPSHOT_VERSION;
 
 /// Flush interval for group commit (~10ms batches)
 const FLUSH_INTERVAL: Duration = Duration::from_millis(10);
@@ -23,6 +32,15 @@ const FLUSH_INTERVAL: Duration = Duration::from_millis(10);
 /// Maximum entries to buffer before forcing flush
 const FLUSH_THRESHOLD: usize = 100;
 
+/// Size at which the active segment is sealed and a new one started
+const SEGMENT_BYTES: u64 = 1024 * 1024;
+
+/// Single-file JSONL WAL written by earlier versions, imported on open
+const LEGACY_FILE: &str = "events.wal";
+
+/// Schema version of the events in a legacy WAL
+const LEGACY_VERSION: u32 = 1;
+
 /// Errors that can occur in Wal operations
 #[derive(Debug, Error)]
 pub enum WalError {
@@ -30,30 +48,87 @@ pub enum WalError {
     Io(#[from] io::Error),
     #[error("Serialization error: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("migration error: {0}")]
+    Migration(#[from] MigrationError),
+    #[error("WAL no longer holds the entries after seq {0}")]
+    Truncated(u64),
 }
 
-/// Serialization helper for writing WAL entries without cloning the event.
-#[derive(Serialize)]
-struct WalRecordRef<'a> {
-    seq: u64,
-    event: &'a Event,
-}
-
-/// Deserialization helper for reading WAL entries.
+/// A line of the legacy JSONL WAL.
 #[derive(Deserialize)]
-struct WalRecord {
+struct LegacyRecord {
     seq: u64,
-    event: Event,
+    #[serde(default)]
+    ts: u64,
+    event: Value,
 }
 
 /// A single WAL entry with sequence number
 #[derive(Debug, Clone)]
 pub struct WalEntry {
     pub seq: u64,
+    /// When the entry was appended (epoch ms); 0 if unknown
+    pub ts_ms: u64,
     pub event: Event,
 }
 
-/// JSONL WAL for durable event storage with group commit.
+/// What a WAL file holds, checked against the current schema.
+#[derive(Debug)]
+pub struct WalFileReport {
+    pub path: PathBuf,
+    /// Schema version its events were written at
+    pub version: u32,
+    pub entries: usize,
+    /// Entries the event migrations change
+    pub migrated: usize,
+    /// Event types replay does not know, which it ignores
+    pub unknown: BTreeMap<String, usize>,
+    /// Entries of known types that no longer parse
+    pub undecodable: usize,
+}
+
+/// Result of checking every entry in a WAL directory.
+#[derive(Debug, Default)]
+pub struct WalCheck {
+    /// Files checked, oldest first
+    pub files: Vec<PathBuf>,
+    /// Good entries, up to the damage if there is any
+    pub entries: usize,
+    pub first_seq: Option<u64>,
+    /// Last good entry
+    pub last_seq: Option<u64>,
+    /// Good entries of types replay does not know, which it ignores
+    pub unknown: usize,
+    pub damage: Option<WalDamage>,
+}
+
+/// The first point a WAL stops being trustworthy.
+#[derive(Debug)]
+pub struct WalDamage {
+    pub path: PathBuf,
+    /// Offset of the frame (or legacy line) holding the first bad entry
+    pub offset: u64,
+    pub reason: String,
+    /// Bytes from `offset` to the end of the file
+    pub dropped_bytes: u64,
+    /// Files after the damaged one
+    pub later: Vec<PathBuf>,
+    /// Good entries before the bad one in the same frame
+    keep: Vec<PendingEntry>,
+    legacy: bool,
+}
+
+/// A WAL file split into frames (lines, for the legacy WAL).
+struct FileFrames {
+    path: PathBuf,
+    legacy: bool,
+    /// Sequence number the file is named for
+    first_seq: Option<u64>,
+    len: u64,
+    frames: segment::Frames,
+}
+
+/// Segmented WAL for durable event storage with group commit.
 ///
 /// Events are buffered in memory and flushed to disk either:
 /// - When `needs_flush()` returns true (interval elapsed or buffer full)
@@ -62,155 +137,206 @@ pub struct WalEntry {
 /// The WAL tracks both the write sequence (highest seq written) and
 /// processed sequence (highest seq the engine has processed).
 pub struct Wal {
+    dir: PathBuf,
+    /// Segments oldest first; the last one is being appended to
+    segments: Vec<Segment>,
+    /// Append handle for the active segment
     file: File,
-    /// Persistent read handle (cloned once at open) for next_unprocessed
-    read_file: File,
-    path: PathBuf,
+    /// Size of the active segment
+    active_len: u64,
     /// Next sequence number to assign
     write_seq: u64,
     /// Sequence number of last processed entry
     processed_seq: u64,
-    /// Buffered JSON lines waiting to be flushed (without trailing newline)
-    write_buffer: Vec<Vec<u8>>,
+    /// Encoded entries waiting to be flushed
+    write_buffer: Vec<PendingEntry>,
     /// Last flush timestamp for interval checking
     last_flush: Instant,
-    /// Current read position for next_unprocessed
+    /// Segment next_unprocessed is reading, as an index into `segments`
+    read_segment: usize,
+    /// Read handle for that segment
+    read_file: File,
+    /// Schema version of that segment
+    read_version: u32,
+    /// Offset of the next frame to read in that segment
     read_offset: u64,
+    /// Decoded entries from the last frame not yet returned
+    read_pending: VecDeque<WalEntry>,
+    /// Highest seq returned by next_unprocessed
+    read_seq: u64,
+    segment_bytes: u64,
 }
 
 impl Wal {
-    /// Open or create a WAL at the given path.
+    /// Open or create a WAL in the given directory.
     ///
     /// The `processed_seq` should come from the snapshot (or 0 if no snapshot).
-    /// The WAL will scan to find the write_seq and set read_offset appropriately.
-    pub fn open(path: &Path, processed_seq: u64) -> Result<Self, WalError> {
-        // Ensure parent directory exists
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
+    /// The WAL scans its segments to find the write_seq, repairs torn or
+    /// corrupt frames, and positions reading after `processed_seq`.
+    pub fn open(dir: &Path, processed_seq: u64) -> Result<Self, WalError> {
+        std::fs::create_dir_all(dir)?;
+
+        let mut segments = segment::list(dir)?;
+        let legacy = dir.join(LEGACY_FILE);
+        if legacy.is_file() {
+            if segments.is_empty() {
+                segments.extend(Self::import_legacy(dir, &legacy)?);
+            }
+            std::fs::remove_file(&legacy)?;
         }
 
-        let mut file = OpenOptions::new().create(true).read(true).append(true).open(path)?;
-
-        // Scan to find max sequence and build offset index
-        let (mut write_seq, mut read_offset, corrupt) = Self::scan_wal(&file, processed_seq)?;
-
-        if corrupt {
-            // Collect valid entries before corruption
-            let valid_lines = Self::read_valid_lines(&file)?;
-
-            // Drop file handle before rename
-            drop(file);
-
-            // Rotate corrupt WAL to .bak
-            let bak_path = crate::storage::snapshot::rotate_bak_path(path);
-            warn!(
-                path = %path.display(),
-                bak = %bak_path.display(),
-                valid_entries = valid_lines.len(),
-                "Corrupt WAL detected, rotating to .bak and preserving valid entries",
-            );
-            std::fs::rename(path, &bak_path)?;
-
-            // Create new clean WAL with only valid entries
-            {
-                let mut new_file = File::create(path)?;
-                for line in &valid_lines {
-                    new_file.write_all(line.as_bytes())?;
-                    new_file.write_all(b"\n")?;
-                }
-                new_file.sync_all()?;
+        let mut write_seq = processed_seq;
+        // Whether the last segment can take new entries, or was written at
+        // an older schema version
+        let mut last_current = true;
+        let mut last_empty = true;
+        for i in 0..segments.len() {
+            let bytes = std::fs::read(&segments[i].path)?;
+            let scan = segment::scan(&bytes)?;
+            let last_seq = scan.entries.last().map_or(0, |e| e.seq);
+            write_seq = write_seq.max(last_seq).max(segments[i].first_seq.saturating_sub(1));
+            last_current = scan.version == CURRENT_SNAPSHOT_VERSION || scan.valid_len == 0;
+            last_empty = scan.entries.is_empty();
+            if let Some(reason) = scan.corrupt {
+                let valid = match scan.valid_len {
+                    0 => segment::header(CURRENT_SNAPSHOT_VERSION).to_vec(),
+                    len => bytes[..len].to_vec(),
+                };
+                let later: Vec<PathBuf> =
+                    segments[i + 1..].iter().map(|s| s.path.clone()).collect();
+                Self::repair(&segments[i].path, &later, &valid, reason)?;
+                segments.truncate(i + 1);
+                break;
             }
+        }
 
-            // Re-open the clean file
-            file = OpenOptions::new().create(true).read(true).append(true).open(path)?;
-
-            // Re-scan the clean file for correct offsets
-            let scan = Self::scan_wal(&file, processed_seq)?;
-            write_seq = scan.0;
-            read_offset = scan.1;
+        // New entries always go to a segment of the current version
+        if !last_current {
+            if let Some(empty) = segments.pop_if(|_| last_empty) {
+                std::fs::remove_file(&empty.path)?;
+            }
+            let (segment, _) = segment::create(dir, write_seq + 1)?;
+            info!(version = CURRENT_SNAPSHOT_VERSION, "Starting WAL segment at new schema version");
+            segments.push(segment);
         }
 
-        let read_file = file.try_clone()?;
+        let active = match segments.last() {
+            Some(active) => active.path.clone(),
+            None => {
+                let (segment, _) = segment::create(dir, write_seq + 1)?;
+                let path = segment.path.clone();
+                segments.push(segment);
+                path
+            }
+        };
+        let file = OpenOptions::new().read(true).append(true).open(&active)?;
+        let active_len = file.metadata()?.len();
+        let read_segment =
+            segments.iter().rposition(|s| s.first_seq <= processed_seq + 1).unwrap_or(0);
+        let mut read_file = File::open(&segments[read_segment].path)?;
+        let read_version = segment::read_version(&mut read_file)?;
 
         Ok(Self {
+            dir: dir.to_owned(),
+            segments,
             file,
-            read_file,
-            path: path.to_owned(),
+            active_len,
             write_seq,
             processed_seq,
             write_buffer: Vec::new(),
             last_flush: Instant::now(),
-            read_offset,
+            read_segment,
+            read_file,
+            read_version,
+            read_offset: HEADER_LEN,
+            read_pending: VecDeque::new(),
+            read_seq: processed_seq,
+            segment_bytes: SEGMENT_BYTES,
         })
     }
 
-    /// Scan the WAL to find the maximum sequence number and offset for processed_seq.
-    ///
-    /// Returns `(max_seq, read_offset, corrupt)` where `corrupt` is true if
-    /// a parse error was encountered (not just EOF).
-    fn scan_wal(file: &File, processed_seq: u64) -> Result<(u64, u64, bool), WalError> {
-        let mut reader = BufReader::new(file.try_clone()?);
-        reader.seek(SeekFrom::Start(0))?;
-
-        let mut max_seq = 0u64;
-        let mut read_offset = 0u64;
-        let mut current_offset = 0u64;
-        let mut corrupt = false;
-        let mut line = String::new();
-
-        loop {
-            line.clear();
-            let bytes_read = match reader.read_line(&mut line) {
-                Ok(0) => break,
-                Ok(n) => n,
-                Err(e) if e.kind() == io::ErrorKind::InvalidData => {
-                    corrupt = true;
-                    break;
-                }
-                Err(e) => return Err(e.into()),
-            };
-
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                current_offset += bytes_read as u64;
-                continue;
-            }
+    /// Replace a corrupt file with its `valid` bytes and set aside the files
+    /// after it. Originals are kept as `.bak` files.
+    fn repair(
+        damaged: &Path,
+        later: &[PathBuf],
+        valid: &[u8],
+        reason: &str,
+    ) -> Result<(), WalError> {
+        let bak_path = crate::storage::snapshot::rotate_bak_path(damaged);
+        warn!(
+            path = %damaged.display(),
+            bak = %bak_path.display(),
+            reason,
+            "Corrupt WAL segment detected, rotating to .bak and preserving valid entries",
+        );
+        std::fs::rename(damaged, &bak_path)?;
+        {
+            let mut file = File::create(damaged)?;
+            file.write_all(valid)?;
+            file.sync_all()?;
+        }
 
-            // Parse to extract seq; treat parse failure as corruption
-            let record: WalRecord = match serde_json::from_str(trimmed) {
-                Ok(r) => r,
-                Err(_) => {
-                    corrupt = true;
-                    break;
-                }
-            };
+        // Entries after the damage would leave a gap in the sequence
+        for path in later {
+            let bak_path = crate::storage::snapshot::rotate_bak_path(path);
+            warn!(path = %path.display(), "Setting aside WAL segment after corruption");
+            std::fs::rename(path, bak_path)?;
+        }
+        Ok(())
+    }
 
-            max_seq = max_seq.max(record.seq);
+    /// Convert a legacy JSONL WAL into a segment.
+    fn import_legacy(dir: &Path, legacy: &Path) -> Result<Option<Segment>, WalError> {
+        let entries = Self::read_legacy(legacy)?;
+        let segment = Self::write_segment(dir, &entries)?;
+        info!(entries = entries.len(), "Imported legacy JSONL WAL into segments");
+        Ok(segment)
+    }
 
-            // Track offset of entry after processed_seq (for reading unprocessed)
-            if record.seq > processed_seq && read_offset == 0 {
-                read_offset = current_offset;
-            }
+    /// Write entries into a WAL directory holding no segments, keeping
+    /// their sequence numbers. Used to restore an exported WAL tail.
+    pub fn restore(dir: &Path, entries: &[WalEntry]) -> Result<(), WalError> {
+        std::fs::create_dir_all(dir)?;
+        Self::write_segment(dir, entries)?;
+        Ok(())
+    }
 
-            current_offset += bytes_read as u64;
+    /// Write entries as a single durable segment.
+    fn write_segment(dir: &Path, entries: &[WalEntry]) -> Result<Option<Segment>, WalError> {
+        let Some(first) = entries.first() else {
+            return Ok(None);
+        };
+        let (segment, mut file) = segment::create(dir, first.seq)?;
+        let mut pending = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let json = serde_json::to_vec(&entry.event)?;
+            pending.push(PendingEntry { seq: entry.seq, ts_ms: entry.ts_ms, json });
         }
+        file.write_all(&segment::encode_frame(&pending)?)?;
+        file.sync_all()?;
+        Ok(Some(segment))
+    }
 
-        // If no unprocessed entries found, read_offset is at end of file
-        if read_offset == 0 {
-            read_offset = current_offset;
+    /// Read a legacy JSONL WAL up to its first unparseable line.
+    fn read_legacy(path: &Path) -> Result<Vec<WalEntry>, WalError> {
+        let decoder = EventDecoder::new(LEGACY_VERSION);
+        let mut entries = Vec::new();
+        for raw in Self::read_legacy_raw(path)? {
+            let Some(event) = decoder.decode(&raw.json) else {
+                warn!(path = %path.display(), "Corrupt legacy WAL entry, ignoring the rest");
+                break;
+            };
+            entries.push(WalEntry { seq: raw.seq, ts_ms: raw.ts_ms, event });
         }
-
-        Ok((max_seq, read_offset, corrupt))
+        Ok(entries)
     }
 
-    /// Read all valid (parseable) lines from the WAL, stopping at the first corrupt entry.
-    fn read_valid_lines(file: &File) -> Result<Vec<String>, WalError> {
-        let mut reader = BufReader::new(file.try_clone()?);
-        reader.seek(SeekFrom::Start(0))?;
-
-        let mut valid_lines = Vec::new();
+    /// Read a legacy JSONL WAL's records, leaving the events as written.
+    fn read_legacy_raw(path: &Path) -> Result<Vec<PendingEntry>, WalError> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let mut entries = Vec::new();
         let mut line = String::new();
-
         loop {
             line.clear();
             match reader.read_line(&mut line) {
@@ -219,22 +345,18 @@ impl Wal {
                 Err(e) if e.kind() == io::ErrorKind::InvalidData => break,
                 Err(e) => return Err(e.into()),
             }
-
             let trimmed = line.trim();
             if trimmed.is_empty() {
                 continue;
             }
-
-            // Stop at first unparseable entry
-            let _: WalRecord = match serde_json::from_str(trimmed) {
-                Ok(r) => r,
-                Err(_) => break,
+            let Ok(record) = serde_json::from_str::<LegacyRecord>(trimmed) else {
+                warn!(path = %path.display(), "Corrupt legacy WAL entry, ignoring the rest");
+                break;
             };
-
-            valid_lines.push(trimmed.to_string());
+            let json = serde_json::to_vec(&record.event)?;
+            entries.push(PendingEntry { seq: record.seq, ts_ms: record.ts, json });
         }
-
-        Ok(valid_lines)
+        Ok(entries)
     }
 
     /// Append an event to the write buffer.
@@ -242,11 +364,22 @@ impl Wal {
     /// Returns the assigned sequence number. The event is NOT durable until
     /// `flush()` is called.
     pub fn append(&mut self, event: &Event) -> Result<u64, WalError> {
+        let ts_ms =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        self.push(event, ts_ms)
+    }
+
+    /// Append with an explicit timestamp (epoch ms).
+    #[cfg(test)]
+    pub fn append_at(&mut self, event: &Event, ts_ms: u64) -> Result<u64, WalError> {
+        self.push(event, ts_ms)
+    }
+
+    fn push(&mut self, event: &Event, ts_ms: u64) -> Result<u64, WalError> {
+        let json = serde_json::to_vec(event)?;
         self.write_seq += 1;
         let seq = self.write_seq;
-        let record = WalRecordRef { seq, event };
-        let json_bytes = serde_json::to_vec(&record)?;
-        self.write_buffer.push(json_bytes);
+        self.write_buffer.push(PendingEntry { seq, ts_ms, json });
         Ok(seq)
     }
 
@@ -266,13 +399,25 @@ impl Wal {
             return Ok(());
         }
 
-        for mut json_bytes in self.write_buffer.drain(..) {
-            json_bytes.push(b'\n');
-            self.file.write_all(&json_bytes)?;
-        }
-
+        let frame = segment::encode_frame(&self.write_buffer)?;
+        self.write_buffer.clear();
+        self.file.write_all(&frame)?;
         self.file.sync_all()?;
+        self.active_len += frame.len() as u64;
         self.last_flush = Instant::now();
+
+        if self.active_len >= self.segment_bytes {
+            self.rotate()?;
+        }
+        Ok(())
+    }
+
+    /// Seal the active segment and start a new one at the next seq.
+    fn rotate(&mut self) -> Result<(), WalError> {
+        let (segment, file) = segment::create(&self.dir, self.write_seq + 1)?;
+        self.segments.push(segment);
+        self.file = file;
+        self.active_len = HEADER_LEN;
         Ok(())
     }
 
@@ -283,35 +428,35 @@ impl Wal {
         // First flush any pending writes so they're readable
         self.flush()?;
 
-        let mut reader = BufReader::new(&self.read_file);
-        reader.seek(SeekFrom::Start(self.read_offset))?;
-
-        let mut line = String::new();
-        let bytes_read = match reader.read_line(&mut line) {
-            Ok(0) => return Ok(None),
-            Ok(n) => n,
-            Err(e) if e.kind() == io::ErrorKind::InvalidData => return Ok(None),
-            Err(e) => return Err(e.into()),
-        };
-
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            return Ok(None);
-        }
-
-        let record: WalRecord = match serde_json::from_str(trimmed) {
-            Ok(r) => r,
-            Err(e) => {
-                warn!(offset = self.read_offset, error = %e, "Corrupt WAL entry, skipping");
-                // Advance past the corrupt line to avoid getting stuck
-                self.read_offset += bytes_read as u64;
-                return Ok(None);
+        loop {
+            if let Some(entry) = self.read_pending.pop_front() {
+                if entry.seq > self.read_seq {
+                    self.read_seq = entry.seq;
+                    return Ok(Some(entry));
+                }
+                continue;
             }
-        };
-
-        self.read_offset += bytes_read as u64;
 
-        Ok(Some(WalEntry { seq: record.seq, event: record.event }))
+            self.read_file.seek(SeekFrom::Start(self.read_offset))?;
+            match segment::read_frame(&mut BufReader::new(&self.read_file), self.read_version)? {
+                Frame::Entries(entries, len) => {
+                    self.read_offset += len;
+                    self.read_pending.extend(entries);
+                }
+                Frame::End if self.read_segment + 1 < self.segments.len() => {
+                    self.read_segment += 1;
+                    self.open_read_segment()?;
+                }
+                Frame::End => return Ok(None),
+                Frame::Corrupt(reason) => {
+                    warn!(offset = self.read_offset, reason, "Corrupt WAL frame, skipping");
+                    // Skip past what is there now to avoid getting stuck;
+                    // frames flushed later are still read
+                    self.read_offset = self.read_file.metadata()?.len();
+                    return Ok(None);
+                }
+            }
+        }
     }
 
     /// Mark an entry as processed.
@@ -322,145 +467,347 @@ impl Wal {
         self.processed_seq = seq;
     }
 
+    /// Directory the segments live in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
     /// Get the current processed sequence number.
     pub fn processed_seq(&self) -> u64 {
         self.processed_seq
     }
 
     /// Get the current write sequence number.
-    #[cfg(test)]
     pub fn write_seq(&self) -> u64 {
         self.write_seq
     }
 
-    /// Truncate entries before the given sequence number.
+    /// Bytes on disk across all segments, not counting unflushed entries.
+    pub fn disk_bytes(&self) -> u64 {
+        let sealed = &self.segments[..self.segments.len().saturating_sub(1)];
+        let sealed_bytes: u64 = sealed
+            .iter()
+            .filter_map(|segment| std::fs::metadata(&segment.path).ok())
+            .map(|meta| meta.len())
+            .sum();
+        sealed_bytes + self.active_len
+    }
+
+    /// Override the size at which segments are sealed.
+    #[cfg(test)]
+    pub fn set_segment_bytes(&mut self, bytes: u64) {
+        self.segment_bytes = bytes;
+    }
+
+    /// Drop entries before the given sequence number.
     ///
-    /// This is called after checkpoint to reclaim disk space.
-    /// Creates a new WAL file with only entries >= seq.
+    /// This is called after checkpoint to reclaim disk space. Sealed
+    /// segments holding only entries before `seq` are deleted; the active
+    /// segment is always kept, so some older entries may remain.
     pub fn truncate_before(&mut self, seq: u64) -> Result<(), WalError> {
         // Ensure all writes are flushed first
         self.flush()?;
 
-        let tmp_path = self.path.with_extension("tmp");
-
-        // Read lines from the current file, keeping those with seq >= target
-        let mut reader = BufReader::new(self.file.try_clone()?);
-        reader.seek(SeekFrom::Start(0))?;
+        // A segment holds entries up to the next segment's first seq
+        let removable =
+            self.segments.windows(2).take_while(|pair| pair[1].first_seq <= seq).count();
+        for segment in self.segments.drain(..removable) {
+            match std::fs::remove_file(&segment.path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
 
-        let mut kept_lines: Vec<(u64, String)> = Vec::new();
-        let mut line = String::new();
+        if self.read_segment >= removable {
+            self.read_segment -= removable;
+        } else {
+            // Only entries at or before read_seq were dropped, and those
+            // are skipped when read again
+            self.read_segment = 0;
+            self.open_read_segment()?;
+        }
+        Ok(())
+    }
 
-        loop {
-            line.clear();
-            match reader.read_line(&mut line) {
-                Ok(0) => break,
-                Ok(_) => {}
-                Err(e) if e.kind() == io::ErrorKind::InvalidData => break,
-                Err(e) => return Err(e.into()),
-            }
+    /// Start reading `read_segment` from its first frame.
+    fn open_read_segment(&mut self) -> Result<(), WalError> {
+        let mut file = File::open(&self.segments[self.read_segment].path)?;
+        self.read_version = segment::read_version(&mut file)?;
+        self.read_file = file;
+        self.read_offset = HEADER_LEN;
+        Ok(())
+    }
 
-            let trimmed = line.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
+    /// Iterate over all entries after the given sequence number.
+    ///
+    /// Used for recovery (replaying from snapshot).
+    pub fn entries_after(&self, seq: u64) -> Result<Vec<WalEntry>, WalError> {
+        Self::read_segments_after(&self.segments, seq)
+    }
 
-            // Parse to check seq
-            let record: WalRecord = match serde_json::from_str(trimmed) {
-                Ok(r) => r,
-                Err(_) => break,
-            };
+    /// Read entries after `seq` from a WAL directory without opening it for
+    /// writing, for offline inspection of a daemon's history. Also reads a
+    /// legacy JSONL WAL that has not been imported yet.
+    pub fn read_after(dir: &Path, seq: u64) -> Result<Vec<WalEntry>, WalError> {
+        let legacy = dir.join(LEGACY_FILE);
+        let mut entries = if legacy.is_file() { Self::read_legacy(&legacy)? } else { vec![] };
+        entries.retain(|e| e.seq > seq);
+        entries.extend(Self::read_segments_after(&segment::list(dir)?, seq)?);
+        Ok(entries)
+    }
 
-            if record.seq >= seq {
-                // Keep this line as raw bytes (no re-serialize)
-                kept_lines.push((record.seq, trimmed.to_string()));
-            }
+    /// Check every file in a WAL directory against the current schema,
+    /// without changing anything.
+    pub fn survey(dir: &Path) -> Result<Vec<WalFileReport>, WalError> {
+        let mut files = Vec::new();
+        let legacy = dir.join(LEGACY_FILE);
+        if legacy.is_file() {
+            files.push((legacy.clone(), LEGACY_VERSION, Self::read_legacy_raw(&legacy)?));
+        }
+        for segment in segment::list(dir)? {
+            let scan = segment::scan_raw(&std::fs::read(&segment.path)?)?;
+            files.push((segment.path, scan.version, scan.entries));
         }
 
-        // Write to temp file, computing read_offset during the write pass
-        let new_read_offset;
-        {
-            let mut tmp_file = File::create(&tmp_path)?;
-            let mut current_offset = 0u64;
-            let mut found_unprocessed = false;
-            let mut first_unprocessed_offset = 0u64;
-
-            for (entry_seq, kept_line) in &kept_lines {
-                if *entry_seq > self.processed_seq && !found_unprocessed {
-                    first_unprocessed_offset = current_offset;
-                    found_unprocessed = true;
+        let registry = MigrationRegistry::new();
+        let mut reports = Vec::new();
+        for (path, version, entries) in files {
+            let mut report = WalFileReport {
+                path,
+                version,
+                entries: entries.len(),
+                migrated: 0,
+                unknown: BTreeMap::new(),
+                undecodable: 0,
+            };
+            for entry in entries {
+                let Ok(original) = serde_json::from_slice::<Value>(&entry.json) else {
+                    report.undecodable += 1;
+                    continue;
+                };
+                let value = registry.migrate_event(original.clone(), version)?;
+                if value != original {
+                    report.migrated += 1;
+                }
+                let tag = value.get("type").and_then(Value::as_str).unwrap_or("").to_string();
+                match segment::event_from_value(&value) {
+                    Ok(Event::Custom) => *report.unknown.entry(tag).or_default() += 1,
+                    Ok(_) => {}
+                    Err(_) => report.undecodable += 1,
                 }
-                tmp_file.write_all(kept_line.as_bytes())?;
-                tmp_file.write_all(b"\n")?;
-                current_offset += kept_line.len() as u64 + 1;
             }
-
-            // If no unprocessed entries found, read_offset is at end of file
-            new_read_offset =
-                if found_unprocessed { first_unprocessed_offset } else { current_offset };
-
-            tmp_file.sync_all()?;
+            reports.push(report);
         }
-
-        // Atomic rename
-        std::fs::rename(&tmp_path, &self.path)?;
-
-        // Reopen file
-        self.file = OpenOptions::new().create(true).read(true).append(true).open(&self.path)?;
-        self.read_file = self.file.try_clone()?;
-        self.read_offset = new_read_offset;
-
-        Ok(())
+        Ok(reports)
     }
 
-    /// Iterate over all entries after the given sequence number.
+    /// Rewrite segments written at an older schema version with their
+    /// events migrated, returning how many were rewritten.
     ///
-    /// Used for recovery (replaying from snapshot) and truncation.
-    pub fn entries_after(&self, seq: u64) -> Result<Vec<WalEntry>, WalError> {
-        let mut reader = BufReader::new(self.file.try_clone()?);
-        reader.seek(SeekFrom::Start(0))?;
+    /// Only for a WAL no daemon has open. Damaged segments are left for
+    /// `open` to repair.
+    pub fn upgrade(dir: &Path) -> Result<usize, WalError> {
+        let registry = MigrationRegistry::new();
+        let mut rewritten = 0;
+        for segment in segment::list(dir)? {
+            let scan = segment::scan_raw(&std::fs::read(&segment.path)?)?;
+            if scan.version == CURRENT_SNAPSHOT_VERSION || scan.corrupt.is_some() {
+                continue;
+            }
+            let mut entries = Vec::with_capacity(scan.entries.len());
+            for entry in scan.entries {
+                let value = serde_json::from_slice(&entry.json)?;
+                let json = serde_json::to_vec(&registry.migrate_event(value, scan.version)?)?;
+                entries.push(PendingEntry { json, ..entry });
+            }
 
-        let mut entries = Vec::new();
-        let mut line = String::new();
-        let mut current_offset = 0u64;
+            let tmp_path = segment.path.with_extension("tmp");
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(&segment::header(CURRENT_SNAPSHOT_VERSION))?;
+            if !entries.is_empty() {
+                file.write_all(&segment::encode_frame(&entries)?)?;
+            }
+            file.sync_all()?;
+            std::fs::rename(&tmp_path, &segment.path)?;
+            rewritten += 1;
+        }
+        if rewritten > 0 {
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(rewritten)
+    }
 
-        loop {
-            line.clear();
-            let bytes_read = match reader.read_line(&mut line) {
-                Ok(0) => break,
-                Ok(n) => n,
-                Err(e) if e.kind() == io::ErrorKind::InvalidData => break,
-                Err(e) => return Err(e.into()),
+    /// Check every entry in a WAL directory: frames are intact, sequence
+    /// numbers climb one at a time, and events parse. `after_seq` is the
+    /// snapshot's sequence number, which entries must continue from
+    /// without a gap. Stops at the first bad entry.
+    pub fn check(dir: &Path, after_seq: u64) -> Result<WalCheck, WalError> {
+        let mut files = Vec::new();
+        let segments = segment::list(dir)?;
+        let legacy = dir.join(LEGACY_FILE);
+        // `open` only imports a legacy WAL when there are no segments
+        if segments.is_empty() && legacy.is_file() {
+            let bytes = std::fs::read(&legacy)?;
+            files.push(FileFrames {
+                path: legacy,
+                legacy: true,
+                first_seq: None,
+                len: bytes.len() as u64,
+                frames: Self::legacy_frames(&bytes)?,
+            });
+        }
+        for segment in segments {
+            let bytes = std::fs::read(&segment.path)?;
+            files.push(FileFrames {
+                path: segment.path,
+                legacy: false,
+                first_seq: Some(segment.first_seq),
+                len: bytes.len() as u64,
+                frames: segment::frames(&bytes)?,
+            });
+        }
+
+        let mut check = WalCheck {
+            files: files.iter().map(|f| f.path.clone()).collect(),
+            entries: 0,
+            first_seq: None,
+            last_seq: None,
+            unknown: 0,
+            damage: None,
+        };
+        for (i, file) in files.iter().enumerate() {
+            let damage = |offset: u64, reason: String, keep: &[PendingEntry]| WalDamage {
+                path: file.path.clone(),
+                offset,
+                reason,
+                dropped_bytes: file.len.saturating_sub(offset),
+                later: files[i + 1..].iter().map(|f| f.path.clone()).collect(),
+                keep: keep.to_vec(),
+                legacy: file.legacy,
             };
+            let decoder = EventDecoder::new(file.frames.version);
+            let mut named_for = file.first_seq;
+            for (offset, entries) in &file.frames.frames {
+                for (k, entry) in entries.iter().enumerate() {
+                    let covered = check.last_seq.unwrap_or(0).max(after_seq);
+                    let problem = match (named_for.take(), check.last_seq) {
+                        (Some(named), _) if named != entry.seq => Some(format!(
+                            "starts at seq {} but is named for seq {}",
+                            entry.seq, named
+                        )),
+                        (_, Some(last)) if entry.seq <= last => {
+                            Some(format!("seq {} does not follow seq {}", entry.seq, last))
+                        }
+                        _ if entry.seq > covered + 1 => Some(missing(covered + 1, entry.seq - 1)),
+                        _ => match decoder.decode(&entry.json) {
+                            None => Some(format!("seq {} does not parse as an event", entry.seq)),
+                            Some(event) => {
+                                if matches!(event, Event::Custom) {
+                                    check.unknown += 1;
+                                }
+                                None
+                            }
+                        },
+                    };
+                    if let Some(reason) = problem {
+                        check.damage = Some(damage(*offset, reason, &entries[..k]));
+                        return Ok(check);
+                    }
+                    check.entries += 1;
+                    check.first_seq.get_or_insert(entry.seq);
+                    check.last_seq = Some(entry.seq);
+                }
+            }
+            if let Some((offset, reason)) = file.frames.corrupt {
+                check.damage = Some(damage(offset, reason.to_string(), &[]));
+                return Ok(check);
+            }
+        }
+        Ok(check)
+    }
 
-            let trimmed = line.trim();
+    /// A legacy JSONL WAL split into one frame per line.
+    fn legacy_frames(bytes: &[u8]) -> Result<segment::Frames, WalError> {
+        let mut frames =
+            segment::Frames { version: LEGACY_VERSION, frames: Vec::new(), corrupt: None };
+        let mut offset = 0;
+        for line in bytes.split_inclusive(|b| *b == b'\n') {
+            let start = offset;
+            offset += line.len() as u64;
+            let trimmed = line.trim_ascii();
             if trimmed.is_empty() {
-                current_offset += bytes_read as u64;
                 continue;
             }
-
-            let record: WalRecord = match serde_json::from_str(trimmed) {
-                Ok(r) => r,
-                Err(e) => {
-                    warn!(
-                        offset = current_offset,
-                        error = %e,
-                        "Corrupt WAL entry during replay, stopping at corruption point",
-                    );
-                    break;
-                }
+            let Ok(record) = serde_json::from_slice::<LegacyRecord>(trimmed) else {
+                frames.corrupt = Some((start, "unparseable line"));
+                break;
             };
+            let json = serde_json::to_vec(&record.event)?;
+            frames
+                .frames
+                .push((start, vec![PendingEntry { seq: record.seq, ts_ms: record.ts, json }]));
+        }
+        Ok(frames)
+    }
 
-            current_offset += bytes_read as u64;
-
-            if record.seq > seq {
-                entries.push(WalEntry { seq: record.seq, event: record.event });
+    /// Cut a WAL back to the last good entry before `damage`, found by
+    /// `check`, and set aside the files after it. Originals are kept as
+    /// `.bak` files.
+    ///
+    /// Only for a WAL no daemon has open.
+    pub fn truncate(dir: &Path, damage: &WalDamage) -> Result<(), WalError> {
+        let bytes = std::fs::read(&damage.path)?;
+        let mut valid = bytes.get(..damage.offset as usize).unwrap_or(&bytes).to_vec();
+        if !damage.legacy {
+            if valid.is_empty() {
+                valid.extend_from_slice(&segment::header(CURRENT_SNAPSHOT_VERSION));
+            }
+            if !damage.keep.is_empty() {
+                valid.extend_from_slice(&segment::encode_frame(&damage.keep)?);
             }
         }
+        Self::repair(&damage.path, &damage.later, &valid, &damage.reason)?;
+        File::open(dir)?.sync_all()?;
+        Ok(())
+    }
 
+    fn read_segments_after(segments: &[Segment], seq: u64) -> Result<Vec<WalEntry>, WalError> {
+        let mut entries = Vec::new();
+        for (i, segment) in segments.iter().enumerate() {
+            // Skip segments that end before `seq`
+            if segments.get(i + 1).is_some_and(|next| next.first_seq <= seq + 1) {
+                continue;
+            }
+            let bytes = match std::fs::read(&segment.path) {
+                Ok(bytes) => bytes,
+                // Truncated away by a running daemon since listing
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let scan = segment::scan(&bytes)?;
+            entries.extend(scan.entries.into_iter().filter(|e| e.seq > seq));
+            if let Some(reason) = scan.corrupt {
+                warn!(
+                    path = %segment.path.display(),
+                    offset = scan.valid_len,
+                    reason,
+                    "Corrupt WAL frame during replay, stopping at corruption point",
+                );
+                break;
+            }
+        }
         Ok(entries)
     }
 }
 
+fn missing(from: u64, to: u64) -> String {
+    if from == to {
+        format!("seq {} is missing", from)
+    } else {
+        format!("seqs {}-{} are missing", from, to)
+    }
+}
+
 #[cfg(test)]
 #[path = "wal_tests.rs"]
 mod tests;
@@ -4,12 +4,27 @@
 use super::*;
 use oj_core::{Event, TimerId};
 use std::io::Write;
+use std::path::{Path, PathBuf};
 use tempfile::tempdir;
 
 fn test_event(cmd: &str) -> Event {
     Event::TimerStart { id: TimerId::from_string(format!("test:{}", cmd)) }
 }
 
+/// The segment currently being appended to.
+fn active_segment(dir: &Path) -> PathBuf {
+    segment::list(dir).unwrap().pop().unwrap().path
+}
+
+fn append_bytes(path: &Path, bytes: &[u8]) {
+    let mut f = std::fs::OpenOptions::new().append(true).open(path).unwrap();
+    f.write_all(bytes).unwrap();
+}
+
+fn seqs(entries: &[WalEntry]) -> Vec<u64> {
+    entries.iter().map(|e| e.seq).collect()
+}
+
 #[test]
 fn test_open_creates_file() {
     let dir = tempdir().unwrap();
@@ -37,9 +52,31 @@ fn test_append_and_flush() {
 
     wal.flush().unwrap();
 
-    // File should now have content
-    let metadata = std::fs::metadata(&path).unwrap();
-    assert!(metadata.len() > 0);
+    // Segment should now have a frame after its header
+    let metadata = std::fs::metadata(active_segment(&path)).unwrap();
+    assert!(metadata.len() > segment::HEADER_LEN);
+}
+
+#[test]
+fn test_disk_bytes_counts_flushed_segments() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    let mut wal = Wal::open(&path, 0).unwrap();
+    wal.set_segment_bytes(1);
+
+    wal.append(&test_event("cmd1")).unwrap();
+    wal.flush().unwrap();
+    wal.append(&test_event("cmd2")).unwrap();
+    let before_flush = wal.disk_bytes();
+    wal.flush().unwrap();
+
+    let on_disk: u64 = segment::list(&path)
+        .unwrap()
+        .iter()
+        .map(|s| std::fs::metadata(&s.path).unwrap().len())
+        .sum();
+    assert_eq!(wal.disk_bytes(), on_disk);
+    assert!(before_flush < on_disk);
 }
 
 #[test]
@@ -135,26 +172,251 @@ fn test_entries_after() {
     assert_eq!(entries[1].seq, 3);
 }
 
+#[test]
+fn test_entries_record_append_time() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    let before = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+
+    let mut wal = Wal::open(&path, 0).unwrap();
+    wal.append(&test_event("cmd1")).unwrap();
+    wal.flush().unwrap();
+
+    let entries = wal.entries_after(0).unwrap();
+    assert!(entries[0].ts_ms >= before, "{} < {}", entries[0].ts_ms, before);
+}
+
+#[test]
+fn test_entries_without_timestamp_still_load() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    std::fs::create_dir_all(&path).unwrap();
+    std::fs::write(
+        path.join(LEGACY_FILE),
+        "{\"seq\":1,\"event\":{\"type\":\"system:shutdown\"}}\n",
+    )
+    .unwrap();
+
+    let entries = Wal::read_after(&path, 0).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].ts_ms, 0);
+    assert_eq!(entries[0].event, Event::Shutdown);
+}
+
+#[test]
+fn test_read_after_does_not_modify_file() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.append(&test_event("cmd1")).unwrap();
+        wal.append(&test_event("cmd2")).unwrap();
+        wal.flush().unwrap();
+    }
+    let segment = active_segment(&path);
+    append_bytes(&segment, b"partial-frame");
+    let before = std::fs::read(&segment).unwrap();
+
+    let entries = Wal::read_after(&path, 1).unwrap();
+
+    assert_eq!(seqs(&entries), [2]);
+    assert_eq!(std::fs::read(&segment).unwrap(), before);
+}
+
 #[test]
 fn test_truncate_before() {
     let dir = tempdir().unwrap();
     let path = dir.path().join("test.wal");
 
     let mut wal = Wal::open(&path, 0).unwrap();
+    wal.set_segment_bytes(1);
+
+    // Every flush seals its segment: segments start at 1, 2, 3 and 4 (active)
+    for cmd in ["cmd1", "cmd2", "cmd3"] {
+        wal.append(&test_event(cmd)).unwrap();
+        wal.flush().unwrap();
+    }
+    assert_eq!(segment::list(&path).unwrap().len(), 4);
 
+    // Truncate entries before seq=2 (keep seq 2 and 3)
+    wal.truncate_before(2).unwrap();
+
+    // Check that only entries 2 and 3 remain
+    let entries = wal.entries_after(0).unwrap();
+    assert_eq!(seqs(&entries), [2, 3]);
+    assert_eq!(segment::list(&path).unwrap().len(), 3);
+}
+
+#[test]
+fn test_truncate_before_keeps_active_segment() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+
+    let mut wal = Wal::open(&path, 0).unwrap();
     wal.append(&test_event("cmd1")).unwrap();
     wal.append(&test_event("cmd2")).unwrap();
-    wal.append(&test_event("cmd3")).unwrap();
     wal.flush().unwrap();
 
-    // Truncate entries before seq=2 (keep seq 2 and 3)
+    wal.truncate_before(3).unwrap();
+
+    // Whole segments are dropped, so entries sharing the active one stay
+    assert_eq!(seqs(&wal.entries_after(0).unwrap()), [1, 2]);
+    assert_eq!(wal.append(&test_event("cmd3")).unwrap(), 3);
+}
+
+#[test]
+fn test_segments_rotate_and_read_in_order() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.set_segment_bytes(1);
+        for i in 1..=5 {
+            wal.append(&test_event(&format!("cmd{}", i))).unwrap();
+            wal.flush().unwrap();
+        }
+
+        for expected in 1..=5 {
+            assert_eq!(wal.next_unprocessed().unwrap().unwrap().seq, expected);
+        }
+        assert!(wal.next_unprocessed().unwrap().is_none());
+    }
+
+    let firsts: Vec<u64> = segment::list(&path).unwrap().iter().map(|s| s.first_seq).collect();
+    assert_eq!(firsts, [1, 2, 3, 4, 5, 6]);
+
+    // Reopening continues the sequence and resumes after processed_seq
+    let mut wal = Wal::open(&path, 3).unwrap();
+    assert_eq!(wal.write_seq(), 5);
+    assert_eq!(seqs(&wal.entries_after(3).unwrap()), [4, 5]);
+    assert_eq!(wal.next_unprocessed().unwrap().unwrap().seq, 4);
+}
+
+#[test]
+fn test_truncate_before_while_reading() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+
+    let mut wal = Wal::open(&path, 0).unwrap();
+    wal.set_segment_bytes(1);
+    for cmd in ["cmd1", "cmd2"] {
+        wal.append(&test_event(cmd)).unwrap();
+        wal.flush().unwrap();
+    }
+    assert_eq!(wal.next_unprocessed().unwrap().unwrap().seq, 1);
+    wal.mark_processed(1);
+
+    // Drops the segment being read
     wal.truncate_before(2).unwrap();
 
-    // Check that only entries 2 and 3 remain
+    assert_eq!(wal.next_unprocessed().unwrap().unwrap().seq, 2);
+    assert!(wal.next_unprocessed().unwrap().is_none());
+}
+
+#[test]
+fn test_reopen_empty_wal_after_snapshot_continues_sequence() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+
+    let mut wal = Wal::open(&path, 7).unwrap();
+
+    assert_eq!(wal.append(&test_event("cmd1")).unwrap(), 8);
+}
+
+#[test]
+fn test_open_imports_legacy_jsonl() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("wal");
+    std::fs::create_dir_all(&path).unwrap();
+    let lines = [
+        r#"{"seq":4,"ts":1000,"event":{"type":"system:shutdown"}}"#,
+        r#"{"seq":5,"event":{"type":"timer:start","id":"test:cmd"}}"#,
+    ];
+    std::fs::write(path.join(LEGACY_FILE), lines.join("\n") + "\n").unwrap();
+
+    let mut wal = Wal::open(&path, 4).unwrap();
+
+    assert!(!path.join(LEGACY_FILE).exists());
     let entries = wal.entries_after(0).unwrap();
-    assert_eq!(entries.len(), 2);
-    assert_eq!(entries[0].seq, 2);
-    assert_eq!(entries[1].seq, 3);
+    assert_eq!(seqs(&entries), [4, 5]);
+    assert_eq!((entries[0].ts_ms, entries[1].ts_ms), (1000, 0));
+    assert_eq!(wal.next_unprocessed().unwrap().unwrap().event, test_event("cmd"));
+    assert_eq!(wal.append(&test_event("cmd6")).unwrap(), 6);
+}
+
+#[test]
+fn test_open_truncates_torn_frame() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.append(&test_event("cmd1")).unwrap();
+        wal.flush().unwrap();
+        wal.append(&test_event("cmd2")).unwrap();
+        wal.flush().unwrap();
+    }
+    // Cut the second frame short, as a crash mid-write would
+    let segment = active_segment(&path);
+    let len = std::fs::metadata(&segment).unwrap().len();
+    std::fs::OpenOptions::new().write(true).open(&segment).unwrap().set_len(len - 3).unwrap();
+
+    let mut wal = Wal::open(&path, 0).unwrap();
+
+    assert_eq!(wal.write_seq(), 1);
+    assert_eq!(seqs(&wal.entries_after(0).unwrap()), [1]);
+    assert!(segment.with_extension("bak").exists());
+    assert_eq!(wal.append(&test_event("cmd2")).unwrap(), 2);
+    wal.flush().unwrap();
+    assert_eq!(seqs(&wal.entries_after(0).unwrap()), [1, 2]);
+}
+
+#[test]
+fn test_open_detects_checksum_mismatch() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.append(&test_event("cmd1")).unwrap();
+        wal.flush().unwrap();
+        wal.append(&test_event("cmd2")).unwrap();
+        wal.flush().unwrap();
+    }
+    // Flip the last byte of the second frame's payload
+    let segment = active_segment(&path);
+    let mut bytes = std::fs::read(&segment).unwrap();
+    if let Some(last) = bytes.last_mut() {
+        *last ^= 0xff;
+    }
+    std::fs::write(&segment, bytes).unwrap();
+
+    let wal = Wal::open(&path, 0).unwrap();
+
+    assert_eq!(seqs(&wal.entries_after(0).unwrap()), [1]);
+}
+
+#[test]
+fn test_open_sets_aside_segments_after_corruption() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("test.wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.set_segment_bytes(1);
+        for cmd in ["cmd1", "cmd2", "cmd3"] {
+            wal.append(&test_event(cmd)).unwrap();
+            wal.flush().unwrap();
+        }
+    }
+    let segments = segment::list(&path).unwrap();
+    append_bytes(&segments[1].path, b"garbage!");
+
+    let wal = Wal::open(&path, 0).unwrap();
+
+    // seq 3 would follow a damaged segment, so it is not replayed
+    assert_eq!(seqs(&wal.entries_after(0).unwrap()), [1, 2]);
+    assert_eq!(wal.write_seq(), 2);
+    assert!(segments[2].path.with_extension("bak").exists());
 }
 
 /// Regression test: Shutdown events persisted in the WAL must be visible on
@@ -231,10 +493,8 @@ fn test_open_corrupt_wal_creates_bak_and_preserves_valid_entries() {
         wal.append(&test_event("cmd2")).unwrap();
         wal.flush().unwrap();
     }
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"not-valid-json\n").unwrap();
-    }
+    let segment = active_segment(&path);
+    append_bytes(&segment, b"not-valid-frame\n");
 
     // Open should handle corruption gracefully
     let wal = Wal::open(&path, 0).unwrap();
@@ -242,8 +502,8 @@ fn test_open_corrupt_wal_creates_bak_and_preserves_valid_entries() {
     // Valid entries should be preserved
     assert_eq!(wal.write_seq(), 2);
 
-    // Corrupt file should have been rotated to .bak
-    let bak = path.with_extension("bak");
+    // Corrupt segment should have been rotated to .bak
+    let bak = segment.with_extension("bak");
     assert!(bak.exists());
 
     // Clean WAL should have only valid entries
@@ -257,13 +517,12 @@ fn test_open_corrupt_wal_creates_bak_and_preserves_valid_entries() {
 fn test_open_corrupt_wal_rotates_bak_files() {
     let dir = tempdir().unwrap();
     let path = dir.path().join("test.wal");
+    std::fs::create_dir_all(&path).unwrap();
+    let segment = path.join("00000000000000000001.seg");
 
     // Simulate 4 corrupt opens — should keep at most 3 backups
     for i in 1..=4u8 {
-        {
-            let mut f = std::fs::File::create(&path).unwrap();
-            f.write_all(&[i; 8]).unwrap();
-        }
+        std::fs::write(&segment, [i; 8]).unwrap();
 
         // Open should handle corruption gracefully (fully corrupt = no valid entries)
         let wal = Wal::open(&path, 0).unwrap();
@@ -271,22 +530,22 @@ fn test_open_corrupt_wal_rotates_bak_files() {
     }
 
     // .bak (most recent = round 4)
-    let bak1 = path.with_extension("bak");
+    let bak1 = segment.with_extension("bak");
     assert!(bak1.exists());
     assert_eq!(std::fs::read(&bak1).unwrap(), vec![4u8; 8]);
 
     // .bak.2 (round 3)
-    let bak2 = path.with_extension("bak.2");
+    let bak2 = segment.with_extension("bak.2");
     assert!(bak2.exists());
     assert_eq!(std::fs::read(&bak2).unwrap(), vec![3u8; 8]);
 
     // .bak.3 (round 2)
-    let bak3 = path.with_extension("bak.3");
+    let bak3 = segment.with_extension("bak.3");
     assert!(bak3.exists());
     assert_eq!(std::fs::read(&bak3).unwrap(), vec![2u8; 8]);
 
     // Round 1 was evicted
-    let bak4 = path.with_extension("bak.4");
+    let bak4 = segment.with_extension("bak.4");
     assert!(!bak4.exists());
 }
 
@@ -302,19 +561,13 @@ fn test_entries_after_stops_at_corruption() {
         wal.append(&test_event("cmd2")).unwrap();
         wal.flush().unwrap();
     }
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"corrupted-data\n").unwrap();
-    }
+    append_bytes(&active_segment(&path), b"corrupted-data\n");
 
     // Open cleans up corruption, so we corrupt after open to test entries_after
     let wal = Wal::open(&path, 0).unwrap();
 
     // Now append garbage directly to the underlying file
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"post-open-corruption\n").unwrap();
-    }
+    append_bytes(&active_segment(&path), b"post-open-corruption\n");
 
     // entries_after should return valid entries and stop at corruption
     let entries = wal.entries_after(0).unwrap();
@@ -337,10 +590,7 @@ fn test_next_unprocessed_skips_corrupt_entry() {
     assert_eq!(entry.seq, 1);
 
     // Append garbage directly to the file
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"corrupt-line\n").unwrap();
-    }
+    append_bytes(&active_segment(&path), b"corrupt-line\n");
 
     // next_unprocessed should return None (not error) on corrupt entry
     let result = wal.next_unprocessed().unwrap();
@@ -360,15 +610,17 @@ fn test_open_with_binary_wal_data() {
     let dir = tempdir().unwrap();
     let path = dir.path().join("test.wal");
 
-    // Write binary (non-UTF-8) data to simulate corrupt WAL
-    std::fs::write(&path, b"\x80\x81\x82\xff\xfe\n").unwrap();
+    // Write a segment without a valid header to simulate corrupt WAL
+    std::fs::create_dir_all(&path).unwrap();
+    let segment = path.join("00000000000000000001.seg");
+    std::fs::write(&segment, b"\x80\x81\x82\xff\xfe\n").unwrap();
 
     // Should open successfully, treating binary data as corrupt
     let wal = Wal::open(&path, 0).unwrap();
     assert_eq!(wal.write_seq(), 0);
 
-    // Corrupt file should have been rotated to .bak
-    let bak = path.with_extension("bak");
+    // Corrupt segment should have been rotated to .bak
+    let bak = segment.with_extension("bak");
     assert!(bak.exists());
 }
 
@@ -386,16 +638,14 @@ fn test_open_with_valid_entries_then_binary() {
     }
 
     // Append binary garbage after the valid entries
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"\x80\x81\x82\xff\xfe\n").unwrap();
-    }
+    let segment = active_segment(&path);
+    append_bytes(&segment, b"\x80\x81\x82\xff\xfe\n");
 
-    // Should open, preserve valid entries, and rotate corrupt file
+    // Should open, preserve valid entries, and rotate corrupt segment
     let wal = Wal::open(&path, 0).unwrap();
     assert_eq!(wal.write_seq(), 2);
 
-    let bak = path.with_extension("bak");
+    let bak = segment.with_extension("bak");
     assert!(bak.exists());
 
     let entries = wal.entries_after(0).unwrap();
@@ -414,10 +664,7 @@ fn test_entries_after_stops_at_binary_data() {
     wal.flush().unwrap();
 
     // Append binary garbage after valid entry
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"\x80\x81\x82\xff\xfe\n").unwrap();
-    }
+    append_bytes(&active_segment(&path), b"\x80\x81\x82\xff\xfe\n");
 
     // entries_after should return valid entries and stop at binary data
     let entries = wal.entries_after(0).unwrap();
@@ -439,10 +686,7 @@ fn test_next_unprocessed_handles_binary_data() {
     assert_eq!(entry.seq, 1);
 
     // Append binary garbage
-    {
-        let mut f = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
-        f.write_all(b"\x80\x81\x82\xff\xfe\n").unwrap();
-    }
+    append_bytes(&active_segment(&path), b"\x80\x81\x82\xff\xfe\n");
 
     // next_unprocessed should return None (not error) on binary data
     let result = wal.next_unprocessed().unwrap();
@@ -485,3 +729,165 @@ fn test_truncate_before_read_offset_is_correct() {
     // No more entries
     assert!(wal.next_unprocessed().unwrap().is_none());
 }
+
+/// Write a segment from frames of `(seq, event JSON)` entries.
+fn write_frames(dir: &Path, first_seq: u64, frames: &[&[(u64, String)]]) -> PathBuf {
+    std::fs::create_dir_all(dir).unwrap();
+    let (segment, mut file) = segment::create(dir, first_seq).unwrap();
+    for frame in frames {
+        let entries: Vec<PendingEntry> = frame
+            .iter()
+            .map(|(seq, json)| PendingEntry {
+                seq: *seq,
+                ts_ms: 0,
+                json: json.clone().into_bytes(),
+            })
+            .collect();
+        file.write_all(&segment::encode_frame(&entries).unwrap()).unwrap();
+    }
+    segment.path
+}
+
+fn event_json(cmd: &str) -> String {
+    serde_json::to_string(&test_event(cmd)).unwrap()
+}
+
+#[test]
+fn test_check_clean_wal() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.set_segment_bytes(1);
+        for cmd in ["cmd1", "cmd2", "cmd3"] {
+            wal.append(&test_event(cmd)).unwrap();
+            wal.flush().unwrap();
+        }
+    }
+
+    let check = Wal::check(&path, 0).unwrap();
+
+    // Three sealed segments and an empty active one
+    assert_eq!(check.files.len(), 4);
+    assert_eq!((check.entries, check.first_seq, check.last_seq), (3, Some(1), Some(3)));
+    assert!(check.damage.is_none(), "{:?}", check.damage);
+}
+
+#[test]
+fn test_check_reports_first_bad_frame_offset() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("wal");
+    {
+        let mut wal = Wal::open(&path, 0).unwrap();
+        wal.append(&test_event("cmd1")).unwrap();
+        wal.flush().unwrap();
+    }
+    let segment = active_segment(&path);
+    let first_frame_end = std::fs::metadata(&segment).unwrap().len();
+    append_bytes(&segment, b"garbage!");
+
+    let check = Wal::check(&path, 0).unwrap();
+
+    let Some(damage) = check.damage else { unreachable!("expected damage") };
+    assert_eq!((damage.path, damage.offset), (segment, first_frame_end));
+    assert_eq!((damage.reason.as_str(), damage.dropped_bytes), ("frame length out of range", 8));
+    assert_eq!(check.last_seq, Some(1));
+}
+
+#[yare::parameterized(
+    repeated = { &[1, 2, 2], 2, "seq 2 does not follow seq 2" },
+    backwards = { &[1, 2, 1], 2, "seq 1 does not follow seq 2" },
+    gap = { &[1, 2, 5], 2, "seqs 3-4 are missing" },
+    single_gap = { &[1, 3], 1, "seq 2 is missing" },
+)]
+fn test_check_requires_consecutive_seqs(seqs: &[u64], last_good: u64, reason: &str) {
+    let dir = tempdir().unwrap();
+    let frame: Vec<(u64, String)> = seqs.iter().map(|seq| (*seq, event_json("cmd"))).collect();
+    write_frames(dir.path(), 1, &[&frame]);
+
+    let check = Wal::check(dir.path(), 0).unwrap();
+
+    assert_eq!(check.damage.map(|d| d.reason).as_deref(), Some(reason));
+    assert_eq!(check.last_seq, Some(last_good));
+}
+
+#[yare::parameterized(
+    continues_snapshot = { 4, None },
+    before_snapshot = { 9, None },
+    gap_after_snapshot = { 2, Some("seqs 3-4 are missing") },
+)]
+fn test_check_crosses_snapshot_seq(snapshot_seq: u64, reason: Option<&str>) {
+    let dir = tempdir().unwrap();
+    write_frames(dir.path(), 5, &[&[(5, event_json("cmd5")), (6, event_json("cmd6"))]]);
+
+    let check = Wal::check(dir.path(), snapshot_seq).unwrap();
+
+    assert_eq!(check.damage.map(|d| d.reason).as_deref(), reason);
+}
+
+#[test]
+fn test_check_reports_undecodable_event() {
+    let dir = tempdir().unwrap();
+    write_frames(dir.path(), 1, &[&[(1, event_json("cmd1")), (2, "{\"type\":".to_string())]]);
+
+    let check = Wal::check(dir.path(), 0).unwrap();
+
+    let Some(damage) = check.damage else { unreachable!("expected damage") };
+    assert_eq!(damage.reason, "seq 2 does not parse as an event");
+    assert_eq!(damage.offset, HEADER_LEN);
+}
+
+#[test]
+fn test_check_counts_unknown_events() {
+    let dir = tempdir().unwrap();
+    let unknown = r#"{"type":"session:created","id":"s1"}"#.to_string();
+    write_frames(dir.path(), 1, &[&[(1, event_json("cmd1")), (2, unknown)]]);
+
+    let check = Wal::check(dir.path(), 0).unwrap();
+
+    assert!(check.damage.is_none());
+    assert_eq!((check.entries, check.unknown), (2, 1));
+}
+
+#[test]
+fn test_truncate_keeps_good_entries_of_damaged_frame() {
+    let dir = tempdir().unwrap();
+    let path = write_frames(
+        dir.path(),
+        1,
+        &[
+            &[(1, event_json("cmd1"))],
+            &[(2, event_json("cmd2")), (3, event_json("cmd3")), (3, event_json("dup"))],
+        ],
+    );
+    let later = write_frames(dir.path(), 4, &[&[(4, event_json("cmd4"))]]);
+    let Some(damage) = Wal::check(dir.path(), 0).unwrap().damage else {
+        unreachable!("expected damage")
+    };
+
+    Wal::truncate(dir.path(), &damage).unwrap();
+
+    let check = Wal::check(dir.path(), 0).unwrap();
+    assert!(check.damage.is_none(), "{:?}", check.damage);
+    assert_eq!(seqs(&Wal::read_after(dir.path(), 0).unwrap()), [1, 2, 3]);
+    assert!(path.with_extension("bak").exists());
+    assert!(!later.exists() && later.with_extension("bak").exists());
+    // The daemon continues from the last good entry
+    let mut wal = Wal::open(dir.path(), 3).unwrap();
+    assert_eq!(wal.append(&test_event("cmd4")).unwrap(), 4);
+}
+
+#[test]
+fn test_check_and_truncate_legacy_wal() {
+    let dir = tempdir().unwrap();
+    let first = format!("{{\"seq\":1,\"event\":{}}}\n", event_json("cmd1"));
+    std::fs::write(dir.path().join("events.wal"), format!("{first}{{\"seq\":2,\"ev")).unwrap();
+
+    let check = Wal::check(dir.path(), 0).unwrap();
+    let Some(damage) = check.damage else { unreachable!("expected damage") };
+    assert_eq!((damage.offset, damage.reason.as_str()), (first.len() as u64, "unparseable line"));
+    Wal::truncate(dir.path(), &damage).unwrap();
+
+    assert_eq!(std::fs::read_to_string(dir.path().join("events.wal")).unwrap(), first);
+    assert!(Wal::check(dir.path(), 0).unwrap().damage.is_none());
+}
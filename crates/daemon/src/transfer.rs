@@ -0,0 +1,413 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! `ojd export` / `ojd import` — move daemon state to another host.
+//!
+//! An export is one zstd-compressed JSON file holding the snapshot (with its
+//! deltas applied), the WAL entries after it, and the archived jobs. Stored
+//! runbooks travel inside the snapshot state. Exporting only reads files, so
+//! it is safe while the daemon runs.
+//!
+//! Importing writes that state into an empty state directory while holding
+//! the daemon's lock; the daemon replays the WAL tail on its next start.
+//! Paths can be remapped on the way in (`--map /old/src=/new/src`): every
+//! string that is a mapped path or lies under one is rewritten, in the state
+//! and in the WAL events alike, so `project_paths`, workspace paths, and job
+//! and crew working directories follow the move. The exporting host's state
+//! directory is always mapped to the importing one. Workspace directories
+//! themselves are not exported.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use oj_core::{Event, Job};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::lifecycle::{Config, LifecycleError, StateLock};
+use crate::storage::{
+    load_snapshot, read_unmigrated, snapshot_version, ArchiveError, CheckpointError, Checkpointer,
+    JobArchive, MaterializedState, MigrationError, MigrationRegistry, Snapshot, SnapshotError, Wal,
+    WalEntry, WalError, CURRENT_SNAPSHOT_VERSION,
+};
+
+const EXPORT_USAGE: &str = "Usage: ojd export [--state-dir <DIR>] <FILE>";
+const IMPORT_USAGE: &str = "Usage: ojd import [--state-dir <DIR>] [--map <OLD>=<NEW> ...] <FILE>";
+
+/// Marks a file as an oj export
+const FORMAT: &str = "oj-export";
+
+const ZSTD_LEVEL: i32 = 3;
+
+#[derive(Debug, Error)]
+pub(crate) enum TransferError {
+    #[error("{0}")]
+    Usage(String),
+    #[error("failed to load snapshot: {0}")]
+    Snapshot(#[from] SnapshotError),
+    #[error("failed to access WAL: {0}")]
+    Wal(#[from] WalError),
+    #[error("failed to write snapshot: {0}")]
+    Checkpoint(#[from] CheckpointError),
+    #[error("failed to access job archive: {0}")]
+    Archive(#[from] ArchiveError),
+    #[error("{0}")]
+    Migration(#[from] MigrationError),
+    #[error("{0}")]
+    Lock(#[from] LifecycleError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid export: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("{0} is not an oj export")]
+    NotAnExport(PathBuf),
+    #[error("WAL resumes at seq {found}, expected {expected}; the daemon checkpointed while reading, try again")]
+    Gap { expected: u64, found: u64 },
+    #[error("{0} already holds daemon state; import into an empty state directory")]
+    NotEmpty(PathBuf),
+}
+
+/// The export file's contents.
+#[derive(Debug, Serialize, Deserialize)]
+struct Export {
+    format: String,
+    /// Schema version of the snapshot and events
+    #[serde(rename = "v")]
+    version: u32,
+    exported_at: DateTime<Utc>,
+    /// State directory on the exporting host
+    state_dir: PathBuf,
+    snapshot: Snapshot,
+    /// WAL entries after the snapshot
+    wal: Vec<ExportEntry>,
+    #[serde(default)]
+    archived_jobs: Vec<Job>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExportEntry {
+    seq: u64,
+    #[serde(default)]
+    ts: u64,
+    event: Event,
+}
+
+/// What an export or import moved.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Summary {
+    pub seq: u64,
+    pub jobs: usize,
+    pub workers: usize,
+    pub crons: usize,
+    pub queue_items: usize,
+    pub decisions: usize,
+    pub runbooks: usize,
+    pub wal_entries: usize,
+    pub archived_jobs: usize,
+    /// Strings rewritten by path mappings
+    pub remapped: usize,
+}
+
+impl Summary {
+    fn new(state: &MaterializedState, seq: u64, wal_entries: usize, archived_jobs: usize) -> Self {
+        Self {
+            seq,
+            jobs: state.jobs.len(),
+            workers: state.workers.len(),
+            crons: state.crons.len(),
+            queue_items: state.queue_items.values().map(Vec::len).sum(),
+            decisions: state.decisions.len(),
+            runbooks: state.runbooks.len(),
+            wal_entries,
+            archived_jobs,
+            remapped: 0,
+        }
+    }
+
+    fn describe(&self) -> String {
+        format!(
+            "state at seq {} ({} jobs, {} workers, {} crons, {} queue items, {} decisions, {} runbooks), {} WAL entries, {} archived jobs",
+            self.seq,
+            self.jobs,
+            self.workers,
+            self.crons,
+            self.queue_items,
+            self.decisions,
+            self.runbooks,
+            self.wal_entries,
+            self.archived_jobs,
+        )
+    }
+}
+
+/// A path prefix to rewrite on import.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PathMap {
+    from: String,
+    to: String,
+}
+
+impl PathMap {
+    pub(crate) fn new(from: &Path, to: &Path) -> Self {
+        Self { from: trim_slash(&from.to_string_lossy()), to: trim_slash(&to.to_string_lossy()) }
+    }
+
+    /// `value` with the prefix replaced, if it is `from` or a path under it.
+    fn apply(&self, value: &str) -> Option<String> {
+        let rest = value.strip_prefix(&self.from)?;
+        // What follows `from` must start a new path component
+        let rest = match rest.strip_prefix('/') {
+            Some(rest) => rest,
+            None if rest.is_empty() || self.from == "/" => rest,
+            None => return None,
+        };
+        Some(match (self.to.as_str(), rest) {
+            (to, "") => to.to_string(),
+            ("/", rest) => format!("/{}", rest),
+            (to, rest) => format!("{}/{}", to, rest),
+        })
+    }
+}
+
+fn trim_slash(path: &str) -> String {
+    match path.trim_end_matches('/') {
+        "" => "/".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// Rewrite every string in `value` that falls under one of `maps`, the
+/// longest matching prefix winning. Returns how many were rewritten.
+pub(crate) fn remap_paths(value: &mut Value, maps: &[PathMap]) -> usize {
+    match value {
+        Value::String(s) => {
+            let best = maps.iter().filter(|m| m.apply(s).is_some()).max_by_key(|m| m.from.len());
+            match best.and_then(|m| m.apply(s)) {
+                Some(mapped) if mapped != *s => {
+                    *s = mapped;
+                    1
+                }
+                _ => 0,
+            }
+        }
+        Value::Array(items) => items.iter_mut().map(|v| remap_paths(v, maps)).sum(),
+        Value::Object(fields) => fields.values_mut().map(|v| remap_paths(v, maps)).sum(),
+        _ => 0,
+    }
+}
+
+/// Write the state under `config` to `path`.
+pub(crate) fn export(config: &Config, path: &Path) -> Result<Summary, TransferError> {
+    let snapshot = load_snapshot(&config.snapshot_path)?.unwrap_or_else(|| Snapshot {
+        version: CURRENT_SNAPSHOT_VERSION,
+        seq: 0,
+        state: MaterializedState::default(),
+        created_at: Utc::now(),
+    });
+    let entries = if config.wal_path.exists() {
+        Wal::read_after(&config.wal_path, snapshot.seq)?
+    } else {
+        vec![]
+    };
+    if let Some(first) = entries.first().filter(|e| e.seq > snapshot.seq + 1) {
+        return Err(TransferError::Gap { expected: snapshot.seq + 1, found: first.seq });
+    }
+    let wal: Vec<ExportEntry> = entries
+        .into_iter()
+        // Unknown events are ignored on replay and cannot be written back
+        .filter(|e| !matches!(e.event, Event::Custom))
+        .map(|e| ExportEntry { seq: e.seq, ts: e.ts_ms, event: e.event })
+        .collect();
+    let archived_jobs = JobArchive::new(&config.archive_path).list()?;
+
+    let summary = Summary::new(&snapshot.state, snapshot.seq, wal.len(), archived_jobs.len());
+    let export = Export {
+        format: FORMAT.to_string(),
+        version: CURRENT_SNAPSHOT_VERSION,
+        exported_at: Utc::now(),
+        state_dir: config.state_dir.clone(),
+        snapshot,
+        wal,
+        archived_jobs,
+    };
+    let json = serde_json::to_vec(&export)?;
+    let compressed = zstd::encode_all(json.as_slice(), ZSTD_LEVEL)?;
+    let mut file = File::create(path)?;
+    file.write_all(&compressed)?;
+    file.sync_all()?;
+    Ok(summary)
+}
+
+/// Read an export from `path` into the empty state directory of `config`,
+/// rewriting paths with `maps`.
+pub(crate) fn import(
+    config: &Config,
+    path: &Path,
+    maps: &[PathMap],
+) -> Result<Summary, TransferError> {
+    let _lock = StateLock::acquire(config)?;
+    let has_wal = config.wal_path.read_dir().is_ok_and(|mut entries| entries.next().is_some());
+    if has_wal || !read_unmigrated(&config.snapshot_path)?.is_empty() {
+        return Err(TransferError::NotEmpty(config.state_dir.clone()));
+    }
+
+    let file = File::open(path)?;
+    let mut value: Value = zstd::stream::read::Decoder::new(file)
+        .and_then(|decoder| serde_json::from_reader(decoder).map_err(std::io::Error::other))
+        .map_err(|_| TransferError::NotAnExport(path.to_owned()))?;
+    if value.get("format").and_then(Value::as_str) != Some(FORMAT) {
+        return Err(TransferError::NotAnExport(path.to_owned()));
+    }
+    upgrade(&mut value)?;
+
+    let mut maps = maps.to_vec();
+    if let Some(old_dir) = value.get("state_dir").and_then(Value::as_str) {
+        maps.push(PathMap::new(Path::new(old_dir), &config.state_dir));
+    }
+    let mut remapped = 0;
+    for key in ["snapshot", "wal", "archived_jobs"] {
+        if let Some(part) = value.get_mut(key) {
+            remapped += remap_paths(part, &maps);
+        }
+    }
+    // Back through bytes: IDs only deserialize from borrowed strings
+    let export: Export = serde_json::from_slice(&serde_json::to_vec(&value)?)?;
+
+    let mut summary = Summary::new(
+        &export.snapshot.state,
+        export.snapshot.seq,
+        export.wal.len(),
+        export.archived_jobs.len(),
+    );
+    summary.remapped = remapped;
+
+    JobArchive::new(&config.archive_path).append(&export.archived_jobs)?;
+    let entries: Vec<WalEntry> = export
+        .wal
+        .into_iter()
+        .map(|e| WalEntry { seq: e.seq, ts_ms: e.ts, event: e.event })
+        .collect();
+    Wal::restore(&config.wal_path, &entries)?;
+    // Snapshot last: until it exists the directory reads as empty, so a
+    // failed import can be retried after clearing the WAL
+    Checkpointer::new(config.snapshot_path.clone())
+        .checkpoint_sync(export.snapshot.seq, &export.snapshot.state)?;
+    Ok(summary)
+}
+
+/// Migrate an export written at an older schema version.
+fn upgrade(export: &mut Value) -> Result<(), TransferError> {
+    let version = snapshot_version(export);
+    let registry = MigrationRegistry::new();
+    if let Some(snapshot) = export.get_mut("snapshot") {
+        *snapshot = registry.migrate_to(snapshot.take(), CURRENT_SNAPSHOT_VERSION)?;
+    }
+    if let Some(Value::Array(entries)) = export.get_mut("wal") {
+        for entry in entries {
+            if let Some(event) = entry.get_mut("event") {
+                *event = registry.migrate_event(event.take(), version)?;
+            }
+        }
+    }
+    // Errors if the export is newer than this daemon
+    registry.path(version, CURRENT_SNAPSHOT_VERSION)?;
+    export["v"] = CURRENT_SNAPSHOT_VERSION.into();
+    Ok(())
+}
+
+/// Entry point for `ojd export`. Returns the process exit code.
+pub(crate) fn export_main(args: &[String]) -> i32 {
+    exit_code(run_export(args))
+}
+
+/// Entry point for `ojd import`. Returns the process exit code.
+pub(crate) fn import_main(args: &[String]) -> i32 {
+    exit_code(run_import(args))
+}
+
+fn exit_code(result: Result<(), Box<dyn std::error::Error>>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            1
+        }
+    }
+}
+
+fn run_export(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_args(args, EXPORT_USAGE, false)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    let summary = export(&config, &parsed.file)?;
+    println!("exported {} to {}", summary.describe(), parsed.file.display());
+    Ok(())
+}
+
+fn run_import(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let parsed = parse_args(args, IMPORT_USAGE, true)?;
+    let config = match parsed.state_dir {
+        Some(dir) => Config::at(dir),
+        None => Config::load()?,
+    };
+    let summary = import(&config, &parsed.file, &parsed.maps)?;
+    println!("imported {} into {}", summary.describe(), config.state_dir.display());
+    if summary.remapped > 0 {
+        println!("remapped {} paths", summary.remapped);
+    }
+    Ok(())
+}
+
+/// Parsed `ojd export` / `ojd import` arguments.
+#[derive(Debug, PartialEq)]
+struct TransferArgs {
+    state_dir: Option<PathBuf>,
+    maps: Vec<PathMap>,
+    file: PathBuf,
+}
+
+fn parse_args(
+    args: &[String],
+    usage: &str,
+    allow_maps: bool,
+) -> Result<TransferArgs, TransferError> {
+    let error = |message: String| TransferError::Usage(format!("{message}\n{usage}"));
+    let mut state_dir = None;
+    let mut maps = Vec::new();
+    let mut file = None;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--state-dir" => {
+                let value = args.next().ok_or_else(|| error("--state-dir needs a value".into()))?;
+                state_dir = Some(PathBuf::from(value));
+            }
+            "--map" if allow_maps => {
+                let value = args.next().ok_or_else(|| error("--map needs a value".into()))?;
+                let (from, to) = value
+                    .split_once('=')
+                    .filter(|(from, to)| from.starts_with('/') && to.starts_with('/'))
+                    .ok_or_else(|| {
+                        error(format!("expected --map /OLD/PATH=/NEW/PATH, got '{value}'"))
+                    })?;
+                maps.push(PathMap::new(Path::new(from), Path::new(to)));
+            }
+            flag if flag.starts_with("--") => {
+                return Err(error(format!("unexpected argument '{flag}'")));
+            }
+            _ if file.is_some() => return Err(error(format!("unexpected argument '{arg}'"))),
+            _ => file = Some(PathBuf::from(arg)),
+        }
+    }
+    let file = file.ok_or_else(|| error("missing export file".into()))?;
+    Ok(TransferArgs { state_dir, maps, file })
+}
+
+#[cfg(test)]
+#[path = "transfer_tests.rs"]
+mod tests;
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use std::collections::HashMap;
+
+use oj_core::{JobId, OwnerId, WorkspaceId};
+use serde_json::json;
+use tempfile::tempdir;
+
+use super::*;
+
+fn args(input: &[&str]) -> Vec<String> {
+    input.iter().map(|s| s.to_string()).collect()
+}
+
+fn job_created(id: &str, cwd: &str) -> Event {
+    Event::JobCreated {
+        id: JobId::from_string(id),
+        kind: "build".to_string(),
+        name: id.to_string(),
+        runbook_hash: "abc".to_string(),
+        cwd: PathBuf::from(cwd),
+        vars: HashMap::new(),
+        initial_step: "plan".to_string(),
+        created_at_ms: 1_000,
+        project: "api".to_string(),
+        cron: None,
+    }
+}
+
+/// A daemon's state directory: job-1 and a stored runbook in the snapshot
+/// at seq 2, then a worker and a workspace under the state directory in
+/// the WAL.
+fn source_state(dir: &Path) -> Config {
+    let config = Config::at(dir.to_path_buf());
+    let snapshot_events = [
+        job_created("job-1", "/home/alice/src/api"),
+        Event::RunbookLoaded { hash: "abc".to_string(), version: 1, runbook: json!({}) },
+    ];
+    let wal_events = [
+        Event::WorkerStarted {
+            queue: "bugs".to_string(),
+            worker: "fixer".to_string(),
+            runbook_hash: "abc".to_string(),
+            concurrency: 1,
+            project: "api".to_string(),
+            project_path: PathBuf::from("/home/alice/src/api"),
+        },
+        Event::WorkspaceCreated {
+            id: WorkspaceId::from_string("ws-1"),
+            path: dir.join("workspaces/ws-1"),
+            branch: None,
+            owner: OwnerId::Job(JobId::from_string("job-1")),
+            workspace_type: None,
+        },
+    ];
+
+    let mut state = MaterializedState::default();
+    let mut wal = Wal::open(&config.wal_path, 0).unwrap();
+    for event in snapshot_events.iter().chain(&wal_events) {
+        wal.append(event).unwrap();
+    }
+    wal.flush().unwrap();
+    for event in &snapshot_events {
+        state.apply_event(event);
+    }
+    Checkpointer::new(config.snapshot_path.clone()).checkpoint_sync(2, &state).unwrap();
+    config
+}
+
+/// State as the daemon would load it: snapshot plus WAL tail.
+fn loaded_state(config: &Config) -> MaterializedState {
+    let snapshot = load_snapshot(&config.snapshot_path).unwrap().unwrap();
+    let mut state = snapshot.state;
+    for entry in Wal::read_after(&config.wal_path, snapshot.seq).unwrap() {
+        state.apply_event(&entry.event);
+    }
+    state
+}
+
+#[test]
+fn round_trip_restores_snapshot_and_wal_tail() {
+    let source = tempdir().unwrap();
+    let target = tempdir().unwrap();
+    let config = source_state(source.path());
+    let file = source.path().join("oj.export");
+
+    let exported = export(&config, &file).unwrap();
+    let target_config = Config::at(target.path().to_path_buf());
+    let imported = import(&target_config, &file, &[]).unwrap();
+
+    assert_eq!(
+        (exported.seq, exported.jobs, exported.runbooks, exported.wal_entries),
+        (2, 1, 1, 2)
+    );
+    assert_eq!(imported, Summary { remapped: 1, ..exported });
+    let state = loaded_state(&target_config);
+    assert_eq!(state.jobs["job-1"].cwd, PathBuf::from("/home/alice/src/api"));
+    assert!(state.runbooks.contains_key("abc"));
+    assert_eq!(state.workers.len(), 1);
+    assert!(!target_config.lock_path.exists());
+}
+
+#[test]
+fn import_remaps_project_paths_and_state_dir() {
+    let source = tempdir().unwrap();
+    let target = tempdir().unwrap();
+    let config = source_state(source.path());
+    let file = source.path().join("oj.export");
+    export(&config, &file).unwrap();
+
+    let target_config = Config::at(target.path().to_path_buf());
+    let maps = [PathMap::new(Path::new("/home/alice/src/"), Path::new("/srv/src"))];
+    let summary = import(&target_config, &file, &maps).unwrap();
+
+    // job cwd, worker project path, workspace path
+    assert_eq!(summary.remapped, 3);
+    let state = loaded_state(&target_config);
+    assert_eq!(state.jobs["job-1"].cwd, PathBuf::from("/srv/src/api"));
+    assert_eq!(state.project_paths["api"], PathBuf::from("/srv/src/api"));
+    assert_eq!(state.workspaces["ws-1"].path, target.path().join("workspaces/ws-1"));
+}
+
+#[test]
+fn import_refuses_state_dir_with_state() {
+    let source = tempdir().unwrap();
+    let config = source_state(source.path());
+    let file = source.path().join("oj.export");
+    export(&config, &file).unwrap();
+    let before = std::fs::read(&config.snapshot_path).unwrap();
+
+    let result = import(&config, &file, &[]);
+
+    assert!(matches!(result, Err(TransferError::NotEmpty(_))), "{result:?}");
+    assert_eq!(std::fs::read(&config.snapshot_path).unwrap(), before);
+}
+
+#[test]
+fn import_rejects_other_files() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("notes.txt");
+    std::fs::write(&file, "not an export").unwrap();
+    let config = Config::at(dir.path().join("state"));
+
+    let result = import(&config, &file, &[]);
+
+    assert!(matches!(result, Err(TransferError::NotAnExport(_))), "{result:?}");
+}
+
+#[test]
+fn export_of_empty_state_dir_imports_as_empty() {
+    let source = tempdir().unwrap();
+    let target = tempdir().unwrap();
+    let file = source.path().join("oj.export");
+
+    let summary = export(&Config::at(source.path().to_path_buf()), &file).unwrap();
+    import(&Config::at(target.path().to_path_buf()), &file, &[]).unwrap();
+
+    assert_eq!((summary.seq, summary.jobs, summary.wal_entries), (0, 0, 0));
+}
+
+#[yare::parameterized(
+    exact = { "/home/alice/src", Some("/srv/src") },
+    nested = { "/home/alice/src/api/.git", Some("/srv/src/api/.git") },
+    sibling_prefix = { "/home/alice/src2", None },
+    unrelated = { "/tmp/src", None },
+    not_a_path = { "main", None },
+)]
+fn path_map_matches_whole_components(input: &str, expected: Option<&str>) {
+    let map = PathMap::new(Path::new("/home/alice/src"), Path::new("/srv/src"));
+    assert_eq!(map.apply(input).as_deref(), expected);
+}
+
+#[yare::parameterized(
+    root_from = { "/", "/new", "/abc/def", Some("/new/abc/def") },
+    root_from_exact = { "/", "/new", "/", Some("/new") },
+    root_from_relative = { "/", "/new", "main", None },
+    root_to = { "/home/alice", "/", "/home/alice/src", Some("/src") },
+    root_to_exact = { "/home/alice", "/", "/home/alice", Some("/") },
+)]
+fn path_map_handles_root(from: &str, to: &str, input: &str, expected: Option<&str>) {
+    let map = PathMap::new(Path::new(from), Path::new(to));
+    assert_eq!(map.apply(input).as_deref(), expected);
+}
+
+#[test]
+fn longest_mapping_wins() {
+    let maps = [
+        PathMap::new(Path::new("/home/alice"), Path::new("/home/bob")),
+        PathMap::new(Path::new("/home/alice/src"), Path::new("/srv/src")),
+    ];
+    let mut value = json!({ "cwd": "/home/alice/src/api", "logs": ["/home/alice/logs"], "n": 3 });
+
+    assert_eq!(remap_paths(&mut value, &maps), 2);
+    assert_eq!(value, json!({ "cwd": "/srv/src/api", "logs": ["/home/bob/logs"], "n": 3 }));
+}
+
+#[yare::parameterized(
+    file_only = { &["oj.export"], None, &[] },
+    state_dir = { &["--state-dir", "/backup/oj", "oj.export"], Some("/backup/oj"), &[] },
+    maps = {
+        &["--map", "/home/alice/src=/srv/src", "--map", "/opt/a/=/opt/b", "oj.export"],
+        None,
+        &[("/home/alice/src", "/srv/src"), ("/opt/a", "/opt/b")]
+    },
+)]
+fn parses_import_flags(input: &[&str], state_dir: Option<&str>, maps: &[(&str, &str)]) {
+    assert_eq!(
+        parse_args(&args(input), IMPORT_USAGE, true).unwrap(),
+        TransferArgs {
+            state_dir: state_dir.map(PathBuf::from),
+            maps: maps.iter().map(|(f, t)| PathMap::new(Path::new(f), Path::new(t))).collect(),
+            file: PathBuf::from("oj.export"),
+        }
+    );
+}
+
+#[yare::parameterized(
+    missing_file = { &[], true },
+    two_files = { &["a", "b"], true },
+    state_dir_missing = { &["--state-dir"], true },
+    map_missing = { &["--map"], true },
+    map_without_equals = { &["--map", "/a", "f"], true },
+    map_relative = { &["--map", "src=/srv/src", "f"], true },
+    map_on_export = { &["--map", "/a=/b", "f"], false },
+    unknown_flag = { &["--force", "f"], true },
+)]
+fn rejects_bad_flags(input: &[&str], allow_maps: bool) {
+    assert!(matches!(
+        parse_args(&args(input), IMPORT_USAGE, allow_maps),
+        Err(TransferError::Usage(_))
+    ));
+}
@@ -204,6 +204,50 @@ pub struct AgentDef {
     /// Notification messages for agent lifecycle events
     #[serde(default)]
     pub notify: crate::job::NotifyConfig,
+
+    /// Expiry and assignment for decisions raised by this agent (including
+    /// failed gates). Fields left unset fall back to the job's `decision`.
+    #[serde(default)]
+    pub decision: Option<DecisionConfig>,
+}
+
+/// Decision expiry and assignment:
+/// `decision { timeout = "4h", default = "Done", assignee = "alice" }`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DecisionConfig {
+    /// How long a decision waits before resolving itself (e.g., "30m", "4h").
+    /// None = decisions wait for a human indefinitely.
+    #[serde(default)]
+    pub timeout: Option<String>,
+    /// Label of the option to choose on expiry (case-insensitive).
+    /// None = the decision's recommended option.
+    #[serde(default)]
+    pub default: Option<String>,
+    /// Person responsible for answering
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Others who may answer, shown in their `oj decision inbox`
+    #[serde(default)]
+    pub reviewers: Vec<String>,
+}
+
+impl DecisionConfig {
+    /// Combine with a fallback config (e.g. the job's), field by field.
+    pub fn or(&self, fallback: Option<&DecisionConfig>) -> DecisionConfig {
+        let Some(fallback) = fallback else {
+            return self.clone();
+        };
+        DecisionConfig {
+            timeout: self.timeout.clone().or_else(|| fallback.timeout.clone()),
+            default: self.default.clone().or_else(|| fallback.default.clone()),
+            assignee: self.assignee.clone().or_else(|| fallback.assignee.clone()),
+            reviewers: if self.reviewers.is_empty() {
+                fallback.reviewers.clone()
+            } else {
+                self.reviewers.clone()
+            },
+        }
+    }
 }
 
 /// Action configuration - simple or with options
@@ -501,6 +545,7 @@ impl Default for AgentDef {
             on_error: default_on_error(),
             max_concurrency: None,
             notify: Default::default(),
+            decision: None,
         }
     }
 }
@@ -306,6 +306,7 @@ fn agent_build_command_with_prompt_field() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> = HashMap::new();
@@ -332,6 +333,7 @@ fn agent_build_command_with_inline_prompt() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> =
@@ -357,6 +359,7 @@ fn agent_build_command_print_mode() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> = HashMap::new();
@@ -387,6 +390,7 @@ fn agent_build_env() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> =
@@ -416,6 +420,7 @@ fn agent_get_prompt_from_field() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> =
@@ -444,6 +449,7 @@ fn agent_get_prompt_empty_when_unset() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars = HashMap::new();
@@ -473,6 +479,7 @@ fn agent_get_prompt_from_file() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars: HashMap<String, String> =
@@ -501,6 +508,7 @@ fn agent_get_prompt_file_not_found() {
         on_error: default_on_error(),
         max_concurrency: None,
         notify: Default::default(),
+        decision: None,
     };
 
     let vars = HashMap::new();
@@ -814,3 +822,28 @@ fn on_exit_alias_no_longer_populates_on_dead() {
         "on_exit should not populate on_dead"
     );
 }
+
+#[test]
+fn decision_config_falls_back_per_field() {
+    let agent = DecisionConfig {
+        assignee: Some("alice".to_string()),
+        default: Some("Done".to_string()),
+        ..Default::default()
+    };
+    let job = DecisionConfig {
+        timeout: Some("4h".to_string()),
+        assignee: Some("ops".to_string()),
+        reviewers: vec!["bob".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        agent.or(Some(&job)),
+        DecisionConfig {
+            timeout: Some("4h".to_string()),
+            default: Some("Done".to_string()),
+            assignee: Some("alice".to_string()),
+            reviewers: vec!["bob".to_string()],
+        }
+    );
+    assert_eq!(agent.or(None), agent);
+}
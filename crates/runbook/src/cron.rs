@@ -2,18 +2,36 @@
 // Copyright (c) 2026 Alfred Jean LLC
 
 //! Cron definition for runbooks
+//!
+//! A cron fires either every `interval` or on a calendar `schedule` written
+//! as a standard cron expression. Expressions are parsed here so runbooks are
+//! validated up front; the daemon computes the actual fire times.
 
 use crate::RunDirective;
+use oj_core::CronCatchup;
 use serde::{Deserialize, Serialize};
 
-/// A cron definition that runs a job or agent on a timer interval.
+/// A cron definition that runs a job or agent on a timer interval or a
+/// calendar schedule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CronDef {
     /// Cron name (injected from map key)
     #[serde(skip)]
     pub name: String,
     /// Interval duration string (e.g. "30m", "6h", "24h")
-    pub interval: String,
+    #[serde(default)]
+    pub interval: Option<String>,
+    /// Cron expression with 5 or 6 fields (e.g. "0 9 * * MON-FRI")
+    #[serde(default)]
+    pub schedule: Option<String>,
+    /// Timezone for `schedule`: "UTC" (default), "local", or a fixed offset
+    /// like "+05:30"
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// What to do about runs missed while the daemon was down: "none"
+    /// (default), "once", or "all"
+    #[serde(default)]
+    pub catchup: CronCatchup,
     /// What to run (job reference only)
     pub run: RunDirective,
     /// Maximum number of active jobs this cron can have running
@@ -21,3 +39,236 @@ pub struct CronDef {
     #[serde(default)]
     pub concurrency: Option<u32>,
 }
+
+/// A parsed cron expression.
+///
+/// Accepts 5 fields (`minute hour day-of-month month day-of-week`) or 6 with
+/// a leading seconds field, plus the `@hourly`, `@daily`, `@weekly`,
+/// `@monthly` and `@yearly` shorthands. Fields support `*`, `?`, lists,
+/// ranges, `/` steps, and month/weekday names. As in Vixie cron, when both
+/// day-of-month and day-of-week are restricted, a day matching either fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronExpr {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Both day fields are restricted: match either instead of both
+    days_or: bool,
+}
+
+const MONTH_NAMES: [&str; 12] =
+    ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Longest length of each month (February counts its leap day)
+const MONTH_MAX_DAYS: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+impl CronExpr {
+    /// Parse a cron expression.
+    pub fn parse(expr: &str) -> Result<Self, String> {
+        let expanded = match expr.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other if other.starts_with('@') => {
+                return Err(format!("unknown cron shorthand '{}'", other));
+            }
+            other => other,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        let (seconds, minutes, hours, dom, months, dow) = match fields[..] {
+            [mi, h, dom, mo, dow] => ("0", mi, h, dom, mo, dow),
+            [s, mi, h, dom, mo, dow] => (s, mi, h, dom, mo, dow),
+            _ => return Err(format!("expected 5 or 6 fields, got {}", fields.len())),
+        };
+
+        let parsed = CronExpr {
+            seconds: parse_field(seconds, "second", 0, 59, &[])?,
+            minutes: parse_field(minutes, "minute", 0, 59, &[])?,
+            hours: parse_field(hours, "hour", 0, 23, &[])?,
+            days_of_month: parse_field(dom, "day-of-month", 1, 31, &[])?,
+            months: parse_field(months, "month", 1, 12, &MONTH_NAMES)?,
+            days_of_week: parse_weekdays(dow)?,
+            days_or: is_restricted(dom) && is_restricted(dow),
+        };
+
+        // Reject day-of-month sets that no allowed month can reach (e.g. "30 2")
+        if !parsed.days_or {
+            let reachable = (1..=12u32).filter(|&m| parsed.matches_month(m)).any(|m| {
+                (1..=MONTH_MAX_DAYS[(m - 1) as usize]).any(|d| parsed.days_of_month & bit(d) != 0)
+            });
+            if !reachable {
+                return Err("day-of-month never occurs in the selected months".to_string());
+            }
+        }
+        Ok(parsed)
+    }
+
+    pub fn matches_second(&self, second: u32) -> bool {
+        self.seconds & bit(second) != 0
+    }
+
+    pub fn matches_minute(&self, minute: u32) -> bool {
+        self.minutes & bit(minute) != 0
+    }
+
+    pub fn matches_hour(&self, hour: u32) -> bool {
+        self.hours & bit(hour) != 0
+    }
+
+    pub fn matches_month(&self, month: u32) -> bool {
+        self.months & bit(month) != 0
+    }
+
+    /// Whether a calendar day matches. `weekday` counts from Sunday = 0.
+    pub fn matches_day(&self, day_of_month: u32, weekday: u32) -> bool {
+        let dom = self.days_of_month & bit(day_of_month) != 0;
+        let dow = self.days_of_week & bit(weekday) != 0;
+        if self.days_or {
+            dom || dow
+        } else {
+            dom && dow
+        }
+    }
+}
+
+/// Timezone a cron `schedule` is evaluated in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CronTimezone {
+    #[default]
+    Utc,
+    /// The daemon's local time, following its DST rules
+    Local,
+    /// A fixed offset in seconds east of UTC
+    Fixed(i32),
+    /// An IANA zone such as "America/New_York", following its DST rules
+    Named(chrono_tz::Tz),
+}
+
+impl CronTimezone {
+    /// Parse "UTC", "local", an IANA name like "Europe/Berlin", or an offset
+    /// like "+05:30", "-08:00", "+0200".
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        match s {
+            "UTC" | "utc" | "Z" | "GMT" | "Etc/UTC" => return Ok(CronTimezone::Utc),
+            "local" | "Local" => return Ok(CronTimezone::Local),
+            _ => {}
+        }
+        let named = s.contains('/') || s.bytes().all(|b| b.is_ascii_alphabetic());
+        if named && !s.is_empty() {
+            return s.parse().map(CronTimezone::Named).map_err(|_| {
+                format!(
+                    "unknown timezone '{}': use an IANA name like \"Europe/Berlin\", \"UTC\", \
+                     \"local\", or an offset like \"+05:30\"",
+                    s
+                )
+            });
+        }
+        let unsupported = || {
+            format!(
+                "unsupported timezone '{}': use \"UTC\", \"local\", or an offset like \"+05:30\"",
+                s
+            )
+        };
+        let offset = s.strip_prefix("UTC").unwrap_or(s);
+        let (sign, digits) = match offset.as_bytes().first() {
+            Some(b'+') => (1, &offset[1..]),
+            Some(b'-') => (-1, &offset[1..]),
+            _ => return Err(unsupported()),
+        };
+        let (hh, mm) = match digits.split_once(':') {
+            Some((h, m)) => (h, m),
+            None if digits.len() == 4 => digits.split_at(2),
+            None => (digits, "0"),
+        };
+        let valid =
+            |v: &str| !v.is_empty() && v.len() <= 2 && v.bytes().all(|b| b.is_ascii_digit());
+        if !valid(hh) || !valid(mm) {
+            return Err(unsupported());
+        }
+        let hours: i32 = hh.parse().map_err(|_| unsupported())?;
+        let minutes: i32 = mm.parse().map_err(|_| unsupported())?;
+        if hours > 14 || minutes > 59 {
+            return Err(format!("timezone offset '{}' is out of range", s));
+        }
+        Ok(CronTimezone::Fixed(sign * (hours * 3600 + minutes * 60)))
+    }
+}
+
+fn bit(n: u32) -> u64 {
+    1u64 << n
+}
+
+/// A day field is restricted unless it is a bare `*` or `?`.
+fn is_restricted(field: &str) -> bool {
+    !matches!(field, "*" | "?")
+}
+
+/// Parse a day-of-week field, where both 0 and 7 mean Sunday.
+fn parse_weekdays(field: &str) -> Result<u64, String> {
+    let mask = parse_field(field, "day-of-week", 0, 7, &WEEKDAY_NAMES)?;
+    Ok(if mask & bit(7) != 0 { (mask & !bit(7)) | bit(0) } else { mask })
+}
+
+/// Parse one field into a bitmask of allowed values in `min..=max`.
+///
+/// `names` map case-insensitively to consecutive values starting at `min`.
+fn parse_field(field: &str, what: &str, min: u32, max: u32, names: &[&str]) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((r, s)) => {
+                let step: u32 =
+                    s.parse().map_err(|_| format!("invalid {} step '{}'", what, part))?;
+                if step == 0 {
+                    return Err(format!("{} step must be at least 1", what));
+                }
+                (r, Some(step))
+            }
+            None => (part, None),
+        };
+        let (lo, hi) = match range {
+            "*" | "?" => (min, max),
+            _ => match range.split_once('-') {
+                Some((a, b)) => {
+                    (parse_value(a, what, min, max, names)?, parse_value(b, what, min, max, names)?)
+                }
+                // "5/15" means from 5 to the end of the range, every 15
+                None if step.is_some() => (parse_value(range, what, min, max, names)?, max),
+                None => {
+                    let v = parse_value(range, what, min, max, names)?;
+                    (v, v)
+                }
+            },
+        };
+        if lo > hi {
+            return Err(format!("invalid {} range '{}'", what, range));
+        }
+        for v in (lo..=hi).step_by(step.unwrap_or(1) as usize) {
+            mask |= bit(v);
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(s: &str, what: &str, min: u32, max: u32, names: &[&str]) -> Result<u32, String> {
+    if let Some(i) = names.iter().position(|n| n.eq_ignore_ascii_case(s)) {
+        return Ok(i as u32 + min);
+    }
+    let v: u32 = s.parse().map_err(|_| format!("invalid {} '{}'", what, s))?;
+    if v < min || v > max {
+        return Err(format!("{} {} out of range {}-{}", what, v, min, max));
+    }
+    Ok(v)
+}
+
+#[cfg(test)]
+#[path = "cron_tests.rs"]
+mod tests;
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn expr(s: &str) -> CronExpr {
+    CronExpr::parse(s).unwrap()
+}
+
+#[test]
+fn five_fields_fire_on_second_zero() {
+    let e = expr("30 9 * * *");
+    assert!(e.matches_second(0));
+    assert!(!e.matches_second(1));
+    assert!(e.matches_minute(30));
+    assert!(!e.matches_minute(31));
+    assert!(e.matches_hour(9));
+    assert!(e.matches_day(15, 3));
+    assert!(e.matches_month(7));
+}
+
+#[test]
+fn six_fields_lead_with_seconds() {
+    let e = expr("*/15 0 12 * * *");
+    for s in [0, 15, 30, 45] {
+        assert!(e.matches_second(s), "second {}", s);
+    }
+    assert!(!e.matches_second(10));
+    assert!(e.matches_minute(0));
+    assert!(e.matches_hour(12));
+}
+
+#[test]
+fn lists_ranges_and_steps() {
+    let e = expr("0,30 8-10 1-10/3 * *");
+    assert!(e.matches_minute(0) && e.matches_minute(30) && !e.matches_minute(15));
+    assert!(e.matches_hour(8) && e.matches_hour(10) && !e.matches_hour(11));
+    let days: Vec<u32> = (1..=31).filter(|&d| e.matches_day(d, 0)).collect();
+    assert_eq!(days, vec![1, 4, 7, 10]);
+}
+
+#[test]
+fn step_from_start_value_runs_to_end_of_range() {
+    let e = expr("5/20 * * * *");
+    let minutes: Vec<u32> = (0..60).filter(|&m| e.matches_minute(m)).collect();
+    assert_eq!(minutes, vec![5, 25, 45]);
+}
+
+#[test]
+fn month_and_weekday_names() {
+    let e = expr("0 9 * jan,JUL MON-fri");
+    assert!(e.matches_month(1) && e.matches_month(7) && !e.matches_month(2));
+    assert!(e.matches_day(1, 1) && e.matches_day(1, 5));
+    assert!(!e.matches_day(1, 0) && !e.matches_day(1, 6));
+}
+
+#[test]
+fn sunday_is_zero_or_seven() {
+    assert!(expr("0 0 * * 7").matches_day(4, 0));
+    assert!(expr("0 0 * * 0").matches_day(4, 0));
+    assert!(expr("0 0 * * 5-7").matches_day(4, 0));
+}
+
+#[test]
+fn restricted_day_fields_match_either() {
+    // 1st of the month OR any Monday
+    let e = expr("0 0 1 * MON");
+    assert!(e.matches_day(1, 4));
+    assert!(e.matches_day(9, 1));
+    assert!(!e.matches_day(9, 2));
+}
+
+#[test]
+fn single_restricted_day_field_matches_alone() {
+    let e = expr("0 0 ? * MON");
+    assert!(e.matches_day(9, 1));
+    assert!(!e.matches_day(1, 4));
+}
+
+#[yare::parameterized(
+    hourly = { "@hourly", "0 * * * *" },
+    daily = { "@daily", "0 0 * * *" },
+    midnight = { "@midnight", "0 0 * * *" },
+    weekly = { "@weekly", "0 0 * * 0" },
+    monthly = { "@monthly", "0 0 1 * *" },
+    yearly = { "@yearly", "0 0 1 1 *" },
+    annually = { "@annually", "0 0 1 1 *" },
+)]
+fn shorthands(shorthand: &str, equivalent: &str) {
+    assert_eq!(expr(shorthand), expr(equivalent));
+}
+
+#[yare::parameterized(
+    every_one = { "5/1 * * * *", 5, 59, 55 },
+    every_two = { "10/2 * * * *", 10, 58, 25 },
+)]
+fn step_of_start_value_is_a_range(input: &str, first: u32, last: u32, count: usize) {
+    let e = expr(input);
+    let minutes: Vec<u32> = (0..60).filter(|&m| e.matches_minute(m)).collect();
+    assert_eq!((minutes[0], minutes[minutes.len() - 1], minutes.len()), (first, last, count));
+}
+
+#[yare::parameterized(
+    too_few = { "* * * *", "expected 5 or 6 fields" },
+    too_many = { "* * * * * * *", "expected 5 or 6 fields" },
+    minute_range = { "60 * * * *", "minute 60 out of range" },
+    hour_range = { "0 24 * * *", "hour 24 out of range" },
+    zero_dom = { "0 0 0 * *", "day-of-month 0 out of range" },
+    bad_month = { "0 0 * FOO *", "invalid month 'FOO'" },
+    zero_step = { "*/0 * * * *", "step must be at least 1" },
+    reversed = { "0 10-8 * * *", "invalid hour range" },
+    shorthand = { "@often", "unknown cron shorthand" },
+    never = { "0 0 30 2 *", "never occurs" },
+)]
+fn invalid_expressions(input: &str, expected: &str) {
+    let err = CronExpr::parse(input).unwrap_err();
+    assert!(err.contains(expected), "'{}' gave: {}", input, err);
+}
+
+#[test]
+fn leap_day_is_reachable() {
+    assert!(CronExpr::parse("0 0 29 2 *").is_ok());
+}
+
+#[yare::parameterized(
+    utc = { "UTC", CronTimezone::Utc },
+    utc_lower = { "utc", CronTimezone::Utc },
+    local = { "local", CronTimezone::Local },
+    colon = { "+05:30", CronTimezone::Fixed(19_800) },
+    negative = { "-08:00", CronTimezone::Fixed(-28_800) },
+    compact = { "+0200", CronTimezone::Fixed(7_200) },
+    hours_only = { "-3", CronTimezone::Fixed(-10_800) },
+    utc_prefix = { "UTC+01:00", CronTimezone::Fixed(3_600) },
+    iana = { "Europe/Berlin", CronTimezone::Named(chrono_tz::Europe::Berlin) },
+    nested_iana = {
+        "America/Argentina/Buenos_Aires",
+        CronTimezone::Named(chrono_tz::America::Argentina::Buenos_Aires)
+    },
+    abbreviation = { "EST", CronTimezone::Named(chrono_tz::EST) },
+)]
+fn timezone_parsing(input: &str, expected: CronTimezone) {
+    assert_eq!(CronTimezone::parse(input).unwrap(), expected);
+}
+
+#[yare::parameterized(
+    unknown_iana = { "Mars/Olympus_Mons", "unknown timezone 'Mars/Olympus_Mons'" },
+    unknown_abbreviation = { "XYZ", "unknown timezone 'XYZ'" },
+    garbage = { "+ab:cd", "unsupported timezone" },
+    hours = { "+15:00", "out of range" },
+    minutes = { "+01:60", "out of range" },
+)]
+fn invalid_timezones(input: &str, expected: &str) {
+    let err = CronTimezone::parse(input).unwrap_err();
+    assert!(err.contains(expected), "'{}' gave: {}", input, err);
+}
@@ -3,12 +3,13 @@
 
 //! Job definitions
 //!
-//! # Design Note: No Step Timeouts
+//! # Design Note: Step Timeouts Are Opt-In
 //!
-//! This module deliberately does not support step-level timeouts for agent steps.
-//! This is a conscious design decision, not an oversight.
+//! Steps may declare `timeout = "20m"` (or inherit a job-level `timeout`), and
+//! route to `on_timeout` when the deadline passes. There is no default: a step
+//! without a timeout runs until it finishes or the monitors intervene.
 //!
-//! ## Why No Timeout Feature?
+//! ## Why Not Time Out By Default?
 //!
 //! **This is a dynamic, monitored system.** Agents and jobs are actively watched by
 //! both automated handlers (`on_idle`, `on_dead`, `on_error`) and human operators. When
@@ -19,10 +20,6 @@
 //! taking days or weeks of actual productive work. A timeout would kill legitimate work.
 //! The system must distinguish "working for a long time" from "stuck"—timeouts cannot.
 //!
-//! **The default must be NO timeout.** If a timeout feature existed, the only safe default
-//! would be infinite (no timeout). An infinite-default timeout adds complexity and
-//! misconfiguration risk without benefit.
-//!
 //! **Timeouts hide root causes.** If an agent is stuck, restarting it via timeout provides
 //! no information about why. The monitoring system detects actual states:
 //! - `on_idle`: Agent waiting for input (stuck on a prompt)
@@ -32,16 +29,17 @@
 //! ## When Timeouts ARE Appropriate
 //!
 //! Timeouts make sense for bounded operations like shell commands with known
-//! execution bounds — not for agent steps.
+//! execution bounds, or as a last-resort backstop for unattended jobs.
 //!
 //! See [`docs/01-concepts/EXECUTION.md`] for the full rationale.
 
+use crate::agent::DecisionConfig;
 use crate::command::RunDirective;
 use crate::container::ContainerConfig;
 use indexmap::IndexMap;
 use serde::de::{self, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 /// A step transition target: `{ step = "name" }`.
@@ -56,6 +54,59 @@ impl StepTransition {
     }
 }
 
+/// A single value or an array of values.
+///
+/// Used for step `on_done`, which accepts either one transition or an array
+/// of transitions that fan out into parallel branches:
+///   `on_done = { step = "test" }`
+///   `on_done = [{ step = "test-unit" }, { step = "test-integration" }]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    /// All values as a slice (a single value is a one-element slice).
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            OneOrMany::One(v) => std::slice::from_ref(v),
+            OneOrMany::Many(v) => v,
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+}
+
+impl<T> From<T> for OneOrMany<T> {
+    fn from(v: T) -> Self {
+        OneOrMany::One(v)
+    }
+}
+
+impl OneOrMany<StepTransition> {
+    /// Target step names, in declaration order.
+    pub fn step_names(&self) -> Vec<String> {
+        self.iter().map(|t| t.step.clone()).collect()
+    }
+
+    /// True when this transition fans out into more than one parallel branch.
+    pub fn is_fan_out(&self) -> bool {
+        self.len() > 1
+    }
+}
+
 /// Notification configuration for lifecycle events
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct NotifyConfig {
@@ -142,15 +193,27 @@ pub struct StepDef {
     pub name: String,
     /// What to run: shell command or agent
     pub run: RunDirective,
-    /// Next step on success
+    /// Steps that must all complete before this step starts (fan-in).
+    ///
+    /// A step with `after` is pull-activated: it must not also be the target
+    /// of any `on_done`/`on_fail`/`on_cancel` transition.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<String>,
+    /// Next step on success. An array fans out into parallel branches.
     #[serde(default)]
-    pub on_done: Option<StepTransition>,
+    pub on_done: Option<OneOrMany<StepTransition>>,
     /// Step to go to on failure
     #[serde(default)]
     pub on_fail: Option<StepTransition>,
     /// Step to route to when the job is cancelled during this step
     #[serde(default)]
     pub on_cancel: Option<StepTransition>,
+    /// Maximum time the step may run (e.g. "20m"), overriding the job default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Step to go to when the step times out (falls back to on_fail)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_timeout: Option<StepTransition>,
 }
 
 impl StepDef {
@@ -173,6 +236,11 @@ impl StepDef {
     pub fn shell_command(&self) -> Option<&str> {
         self.run.shell_command()
     }
+
+    /// Check if this step waits on other steps (`after = [...]`)
+    pub fn is_join(&self) -> bool {
+        !self.after.is_empty()
+    }
 }
 
 /// A job definition from the runbook
@@ -211,6 +279,9 @@ pub struct JobDef {
     /// Step to route to when the job is cancelled (no step-level on_cancel)
     #[serde(default)]
     pub on_cancel: Option<StepTransition>,
+    /// Default timeout for steps that do not set their own (e.g. "1h")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
     /// Local variables computed at job creation time.
     /// Values are template strings evaluated once, available as ${local.*}.
     #[serde(default)]
@@ -218,6 +289,10 @@ pub struct JobDef {
     /// Notification messages for job lifecycle events
     #[serde(default)]
     pub notify: NotifyConfig,
+    /// Decision expiry and assignment for this job's agents.
+    /// Agent-level `decision` fields take precedence.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decision: Option<DecisionConfig>,
     /// Ordered steps
     #[serde(default, alias = "step", deserialize_with = "deserialize_steps")]
     pub steps: Vec<StepDef>,
@@ -278,6 +353,26 @@ impl JobDef {
     pub fn first_step(&self) -> Option<&StepDef> {
         self.steps.first()
     }
+
+    /// Effective timeout for a step: its own `timeout`, else the job default
+    pub fn step_timeout(&self, name: &str) -> Option<&str> {
+        self.get_step(name)?.timeout.as_deref().or(self.timeout.as_deref())
+    }
+
+    /// Join steps whose `after` prerequisites are all in `completed`.
+    ///
+    /// Steps for which `skip` returns true (e.g. already started) are excluded.
+    pub fn ready_joins<'a>(
+        &'a self,
+        completed: &HashSet<&str>,
+        skip: impl Fn(&str) -> bool,
+    ) -> Vec<&'a StepDef> {
+        self.steps
+            .iter()
+            .filter(|s| s.is_join() && !skip(&s.name))
+            .filter(|s| s.after.iter().all(|a| completed.contains(a.as_str())))
+            .collect()
+    }
 }
 
 #[cfg(test)]
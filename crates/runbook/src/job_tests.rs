@@ -17,42 +17,59 @@ fn sample_job() -> JobDef {
         on_done: None,
         on_fail: None,
         on_cancel: None,
+        timeout: None,
         notify: Default::default(),
+        decision: None,
         steps: vec![
             StepDef {
                 name: "init".to_string(),
                 run: RunDirective::Shell("git worktree add".to_string()),
+                after: vec![],
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             },
             StepDef {
                 name: "plan".to_string(),
                 run: RunDirective::Agent { agent: "planner".to_string(), attach: None },
+                after: vec![],
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             },
             StepDef {
                 name: "execute".to_string(),
                 run: RunDirective::Agent { agent: "executor".to_string(), attach: None },
-                on_done: Some(StepTransition { step: "done".to_string() }),
+                after: vec![],
+                on_done: Some(StepTransition { step: "done".to_string() }.into()),
                 on_fail: Some(StepTransition { step: "failed".to_string() }),
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             },
             StepDef {
                 name: "done".to_string(),
                 run: RunDirective::Shell("echo done".to_string()),
+                after: vec![],
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             },
             StepDef {
                 name: "failed".to_string(),
                 run: RunDirective::Shell("echo failed".to_string()),
+                after: vec![],
                 on_done: None,
                 on_fail: None,
                 on_cancel: None,
+                timeout: None,
+                on_timeout: None,
             },
         ],
     }
@@ -169,7 +186,7 @@ fn parse_structured_step_transition(input: &str, fmt: Format) {
     let runbook = parse_runbook_with_format(input, fmt).unwrap();
     let job = runbook.get_job("deploy").unwrap();
     let init = job.get_step("init").unwrap();
-    assert_eq!(init.on_done.as_ref().map(|t| t.step_name()), Some("next"));
+    assert_eq!(init.on_done.as_ref().map(|t| t.step_names()), Some(vec!["next".to_string()]));
 }
 
 #[test]
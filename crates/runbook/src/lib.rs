@@ -24,15 +24,15 @@ mod validate;
 mod worker;
 
 pub use agent::{
-    ActionConfig, ActionTrigger, AgentAction, AgentDef, Attempts, ErrorActionConfig, ErrorMatch,
-    ErrorType, PrimeDef, VALID_PRIME_SOURCES,
+    ActionConfig, ActionTrigger, AgentAction, AgentDef, Attempts, DecisionConfig,
+    ErrorActionConfig, ErrorMatch, ErrorType, PrimeDef, VALID_PRIME_SOURCES,
 };
 pub use command::{
     parse_arg_spec, ArgDef, ArgSpec, ArgSpecError, ArgValidationError, CommandDef, FlagDef,
     OptionDef, RunDirective, VariadicDef,
 };
 pub use container::ContainerConfig;
-pub use cron::CronDef;
+pub use cron::{CronDef, CronExpr, CronTimezone};
 pub use find::{
     collect_all_commands, collect_all_crons, collect_all_queues, collect_all_workers,
     collect_runbook_summaries, extract_block_comments, extract_file_comment,
@@ -45,11 +45,11 @@ pub use import::{
     ImportConst, ImportDef, ImportWarning, LibraryFiles, LibraryInfo,
 };
 pub use job::{
-    GitWorkspaceMode, JobDef, NotifyConfig, StepDef, StepTransition, WorkspaceBlock,
+    GitWorkspaceMode, JobDef, NotifyConfig, OneOrMany, StepDef, StepTransition, WorkspaceBlock,
     WorkspaceConfig, WorkspaceType,
 };
 pub use parser::{parse_runbook, parse_runbook_with_format, Format, ParseError, Runbook};
-pub use queue::{QueueDef, QueueType};
+pub use queue::{Backoff, QueueDef, QueueOrder, QueueType, RetryConfig};
 pub use slug::{job_display_name, slugify};
 pub use template::{escape_for_shell, interpolate, interpolate_shell};
-pub use worker::{WorkerDef, WorkerHandler, WorkerSource};
+pub use worker::{ActiveWindow, WorkerDef, WorkerHandler, WorkerRate, WorkerSource};
@@ -6,7 +6,9 @@
 use crate::import::{ConstDef, ImportDef};
 use crate::validate::{
     sorted_keys, sorted_names, validate_agent_command, validate_command_template_refs,
-    validate_duration_str, validate_shell_command, validate_template_namespaces,
+    validate_cron_timing, validate_decision_config, validate_dedupe_template,
+    validate_duration_str, validate_parallel_steps, validate_shell_command,
+    validate_template_namespaces, validate_worker_limits,
 };
 use crate::{
     ActionTrigger, AgentDef, ArgSpecError, CommandDef, CronDef, JobDef, PrimeDef, QueueDef,
@@ -294,6 +296,18 @@ fn parse_runbook_inner(
                         message: "external queue must not have 'retry' field".to_string(),
                     });
                 }
+                if queue.priority != 0 {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have 'priority' field".to_string(),
+                    });
+                }
+                if queue.dedupe.is_some() {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("queue.{}", name),
+                        message: "external queue must not have 'dedupe' field".to_string(),
+                    });
+                }
                 if let Some(ref poll) = queue.poll {
                     if let Err(e) = validate_duration_str(poll) {
                         return Err(ParseError::InvalidFormat {
@@ -335,22 +349,64 @@ fn parse_runbook_inner(
                             message: e,
                         });
                     }
+                    if let Some(ref max_cooldown) = retry.max_cooldown {
+                        if let Err(e) = validate_duration_str(max_cooldown) {
+                            return Err(ParseError::InvalidFormat {
+                                location: format!("queue.{}.retry.max_cooldown", name),
+                                message: e,
+                            });
+                        }
+                    }
+                    if !(0.0..=1.0).contains(&retry.jitter) {
+                        return Err(ParseError::InvalidFormat {
+                            location: format!("queue.{}.retry.jitter", name),
+                            message: format!(
+                                "jitter must be between 0.0 and 1.0, got {}",
+                                retry.jitter
+                            ),
+                        });
+                    }
+                }
+                if let Some(ref dedupe) = queue.dedupe {
+                    validate_dedupe_template(queue, dedupe, name)?;
                 }
             }
         }
     }
 
-    // 6.5. Validate cron interval syntax
+    // 6.5. Validate cron interval and schedule syntax
     for (name, cron) in &runbook.crons {
-        if let Err(e) = validate_duration_str(&cron.interval) {
-            return Err(ParseError::InvalidFormat {
-                location: format!("cron.{}.interval", name),
-                message: e,
-            });
+        validate_cron_timing(name, cron)?;
+    }
+
+    // 6.52. Validate worker rate and active window syntax
+    for (name, worker) in &runbook.workers {
+        validate_worker_limits(name, worker)?;
+    }
+
+    // 6.55. Validate job and step timeout syntax
+    for (job_name, job) in &runbook.jobs {
+        if let Some(ref timeout) = job.timeout {
+            if let Err(e) = validate_duration_str(timeout) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("job.{}.timeout", job_name),
+                    message: e,
+                });
+            }
+        }
+        for step in &job.steps {
+            if let Some(ref timeout) = step.timeout {
+                if let Err(e) = validate_duration_str(timeout) {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("job.{}.step.{}.timeout", job_name, step.name),
+                        message: e,
+                    });
+                }
+            }
         }
     }
 
-    // 6.6. Validate agent max_concurrency
+    // 6.6. Validate agent max_concurrency and decision configs
     for (name, agent) in &runbook.agents {
         if let Some(max) = agent.max_concurrency {
             if max == 0 {
@@ -360,6 +416,14 @@ fn parse_runbook_inner(
                 });
             }
         }
+        if let Some(ref decision) = agent.decision {
+            validate_decision_config(decision, &format!("agent.{}.decision", name))?;
+        }
+    }
+    for (name, job) in &runbook.jobs {
+        if let Some(ref decision) = job.decision {
+            validate_decision_config(decision, &format!("job.{}.decision", name))?;
+        }
     }
 
     // 7. Validate action-trigger compatibility
@@ -457,30 +521,32 @@ fn parse_runbook_inner(
 
         // Check step-level transitions
         for (i, step) in job.steps.iter().enumerate() {
-            for (field, transition) in [
-                ("on_done", &step.on_done),
-                ("on_fail", &step.on_fail),
-                ("on_cancel", &step.on_cancel),
-            ] {
-                if let Some(t) = transition {
-                    if !step_names.contains(t.step_name()) {
-                        return Err(ParseError::InvalidFormat {
-                            location: format!(
-                                "job.{}.step[{}]({}).{}",
-                                job_name, i, step.name, field
-                            ),
-                            message: format!(
-                                "references unknown step '{}'; available steps: {}",
-                                t.step_name(),
-                                sorted_names(&step_names),
-                            ),
-                        });
-                    }
+            let on_done = step.on_done.iter().flat_map(|t| t.iter());
+            let targets = on_done
+                .map(|t| ("on_done", t))
+                .chain(step.on_fail.iter().map(|t| ("on_fail", t)))
+                .chain(step.on_cancel.iter().map(|t| ("on_cancel", t)))
+                .chain(step.on_timeout.iter().map(|t| ("on_timeout", t)));
+            for (field, t) in targets {
+                if !step_names.contains(t.step_name()) {
+                    return Err(ParseError::InvalidFormat {
+                        location: format!("job.{}.step[{}]({}).{}", job_name, i, step.name, field),
+                        message: format!(
+                            "references unknown step '{}'; available steps: {}",
+                            t.step_name(),
+                            sorted_names(&step_names),
+                        ),
+                    });
                 }
             }
         }
     }
 
+    // 10. Validate parallel fan-out (array on_done) and fan-in (after)
+    for (job_name, job) in &runbook.jobs {
+        validate_parallel_steps(job_name, job)?;
+    }
+
     // 11. Warn on unreachable steps
     let mut sorted_jobs: Vec<_> = runbook.jobs.iter().collect();
     sorted_jobs.sort_by_key(|(name, _)| *name);
@@ -493,17 +559,21 @@ fn parse_runbook_inner(
             referenced.insert(t.step_name());
         }
         for step in &job.steps {
-            for t in [&step.on_done, &step.on_fail, &step.on_cancel].into_iter().flatten() {
+            for t in step.on_done.iter().flat_map(|t| t.iter()) {
+                referenced.insert(t.step_name());
+            }
+            for t in [&step.on_fail, &step.on_cancel, &step.on_timeout].into_iter().flatten() {
                 referenced.insert(t.step_name());
             }
         }
-        for step in job.steps.iter().skip(1) {
+        // Join steps are activated by their `after` prerequisites, not by transitions
+        for step in job.steps.iter().skip(1).filter(|s| !s.is_join()) {
             if !referenced.contains(step.name.as_str()) {
                 return Err(ParseError::InvalidFormat {
                     location: format!("job.{}.step.{}", job_name, step.name),
                     message: format!(
                         "step '{}' is unreachable \
-                         (not referenced by any on_done/on_fail/on_cancel)",
+                         (not referenced by any on_done/on_fail/on_cancel/on_timeout)",
                         step.name
                     ),
                 });
@@ -17,6 +17,19 @@ pub enum QueueType {
     Persisted,
 }
 
+/// Order in which workers take a queue's items.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QueueOrder {
+    /// Oldest first
+    #[default]
+    Fifo,
+    /// Newest first
+    Lifo,
+    /// Highest priority first, oldest first among equals
+    Priority,
+}
+
 /// Retry configuration for persisted queues.
 ///
 /// Controls automatic retry behavior when queue items fail.
@@ -29,6 +42,27 @@ pub struct RetryConfig {
     /// Cooldown duration between retries (e.g. "30s", "5m"), default "0s"
     #[serde(default = "default_cooldown")]
     pub cooldown: String,
+    /// How the cooldown grows with repeated failures
+    #[serde(default)]
+    pub backoff: Backoff,
+    /// Upper bound on the cooldown once backoff has grown it (e.g. "1h")
+    #[serde(default)]
+    pub max_cooldown: Option<String>,
+    /// Fraction of the cooldown (0.0–1.0) randomly taken off each delay,
+    /// so items that failed together don't retry together
+    #[serde(default)]
+    pub jitter: f64,
+}
+
+/// Growth of the retry cooldown across failures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Backoff {
+    /// Wait `cooldown` before every retry
+    #[default]
+    Fixed,
+    /// Double the cooldown after each failure
+    Exponential,
 }
 
 fn default_cooldown() -> String {
@@ -66,4 +100,27 @@ pub struct QueueDef {
     /// When set, workers periodically check the queue at this interval
     #[serde(default)]
     pub poll: Option<String>,
+    /// Order workers take items in. External queues read each item's
+    /// `priority` field for `priority` order.
+    #[serde(default)]
+    pub order: QueueOrder,
+    /// Priority of items pushed without `--priority` (persisted queues only)
+    #[serde(default)]
+    pub priority: i32,
+    /// Key template (e.g. `"${item.branch}"`); a push whose key matches a
+    /// pending or active item returns that item instead (persisted queues only)
+    #[serde(default)]
+    pub dedupe: Option<String>,
+}
+
+impl QueueDef {
+    /// Evaluate the `dedupe` template against an item's data.
+    ///
+    /// Returns `None` when the queue has no `dedupe` key.
+    pub fn dedupe_key(&self, data: &HashMap<String, String>) -> Option<String> {
+        let template = self.dedupe.as_deref()?;
+        let vars: HashMap<String, String> =
+            data.iter().map(|(k, v)| (format!("item.{}", k), v.clone())).collect();
+        Some(crate::template::interpolate(template, &vars))
+    }
 }
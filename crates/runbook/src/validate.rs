@@ -4,6 +4,10 @@
 //! Validation helpers for runbook parsing
 
 use crate::parser::ParseError;
+use crate::{
+    ActiveWindow, CronDef, CronExpr, CronTimezone, DecisionConfig, JobDef, QueueDef, WorkerDef,
+    WorkerRate,
+};
 use oj_shell as shell;
 use std::collections::{HashMap, HashSet};
 
@@ -157,6 +161,115 @@ pub(crate) fn validate_duration_str(s: &str) -> Result<(), String> {
     }
 }
 
+/// Validate a persisted queue's `dedupe` template: it must reference at least
+/// one item field, and only fields every item has (`vars` or `defaults`).
+pub(crate) fn validate_dedupe_template(
+    queue: &QueueDef,
+    template: &str,
+    name: &str,
+) -> Result<(), ParseError> {
+    let invalid = |message: String| ParseError::InvalidFormat {
+        location: format!("queue.{}.dedupe", name),
+        message,
+    };
+    let mut refs = 0;
+    for cap in crate::template::VAR_PATTERN.captures_iter(template) {
+        let var_name = &cap[1];
+        let Some(field) = var_name.strip_prefix("item.") else {
+            return Err(invalid(format!(
+                "dedupe key can only reference item fields, found ${{{}}}",
+                var_name
+            )));
+        };
+        if !queue.vars.iter().any(|v| v == field) && !queue.defaults.contains_key(field) {
+            return Err(invalid(format!(
+                "${{{}}} is not a declared var of this queue (vars: {})",
+                var_name,
+                queue.vars.join(", ")
+            )));
+        }
+        refs += 1;
+    }
+    if refs == 0 {
+        return Err(invalid("dedupe key must reference an item field, e.g. ${item.branch}".into()));
+    }
+    Ok(())
+}
+
+/// Validate a cron's timing: exactly one of `interval` or `schedule`, and a
+/// `timezone` only alongside `schedule`.
+pub(crate) fn validate_cron_timing(name: &str, cron: &CronDef) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: String| ParseError::InvalidFormat {
+        location: format!("cron.{}{}", name, field),
+        message,
+    };
+    match (&cron.interval, &cron.schedule) {
+        (Some(interval), None) => {
+            validate_duration_str(interval).map_err(|e| invalid(".interval", e))?;
+        }
+        (None, Some(schedule)) => {
+            CronExpr::parse(schedule).map_err(|e| invalid(".schedule", e))?;
+        }
+        (Some(_), Some(_)) => {
+            return Err(invalid("", "set either 'interval' or 'schedule', not both".to_string()));
+        }
+        (None, None) => {
+            return Err(invalid("", "missing 'interval' or 'schedule'".to_string()));
+        }
+    }
+    if let Some(ref timezone) = cron.timezone {
+        if cron.schedule.is_none() {
+            return Err(invalid(".timezone", "'timezone' requires 'schedule'".to_string()));
+        }
+        CronTimezone::parse(timezone).map_err(|e| invalid(".timezone", e))?;
+    }
+    Ok(())
+}
+
+/// Validate a worker's throughput cap and active window, and a `timezone`
+/// only alongside `window`.
+pub(crate) fn validate_worker_limits(name: &str, worker: &WorkerDef) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: String| ParseError::InvalidFormat {
+        location: format!("worker.{}.{}", name, field),
+        message,
+    };
+    if let Some(ref rate) = worker.rate {
+        WorkerRate::parse(rate).map_err(|e| invalid("rate", e))?;
+    }
+    if let Some(ref window) = worker.window {
+        ActiveWindow::parse(window).map_err(|e| invalid("window", e))?;
+    }
+    if let Some(ref timezone) = worker.timezone {
+        if worker.window.is_none() {
+            return Err(invalid("timezone", "'timezone' requires 'window'".to_string()));
+        }
+        CronTimezone::parse(timezone).map_err(|e| invalid("timezone", e))?;
+    }
+    Ok(())
+}
+
+/// Validate a `decision` block: a parseable `timeout` and non-blank
+/// assignee and reviewer names.
+pub(crate) fn validate_decision_config(
+    decision: &DecisionConfig,
+    location: &str,
+) -> Result<(), ParseError> {
+    let invalid = |field: &str, message: String| ParseError::InvalidFormat {
+        location: format!("{}.{}", location, field),
+        message,
+    };
+    if let Some(ref timeout) = decision.timeout {
+        validate_duration_str(timeout).map_err(|e| invalid("timeout", e))?;
+    }
+    if decision.assignee.as_deref().is_some_and(|a| a.trim().is_empty()) {
+        return Err(invalid("assignee", "assignee must not be empty".to_string()));
+    }
+    if decision.reviewers.iter().any(|r| r.trim().is_empty()) {
+        return Err(invalid("reviewers", "reviewer names must not be empty".to_string()));
+    }
+    Ok(())
+}
+
 /// Validate that an agent's run command uses a recognized agent command.
 ///
 /// Parses the shell AST and extracts the first command name (taking basename
@@ -276,6 +389,91 @@ pub(crate) fn validate_template_namespaces(
     Ok(())
 }
 
+/// Validate parallel fan-out (`on_done = [...]`) and fan-in (`after = [...]`).
+///
+/// Checks that:
+/// - Array `on_done` is non-empty
+/// - `after` lists known steps, excluding the step itself
+/// - Join steps are not the first step and not the target of any transition
+pub(crate) fn validate_parallel_steps(job_name: &str, job: &JobDef) -> Result<(), ParseError> {
+    let step_names: HashSet<&str> = job.steps.iter().map(|s| s.name.as_str()).collect();
+    let joins: HashSet<&str> =
+        job.steps.iter().filter(|s| s.is_join()).map(|s| s.name.as_str()).collect();
+
+    let job_targets =
+        [("on_done", &job.on_done), ("on_fail", &job.on_fail), ("on_cancel", &job.on_cancel)];
+    for (field, t) in job_targets.into_iter().filter_map(|(f, t)| t.as_ref().map(|t| (f, t))) {
+        if joins.contains(t.step_name()) {
+            return Err(ParseError::InvalidFormat {
+                location: format!("job.{}.{}", job_name, field),
+                message: format!(
+                    "step '{}' has 'after' and cannot be the target of a transition",
+                    t.step_name()
+                ),
+            });
+        }
+    }
+
+    for (i, step) in job.steps.iter().enumerate() {
+        let location = format!("job.{}.step[{}]({})", job_name, i, step.name);
+
+        if let Some(on_done) = &step.on_done {
+            if on_done.is_empty() {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("{}.on_done", location),
+                    message: "on_done array must list at least one step".to_string(),
+                });
+            }
+        }
+
+        let on_done = step.on_done.iter().flat_map(|t| t.iter()).map(|t| ("on_done", t));
+        let targets = on_done
+            .chain(step.on_fail.iter().map(|t| ("on_fail", t)))
+            .chain(step.on_cancel.iter().map(|t| ("on_cancel", t)))
+            .chain(step.on_timeout.iter().map(|t| ("on_timeout", t)));
+        for (field, t) in targets {
+            if joins.contains(t.step_name()) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("{}.{}", location, field),
+                    message: format!(
+                        "step '{}' has 'after' and cannot be the target of a transition",
+                        t.step_name()
+                    ),
+                });
+            }
+        }
+
+        if !step.is_join() {
+            continue;
+        }
+        if i == 0 {
+            return Err(ParseError::InvalidFormat {
+                location: format!("{}.after", location),
+                message: "the first step cannot have 'after'".to_string(),
+            });
+        }
+        for dep in &step.after {
+            if dep == &step.name {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("{}.after", location),
+                    message: format!("step '{}' cannot wait on itself", step.name),
+                });
+            }
+            if !step_names.contains(dep.as_str()) {
+                return Err(ParseError::InvalidFormat {
+                    location: format!("{}.after", location),
+                    message: format!(
+                        "references unknown step '{}'; available steps: {}",
+                        dep,
+                        sorted_names(&step_names),
+                    ),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
 /// Sort and join names from a HashSet for deterministic error messages.
 pub(crate) fn sorted_names(names: &HashSet<&str>) -> String {
     let mut v: Vec<&str> = names.iter().copied().collect();
@@ -3,6 +3,7 @@
 
 //! Worker definition for runbooks
 
+use crate::validate::validate_duration_str;
 use serde::{Deserialize, Serialize};
 
 fn default_concurrency() -> u32 {
@@ -22,6 +23,104 @@ pub struct WorkerDef {
     /// Max concurrent job instances (default 1)
     #[serde(default = "default_concurrency")]
     pub concurrency: u32,
+    /// Throughput cap such as "10/h": at most 10 dispatches in any hour
+    #[serde(default)]
+    pub rate: Option<String>,
+    /// Time of day dispatch is allowed, such as "08:00-20:00"
+    #[serde(default)]
+    pub window: Option<String>,
+    /// Timezone for `window`: "UTC" (default), "local", or a fixed offset
+    /// like "+05:30"
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+/// A parsed worker `rate`: at most `limit` dispatches in any `per`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerRate {
+    pub limit: u32,
+    /// Duration string, e.g. "1h" or "30m"
+    pub per: String,
+}
+
+impl WorkerRate {
+    /// Parse "10/h", "100/day", or "3/30m".
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let (limit, per) = s
+            .split_once('/')
+            .ok_or_else(|| format!("invalid rate '{}': expected a form like \"10/h\"", s))?;
+        let limit: u32 = limit
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid rate '{}': count must be a whole number", s))?;
+        if limit == 0 {
+            return Err(format!("invalid rate '{}': count must be at least 1", s));
+        }
+        let per = per.trim();
+        let per = if per.starts_with(|c: char| c.is_ascii_digit()) {
+            per.to_string()
+        } else {
+            format!("1{}", per)
+        };
+        validate_duration_str(&per).map_err(|e| format!("invalid rate '{}': {}", s, e))?;
+        if !per.trim_start_matches('0').starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(format!("invalid rate '{}': period must be longer than zero", s));
+        }
+        Ok(WorkerRate { limit, per })
+    }
+}
+
+/// A daily time-of-day window, such as "08:00-20:00".
+///
+/// A window whose end is before its start wraps past midnight, so
+/// "22:00-06:00" covers the night.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveWindow {
+    /// Opening time, in minutes after midnight
+    pub start: u32,
+    /// Closing time, in minutes after midnight (exclusive)
+    pub end: u32,
+}
+
+impl ActiveWindow {
+    /// Parse "HH:MM-HH:MM". The end may be "24:00".
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let invalid = || format!("invalid window '{}': expected a form like \"08:00-20:00\"", s);
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start =
+            parse_time_of_day(start).filter(|&m| m < MINUTES_PER_DAY).ok_or_else(invalid)?;
+        let end = parse_time_of_day(end).ok_or_else(invalid)?;
+        if start == end % MINUTES_PER_DAY {
+            return Err(format!("invalid window '{}': start and end must differ", s));
+        }
+        Ok(ActiveWindow { start, end })
+    }
+
+    /// Whether `minute` (minutes after midnight) falls inside the window.
+    pub fn contains(&self, minute: u32) -> bool {
+        if self.start < self.end {
+            (self.start..self.end).contains(&minute)
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+}
+
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// Parse "HH:MM" into minutes after midnight, allowing "24:00".
+fn parse_time_of_day(s: &str) -> Option<u32> {
+    let (hours, minutes) = s.trim().split_once(':')?;
+    let two_digits = |v: &str| v.len() == 2 && v.bytes().all(|b| b.is_ascii_digit());
+    if !two_digits(hours) || !two_digits(minutes) {
+        return None;
+    }
+    let (hours, minutes): (u32, u32) = (hours.parse().ok()?, minutes.parse().ok()?);
+    match (hours, minutes) {
+        (24, 0) => Some(MINUTES_PER_DAY),
+        (0..=23, 0..=59) => Some(hours * 60 + minutes),
+        _ => None,
+    }
 }
 
 /// Source configuration for a worker
@@ -37,3 +136,7 @@ pub struct WorkerHandler {
     /// Name of the job to dispatch items to
     pub job: String,
 }
+
+#[cfg(test)]
+#[path = "worker_tests.rs"]
+mod tests;
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+#[yare::parameterized(
+    unit_only     = { "10/h",    10, "1h" },
+    unit_word     = { "100/day", 100, "1day" },
+    with_count    = { "3/30m",   3,  "30m" },
+    spaced        = { " 5 / m ", 5,  "1m" },
+)]
+fn parses_rate(input: &str, limit: u32, per: &str) {
+    assert_eq!(WorkerRate::parse(input).unwrap(), WorkerRate { limit, per: per.to_string() });
+}
+
+#[yare::parameterized(
+    no_slash     = { "10",     "expected a form like" },
+    zero_count   = { "0/h",    "at least 1" },
+    bad_count    = { "ten/h",  "whole number" },
+    bad_unit     = { "10/fortnight", "unknown duration suffix" },
+    zero_period  = { "10/0m",  "longer than zero" },
+)]
+fn rejects_invalid_rate(input: &str, message: &str) {
+    let err = WorkerRate::parse(input).unwrap_err();
+    assert!(err.contains(message), "{err}");
+}
+
+#[test]
+fn daytime_window() {
+    let window = ActiveWindow::parse("08:00-20:00").unwrap();
+    assert_eq!(window, ActiveWindow { start: 480, end: 1200 });
+    assert!(window.contains(480));
+    assert!(window.contains(1199));
+    assert!(!window.contains(1200));
+    assert!(!window.contains(0));
+}
+
+#[test]
+fn overnight_window_wraps_midnight() {
+    let window = ActiveWindow::parse("22:00-06:30").unwrap();
+    assert!(window.contains(23 * 60));
+    assert!(window.contains(0));
+    assert!(window.contains(6 * 60 + 29));
+    assert!(!window.contains(6 * 60 + 30));
+    assert!(!window.contains(12 * 60));
+}
+
+#[test]
+fn window_can_end_at_midnight() {
+    let window = ActiveWindow::parse("18:00-24:00").unwrap();
+    assert!(window.contains(23 * 60 + 59));
+    assert!(!window.contains(0));
+}
+
+#[yare::parameterized(
+    no_dash      = { "08:00" },
+    bad_hour     = { "25:00-26:00" },
+    bad_minute   = { "08:60-20:00" },
+    one_digit    = { "8:00-20:00" },
+    start_at_24  = { "24:00-06:00" },
+    empty        = { "08:00-08:00" },
+    full_day     = { "00:00-24:00" },
+)]
+fn rejects_invalid_window(input: &str) {
+    assert!(ActiveWindow::parse(input).is_err());
+}
@@ -19,6 +19,8 @@ mod epic;
 mod errors;
 #[path = "parsing/formats.rs"]
 mod formats;
+#[path = "parsing/parallel.rs"]
+mod parallel;
 #[path = "parsing/prime.rs"]
 mod prime;
 #[path = "parsing/queues.rs"]
@@ -27,6 +29,10 @@ mod queues;
 mod references;
 #[path = "parsing/template_refs.rs"]
 mod template_refs;
+#[path = "parsing/timeouts.rs"]
+mod timeouts;
+#[path = "parsing/workers.rs"]
+mod workers;
 
 pub(crate) fn parse_hcl(input: &str) -> Runbook {
     parse_runbook_with_format(input, Format::Hcl).unwrap()
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-//! Agent configuration tests: command recognition, prompt config, session config.
+//! Agent configuration tests: command recognition, prompt config, session config,
+//! decision expiry and assignment.
 
 use oj_runbook::{parse_runbook, ParseError};
 
@@ -92,3 +93,90 @@ fn session_id_equals_rejected() {
     assert!(matches!(err, ParseError::InvalidFormat { .. }));
     super::assert_err_contains(&err, &["session-id"]);
 }
+
+#[test]
+fn decision_expiry_hcl() {
+    let hcl = r#"
+agent "fixer" {
+  run     = "claude"
+  on_idle = { action = "gate", run = "make check" }
+
+  decision {
+    timeout = "4h"
+    default = "Skip"
+  }
+}
+"#;
+    let decision = super::parse_hcl(hcl).agents["fixer"].decision.clone().unwrap();
+    assert_eq!(decision.timeout.as_deref(), Some("4h"));
+    assert_eq!(decision.default.as_deref(), Some("Skip"));
+}
+
+#[test]
+fn decision_expiry_defaults_to_recommended() {
+    let toml = "[agent.fixer]\nrun = \"claude\"\ndecision = { timeout = \"30m\" }\n";
+    let decision = parse_runbook(toml).unwrap().agents["fixer"].decision.clone().unwrap();
+    assert_eq!(decision.default, None);
+    assert!(parse_runbook("[agent.fixer]\nrun = \"claude\"").unwrap().agents["fixer"]
+        .decision
+        .is_none());
+}
+
+#[test]
+fn decision_expiry_invalid_timeout() {
+    super::assert_toml_err(
+        "[agent.fixer]\nrun = \"claude\"\ndecision = { timeout = \"after lunch\" }\n",
+        &["agent.fixer.decision.timeout"],
+    );
+}
+
+#[test]
+fn decision_assignment_hcl() {
+    let hcl = r#"
+job "deploy" {
+  decision {
+    assignee  = "ops"
+    reviewers = ["alice", "bob"]
+  }
+
+  step "ship" {
+    run = { agent = "fixer" }
+  }
+}
+
+agent "fixer" {
+  run = "claude"
+
+  decision {
+    assignee = "alice"
+  }
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    let job = runbook.jobs["deploy"].decision.clone().unwrap();
+    assert_eq!(job.assignee.as_deref(), Some("ops"));
+    assert_eq!(job.reviewers, ["alice", "bob"]);
+    assert_eq!(job.timeout, None);
+    let agent = runbook.agents["fixer"].decision.clone().unwrap();
+    assert_eq!(agent.assignee.as_deref(), Some("alice"));
+    assert!(agent.reviewers.is_empty());
+}
+
+#[yare::parameterized(
+    agent_blank_assignee = {
+        "[agent.fixer]\nrun = \"claude\"\ndecision = { assignee = \" \" }\n",
+        "agent.fixer.decision.assignee"
+    },
+    agent_blank_reviewer = {
+        "[agent.fixer]\nrun = \"claude\"\ndecision = { reviewers = [\"alice\", \"\"] }\n",
+        "agent.fixer.decision.reviewers"
+    },
+    job_invalid_timeout = {
+        "[job.deploy]\ndecision = { timeout = \"soon\" }\n[[job.deploy.step]]\nname = \"a\"\nrun = \"true\"\n",
+        "job.deploy.decision.timeout"
+    },
+)]
+fn decision_config_invalid(toml: &str, location: &str) {
+    let err = parse_runbook(toml).unwrap_err();
+    super::super::assert_err_contains(&err, &[location]);
+}
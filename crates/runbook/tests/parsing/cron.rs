@@ -16,7 +16,7 @@ cron "janitor" {
 "#;
     let cron = &super::parse_hcl(hcl).crons["janitor"];
     assert_eq!(cron.name, "janitor");
-    assert_eq!(cron.interval, "30m");
+    assert_eq!(cron.interval.as_deref(), Some("30m"));
     assert_eq!(cron.run.job_name(), Some("cleanup"));
 }
 
@@ -34,7 +34,7 @@ run = { job = "deploy" }
 "#;
     let cron = &parse_runbook(toml).unwrap().crons["nightly"];
     assert_eq!(cron.name, "nightly");
-    assert_eq!(cron.interval, "24h");
+    assert_eq!(cron.interval.as_deref(), Some("24h"));
     assert_eq!(cron.run.job_name(), Some("deploy"));
 }
 
@@ -64,7 +64,7 @@ cron "janitor" {
 "#;
     let cron = &super::parse_hcl(hcl).crons["janitor"];
     assert_eq!(cron.name, "janitor");
-    assert_eq!(cron.interval, "30m");
+    assert_eq!(cron.interval.as_deref(), Some("30m"));
     assert!(cron.run.is_shell());
 }
 
@@ -82,7 +82,7 @@ cron "health_check" {
 }
 "#;
     let cron = &super::parse_hcl(hcl).crons["health_check"];
-    assert_eq!(cron.interval, "30m");
+    assert_eq!(cron.interval.as_deref(), Some("30m"));
     assert_eq!(cron.run.agent_name(), Some("doctor"));
 }
 
@@ -133,3 +133,103 @@ agent "doctor" {
 "#;
     super::assert_hcl_err(hcl, &["max_concurrency must be >= 1"]);
 }
+
+#[test]
+fn hcl_cron_schedule_with_timezone() {
+    let hcl = r#"
+cron "standup" {
+  schedule = "0 9 * * MON-FRI"
+  timezone = "+05:30"
+  run      = "echo standup"
+}
+"#;
+    let cron = &super::parse_hcl(hcl).crons["standup"];
+    assert_eq!(cron.interval, None);
+    assert_eq!(cron.schedule.as_deref(), Some("0 9 * * MON-FRI"));
+    assert_eq!(cron.timezone.as_deref(), Some("+05:30"));
+}
+
+#[test]
+fn toml_cron_schedule_shorthand() {
+    let toml = r#"
+[cron.monthly]
+schedule = "@monthly"
+run = "echo report"
+"#;
+    let cron = &parse_runbook(toml).unwrap().crons["monthly"];
+    assert_eq!(cron.schedule.as_deref(), Some("@monthly"));
+    assert_eq!(cron.timezone, None);
+}
+
+#[test]
+fn error_cron_invalid_schedule() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  schedule = \"0 25 * * *\"\n  run = \"echo\"\n}",
+        &["cron.c.schedule", "hour 25 out of range"],
+    );
+}
+
+#[test]
+fn error_cron_interval_and_schedule() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  interval = \"1h\"\n  schedule = \"@daily\"\n  run = \"echo\"\n}",
+        &["cron.c", "not both"],
+    );
+}
+
+#[test]
+fn error_cron_missing_timing() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  run = \"echo\"\n}",
+        &["missing 'interval' or 'schedule'"],
+    );
+}
+
+#[test]
+fn error_cron_timezone_without_schedule() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  interval = \"1h\"\n  timezone = \"UTC\"\n  run = \"echo\"\n}",
+        &["cron.c.timezone", "requires 'schedule'"],
+    );
+}
+
+#[test]
+fn cron_named_timezone() {
+    let hcl = "cron \"c\" {\n  schedule = \"@daily\"\n  timezone = \"America/Chicago\"\n  run = \"echo\"\n}";
+    let cron = &super::parse_hcl(hcl).crons["c"];
+    assert_eq!(cron.timezone.as_deref(), Some("America/Chicago"));
+}
+
+#[test]
+fn error_cron_unknown_timezone() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  schedule = \"@daily\"\n  timezone = \"America/Gotham\"\n  run = \"echo\"\n}",
+        &["cron.c.timezone", "unknown timezone 'America/Gotham'"],
+    );
+}
+
+#[test]
+fn cron_catchup_policy() {
+    let hcl = r#"
+cron "nightly" {
+  interval = "24h"
+  catchup  = "all"
+  run      = "echo nightly"
+}
+cron "hourly" {
+  interval = "1h"
+  run      = "echo hourly"
+}
+"#;
+    let runbook = super::parse_hcl(hcl);
+    assert_eq!(runbook.crons["nightly"].catchup, oj_core::CronCatchup::All);
+    assert_eq!(runbook.crons["hourly"].catchup, oj_core::CronCatchup::None);
+}
+
+#[test]
+fn error_cron_unknown_catchup() {
+    super::assert_hcl_err(
+        "cron \"c\" {\n  interval = \"1h\"\n  catchup = \"sometimes\"\n  run = \"echo\"\n}",
+        &["sometimes"],
+    );
+}
@@ -33,7 +33,10 @@ fn job() {
     assert_eq!(steps, ["init", "decompose", "build", "submit", "cleanup"]);
 
     assert!(job.steps[0].run.is_shell());
-    assert_eq!(job.steps[0].on_done.as_ref().map(|t| t.step_name()), Some("decompose"));
+    assert_eq!(
+        job.steps[0].on_done.as_ref().map(|t| t.step_names()),
+        Some(vec!["decompose".to_string()])
+    );
     assert!(job.steps[1].run.is_agent());
     assert_eq!(job.steps[1].agent_name(), Some("decompose"));
     assert!(job.steps[2].run.is_agent());
@@ -64,7 +64,10 @@ fn json_sample_runbook() {
     assert_sample_build_runbook(&runbook);
 
     let job = &runbook.jobs["build"];
-    assert_eq!(job.steps[2].on_done.as_ref().map(|t| t.step_name()), Some("done"));
+    assert_eq!(
+        job.steps[2].on_done.as_ref().map(|t| t.step_names()),
+        Some(vec!["done".to_string()])
+    );
     assert_eq!(job.steps[2].on_fail.as_ref().map(|t| t.step_name()), Some("failed"));
 }
 
@@ -81,7 +84,10 @@ fn hcl_sample_runbook() {
     assert_sample_build_runbook(&runbook);
 
     let job = &runbook.jobs["build"];
-    assert_eq!(job.steps[2].on_done.as_ref().map(|t| t.step_name()), Some("done"));
+    assert_eq!(
+        job.steps[2].on_done.as_ref().map(|t| t.step_names()),
+        Some(vec!["done".to_string()])
+    );
     assert_eq!(job.steps[2].on_fail.as_ref().map(|t| t.step_name()), Some("failed"));
 }
 
@@ -117,6 +123,9 @@ job "deploy" {
     assert_eq!(job.steps.len(), 3);
     assert_eq!(job.steps[0].name, "build");
     assert_eq!(job.steps[1].name, "test");
-    assert_eq!(job.steps[1].on_done.as_ref().map(|t| t.step_name()), Some("deploy"));
+    assert_eq!(
+        job.steps[1].on_done.as_ref().map(|t| t.step_names()),
+        Some(vec!["deploy".to_string()])
+    );
     assert_eq!(job.steps[2].name, "deploy");
 }
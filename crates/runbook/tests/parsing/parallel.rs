@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Parallel steps: array on_done (fan-out) and after (fan-in).
+
+const FAN_OUT_HCL: &str = r#"
+job "ci" {
+  step "build" {
+    run     = "make build"
+    on_done = [{ step = "lint" }, { step = "test" }]
+  }
+
+  step "lint" {
+    run = "make lint"
+  }
+
+  step "test" {
+    run = "make test"
+  }
+
+  step "publish" {
+    run   = "make publish"
+    after = ["lint", "test"]
+  }
+}
+"#;
+
+#[test]
+fn fan_out_and_join_hcl() {
+    let runbook = crate::parse_hcl(FAN_OUT_HCL);
+    let job = &runbook.jobs["ci"];
+    let build = job.get_step("build").unwrap();
+    let on_done = build.on_done.as_ref().unwrap();
+    assert!(on_done.is_fan_out());
+    assert_eq!(on_done.step_names(), vec!["lint", "test"]);
+
+    let publish = job.get_step("publish").unwrap();
+    assert!(publish.is_join());
+    assert_eq!(publish.after, vec!["lint", "test"]);
+}
+
+#[test]
+fn fan_out_and_join_toml() {
+    let toml = r#"
+[[job.ci.step]]
+name = "build"
+run = "make build"
+on_done = [{ step = "lint" }, { step = "test" }]
+
+[[job.ci.step]]
+name = "lint"
+run = "make lint"
+
+[[job.ci.step]]
+name = "test"
+run = "make test"
+
+[[job.ci.step]]
+name = "publish"
+run = "make publish"
+after = ["lint", "test"]
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let job = &runbook.jobs["ci"];
+    assert_eq!(job.steps[0].on_done.as_ref().unwrap().len(), 2);
+    assert_eq!(job.steps[3].after, vec!["lint", "test"]);
+}
+
+#[test]
+fn single_on_done_is_not_fan_out() {
+    let toml = r#"
+[[job.ci.step]]
+name = "build"
+run = "make build"
+on_done = { step = "test" }
+
+[[job.ci.step]]
+name = "test"
+run = "make test"
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let on_done = runbook.jobs["ci"].steps[0].on_done.as_ref().unwrap();
+    assert!(!on_done.is_fan_out());
+}
+
+#[test]
+fn job_def_serde_roundtrip_preserves_parallel_fields() {
+    let runbook = crate::parse_hcl(FAN_OUT_HCL);
+    let json = serde_json::to_value(&runbook.jobs["ci"]).unwrap();
+    let job: oj_runbook::JobDef = serde_json::from_value(json).unwrap();
+    assert_eq!(job.steps[0].on_done.as_ref().unwrap().step_names(), vec!["lint", "test"]);
+    assert_eq!(job.steps[3].after, vec!["lint", "test"]);
+}
+
+#[test]
+fn error_empty_on_done_array() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run     = "make build"
+    on_done = []
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["on_done array must list at least one step"]);
+}
+
+#[test]
+fn error_fan_out_to_unknown_step() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run     = "make build"
+    on_done = [{ step = "lint" }, { step = "ghost" }]
+  }
+
+  step "lint" {
+    run = "make lint"
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["references unknown step 'ghost'", "step[0](build).on_done"]);
+}
+
+#[test]
+fn fan_out_to_agent_step() {
+    let hcl = r#"
+agent "reviewer" {
+  run    = "claude"
+  prompt = "Review"
+}
+
+job "ci" {
+  step "build" {
+    run     = "make build"
+    on_done = [{ step = "lint" }, { step = "review" }]
+  }
+
+  step "lint" {
+    run = "make lint"
+  }
+
+  step "review" {
+    run = { agent = "reviewer" }
+  }
+}
+"#;
+    let runbook = crate::parse_hcl(hcl);
+    let job = &runbook.jobs["ci"];
+    let on_done = job.get_step("build").unwrap().on_done.as_ref().unwrap();
+    assert_eq!(on_done.step_names(), vec!["lint", "review"]);
+    assert!(job.get_step("review").unwrap().is_agent());
+}
+
+#[test]
+fn error_after_unknown_step() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run = "make build"
+  }
+
+  step "publish" {
+    run   = "make publish"
+    after = ["build", "ghost"]
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["references unknown step 'ghost'", "step[1](publish).after"]);
+}
+
+#[test]
+fn error_after_self() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run = "make build"
+  }
+
+  step "publish" {
+    run   = "make publish"
+    after = ["publish"]
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["step 'publish' cannot wait on itself"]);
+}
+
+#[test]
+fn error_first_step_with_after() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run   = "make build"
+    after = ["test"]
+  }
+
+  step "test" {
+    run = "make test"
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["the first step cannot have 'after'"]);
+}
+
+#[yare::parameterized(
+    on_done   = { "on_done" },
+    on_fail   = { "on_fail" },
+    on_cancel = { "on_cancel" },
+)]
+fn error_transition_targets_join_step(trigger: &str) {
+    let hcl = format!(
+        r#"
+job "ci" {{
+  step "build" {{
+    run = "make build"
+    {trigger} = {{ step = "publish" }}
+  }}
+
+  step "publish" {{
+    run   = "make publish"
+    after = ["build"]
+  }}
+}}
+"#
+    );
+    crate::assert_hcl_err(
+        &hcl,
+        &["step 'publish' has 'after' and cannot be the target of a transition", trigger],
+    );
+}
+
+#[test]
+fn error_job_on_fail_targets_join_step() {
+    let hcl = r#"
+job "ci" {
+  on_fail = { step = "publish" }
+
+  step "build" {
+    run = "make build"
+  }
+
+  step "publish" {
+    run   = "make publish"
+    after = ["build"]
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["cannot be the target of a transition", "job.ci.on_fail"]);
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: BUSL-1.1
 // Copyright (c) 2026 Alfred Jean LLC
 
-use oj_runbook::QueueType;
+use oj_runbook::{Backoff, QueueOrder, QueueType};
 
 #[test]
 fn external_queue_with_explicit_type() {
@@ -136,3 +136,151 @@ fn error_persisted_with_poll() {
         &["persisted queue must not have 'poll' field"],
     );
 }
+
+#[test]
+fn persisted_queue_with_order_and_priority() {
+    let hcl = r#"
+queue "merges" {
+  type     = "persisted"
+  vars     = ["branch"]
+  order    = "priority"
+  priority = 5
+}
+"#;
+    let queue = &super::parse_hcl(hcl).queues["merges"];
+    assert_eq!(queue.order, QueueOrder::Priority);
+    assert_eq!(queue.priority, 5);
+}
+
+#[test]
+fn queue_order_defaults_to_fifo() {
+    let hcl = "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n}";
+    let queue = &super::parse_hcl(hcl).queues["items"];
+    assert_eq!(queue.order, QueueOrder::Fifo);
+    assert_eq!(queue.priority, 0);
+}
+
+#[test]
+fn external_queue_with_lifo_order() {
+    let hcl =
+        "queue \"bugs\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  order = \"lifo\"\n}";
+    assert_eq!(super::parse_hcl(hcl).queues["bugs"].order, QueueOrder::Lifo);
+}
+
+#[test]
+fn error_external_with_priority() {
+    super::assert_hcl_err(
+        "queue \"bugs\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  priority = 3\n}",
+        &["external queue must not have 'priority' field"],
+    );
+}
+
+#[test]
+fn error_unknown_queue_order() {
+    super::assert_hcl_err(
+        "queue \"items\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  order = \"random\"\n}",
+        &["random"],
+    );
+}
+
+#[test]
+fn persisted_queue_with_dedupe_key() {
+    let hcl = r#"
+queue "merges" {
+  type   = "persisted"
+  vars   = ["branch", "title"]
+  dedupe = "${item.branch}"
+}
+"#;
+    let queue = &super::parse_hcl(hcl).queues["merges"];
+    assert_eq!(queue.dedupe.as_deref(), Some("${item.branch}"));
+
+    let data = [("branch", "fix-1"), ("title", "Fix it")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    assert_eq!(queue.dedupe_key(&data).as_deref(), Some("fix-1"));
+}
+
+#[test]
+fn dedupe_key_can_use_defaults() {
+    let hcl = r#"
+queue "merges" {
+  type     = "persisted"
+  vars     = ["branch"]
+  defaults = { base = "main" }
+  dedupe   = "${item.base}:${item.branch}"
+}
+"#;
+    assert!(super::parse_hcl(hcl).queues["merges"].dedupe.is_some());
+}
+
+#[yare::parameterized(
+    external = {
+        "queue \"bugs\" {\n  list = \"echo '[]'\"\n  take = \"echo ok\"\n  dedupe = \"${item.id}\"\n}",
+        "external queue must not have 'dedupe' field",
+    },
+    undeclared_field = {
+        "queue \"m\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  dedupe = \"${item.title}\"\n}",
+        "not a declared var",
+    },
+    non_item_reference = {
+        "queue \"m\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  dedupe = \"${var.branch}\"\n}",
+        "can only reference item fields",
+    },
+    no_reference = {
+        "queue \"m\" {\n  type = \"persisted\"\n  vars = [\"branch\"]\n  dedupe = \"branch\"\n}",
+        "must reference an item field",
+    },
+)]
+fn error_invalid_dedupe(hcl: &str, message: &str) {
+    crate::assert_hcl_err(hcl, &[message]);
+}
+
+#[test]
+fn retry_with_exponential_backoff() {
+    let hcl = r#"
+queue "calls" {
+  type = "persisted"
+  vars = ["url"]
+
+  retry {
+    attempts     = 5
+    cooldown     = "30s"
+    backoff      = "exponential"
+    max_cooldown = "10m"
+    jitter       = 0.2
+  }
+}
+"#;
+    let retry = super::parse_hcl(hcl).queues["calls"].retry.clone().unwrap();
+    assert_eq!(retry.attempts, 5);
+    assert_eq!(retry.backoff, Backoff::Exponential);
+    assert_eq!(retry.max_cooldown.as_deref(), Some("10m"));
+    assert_eq!(retry.jitter, 0.2);
+}
+
+#[test]
+fn retry_backoff_defaults_to_fixed() {
+    let hcl = "queue \"calls\" {\n  type = \"persisted\"\n  vars = [\"url\"]\n  retry { attempts = 2 }\n}";
+    let retry = super::parse_hcl(hcl).queues["calls"].retry.clone().unwrap();
+    assert_eq!(retry.backoff, Backoff::Fixed);
+    assert_eq!(retry.cooldown, "0s");
+    assert!(retry.max_cooldown.is_none());
+    assert_eq!(retry.jitter, 0.0);
+}
+
+#[yare::parameterized(
+    bad_cooldown = { "cooldown = \"soon\"", "queue.calls.retry.cooldown" },
+    bad_max_cooldown = { "max_cooldown = \"later\"", "queue.calls.retry.max_cooldown" },
+    jitter_above_one = { "jitter = 1.5", "jitter must be between 0.0 and 1.0" },
+    negative_jitter = { "jitter = -0.1", "jitter must be between 0.0 and 1.0" },
+    unknown_backoff = { "backoff = \"linear\"", "linear" },
+)]
+fn error_invalid_retry(field: &str, message: &str) {
+    let hcl = format!(
+        "queue \"calls\" {{\n  type = \"persisted\"\n  vars = [\"url\"]\n  retry {{\n    attempts = 3\n    {}\n  }}\n}}",
+        field
+    );
+    crate::assert_hcl_err(&hcl, &[message]);
+}
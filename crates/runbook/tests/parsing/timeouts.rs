@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Step timeouts: `timeout` on steps and jobs, and `on_timeout` routing.
+
+#[test]
+fn step_timeout_and_on_timeout_hcl() {
+    let hcl = r#"
+job "deploy" {
+  timeout = "1h"
+
+  step "build" {
+    run        = "make build"
+    timeout    = "20m"
+    on_timeout = { step = "alert" }
+    on_done    = { step = "ship" }
+  }
+
+  step "ship" {
+    run = "make ship"
+  }
+
+  step "alert" {
+    run = "echo slow"
+  }
+}
+"#;
+    let runbook = crate::parse_hcl(hcl);
+    let job = &runbook.jobs["deploy"];
+    assert_eq!(job.timeout.as_deref(), Some("1h"));
+
+    let build = job.get_step("build").unwrap();
+    assert_eq!(build.timeout.as_deref(), Some("20m"));
+    assert_eq!(build.on_timeout.as_ref().map(|t| t.step_name()), Some("alert"));
+
+    // Step timeout overrides the job default; other steps inherit it
+    assert_eq!(job.step_timeout("build"), Some("20m"));
+    assert_eq!(job.step_timeout("ship"), Some("1h"));
+    assert_eq!(job.step_timeout("missing"), None);
+}
+
+#[test]
+fn step_timeout_toml() {
+    let toml = r#"
+[[job.deploy.step]]
+name = "build"
+run = "make build"
+timeout = "30s"
+"#;
+    let runbook = oj_runbook::parse_runbook(toml).unwrap();
+    let job = &runbook.jobs["deploy"];
+    assert_eq!(job.step_timeout("build"), Some("30s"));
+    assert!(job.get_step("build").unwrap().on_timeout.is_none());
+}
+
+#[test]
+fn no_timeout_by_default() {
+    let hcl = r#"
+job "deploy" {
+  step "build" {
+    run = "make build"
+  }
+}
+"#;
+    let runbook = crate::parse_hcl(hcl);
+    assert_eq!(runbook.jobs["deploy"].step_timeout("build"), None);
+}
+
+#[test]
+fn on_timeout_target_is_reachable() {
+    let hcl = r#"
+job "deploy" {
+  step "build" {
+    run        = "make build"
+    timeout    = "5m"
+    on_timeout = { step = "cleanup" }
+  }
+
+  step "cleanup" {
+    run = "make clean"
+  }
+}
+"#;
+    let runbook = crate::parse_hcl(hcl);
+    assert!(runbook.jobs["deploy"].get_step("cleanup").is_some());
+}
+
+#[test]
+fn error_invalid_step_timeout() {
+    let hcl = r#"
+job "deploy" {
+  step "build" {
+    run     = "make build"
+    timeout = "soon"
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["job.deploy.step.build.timeout"]);
+}
+
+#[test]
+fn error_invalid_job_timeout() {
+    let hcl = r#"
+job "deploy" {
+  timeout = "10 parsecs"
+
+  step "build" {
+    run = "make build"
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["job.deploy.timeout"]);
+}
+
+#[test]
+fn error_on_timeout_unknown_step() {
+    let hcl = r#"
+job "deploy" {
+  step "build" {
+    run        = "make build"
+    timeout    = "5m"
+    on_timeout = { step = "ghost" }
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["references unknown step 'ghost'", "step[0](build).on_timeout"]);
+}
+
+#[test]
+fn error_on_timeout_targets_join_step() {
+    let hcl = r#"
+job "ci" {
+  step "build" {
+    run        = "make build"
+    timeout    = "5m"
+    on_timeout = { step = "publish" }
+    on_done    = [{ step = "lint" }, { step = "test" }]
+  }
+
+  step "lint" {
+    run = "make lint"
+  }
+
+  step "test" {
+    run = "make test"
+  }
+
+  step "publish" {
+    run   = "make publish"
+    after = ["lint", "test"]
+  }
+}
+"#;
+    crate::assert_hcl_err(hcl, &["cannot be the target of a transition", "on_timeout"]);
+}
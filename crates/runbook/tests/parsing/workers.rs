@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use oj_runbook::{ActiveWindow, WorkerRate};
+
+/// A runbook with one worker whose block gets `fields` added.
+fn worker_hcl(fields: &str) -> String {
+    format!(
+        r#"
+job "review" {{
+  step "run" {{ run = "echo review" }}
+}}
+queue "prs" {{
+  type = "persisted"
+  vars = ["url"]
+}}
+worker "reviewer" {{
+  source      = {{ queue = "prs" }}
+  run         = {{ job = "review" }}
+  concurrency = 2
+  {}
+}}
+"#,
+        fields
+    )
+}
+
+#[test]
+fn worker_with_rate_and_window() {
+    let hcl = worker_hcl("rate = \"10/h\"\n  window = \"08:00-20:00\"\n  timezone = \"local\"");
+    let worker = &super::parse_hcl(&hcl).workers["reviewer"];
+    assert_eq!(worker.rate.as_deref(), Some("10/h"));
+    assert_eq!(worker.window.as_deref(), Some("08:00-20:00"));
+    assert_eq!(worker.timezone.as_deref(), Some("local"));
+
+    let rate = WorkerRate::parse(worker.rate.as_deref().unwrap()).unwrap();
+    assert_eq!(rate, WorkerRate { limit: 10, per: "1h".to_string() });
+    let window = ActiveWindow::parse(worker.window.as_deref().unwrap()).unwrap();
+    assert_eq!(window, ActiveWindow { start: 8 * 60, end: 20 * 60 });
+}
+
+#[test]
+fn worker_limits_default_to_none() {
+    let worker = &super::parse_hcl(&worker_hcl("")).workers["reviewer"];
+    assert!(worker.rate.is_none());
+    assert!(worker.window.is_none());
+    assert!(worker.timezone.is_none());
+}
+
+#[yare::parameterized(
+    bad_rate = { "rate = \"lots\"", "worker.reviewer.rate" },
+    zero_rate = { "rate = \"0/h\"", "count must be at least 1" },
+    bad_window = { "window = \"8am-8pm\"", "worker.reviewer.window" },
+    timezone_without_window = { "timezone = \"UTC\"", "'timezone' requires 'window'" },
+    bad_timezone = { "window = \"08:00-20:00\"\n  timezone = \"Mars/Olympus\"", "unknown timezone 'Mars/Olympus'" },
+)]
+fn error_invalid_worker_limits(fields: &str, message: &str) {
+    crate::assert_hcl_err(&worker_hcl(fields), &[message]);
+}
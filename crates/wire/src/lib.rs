@@ -14,6 +14,7 @@ mod query;
 mod request;
 mod response;
 mod status;
+mod subscribe;
 mod types;
 mod wire;
 
@@ -25,6 +26,7 @@ pub use status::{
     JobStatusEntry, MetricsHealthSummary, OrphanAgent, OrphanSummary, ProjectStatus,
     ProjectSummary, QueueItemEntry, QueueStatus, WorkerEntry,
 };
+pub use subscribe::EventFilter;
 pub use types::{
     AgentDetail, AgentSummary, DecisionDetail, DecisionSummary, JobDetail, JobSummary,
     QueueItemSummary, QueueSummary, StepRecordDetail, WorkerSummary, WorkspaceDetail,
@@ -85,6 +85,8 @@ fn all_requests() -> Vec<Request> {
             project: s(),
             queue: s(),
             data: serde_json::Value::Null,
+            priority: None,
+            not_before_ms: None,
         },
         Request::QueueDrop { project_path: p(), project: s(), queue: s(), item_id: s() },
         Request::QueueRetry {
@@ -110,6 +112,8 @@ fn all_requests() -> Vec<Request> {
         Request::AgentResume { id: s(), kill: false, all: false },
         Request::AgentKill { id: s() },
         Request::AgentAttach { id: s(), token: None },
+        Request::Subscribe { filter: EventFilter::default() },
+        Request::Replicate { after_seq: 0 },
     ]
 }
 
@@ -176,12 +180,15 @@ fn all_responses() -> Vec<Response> {
         Response::JobsResumed { resumed: vec![], skipped: vec![] },
         Response::AgentAttachReady { id: s() },
         Response::AgentAttachLocal { id: s(), socket_path: s() },
+        Response::Subscribed,
+        Response::Replicating,
     ]
 }
 
 fn all_queries() -> Vec<Query> {
     vec![
         Query::ListJobs,
+        Query::ListArchivedJobs,
         Query::GetJob { id: s() },
         Query::ListWorkspaces,
         Query::GetWorkspace { id: s() },
@@ -200,7 +207,7 @@ fn all_queries() -> Vec<Query> {
         Query::ListOrphans,
         Query::DismissOrphan { id: s() },
         Query::GetQueueLogs { queue: s(), project: s(), lines: 0, offset: 0 },
-        Query::ListDecisions { project: s() },
+        Query::ListDecisions { project: s(), assignee: None },
         Query::GetDecision { id: did() },
     ]
 }
@@ -211,6 +218,7 @@ fn arb_step_outcome() -> impl Strategy<Value = StepOutcome> {
         Just(StepOutcome::Completed),
         ".*".prop_map(StepOutcome::Failed),
         ".*".prop_map(StepOutcome::Waiting),
+        ".*".prop_map(StepOutcome::TimedOut),
     ]
 }
 
@@ -254,7 +262,7 @@ proptest! {
 
         // Detail string is preserved for variants that carry one
         match &outcome {
-            StepOutcome::Failed(msg) | StepOutcome::Waiting(msg) => {
+            StepOutcome::Failed(msg) | StepOutcome::Waiting(msg) | StepOutcome::TimedOut(msg) => {
                 prop_assert_eq!(detail.detail.as_ref(), Some(msg));
             }
             StepOutcome::Running | StepOutcome::Completed => {
@@ -13,6 +13,8 @@ use serde::{Deserialize, Serialize};
 #[serde(tag = "type")]
 pub enum Query {
     ListJobs,
+    /// Jobs moved out of live state into the job archive
+    ListArchivedJobs,
     GetJob {
         id: String,
     },
@@ -124,6 +126,9 @@ pub enum Query {
     ListDecisions {
         #[serde(default)]
         project: String,
+        /// Only decisions assigned to this user or naming them as a reviewer
+        #[serde(default)]
+        assignee: Option<String>,
     },
     /// Get a single decision by ID (prefix match supported)
     GetDecision {
@@ -7,7 +7,7 @@ use std::path::PathBuf;
 use oj_core::{DecisionId, Event};
 use serde::{Deserialize, Serialize};
 
-use super::Query;
+use super::{EventFilter, Query};
 
 /// Request from CLI to daemon
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -206,7 +206,18 @@ pub enum Request {
     CronOnce { cron: String, project: String, project_path: PathBuf },
 
     /// Push an item to a queue (persisted: enqueue data; external: trigger poll)
-    QueuePush { queue: String, project: String, project_path: PathBuf, data: serde_json::Value },
+    QueuePush {
+        queue: String,
+        project: String,
+        project_path: PathBuf,
+        data: serde_json::Value,
+        /// Overrides the queue's default priority
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        priority: Option<i32>,
+        /// Workers skip the item until this time (epoch ms)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        not_before_ms: Option<u64>,
+    },
 
     /// Drop an item from a persisted queue
     QueueDrop { queue: String, project: String, project_path: PathBuf, item_id: String },
@@ -290,6 +301,26 @@ pub enum Request {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         token: Option<String>,
     },
+
+    /// Stream events as they are processed.
+    ///
+    /// After `Subscribed`, the connection switches to newline-delimited JSON —
+    /// one `Event` per line — until the client disconnects.
+    Subscribe {
+        #[serde(default)]
+        filter: EventFilter,
+    },
+
+    /// Follow the WAL as a read-only replica.
+    ///
+    /// After `Replicating`, the connection switches to length-prefixed
+    /// replica frames: a snapshot when `after_seq` is no longer in the WAL,
+    /// then WAL entries as they are written.
+    Replicate {
+        /// Last sequence number the replica has applied (0 for none)
+        #[serde(default)]
+        after_seq: u64,
+    },
 }
 
 #[cfg(test)]
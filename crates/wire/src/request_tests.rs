@@ -60,3 +60,17 @@ fn workspace_prune_namespace_defaults_to_none() {
         _ => panic!("Expected WorkspacePrune request"),
     }
 }
+
+#[test]
+fn subscribe_filter_defaults_to_everything() {
+    let json = r#"{"type":"Subscribe"}"#;
+    let decoded: Request = serde_json::from_str(json).expect("deserialize failed");
+    assert_eq!(decoded, Request::Subscribe { filter: EventFilter::default() });
+}
+
+#[test]
+fn replicate_starts_from_zero_by_default() {
+    let json = r#"{"type":"Replicate"}"#;
+    let decoded: Request = serde_json::from_str(json).expect("deserialize failed");
+    assert_eq!(decoded, Request::Replicate { after_seq: 0 });
+}
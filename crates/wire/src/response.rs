@@ -289,6 +289,12 @@ pub enum Response {
     AgentAttachReady { id: String },
     /// Agent is local — CLI should attach directly via socket path
     AgentAttachLocal { id: String, socket_path: String },
+
+    /// Connection now streams matching events as NDJSON
+    Subscribed,
+
+    /// Connection now streams replica frames
+    Replicating,
 }
 
 #[cfg(test)]
@@ -19,11 +19,19 @@ pub struct CronSummary {
     pub name: String,
     pub project: String,
     pub interval: String,
+    /// Cron expression, for crons that fire on a calendar schedule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
     pub target: String,
     pub status: String,
     /// Human-readable time: "in 12m" for running, "3h ago" for stopped
     #[serde(default)]
     pub time: String,
+    /// Next fire of a running cron, on the schedule's wall clock
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_fire: Option<String>,
 }
 
 /// Per-project status summary
@@ -194,14 +202,26 @@ impl From<&CronRecord> for CronEntry {
 // --- Constructor methods ---
 
 impl CronSummary {
-    pub fn from_cron(c: &CronRecord, time: String) -> Self {
+    pub fn from_cron(c: &CronRecord, time: String, next_fire: Option<String>) -> Self {
         CronSummary {
             name: c.name.clone(),
             project: c.project.clone(),
             interval: c.interval.clone(),
+            schedule: c.schedule.clone(),
+            timezone: c.timezone.clone(),
             target: c.target.to_string(),
             status: c.status.clone(),
             time,
+            next_fire,
+        }
+    }
+
+    /// The interval, or the schedule with its timezone (if not UTC).
+    pub fn timing(&self) -> String {
+        match (&self.schedule, &self.timezone) {
+            (Some(schedule), Some(tz)) => format!("{} ({})", schedule, tz),
+            (Some(schedule), None) => schedule.clone(),
+            (None, _) => self.interval.clone(),
         }
     }
 }
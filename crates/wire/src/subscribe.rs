@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+//! Event subscriptions.
+//!
+//! `Request::Subscribe` upgrades a connection: after `Response::Subscribed`
+//! the daemon writes each matching event as one JSON line (NDJSON) until the
+//! client disconnects.
+
+use serde::{Deserialize, Serialize};
+
+/// Which events a subscriber receives. Empty fields match everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct EventFilter {
+    /// Only events in this project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Only events for these owners (job or crew ID prefixes)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+    /// Only these event kinds: a full name (`job:advanced`) or a
+    /// category (`job`, matching every `job:*` event)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub kinds: Vec<String>,
+}
+
+impl EventFilter {
+    /// Whether an event passes the filter, given its kind and the owner and
+    /// project it belongs to (when known).
+    pub fn matches(&self, kind: &str, owner: Option<&str>, project: Option<&str>) -> bool {
+        let kind_ok = self.kinds.is_empty()
+            || self.kinds.iter().any(|k| {
+                kind == k || kind.strip_prefix(k.as_str()).is_some_and(|rest| rest.starts_with(':'))
+            });
+        let owner_ok = self.owners.is_empty()
+            || owner
+                .is_some_and(|o| self.owners.iter().any(|prefix| o.starts_with(prefix.as_str())));
+        let project_ok = self.project.as_deref().is_none_or(|p| project == Some(p));
+        kind_ok && owner_ok && project_ok
+    }
+}
+
+#[cfg(test)]
+#[path = "subscribe_tests.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: BUSL-1.1
+// Copyright (c) 2026 Alfred Jean LLC
+
+use super::*;
+
+fn filter(project: Option<&str>, owners: &[&str], kinds: &[&str]) -> EventFilter {
+    EventFilter {
+        project: project.map(String::from),
+        owners: owners.iter().map(|o| o.to_string()).collect(),
+        kinds: kinds.iter().map(|k| k.to_string()).collect(),
+    }
+}
+
+#[test]
+fn empty_filter_matches_everything() {
+    assert!(EventFilter::default().matches("timer:start", None, None));
+}
+
+#[yare::parameterized(
+    exact = { "job:advanced", true },
+    category = { "job", true },
+    other_category = { "step", false },
+    partial_word = { "jo", false },
+    other_kind = { "job:created", false },
+)]
+fn kind_filter(kind: &str, expected: bool) {
+    assert_eq!(filter(None, &[], &[kind]).matches("job:advanced", None, None), expected);
+}
+
+#[yare::parameterized(
+    longer_id = { Some("job-abc123"), true },
+    same_id = { Some("job-abc"), true },
+    other = { Some("job-xyz"), false },
+    unknown = { None, false },
+)]
+fn owner_filter_matches_prefix(owner: Option<&str>, expected: bool) {
+    let f = filter(None, &["job-abc"], &[]);
+    assert_eq!(f.matches("step:completed", owner, None), expected);
+}
+
+#[yare::parameterized(
+    same = { Some("api"), true },
+    other = { Some("web"), false },
+    unknown = { None, false },
+)]
+fn project_filter(project: Option<&str>, expected: bool) {
+    assert_eq!(filter(Some("api"), &[], &[]).matches("job:created", None, project), expected);
+}
+
+#[test]
+fn all_fields_must_match() {
+    let f = filter(Some("api"), &["job-1"], &["step"]);
+    assert!(f.matches("step:failed", Some("job-1"), Some("api")));
+    assert!(!f.matches("job:advanced", Some("job-1"), Some("api")));
+    assert!(!f.matches("step:failed", Some("job-2"), Some("api")));
+}
+
+#[test]
+fn filter_fields_default_when_missing() {
+    let f: EventFilter = serde_json::from_str("{}").unwrap();
+    assert_eq!(f, EventFilter::default());
+    assert_eq!(serde_json::to_string(&f).unwrap(), "{}");
+}
@@ -8,7 +8,8 @@ use std::path::PathBuf;
 
 use oj_core::{
     AgentId, Crew, CrewId, Decision, DecisionId, DecisionOption, Job, JobId, OwnerId, QueueItem,
-    StepOutcome, StepOutcomeKind, StepRecord, StepStatusKind, WorkerRecord, Workspace, WorkspaceId,
+    StepOutcome, StepOutcomeKind, StepRecord, StepStatusKind, WorkerRecord, WorkerThrottle,
+    Workspace, WorkspaceId,
 };
 use serde::{Deserialize, Serialize};
 
@@ -40,6 +41,9 @@ pub struct JobDetail {
     pub steps: Vec<StepRecordDetail>,
     pub agents: Vec<AgentSummary>,
     pub project: String,
+    /// Moved out of live state into the job archive
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
 }
 
 /// Record of a step execution for display
@@ -64,7 +68,7 @@ impl From<&StepRecord> for StepRecordDetail {
             finished_at_ms: r.finished_at_ms,
             outcome: StepOutcomeKind::from(&r.outcome),
             detail: match &r.outcome {
-                StepOutcome::Failed(e) => Some(e.clone()),
+                StepOutcome::Failed(e) | StepOutcome::TimedOut(e) => Some(e.clone()),
                 StepOutcome::Waiting(r) => Some(r.clone()),
                 _ => None,
             },
@@ -163,6 +167,12 @@ pub struct QueueItemSummary {
     pub worker_name: Option<String>,
     pub pushed_at_ms: u64,
     pub failures: u32,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub not_before_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at_ms: Option<u64>,
 }
 
 /// Summary of a queue for listing
@@ -189,6 +199,10 @@ pub struct DecisionSummary {
     pub source: String,
     pub summary: String,
     pub created_at_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -214,6 +228,18 @@ pub struct DecisionDetail {
     pub resolved_at_ms: Option<u64>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub superseded_by: Option<DecisionId>,
+    /// When an unanswered decision resolves itself with `default_choice`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_choice: Option<usize>,
+    /// Resolved by expiry rather than a human
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub timed_out: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<String>,
 }
 
 /// A question group for multi-question decisions
@@ -245,6 +271,12 @@ pub struct WorkerSummary {
     pub concurrency: u32,
     /// Most recent activity timestamp (from active jobs)
     pub updated_at_ms: u64,
+    /// What is holding a running worker back ("rate" or "window"), if anything
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub throttle: Option<String>,
+    /// When a throttled worker may dispatch again (epoch ms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub throttled_until_ms: Option<u64>,
 }
 
 impl From<&Job> for JobSummary {
@@ -275,6 +307,9 @@ impl From<&QueueItem> for QueueItemSummary {
             worker_name: item.worker.clone(),
             pushed_at_ms: item.pushed_at_ms,
             failures: item.failures,
+            priority: item.priority,
+            not_before_ms: item.not_before_ms,
+            retry_at_ms: item.retry_at_ms,
         }
     }
 }
@@ -307,7 +342,13 @@ impl From<&Workspace> for WorkspaceDetail {
 }
 
 impl WorkerSummary {
-    pub fn from_worker(w: &WorkerRecord, updated_at_ms: u64) -> Self {
+    pub fn from_worker(
+        w: &WorkerRecord,
+        updated_at_ms: u64,
+        throttle: Option<&WorkerThrottle>,
+    ) -> Self {
+        // A stopped worker is not dispatching, so it is not throttled either
+        let throttle = throttle.filter(|_| w.status == "running");
         WorkerSummary {
             name: w.name.clone(),
             project: w.project.clone(),
@@ -316,6 +357,8 @@ impl WorkerSummary {
             active: w.active.len(),
             concurrency: w.concurrency,
             updated_at_ms,
+            throttle: throttle.map(|t| t.reason.to_string()),
+            throttled_until_ms: throttle.map(|t| t.until_ms),
         }
     }
 }
@@ -335,6 +378,8 @@ impl DecisionSummary {
             summary,
             created_at_ms: d.created_at_ms,
             project: d.project.clone(),
+            assignee: d.assignee.clone(),
+            reviewers: d.reviewers.clone(),
         }
     }
 }
@@ -407,6 +452,11 @@ impl DecisionDetail {
             created_at_ms: d.created_at_ms,
             resolved_at_ms: d.resolved_at_ms,
             superseded_by: d.superseded_by,
+            expires_at_ms: d.expires_at_ms,
+            default_choice: d.default_choice,
+            timed_out: d.timed_out,
+            assignee: d.assignee.clone(),
+            reviewers: d.reviewers.clone(),
             project: d.project.clone(),
         }
     }